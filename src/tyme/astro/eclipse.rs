@@ -0,0 +1,305 @@
+//! 日食与月食
+use std::fmt::{Display, Formatter};
+
+use crate::tyme::astro::moon::{get_moon_ecliptic, get_sun_distance};
+use crate::tyme::astro::to_solar_time;
+use crate::tyme::enums::EclipseType;
+use crate::tyme::jd::{JulianDay, J2000};
+use crate::tyme::solar::SolarTime;
+use crate::tyme::util::ShouXingUtil;
+use crate::tyme::zone::{UtcOffset, ZonedSolarTime};
+use crate::tyme::Culture;
+
+/// 地球赤道半径（千米）
+static EARTH_RADIUS: f64 = 6378.14;
+
+/// 月球半径（千米）
+static MOON_RADIUS: f64 = 1737.4;
+
+/// 太阳半径（千米）
+static SUN_RADIUS: f64 = 696000.0;
+
+/// 日食或月食
+///
+/// 按Meeus《天文算法》第54章推算食甚时刻、伽马值（影轴与地心的最近距离，以地球赤道半径为单位）和食分。
+/// 日偏食的食分为月球遮住太阳直径的比例，中心食的食分为月日视直径之比；半影月食的食分为半影食分，月偏食、月全食为本影食分。
+#[derive(Debug, Copy, Clone)]
+pub struct Eclipse {
+    eclipse_type: EclipseType,
+    /// 食甚的世界时（J2000起算的日数）
+    t: f64,
+    gamma: f64,
+    magnitude: f64,
+}
+
+impl Eclipse {
+    /// 第k个朔（k为整数）或望（k为半整数）的交食，k为0时是2000年1月6日的朔，不发生交食时为空
+    fn from_lunation(k: f64) -> Option<Self> {
+        let c: f64 = k / 1236.85;
+        let c2: f64 = c * c;
+        let c3: f64 = c2 * c;
+        let c4: f64 = c3 * c;
+        let f: f64 = (160.7108 + 390.67050284 * k - 0.0016118 * c2 - 0.00000227 * c3
+            + 0.000000011 * c4)
+            .to_radians();
+        // 远离黄白交点
+        if f.sin().abs() > 0.36 {
+            return None;
+        }
+        let solar: bool = k.fract() == 0.0;
+        let m: f64 = (2.5534 + 29.1053567 * k - 0.0000014 * c2 - 0.00000011 * c3).to_radians();
+        let mp: f64 = (201.5643 + 385.81693528 * k + 0.0107582 * c2 + 0.00001238 * c3
+            - 0.000000058 * c4)
+            .to_radians();
+        let o: f64 = (124.7746 - 1.56375588 * k + 0.0020672 * c2 + 0.00000215 * c3).to_radians();
+        let e: f64 = 1.0 - 0.002516 * c - 0.0000074 * c2;
+        let f1: f64 = f - 0.02665f64.to_radians() * o.sin();
+        let a1: f64 = (299.77 + 0.107408 * k - 0.009173 * c2).to_radians();
+
+        let mut jde: f64 = 2451550.09766 + 29.530588861 * k + 0.00015437 * c2 - 0.00000015 * c3
+            + 0.00000000073 * c4;
+        jde += if solar {
+            -0.4075 * mp.sin() + 0.1721 * e * m.sin()
+        } else {
+            -0.4065 * mp.sin() + 0.1727 * e * m.sin()
+        };
+        jde += 0.0161 * (2.0 * mp).sin() - 0.0097 * (2.0 * f1).sin() + 0.0073 * e * (mp - m).sin()
+            - 0.005 * e * (mp + m).sin()
+            - 0.0023 * (mp - 2.0 * f1).sin()
+            + 0.0021 * e * (2.0 * m).sin()
+            + 0.0012 * (mp + 2.0 * f1).sin()
+            + 0.0006 * e * (2.0 * mp + m).sin()
+            - 0.0004 * (3.0 * mp).sin()
+            - 0.0003 * e * (m + 2.0 * f1).sin()
+            + 0.0003 * a1.sin()
+            - 0.0002 * e * (m - 2.0 * f1).sin()
+            - 0.0002 * e * (2.0 * mp - m).sin()
+            - 0.0002 * o.sin();
+
+        let p: f64 = 0.207 * e * m.sin() + 0.0024 * e * (2.0 * m).sin() - 0.0392 * mp.sin()
+            + 0.0116 * (2.0 * mp).sin()
+            - 0.0073 * e * (mp + m).sin()
+            + 0.0067 * e * (mp - m).sin()
+            + 0.0118 * (2.0 * f1).sin();
+        let q: f64 = 5.2207 - 0.0048 * e * m.cos() + 0.002 * e * (2.0 * m).cos()
+            - 0.3299 * mp.cos()
+            - 0.006 * e * (mp + m).cos()
+            + 0.0041 * e * (mp - m).cos();
+        let w: f64 = f1.cos().abs();
+        let gamma: f64 = (p * f1.cos() + q * f1.sin()) * (1.0 - 0.0048 * w);
+        let u: f64 = 0.0059 + 0.0046 * e * m.cos() - 0.0182 * mp.cos() + 0.0004 * (2.0 * mp).cos()
+            - 0.0005 * (m + mp).cos();
+        let g: f64 = gamma.abs();
+
+        let jd: f64 = jde - J2000;
+        let t: f64 = jd - ShouXingUtil::dtt(jd);
+        let (eclipse_type, magnitude) = if solar {
+            if g > 1.5433 + u {
+                return None;
+            }
+            if g > 0.9972 + u.abs() {
+                (
+                    EclipseType::SolarPartial,
+                    (1.5433 + u - g) / (0.5461 + 2.0 * u),
+                )
+            } else {
+                // 中心食：以影轴与地面交点处的月日视半径之比为食分
+                let c: f64 = jd / 36525.0;
+                let h: f64 = EARTH_RADIUS * (1.0 - g.min(1.0).powi(2)).sqrt();
+                let (_, _, r) = get_moon_ecliptic(c);
+                let magnitude: f64 = (MOON_RADIUS / (r - h)).asin()
+                    / (SUN_RADIUS / (get_sun_distance(c) - h)).asin();
+                let eclipse_type: EclipseType = if u < 0.0 {
+                    EclipseType::SolarTotal
+                } else if u > 0.0047 || u >= 0.00464 * (1.0 - gamma * gamma).sqrt() {
+                    EclipseType::SolarAnnular
+                } else {
+                    EclipseType::SolarHybrid
+                };
+                (eclipse_type, magnitude)
+            }
+        } else {
+            let penumbral: f64 = (1.5573 + u - g) / 0.545;
+            if penumbral <= 0.0 {
+                return None;
+            }
+            let umbral: f64 = (1.0128 - u - g) / 0.545;
+            if umbral <= 0.0 {
+                (EclipseType::LunarPenumbral, penumbral)
+            } else if umbral < 1.0 {
+                (EclipseType::LunarPartial, umbral)
+            } else {
+                (EclipseType::LunarTotal, umbral)
+            }
+        };
+        Some(Self {
+            eclipse_type,
+            t,
+            gamma,
+            magnitude,
+        })
+    }
+
+    /// 食甚在公历年（北京时间）内的日食和月食，按时间先后排列
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::astro::eclipse::Eclipse;
+    /// use tyme4rs::tyme::Culture;
+    ///
+    /// let eclipses: Vec<Eclipse> = Eclipse::from_year(2024);
+    /// assert_eq!("半影月食", eclipses[0].get_name());
+    /// ```
+    pub fn from_year(year: isize) -> Vec<Self> {
+        let start: f64 = JulianDay::from_ymd_hms(year, 1, 1, 0, 0, 0).get_day() - J2000 - 1.0 / 3.0;
+        let end: f64 =
+            JulianDay::from_ymd_hms(year + 1, 1, 1, 0, 0, 0).get_day() - J2000 - 1.0 / 3.0;
+        let k: isize = ((start - 5.0) / 29.530588861).floor() as isize - 1;
+        (0..30)
+            .filter_map(|i| Self::from_lunation(k as f64 + i as f64 * 0.5))
+            .filter(|e| e.t >= start && e.t < end)
+            .collect()
+    }
+
+    /// 日月食类型
+    pub fn get_type(&self) -> EclipseType {
+        self.eclipse_type
+    }
+
+    /// 是否日食
+    pub fn is_solar(&self) -> bool {
+        self.eclipse_type.is_solar()
+    }
+
+    /// 食甚（北京时间）
+    pub fn get_solar_time(&self) -> SolarTime {
+        to_solar_time(self.t, UtcOffset::BEIJING)
+    }
+
+    /// 食甚（指定UTC偏移）
+    pub fn get_zoned_solar_time(&self, offset: UtcOffset) -> ZonedSolarTime {
+        ZonedSolarTime::new(to_solar_time(self.t, offset), offset)
+    }
+
+    /// 伽马值，影轴在地心以北为正
+    pub fn get_gamma(&self) -> f64 {
+        self.gamma
+    }
+
+    /// 食分
+    pub fn get_magnitude(&self) -> f64 {
+        self.magnitude
+    }
+}
+
+impl Culture for Eclipse {
+    fn get_name(&self) -> String {
+        self.eclipse_type.get_name()
+    }
+}
+
+impl Display for Eclipse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.get_solar_time(), self.get_name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tyme::astro::eclipse::Eclipse;
+    use crate::tyme::enums::EclipseType;
+    use crate::tyme::solar::{SolarTime, SolarYear};
+    use crate::tyme::zone::{UtcOffset, ZonedSolarTime};
+    use crate::tyme::Culture;
+
+    /// 食甚（世界时）的年月日时分秒、类型、食分
+    type Fixture = ([usize; 6], EclipseType, f64);
+
+    /// NASA日月食表
+    static NASA: [Fixture; 16] = [
+        ([2022, 4, 30, 20, 41, 23], EclipseType::SolarPartial, 0.6396),
+        ([2022, 5, 16, 4, 11, 28], EclipseType::LunarTotal, 1.4137),
+        ([2022, 10, 25, 11, 0, 8], EclipseType::SolarPartial, 0.8619),
+        ([2022, 11, 8, 10, 59, 10], EclipseType::LunarTotal, 1.3589),
+        ([2023, 4, 20, 4, 16, 49], EclipseType::SolarHybrid, 1.0132),
+        (
+            [2023, 5, 5, 17, 22, 52],
+            EclipseType::LunarPenumbral,
+            0.9663,
+        ),
+        ([2023, 10, 14, 18, 0, 40], EclipseType::SolarAnnular, 0.952),
+        ([2023, 10, 28, 20, 14, 2], EclipseType::LunarPartial, 0.1224),
+        (
+            [2024, 3, 25, 7, 12, 51],
+            EclipseType::LunarPenumbral,
+            0.9577,
+        ),
+        ([2024, 4, 8, 18, 17, 16], EclipseType::SolarTotal, 1.0566),
+        ([2024, 9, 18, 2, 44, 17], EclipseType::LunarPartial, 0.0848),
+        ([2024, 10, 2, 18, 45, 3], EclipseType::SolarAnnular, 0.9326),
+        ([2025, 3, 14, 6, 58, 43], EclipseType::LunarTotal, 1.1784),
+        ([2025, 3, 29, 10, 47, 25], EclipseType::SolarPartial, 0.9376),
+        ([2025, 9, 7, 18, 11, 48], EclipseType::LunarTotal, 1.362),
+        ([2025, 9, 21, 19, 41, 59], EclipseType::SolarPartial, 0.855),
+    ];
+
+    #[test]
+    fn test0() {
+        let eclipses: Vec<Eclipse> = (2022..=2025).flat_map(Eclipse::from_year).collect();
+        assert_eq!(NASA.len(), eclipses.len());
+        for (([y, m, d, h, mi, s], eclipse_type, magnitude), e) in NASA.iter().zip(eclipses) {
+            let t: ZonedSolarTime = e.get_zoned_solar_time(UtcOffset::UTC);
+            let expected: SolarTime = SolarTime::from_ymd_hms(*y as isize, *m, *d, *h, *mi, *s);
+            assert!(t.get_solar_time().subtract(expected).abs() < 120, "{}", t);
+            assert_eq!(*eclipse_type, e.get_type());
+            assert!((magnitude - e.get_magnitude()).abs() < 0.02, "{}", e);
+        }
+    }
+
+    #[test]
+    fn test1() {
+        let eclipses: Vec<Eclipse> = SolarYear::from_year(2024).get_eclipses();
+        assert_eq!(4, eclipses.len());
+        assert_eq!("2024年4月9日 02:17:46 日全食", eclipses[1].to_string());
+        assert!(eclipses[1].is_solar());
+        assert_eq!("0.344", format!("{:.3}", eclipses[1].get_gamma()));
+        assert_eq!("月偏食", eclipses[2].get_name());
+        assert!(!eclipses[2].is_solar());
+        assert_eq!("-0.983", format!("{:.3}", eclipses[2].get_gamma()));
+    }
+
+    #[test]
+    fn test2() {
+        // 1999年8月11日欧洲日全食、2009年7月22日长江日全食、2000年7月16日月全食
+        let e: Vec<String> = [1999, 2000, 2009]
+            .iter()
+            .flat_map(|y| SolarYear::from_year(*y).get_eclipses())
+            .filter(|e| e.get_magnitude() > 1.0)
+            .map(|e| e.to_string())
+            .collect();
+        assert_eq!(
+            vec![
+                "1999年8月11日 19:03:19 日全食",
+                "2000年1月21日 12:43:59 月全食",
+                "2000年7月16日 21:55:52 月全食",
+                "2009年7月22日 10:35:31 日全食",
+            ],
+            e
+        );
+        // 2009年12月31日世界时的月偏食在北京时间已是2010年
+        let e: Eclipse = SolarYear::from_year(2010).get_eclipses()[0];
+        assert_eq!(EclipseType::LunarPartial, e.get_type());
+        assert_eq!(
+            "2009年12月31日",
+            e.get_zoned_solar_time(UtcOffset::UTC)
+                .get_solar_time()
+                .get_solar_day()
+                .to_string()
+        );
+        assert_eq!(
+            "2010年1月1日",
+            e.get_solar_time().get_solar_day().to_string()
+        );
+    }
+}
@@ -0,0 +1,75 @@
+//! 天文
+//!
+//! 基于寿星天文历的日月位置计算。除特别说明外，内部时间均为J2000起算的日数（世界时），角度均为弧度。
+use std::f64::consts::PI;
+
+use crate::tyme::jd::{JulianDay, J2000};
+use crate::tyme::solar::SolarTime;
+use crate::tyme::util::{ShouXingUtil, PI_2, SECOND_PER_RAD};
+use crate::tyme::zone::UtcOffset;
+
+pub mod eclipse;
+pub mod moon;
+pub mod planet;
+pub mod sun;
+
+/// 世界时对应的力学时儒略世纪数
+fn get_dynamical_century(t: f64) -> f64 {
+    (t + ShouXingUtil::dtt(t)) / 36525.0
+}
+
+/// 黄经章动与真黄赤交角
+fn get_nutation(c: f64) -> (f64, f64) {
+    let dl: f64 = ShouXingUtil::nutation_lon2(c);
+    let de: f64 = 9.2 * (2.1824 - 33.75705 * c).cos() / SECOND_PER_RAD;
+    (dl, ShouXingUtil::hcjj(c) + de)
+}
+
+/// 格林尼治视恒星时
+fn get_sidereal_time(t: f64) -> f64 {
+    let c: f64 = get_dynamical_century(t);
+    let c2: f64 = c * c;
+    let c3: f64 = c2 * c;
+    let c4: f64 = c3 * c;
+    let (dl, e) = get_nutation(c);
+    PI_2 * (0.7790572732640 + 1.002737811911354 * t)
+        + (0.014506 + 4612.15739966 * c + 1.39667721 * c2 - 0.00009344 * c3 + 0.00001882 * c4)
+            / SECOND_PER_RAD
+        + dl * e.cos()
+}
+
+/// 太阳视赤经、视赤纬
+fn get_sun_equatorial(t: f64) -> (f64, f64) {
+    let c: f64 = get_dynamical_century(t);
+    let (_, e) = get_nutation(c);
+    ShouXingUtil::llr_conv(ShouXingUtil::sa_lon(c, 10), 0.0, e)
+}
+
+/// 角度归一化到[-π, π)
+fn normalize(a: f64) -> f64 {
+    (a + PI).rem_euclid(PI_2) - PI
+}
+
+/// 公历日当地0时对应的世界时
+fn get_local_midnight(julian_day: JulianDay, offset: UtcOffset) -> f64 {
+    julian_day.get_day() - J2000 - offset.get_days()
+}
+
+/// 世界时转当地公历时刻
+fn to_solar_time(t: f64, offset: UtcOffset) -> SolarTime {
+    JulianDay::from_julian_day(J2000 + t + offset.get_days()).get_solar_time()
+}
+
+/// 在[a, b]内二分求f由负变正（或由正变负）的时刻
+fn bisect(mut a: f64, mut b: f64, f: impl Fn(f64) -> f64) -> f64 {
+    let fa: f64 = f(a);
+    for _ in 0..20 {
+        let m: f64 = (a + b) / 2.0;
+        if (f(m) < 0.0) == (fa < 0.0) {
+            a = m;
+        } else {
+            b = m;
+        }
+    }
+    (a + b) / 2.0
+}
@@ -0,0 +1,536 @@
+//! 月球位置与出没
+use std::f64::consts::PI;
+use std::fmt::{Display, Formatter};
+
+use crate::tyme::astro::{
+    bisect, get_dynamical_century, get_local_midnight, get_nutation, get_sidereal_time, normalize,
+    to_solar_time,
+};
+use crate::tyme::jd::J2000;
+use crate::tyme::solar::{SolarDay, SolarTime};
+use crate::tyme::util::{ShouXingUtil, PI_2};
+use crate::tyme::zone::{get_new_moon, UtcOffset, ZonedSolarTime, SYNODIC_MONTH};
+use crate::tyme::Culture;
+
+/// 地球赤道半径（千米）
+static EARTH_RADIUS: f64 = 6378.14;
+
+/// 天文单位（千米）
+static ASTRONOMICAL_UNIT: f64 = 149597870.7;
+
+/// 月球距离周期项（Meeus表47.A）：D、M、M′、F的系数，距离振幅（0.001千米）
+static DISTANCE_TERMS: [[isize; 5]; 46] = [
+    [0, 0, 1, 0, -20905355],
+    [2, 0, -1, 0, -3699111],
+    [2, 0, 0, 0, -2955968],
+    [0, 0, 2, 0, -569925],
+    [0, 1, 0, 0, 48888],
+    [0, 0, 0, 2, -3149],
+    [2, 0, -2, 0, 246158],
+    [2, -1, -1, 0, -152138],
+    [2, 0, 1, 0, -170733],
+    [2, -1, 0, 0, -204586],
+    [0, 1, -1, 0, -129620],
+    [1, 0, 0, 0, 108743],
+    [0, 1, 1, 0, 104755],
+    [2, 0, 0, -2, 10321],
+    [0, 0, 1, -2, 79661],
+    [4, 0, -1, 0, -34782],
+    [0, 0, 3, 0, -23210],
+    [4, 0, -2, 0, -21636],
+    [2, 1, -1, 0, 24208],
+    [2, 1, 0, 0, 30824],
+    [1, 0, -1, 0, -8379],
+    [1, 1, 0, 0, -16675],
+    [2, -1, 1, 0, -12831],
+    [2, 0, 2, 0, -10445],
+    [4, 0, 0, 0, -11650],
+    [2, 0, -3, 0, 14403],
+    [0, 1, -2, 0, -7003],
+    [2, -1, -2, 0, 10056],
+    [1, 0, 1, 0, 6322],
+    [2, -2, 0, 0, -9884],
+    [0, 1, 2, 0, 5751],
+    [2, -2, -1, 0, -4950],
+    [2, 0, 1, -2, 4130],
+    [4, -1, -1, 0, -3958],
+    [3, 0, -1, 0, 3258],
+    [2, 1, 1, 0, 2616],
+    [4, -1, -2, 0, -1897],
+    [0, 2, -1, 0, -2117],
+    [2, 2, -1, 0, 2354],
+    [4, 0, 1, 0, -1423],
+    [0, 0, 4, 0, -1117],
+    [4, -1, 0, 0, -1571],
+    [1, 0, -2, 0, -1739],
+    [0, 0, 2, -2, -4421],
+    [0, 2, 1, 0, 1165],
+    [2, 0, -1, -2, 8752],
+];
+
+/// 月球黄纬周期项（Meeus表47.B）：D、M、M′、F的系数，黄纬振幅（0.000001度）
+static LATITUDE_TERMS: [[isize; 5]; 60] = [
+    [0, 0, 0, 1, 5128122],
+    [0, 0, 1, 1, 280602],
+    [0, 0, 1, -1, 277693],
+    [2, 0, 0, -1, 173237],
+    [2, 0, -1, 1, 55413],
+    [2, 0, -1, -1, 46271],
+    [2, 0, 0, 1, 32573],
+    [0, 0, 2, 1, 17198],
+    [2, 0, 1, -1, 9266],
+    [0, 0, 2, -1, 8822],
+    [2, -1, 0, -1, 8216],
+    [2, 0, -2, -1, 4324],
+    [2, 0, 1, 1, 4200],
+    [2, 1, 0, -1, -3359],
+    [2, -1, -1, 1, 2463],
+    [2, -1, 0, 1, 2211],
+    [2, -1, -1, -1, 2065],
+    [0, 1, -1, -1, -1870],
+    [4, 0, -1, -1, 1828],
+    [0, 1, 0, 1, -1794],
+    [0, 0, 0, 3, -1749],
+    [0, 1, -1, 1, -1565],
+    [1, 0, 0, 1, -1491],
+    [0, 1, 1, 1, -1475],
+    [0, 1, 1, -1, -1410],
+    [0, 1, 0, -1, -1344],
+    [1, 0, 0, -1, -1335],
+    [0, 0, 3, 1, 1107],
+    [4, 0, 0, -1, 1021],
+    [4, 0, -1, 1, 833],
+    [0, 0, 1, -3, 777],
+    [4, 0, -2, 1, 671],
+    [2, 0, 0, -3, 607],
+    [2, 0, 2, -1, 596],
+    [2, -1, 1, -1, 491],
+    [2, 0, -2, 1, -451],
+    [0, 0, 3, -1, 439],
+    [2, 0, 2, 1, 422],
+    [2, 0, -3, -1, 421],
+    [2, 1, -1, 1, -366],
+    [2, 1, 0, 1, -351],
+    [4, 0, 0, 1, 331],
+    [2, -1, 1, 1, 315],
+    [2, -2, 0, -1, 302],
+    [0, 0, 1, 3, -283],
+    [2, 1, 1, -1, -229],
+    [1, 1, 0, -1, 223],
+    [1, 1, 0, 1, 223],
+    [0, 1, -2, -1, -220],
+    [2, 1, -1, -1, -220],
+    [1, 0, 1, 1, -185],
+    [2, -1, -2, -1, 181],
+    [0, 1, 2, 1, -177],
+    [4, 0, -2, -1, 176],
+    [4, -1, -1, -1, 166],
+    [1, 0, 1, -1, -164],
+    [4, 0, 1, -1, 132],
+    [1, 0, -1, -1, -119],
+    [4, -1, 0, -1, 115],
+    [2, -2, 0, 1, 107],
+];
+
+/// 周期项求和，f为sin或cos，含M的项按地球轨道偏心率修正
+fn sum_terms(terms: &[[isize; 5]], c: f64, f: fn(f64) -> f64) -> f64 {
+    let c2: f64 = c * c;
+    let c3: f64 = c2 * c;
+    let c4: f64 = c3 * c;
+    let d: f64 = (297.8501921 + 445267.1114034 * c - 0.0018819 * c2 + c3 / 545868.0
+        - c4 / 113065000.0)
+        .to_radians();
+    let m: f64 = (357.5291092 + 35999.0502909 * c - 0.0001536 * c2 + c3 / 24490000.0).to_radians();
+    let mp: f64 = (134.9633964 + 477198.8675055 * c + 0.0087414 * c2 + c3 / 69699.0
+        - c4 / 14712000.0)
+        .to_radians();
+    let ff: f64 = (93.2720950 + 483202.0175233 * c - 0.0036539 * c2 - c3 / 3526000.0
+        + c4 / 863310000.0)
+        .to_radians();
+    let e: f64 = 1.0 - 0.002516 * c - 0.0000074 * c2;
+    terms
+        .iter()
+        .map(|t| {
+            let a: f64 = t[0] as f64 * d + t[1] as f64 * m + t[2] as f64 * mp + t[3] as f64 * ff;
+            t[4] as f64 * f(a) * e.powi(t[1].abs() as i32)
+        })
+        .sum()
+}
+
+/// 月球地心几何黄经、黄纬（弧度）与距离（千米），c为力学时儒略世纪数
+///
+/// 黄经用寿星天文历的月球黄经序列，黄纬与距离用Meeus《天文算法》第47章的截断序列。
+pub(super) fn get_moon_ecliptic(c: f64) -> (f64, f64, f64) {
+    let c2: f64 = c * c;
+    let c3: f64 = c2 * c;
+    let c4: f64 = c3 * c;
+    let l: f64 = (218.3164477 + 481267.88123421 * c - 0.0015786 * c2 + c3 / 538841.0
+        - c4 / 65194000.0)
+        .to_radians();
+    let mp: f64 = (134.9633964 + 477198.8675055 * c).to_radians();
+    let ff: f64 = (93.2720950 + 483202.0175233 * c).to_radians();
+    let a1: f64 = (119.75 + 131.849 * c).to_radians();
+    let a3: f64 = (313.45 + 481266.484 * c).to_radians();
+    let b: f64 = sum_terms(&LATITUDE_TERMS, c, f64::sin) - 2235.0 * l.sin()
+        + 382.0 * a3.sin()
+        + 175.0 * (a1 - ff).sin()
+        + 175.0 * (a1 + ff).sin()
+        + 127.0 * (l - mp).sin()
+        - 115.0 * (l + mp).sin();
+    let r: f64 = 385000.56 + sum_terms(&DISTANCE_TERMS, c, f64::cos) / 1000.0;
+    (
+        ShouXingUtil::mlon(c, -1).rem_euclid(PI_2),
+        (b / 1000000.0).to_radians(),
+        r,
+    )
+}
+
+/// 月球视赤经、视赤纬（弧度）与距离（千米）
+fn get_moon_equatorial(t: f64) -> (f64, f64, f64) {
+    let c: f64 = get_dynamical_century(t);
+    let (dl, e) = get_nutation(c);
+    let (lon, lat, r) = get_moon_ecliptic(c);
+    let (ra, dec) = ShouXingUtil::llr_conv(lon + dl, lat, e);
+    (ra, dec, r)
+}
+
+/// 日地距离（千米）
+pub(super) fn get_sun_distance(c: f64) -> f64 {
+    let m: f64 = (357.52911 + 35999.05029 * c).to_radians();
+    (1.000140 - 0.016708 * m.cos() - 0.000139 * (2.0 * m).cos()) * ASTRONOMICAL_UNIT
+}
+
+/// 月球视位置、亮面比例与月龄
+#[derive(Debug, Copy, Clone)]
+pub struct MoonPosition {
+    longitude: f64,
+    latitude: f64,
+    distance: f64,
+    right_ascension: f64,
+    declination: f64,
+    illumination: f64,
+    age: f64,
+}
+
+impl MoonPosition {
+    /// 从公历时刻（北京时间）计算
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::astro::moon::MoonPosition;
+    /// use tyme4rs::tyme::solar::SolarTime;
+    ///
+    /// // 2024年3月25日望
+    /// let p: MoonPosition = MoonPosition::from_solar_time(SolarTime::from_ymd_hms(2024, 3, 25, 15, 0, 0));
+    /// assert_eq!(100, p.get_illumination().round() as usize);
+    /// ```
+    pub fn from_solar_time(solar_time: SolarTime) -> Self {
+        Self::from_universal_time(solar_time.get_julian_day().get_day() - J2000 - 1.0 / 3.0)
+    }
+
+    /// 从带UTC偏移的公历时刻计算
+    pub fn from_zoned_solar_time(zoned_solar_time: ZonedSolarTime) -> Self {
+        Self::from_universal_time(zoned_solar_time.get_julian_day().get_day() - J2000)
+    }
+
+    fn from_universal_time(t: f64) -> Self {
+        let c: f64 = get_dynamical_century(t);
+        let (dl, e) = get_nutation(c);
+        let (lon, lat, r) = get_moon_ecliptic(c);
+        let lon: f64 = lon + dl;
+        let (ra, dec) = ShouXingUtil::llr_conv(lon, lat, e);
+
+        // 以日月角距求相位角
+        let sun_lon: f64 = ShouXingUtil::sa_lon(c, 10);
+        let sun_r: f64 = get_sun_distance(c);
+        let psi: f64 = (lat.cos() * (lon - sun_lon).cos()).acos();
+        let i: f64 = (sun_r * psi.sin()).atan2(r - sun_r * psi.cos());
+
+        // 距上一个朔的日数
+        let mut k: isize = ((t - get_new_moon(0)) / SYNODIC_MONTH).floor() as isize;
+        while get_new_moon(k) > t {
+            k -= 1;
+        }
+        while get_new_moon(k + 1) <= t {
+            k += 1;
+        }
+        Self {
+            longitude: lon.rem_euclid(PI_2).to_degrees(),
+            latitude: lat.to_degrees(),
+            distance: r,
+            right_ascension: ra.to_degrees(),
+            declination: dec.to_degrees(),
+            illumination: (1.0 + i.cos()) * 50.0,
+            age: t - get_new_moon(k),
+        }
+    }
+
+    /// 视黄经（度）
+    pub fn get_longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    /// 黄纬（度）
+    pub fn get_latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    /// 地心距离（千米）
+    pub fn get_distance(&self) -> f64 {
+        self.distance
+    }
+
+    /// 视赤经（度）
+    pub fn get_right_ascension(&self) -> f64 {
+        self.right_ascension
+    }
+
+    /// 视赤纬（度）
+    pub fn get_declination(&self) -> f64 {
+        self.declination
+    }
+
+    /// 亮面比例（%）
+    pub fn get_illumination(&self) -> f64 {
+        self.illumination
+    }
+
+    /// 月龄（距上一个朔的日数）
+    pub fn get_age(&self) -> f64 {
+        self.age
+    }
+}
+
+/// 月球在当地的高度减去出没高度（弧度）
+///
+/// 出没高度含大气折射34'、月球视半径与地平视差：0.7275π - 34'。
+fn get_altitude(t: f64, latitude: f64, longitude: f64) -> f64 {
+    let (ra, dec, r) = get_moon_equatorial(t);
+    let h: f64 = get_sidereal_time(t) + longitude - ra;
+    let altitude: f64 = (latitude.sin() * dec.sin() + latitude.cos() * dec.cos() * h.cos()).asin();
+    altitude - (0.7275 * (EARTH_RADIUS / r).asin() - (34.0f64 / 60.0).to_radians())
+}
+
+/// 月球在当地的高度与日月角距（度），latitude、longitude为弧度，用于新月可见性判定
+///
+/// 高度为月心的地心高度，不含大气折射；角距为日月的地心角距。
+pub(crate) fn get_crescent(t: f64, latitude: f64, longitude: f64) -> (f64, f64) {
+    let c: f64 = get_dynamical_century(t);
+    let (dl, e) = get_nutation(c);
+    let (lon, lat, _) = get_moon_ecliptic(c);
+    let (ra, dec) = ShouXingUtil::llr_conv(lon + dl, lat, e);
+    let h: f64 = get_sidereal_time(t) + longitude - ra;
+    let altitude: f64 = (latitude.sin() * dec.sin() + latitude.cos() * dec.cos() * h.cos()).asin();
+    let elongation: f64 = (lat.cos() * (lon + dl - ShouXingUtil::sa_lon(c, 10)).cos()).acos();
+    (altitude.to_degrees(), elongation.to_degrees())
+}
+
+/// 月球时角
+fn get_hour_angle(t: f64, longitude: f64) -> f64 {
+    let (ra, _, _) = get_moon_equatorial(t);
+    normalize(get_sidereal_time(t) + longitude - ra)
+}
+
+/// 月出月落与中天
+///
+/// 月球每天约迟出没50分钟，因此某些日子没有月出、月落或中天。
+#[derive(Debug, Copy, Clone)]
+pub struct MoonEvents {
+    solar_day: SolarDay,
+    latitude: f64,
+    longitude: f64,
+    offset: UtcOffset,
+    moonrise: Option<SolarTime>,
+    moonset: Option<SolarTime>,
+    transit: Option<SolarTime>,
+}
+
+impl MoonEvents {
+    /// 公历日、纬度（度，北纬为正）、经度（度，东经为正）、时间的UTC偏移
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::astro::moon::MoonEvents;
+    /// use tyme4rs::tyme::solar::SolarDay;
+    /// use tyme4rs::tyme::zone::UtcOffset;
+    ///
+    /// // 北京
+    /// let e: MoonEvents = MoonEvents::from_solar_day(SolarDay::from_ymd(2024, 9, 17), 39.9042, 116.4074, UtcOffset::BEIJING);
+    /// assert_eq!("2024年9月17日 月出18:02:14 月落04:26:59 中天23:47:42", e.to_string());
+    /// ```
+    pub fn from_solar_day(
+        solar_day: SolarDay,
+        latitude: f64,
+        longitude: f64,
+        offset: UtcOffset,
+    ) -> Self {
+        let lat: f64 = latitude.to_radians();
+        let lon: f64 = longitude.to_radians();
+        let start: f64 = get_local_midnight(solar_day.get_julian_day(), offset);
+        let altitude = |t: f64| get_altitude(t, lat, lon);
+        let hour_angle = |t: f64| get_hour_angle(t, lon);
+
+        // 逐小时扫描高度与时角的变号
+        let mut moonrise: Option<f64> = None;
+        let mut moonset: Option<f64> = None;
+        let mut transit: Option<f64> = None;
+        let (mut t0, mut a0, mut h0) = (start, altitude(start), hour_angle(start));
+        for i in 1..=24 {
+            let t1: f64 = start + i as f64 / 24.0;
+            let (a1, h1) = (altitude(t1), hour_angle(t1));
+            if a0 < 0.0 && a1 >= 0.0 && moonrise.is_none() {
+                moonrise = Some(bisect(t0, t1, altitude));
+            } else if a0 >= 0.0 && a1 < 0.0 && moonset.is_none() {
+                moonset = Some(bisect(t0, t1, altitude));
+            }
+            if h0 < 0.0 && h1 >= 0.0 && h1 - h0 < PI && transit.is_none() {
+                transit = Some(bisect(t0, t1, hour_angle));
+            }
+            (t0, a0, h0) = (t1, a1, h1);
+        }
+        let local = |t: Option<f64>| -> Option<SolarTime> {
+            t.map(|t| to_solar_time(t, offset))
+                .filter(|t| t.get_solar_day() == solar_day)
+        };
+        Self {
+            solar_day,
+            latitude,
+            longitude,
+            offset,
+            moonrise: local(moonrise),
+            moonset: local(moonset),
+            transit: local(transit),
+        }
+    }
+
+    /// 公历日
+    pub fn get_solar_day(&self) -> SolarDay {
+        self.solar_day
+    }
+
+    /// 纬度（度）
+    pub fn get_latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    /// 经度（度）
+    pub fn get_longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    /// 时间的UTC偏移
+    pub fn get_offset(&self) -> UtcOffset {
+        self.offset
+    }
+
+    /// 月出
+    pub fn get_moonrise(&self) -> Option<SolarTime> {
+        self.moonrise
+    }
+
+    /// 月落
+    pub fn get_moonset(&self) -> Option<SolarTime> {
+        self.moonset
+    }
+
+    /// 月球中天
+    pub fn get_transit(&self) -> Option<SolarTime> {
+        self.transit
+    }
+}
+
+impl Display for MoonEvents {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = |t: Option<SolarTime>| t.map_or("--".to_string(), |t| t.get_name());
+        write!(
+            f,
+            "{} 月出{} 月落{} 中天{}",
+            self.solar_day,
+            name(self.moonrise),
+            name(self.moonset),
+            name(self.transit)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tyme::astro::moon::{get_moon_ecliptic, MoonEvents, MoonPosition};
+    use crate::tyme::solar::{SolarDay, SolarTime};
+    use crate::tyme::zone::{UtcOffset, ZonedSolarTime};
+    use crate::tyme::Culture;
+
+    fn name(t: Option<SolarTime>) -> String {
+        t.unwrap().get_name()
+    }
+
+    #[test]
+    fn test0() {
+        // Meeus《天文算法》例47.a：1992年4月12日0时（力学时）
+        let (lon, lat, r) = get_moon_ecliptic(-0.077221081451);
+        assert!((lon.to_degrees() - 133.162655).abs() < 0.001);
+        assert!((lat.to_degrees() - -3.229126).abs() < 0.000001);
+        assert!((r - 368409.7).abs() < 0.1);
+    }
+
+    #[test]
+    fn test1() {
+        // 2024年2月10日朔
+        let p: MoonPosition = SolarTime::from_ymd_hms(2024, 2, 10, 6, 59, 11).get_moon_position();
+        assert_eq!(0, p.get_age().round() as usize);
+        assert_eq!(0, p.get_illumination().round() as usize);
+        assert_eq!("358745", format!("{:.0}", p.get_distance()));
+
+        // 2024年3月25日望
+        let p: MoonPosition = SolarTime::from_ymd_hms(2024, 3, 25, 15, 0, 0).get_moon_position();
+        assert_eq!("14.9", format!("{:.1}", p.get_age()));
+        assert_eq!("99.99", format!("{:.2}", p.get_illumination()));
+        assert_eq!("185.12", format!("{:.2}", p.get_longitude()));
+        assert_eq!("0.96", format!("{:.2}", p.get_latitude()));
+        assert_eq!("185.08", format!("{:.2}", p.get_right_ascension()));
+        assert_eq!("-1.15", format!("{:.2}", p.get_declination()));
+
+        let z: ZonedSolarTime = ZonedSolarTime::from_ymd_hms(2024, 3, 25, 7, 0, 0, UtcOffset::UTC);
+        assert_eq!(
+            format!("{:.6}", p.get_longitude()),
+            format!(
+                "{:.6}",
+                MoonPosition::from_zoned_solar_time(z).get_longitude()
+            )
+        );
+    }
+
+    #[test]
+    fn test2() {
+        // 北京：9月18日望，月球中天跨过午夜
+        let e: MoonEvents =
+            SolarDay::from_ymd(2024, 9, 17).get_moon_events(39.9042, 116.4074, UtcOffset::BEIJING);
+        assert_eq!(
+            "2024年9月17日 月出18:02:14 月落04:26:59 中天23:47:42",
+            e.to_string()
+        );
+        let e: MoonEvents =
+            SolarDay::from_ymd(2024, 9, 18).get_moon_events(39.9042, 116.4074, UtcOffset::BEIJING);
+        assert_eq!("18:28:06", name(e.get_moonrise()));
+        assert_eq!("05:45:24", name(e.get_moonset()));
+        assert_eq!(None, e.get_transit());
+    }
+
+    #[test]
+    fn test3() {
+        // 特罗姆瑟：月球拱极或终日不出
+        let offset: UtcOffset = UtcOffset::from_hm(2, 0);
+        let e: MoonEvents =
+            SolarDay::from_ymd(2024, 6, 7).get_moon_events(69.6492, 18.9553, offset);
+        assert_eq!("2024年6月7日 月出-- 月落-- 中天13:35:59", e.to_string());
+        let e: MoonEvents =
+            SolarDay::from_ymd(2024, 6, 21).get_moon_events(69.6492, 18.9553, offset);
+        assert_eq!("2024年6月21日 月出-- 月落-- 中天--", e.to_string());
+        let e: MoonEvents =
+            SolarDay::from_ymd(2024, 6, 12).get_moon_events(69.6492, 18.9553, offset);
+        assert_eq!("08:40:04", name(e.get_moonrise()));
+        assert_eq!("02:40:38", name(e.get_moonset()));
+    }
+}
@@ -0,0 +1,767 @@
+//! 五星
+use std::f64::consts::PI;
+use std::fmt::{Display, Formatter};
+use std::ops::{Deref, DerefMut};
+
+use crate::tyme::astro::{bisect, get_dynamical_century, get_nutation, normalize, to_solar_time};
+use crate::tyme::culture::Element;
+use crate::tyme::enums::PlanetEventType;
+use crate::tyme::error::TymeError;
+use crate::tyme::jd::J2000;
+use crate::tyme::solar::{SolarDay, SolarTime};
+use crate::tyme::util::{PI_2, SECOND_PER_RAD};
+use crate::tyme::zone::{UtcOffset, ZonedSolarTime};
+use crate::tyme::{Culture, LoopTyme, Tyme};
+
+pub static PLANET_NAMES: [&str; 5] = ["辰星", "太白", "荧惑", "岁星", "镇星"];
+
+/// 光行1天文单位所需的日数
+static LIGHT_TIME: f64 = 0.0057755183;
+
+/// JPL近似开普勒轨道根数（J2000黄道，1800至2050年）：半长轴（天文单位）、偏心率、倾角、平黄经、近日点黄经、升交点黄经（度），及其每世纪变化率
+///
+/// 依次为水星、金星、火星、木星、土星、地月质心。
+static ELEMENTS: [[f64; 12]; 6] = [
+    [
+        0.38709927,
+        0.20563593,
+        7.00497902,
+        252.2503235,
+        77.45779628,
+        48.33076593,
+        0.00000037,
+        0.00001906,
+        -0.00594749,
+        149472.67411175,
+        0.16047689,
+        -0.12534081,
+    ],
+    [
+        0.72333566,
+        0.00677672,
+        3.39467605,
+        181.9790995,
+        131.60246718,
+        76.67984255,
+        0.0000039,
+        -0.00004107,
+        -0.0007889,
+        58517.81538729,
+        0.00268329,
+        -0.27769418,
+    ],
+    [
+        1.52371034,
+        0.0933941,
+        1.84969142,
+        -4.55343205,
+        -23.94362959,
+        49.55953891,
+        0.00001847,
+        0.00007882,
+        -0.00813131,
+        19140.30268499,
+        0.44441088,
+        -0.29257343,
+    ],
+    [
+        5.202887,
+        0.04838624,
+        1.30439695,
+        34.39644051,
+        14.72847983,
+        100.47390909,
+        -0.00011607,
+        -0.00013253,
+        -0.00183714,
+        3034.74612775,
+        0.21252668,
+        0.20469106,
+    ],
+    [
+        9.53667594,
+        0.05386179,
+        2.48599187,
+        49.95424423,
+        92.59887831,
+        113.66242448,
+        -0.0012506,
+        -0.00050991,
+        0.00193609,
+        1222.49362201,
+        -0.41897216,
+        -0.28867794,
+    ],
+    [
+        1.00000261,
+        0.01671123,
+        -0.00001531,
+        100.46457166,
+        102.93768193,
+        0.0,
+        0.00000562,
+        -0.00004392,
+        -0.01294668,
+        35999.37244981,
+        0.32327364,
+        0.0,
+    ],
+];
+
+/// 轨道根数适用的最小年份
+static MIN_YEAR: isize = 1800;
+
+/// 轨道根数适用的最大年份
+static MAX_YEAR: isize = 2050;
+
+/// 检查年份是否在轨道根数的适用范围内
+fn validate(year: isize) -> Result<(), TymeError> {
+    if !(MIN_YEAR..=MAX_YEAR).contains(&year) {
+        Err(TymeError::YearOutOfRange {
+            calendar: "planet",
+            year,
+            min: MIN_YEAR,
+            max: MAX_YEAR,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// 日心黄道直角坐标（J2000黄道，天文单位），c为力学时儒略世纪数
+fn get_heliocentric(index: usize, c: f64) -> [f64; 3] {
+    let x: [f64; 12] = ELEMENTS[index];
+    let a: f64 = x[0] + x[6] * c;
+    let e: f64 = x[1] + x[7] * c;
+    let i: f64 = (x[2] + x[8] * c).to_radians();
+    let l: f64 = (x[3] + x[9] * c).to_radians();
+    let p: f64 = (x[4] + x[10] * c).to_radians();
+    let o: f64 = (x[5] + x[11] * c).to_radians();
+    let w: f64 = p - o;
+    let m: f64 = normalize(l - p);
+
+    // 开普勒方程
+    let mut ea: f64 = m + e * m.sin();
+    for _ in 0..5 {
+        ea -= (ea - e * ea.sin() - m) / (1.0 - e * ea.cos());
+    }
+    let xp: f64 = a * (ea.cos() - e);
+    let yp: f64 = a * (1.0 - e * e).sqrt() * ea.sin();
+
+    let (sw, cw, so, co, si, ci) = (w.sin(), w.cos(), o.sin(), o.cos(), i.sin(), i.cos());
+    [
+        (cw * co - sw * so * ci) * xp + (-sw * co - cw * so * ci) * yp,
+        (cw * so + sw * co * ci) * xp + (-sw * so + cw * co * ci) * yp,
+        sw * si * xp + cw * si * yp,
+    ]
+}
+
+/// 行星地心黄经、黄纬（J2000黄道，弧度）、地心距离（天文单位）与太阳地心黄经，已做光行时改正
+fn get_geocentric(index: usize, c: f64) -> (f64, f64, f64, f64) {
+    let earth: [f64; 3] = get_heliocentric(5, c);
+    let mut v: [f64; 3] = [0.0; 3];
+    let mut r: f64 = 0.0;
+    for _ in 0..2 {
+        let p: [f64; 3] = get_heliocentric(index, c - r * LIGHT_TIME / 36525.0);
+        v = [p[0] - earth[0], p[1] - earth[1], p[2] - earth[2]];
+        r = (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+    }
+    (
+        v[1].atan2(v[0]),
+        (v[2] / r).asin(),
+        r,
+        (-earth[1]).atan2(-earth[0]),
+    )
+}
+
+/// 行星地心黄经减太阳地心黄经，归一化到[-π, π)
+fn get_elongation(index: usize, t: f64) -> f64 {
+    let (lon, _, _, sun_lon) = get_geocentric(index, get_dynamical_century(t));
+    normalize(lon - sun_lon)
+}
+
+/// 行星地心黄经的日变化（弧度/日）
+fn get_speed(index: usize, t: f64) -> f64 {
+    let (a, _, _, _) = get_geocentric(index, get_dynamical_century(t - 0.5));
+    let (b, _, _, _) = get_geocentric(index, get_dynamical_century(t + 0.5));
+    normalize(b - a)
+}
+
+/// 逐日扫描[start, end)内f的过零点
+fn find_roots(start: f64, end: f64, f: impl Fn(f64) -> f64) -> Vec<f64> {
+    let mut l: Vec<f64> = Vec::new();
+    let (mut t0, mut v0) = (start, f(start));
+    while t0 < end {
+        let t1: f64 = t0 + 1.0;
+        let v1: f64 = f(t1);
+        // 排除角度在±π处的跳变
+        if (v0 < 0.0) != (v1 < 0.0) && (v1 - v0).abs() < PI {
+            let t: f64 = bisect(t0, t1, &f);
+            if t >= start && t < end {
+                l.push(t);
+            }
+        }
+        (t0, v0) = (t1, v1);
+    }
+    l
+}
+
+/// 公历日（北京时间）0时的世界时
+fn get_universal_time(solar_day: SolarDay) -> f64 {
+    solar_day.get_julian_day().get_day() - J2000 - 1.0 / 3.0
+}
+
+/// 五星（辰星、太白、荧惑、岁星、镇星）
+#[derive(Debug, Clone)]
+pub struct Planet {
+    parent: LoopTyme,
+}
+
+impl Deref for Planet {
+    type Target = LoopTyme;
+
+    fn deref(&self) -> &Self::Target {
+        &self.parent
+    }
+}
+
+impl DerefMut for Planet {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.parent
+    }
+}
+
+impl Tyme for Planet {
+    fn next(&self, n: isize) -> Self {
+        Self::from_index(self.parent.next_index(n) as isize)
+    }
+}
+
+impl Culture for Planet {
+    fn get_name(&self) -> String {
+        self.parent.get_name()
+    }
+}
+
+impl Planet {
+    pub fn from_index(index: isize) -> Self {
+        Self {
+            parent: LoopTyme::from_index(
+                PLANET_NAMES
+                    .to_vec()
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect(),
+                index,
+            ),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Self {
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
+                PLANET_NAMES
+                    .to_vec()
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect(),
+                name,
+            )?,
+        })
+    }
+
+    /// 五行
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::astro::planet::Planet;
+    /// use tyme4rs::tyme::Culture;
+    ///
+    /// assert_eq!("木", Planet::from_name("岁星").get_element().get_name());
+    /// ```
+    pub fn get_element(&self) -> Element {
+        Element::from_index([4, 3, 1, 0, 2][self.get_index()])
+    }
+
+    /// 是否内行星（辰星、太白）
+    pub fn is_inferior(&self) -> bool {
+        self.get_index() < 2
+    }
+
+    /// 公历时刻（北京时间）的地心位置，超出1800至2050年时返回错误
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::astro::planet::{Planet, PlanetPosition};
+    /// use tyme4rs::tyme::solar::SolarTime;
+    ///
+    /// let p: PlanetPosition = Planet::from_name("岁星").get_position(SolarTime::from_ymd_hms(2023, 11, 3, 13, 0, 0)).unwrap();
+    /// assert_eq!(true, p.is_retrograde());
+    /// ```
+    pub fn get_position(&self, solar_time: SolarTime) -> Result<PlanetPosition, TymeError> {
+        PlanetPosition::try_from_solar_time(self.clone(), solar_time)
+    }
+
+    /// 公历日期范围（北京时间，含首尾两日）内的合、冲、留，按时间先后排列，超出1800至2050年时返回错误
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::astro::planet::{Planet, PlanetEvent};
+    /// use tyme4rs::tyme::enums::PlanetEventType;
+    /// use tyme4rs::tyme::solar::SolarDay;
+    ///
+    /// let events: Vec<PlanetEvent> = Planet::from_name("荧惑").get_events(SolarDay::from_ymd(2022, 12, 1), SolarDay::from_ymd(2022, 12, 31)).unwrap();
+    /// assert_eq!("2022年12月8日", events[0].get_solar_time().get_solar_day().to_string());
+    /// assert_eq!(PlanetEventType::Opposition, events[0].get_type());
+    /// ```
+    pub fn get_events(
+        &self,
+        start: SolarDay,
+        end: SolarDay,
+    ) -> Result<Vec<PlanetEvent>, TymeError> {
+        validate(start.get_year())?;
+        validate(end.get_year())?;
+        let index: usize = self.get_index();
+        let start: f64 = get_universal_time(start);
+        let end: f64 = get_universal_time(end) + 1.0;
+        let mut l: Vec<PlanetEvent> = Vec::new();
+        let mut push = |t: f64, event_type: PlanetEventType| {
+            l.push(PlanetEvent {
+                planet: self.clone(),
+                event_type,
+                t,
+            })
+        };
+        for t in find_roots(start, end, |t| get_elongation(index, t)) {
+            let event_type: PlanetEventType = if !self.is_inferior() {
+                PlanetEventType::Conjunction
+            } else if get_geocentric(index, get_dynamical_century(t)).2 < 1.0 {
+                PlanetEventType::InferiorConjunction
+            } else {
+                PlanetEventType::SuperiorConjunction
+            };
+            push(t, event_type);
+        }
+        if !self.is_inferior() {
+            for t in find_roots(start, end, |t| normalize(get_elongation(index, t) + PI)) {
+                push(t, PlanetEventType::Opposition);
+            }
+        }
+        for t in find_roots(start, end, |t| get_speed(index, t)) {
+            let event_type: PlanetEventType = if get_speed(index, t + 1.0) < 0.0 {
+                PlanetEventType::StationaryRetrograde
+            } else {
+                PlanetEventType::StationaryDirect
+            };
+            push(t, event_type);
+        }
+        l.sort_by(|a, b| a.t.total_cmp(&b.t));
+        Ok(l)
+    }
+
+    /// 公历日期范围（北京时间，含首尾两日）内与另一行星黄经相合的时刻（北京时间），超出1800至2050年时返回错误
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::astro::planet::Planet;
+    /// use tyme4rs::tyme::solar::{SolarDay, SolarTime};
+    ///
+    /// // 2020年木土大合
+    /// let l: Vec<SolarTime> = Planet::from_name("岁星").get_conjunctions(&Planet::from_name("镇星"), SolarDay::from_ymd(2020, 12, 1), SolarDay::from_ymd(2020, 12, 31)).unwrap();
+    /// assert_eq!(1, l.len());
+    /// ```
+    pub fn get_conjunctions(
+        &self,
+        other: &Planet,
+        start: SolarDay,
+        end: SolarDay,
+    ) -> Result<Vec<SolarTime>, TymeError> {
+        validate(start.get_year())?;
+        validate(end.get_year())?;
+        let (a, b) = (self.get_index(), other.get_index());
+        Ok(find_roots(
+            get_universal_time(start),
+            get_universal_time(end) + 1.0,
+            |t| {
+                let c: f64 = get_dynamical_century(t);
+                normalize(get_geocentric(a, c).0 - get_geocentric(b, c).0)
+            },
+        )
+        .into_iter()
+        .map(|t| to_solar_time(t, UtcOffset::BEIJING))
+        .collect())
+    }
+}
+
+impl Display for Planet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.get_name())
+    }
+}
+
+impl PartialEq for Planet {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for Planet {}
+
+impl From<Planet> for LoopTyme {
+    fn from(val: Planet) -> Self {
+        val.parent
+    }
+}
+
+/// 行星地心视位置
+///
+/// 由JPL近似开普勒轨道根数推算，含光行时、岁差和章动改正，1800至2050年间的误差约为1′，超出此范围的时刻不予计算。
+#[derive(Debug, Clone)]
+pub struct PlanetPosition {
+    planet: Planet,
+    longitude: f64,
+    latitude: f64,
+    distance: f64,
+    elongation: f64,
+    speed: f64,
+}
+
+impl PlanetPosition {
+    /// 从公历时刻（北京时间）计算
+    pub fn from_solar_time(planet: Planet, solar_time: SolarTime) -> Self {
+        Self::try_from_solar_time(planet, solar_time).unwrap()
+    }
+
+    /// 从公历时刻（北京时间）计算，超出1800至2050年时返回错误
+    pub fn try_from_solar_time(planet: Planet, solar_time: SolarTime) -> Result<Self, TymeError> {
+        validate(solar_time.get_solar_day().get_year())?;
+        Ok(Self::from_universal_time(
+            planet,
+            solar_time.get_julian_day().get_day() - J2000 - 1.0 / 3.0,
+        ))
+    }
+
+    /// 从带UTC偏移的公历时刻计算
+    pub fn from_zoned_solar_time(planet: Planet, zoned_solar_time: ZonedSolarTime) -> Self {
+        Self::try_from_zoned_solar_time(planet, zoned_solar_time).unwrap()
+    }
+
+    /// 从带UTC偏移的公历时刻计算，超出1800至2050年时返回错误
+    pub fn try_from_zoned_solar_time(
+        planet: Planet,
+        zoned_solar_time: ZonedSolarTime,
+    ) -> Result<Self, TymeError> {
+        validate(zoned_solar_time.get_solar_time().get_solar_day().get_year())?;
+        Ok(Self::from_universal_time(
+            planet,
+            zoned_solar_time.get_julian_day().get_day() - J2000,
+        ))
+    }
+
+    fn from_universal_time(planet: Planet, t: f64) -> Self {
+        let index: usize = planet.get_index();
+        let c: f64 = get_dynamical_century(t);
+        let (lon, lat, r, sun_lon) = get_geocentric(index, c);
+        // J2000黄道转当日黄道的黄经岁差
+        let precession: f64 = (5029.0966 * c + 1.11113 * c * c) / SECOND_PER_RAD;
+        let (dl, _) = get_nutation(c);
+        Self {
+            planet,
+            longitude: (lon + precession + dl).rem_euclid(PI_2).to_degrees(),
+            latitude: lat.to_degrees(),
+            distance: r,
+            elongation: normalize(lon - sun_lon).to_degrees(),
+            speed: get_speed(index, t).to_degrees(),
+        }
+    }
+
+    /// 行星
+    pub fn get_planet(&self) -> Planet {
+        self.planet.clone()
+    }
+
+    /// 视黄经（度）
+    pub fn get_longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    /// 黄纬（度）
+    pub fn get_latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    /// 地心距离（天文单位）
+    pub fn get_distance(&self) -> f64 {
+        self.distance
+    }
+
+    /// 与太阳的黄经差（度），东为正
+    pub fn get_elongation(&self) -> f64 {
+        self.elongation
+    }
+
+    /// 黄经日变化（度/日）
+    pub fn get_speed(&self) -> f64 {
+        self.speed
+    }
+
+    /// 是否逆行
+    pub fn is_retrograde(&self) -> bool {
+        self.speed < 0.0
+    }
+}
+
+/// 行星动态（合、冲、留）
+#[derive(Debug, Clone)]
+pub struct PlanetEvent {
+    planet: Planet,
+    event_type: PlanetEventType,
+    /// 世界时（J2000起算的日数）
+    t: f64,
+}
+
+impl PlanetEvent {
+    /// 行星
+    pub fn get_planet(&self) -> Planet {
+        self.planet.clone()
+    }
+
+    /// 动态类型
+    pub fn get_type(&self) -> PlanetEventType {
+        self.event_type
+    }
+
+    /// 公历时刻（北京时间）
+    pub fn get_solar_time(&self) -> SolarTime {
+        to_solar_time(self.t, UtcOffset::BEIJING)
+    }
+
+    /// 公历时刻（指定UTC偏移）
+    pub fn get_zoned_solar_time(&self, offset: UtcOffset) -> ZonedSolarTime {
+        ZonedSolarTime::new(to_solar_time(self.t, offset), offset)
+    }
+}
+
+impl Culture for PlanetEvent {
+    fn get_name(&self) -> String {
+        format!("{}{}", self.planet, self.event_type)
+    }
+}
+
+impl Display for PlanetEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.get_solar_time(), self.get_name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tyme::astro::planet::{Planet, PlanetEvent, PlanetPosition};
+    use crate::tyme::enums::PlanetEventType;
+    use crate::tyme::error::TymeError;
+    use crate::tyme::solar::{SolarDay, SolarTime};
+    use crate::tyme::zone::{UtcOffset, ZonedSolarTime};
+    use crate::tyme::{Culture, Tyme};
+
+    /// 行星、动态类型、世界时的年月日时分
+    type Fixture = (&'static str, PlanetEventType, [usize; 5]);
+
+    /// 美国海军天文台天象表
+    static EVENTS: [Fixture; 12] = [
+        (
+            "辰星",
+            PlanetEventType::InferiorConjunction,
+            [2022, 1, 23, 11, 0],
+        ),
+        (
+            "辰星",
+            PlanetEventType::StationaryDirect,
+            [2022, 2, 4, 4, 0],
+        ),
+        (
+            "太白",
+            PlanetEventType::StationaryRetrograde,
+            [2023, 7, 23, 1, 33],
+        ),
+        (
+            "太白",
+            PlanetEventType::InferiorConjunction,
+            [2023, 8, 13, 11, 16],
+        ),
+        (
+            "太白",
+            PlanetEventType::StationaryDirect,
+            [2023, 9, 4, 1, 20],
+        ),
+        (
+            "荧惑",
+            PlanetEventType::StationaryRetrograde,
+            [2022, 10, 30, 13, 26],
+        ),
+        ("荧惑", PlanetEventType::Opposition, [2022, 12, 8, 5, 36]),
+        (
+            "荧惑",
+            PlanetEventType::StationaryDirect,
+            [2023, 1, 12, 20, 56],
+        ),
+        (
+            "岁星",
+            PlanetEventType::StationaryRetrograde,
+            [2023, 9, 4, 14, 10],
+        ),
+        ("岁星", PlanetEventType::Opposition, [2023, 11, 3, 5, 3]),
+        (
+            "岁星",
+            PlanetEventType::StationaryDirect,
+            [2023, 12, 31, 2, 40],
+        ),
+        ("镇星", PlanetEventType::Opposition, [2023, 8, 27, 8, 28]),
+    ];
+
+    #[test]
+    fn test0() {
+        for (name, event_type, [y, m, d, h, mi]) in EVENTS {
+            let expected: ZonedSolarTime =
+                ZonedSolarTime::from_ymd_hms(y as isize, m, d, h, mi, 0, UtcOffset::UTC);
+            let day: SolarDay = expected
+                .to_offset(UtcOffset::BEIJING)
+                .get_solar_time()
+                .get_solar_day();
+            let events: Vec<PlanetEvent> = Planet::from_name(name)
+                .get_events(day.next(-1), day.next(1))
+                .unwrap();
+            let e: &PlanetEvent = events.iter().find(|e| e.get_type() == event_type).unwrap();
+            let diff: isize = e
+                .get_zoned_solar_time(UtcOffset::UTC)
+                .subtract(expected)
+                .abs();
+            assert!(diff < 4 * 3600, "{} {}", e, diff);
+        }
+    }
+
+    #[test]
+    fn test1() {
+        // 水星一年约三次逆行，三次上合、三次下合
+        let events: Vec<PlanetEvent> = Planet::from_name("辰星")
+            .get_events(
+                SolarDay::from_ymd(2023, 1, 1),
+                SolarDay::from_ymd(2023, 12, 31),
+            )
+            .unwrap();
+        let count = |t: PlanetEventType| events.iter().filter(|e| e.get_type() == t).count();
+        assert_eq!(4, count(PlanetEventType::InferiorConjunction));
+        assert_eq!(3, count(PlanetEventType::SuperiorConjunction));
+        assert_eq!(0, count(PlanetEventType::Opposition));
+        assert_eq!("2023年1月7日 20:58:02 辰星下合", events[0].to_string());
+        // 外行星没有上合、下合
+        let events: Vec<PlanetEvent> = Planet::from_name("镇星")
+            .get_events(
+                SolarDay::from_ymd(2023, 1, 1),
+                SolarDay::from_ymd(2023, 12, 31),
+            )
+            .unwrap();
+        assert_eq!(
+            vec!["镇星合", "镇星顺留", "镇星冲", "镇星逆留"],
+            events.iter().map(|e| e.get_name()).collect::<Vec<String>>()
+        );
+    }
+
+    #[test]
+    fn test2() {
+        // 2020年12月21日世界时18时20分木土大合
+        let l: Vec<SolarTime> = Planet::from_name("岁星")
+            .get_conjunctions(
+                &Planet::from_name("镇星"),
+                SolarDay::from_ymd(2020, 1, 1),
+                SolarDay::from_ymd(2021, 12, 31),
+            )
+            .unwrap();
+        assert_eq!(1, l.len());
+        assert!(
+            l[0].subtract(SolarTime::from_ymd_hms(2020, 12, 22, 2, 20, 0))
+                .abs()
+                < 86400
+        );
+    }
+
+    #[test]
+    fn test3() {
+        let p: PlanetPosition = Planet::from_name("荧惑")
+            .get_position(SolarTime::from_ymd_hms(2022, 12, 1, 8, 0, 0))
+            .unwrap();
+        // 2022年12月1日火星最近地球
+        assert_eq!("0.545", format!("{:.3}", p.get_distance()));
+        assert_eq!("78.9", format!("{:.1}", p.get_longitude()));
+        assert_eq!("2.0", format!("{:.1}", p.get_latitude()));
+        assert!(p.is_retrograde());
+        let p: PlanetPosition = Planet::from_name("岁星")
+            .get_position(SolarTime::from_ymd_hms(2023, 11, 3, 13, 0, 0))
+            .unwrap();
+        assert_eq!("40.4", format!("{:.1}", p.get_longitude()));
+        assert_eq!("180", format!("{:.0}", p.get_elongation().abs()));
+        assert_eq!("-0.14", format!("{:.2}", p.get_speed()));
+        assert_eq!(
+            format!("{:.6}", p.get_longitude()),
+            format!(
+                "{:.6}",
+                PlanetPosition::from_zoned_solar_time(
+                    p.get_planet(),
+                    ZonedSolarTime::from_ymd_hms(2023, 11, 3, 5, 0, 0, UtcOffset::UTC)
+                )
+                .get_longitude()
+            )
+        );
+        assert_eq!("木", Planet::from_name("岁星").get_element().get_name());
+        assert_eq!("水", Planet::from_index(0).get_element().get_name());
+        assert_eq!("辰星", Planet::from_name("镇星").next(1).get_name());
+        assert!(Planet::from_name("太白").is_inferior());
+        assert!(Planet::try_from_name("金星").is_err());
+    }
+    #[test]
+    fn test4() {
+        // 轨道根数仅适用于1800至2050年
+        let p: Planet = Planet::from_name("岁星");
+        assert_eq!(
+            Err(TymeError::YearOutOfRange {
+                calendar: "planet",
+                year: 2051,
+                min: 1800,
+                max: 2050
+            }),
+            p.get_position(SolarTime::from_ymd_hms(2051, 1, 1, 0, 0, 0))
+                .map(|p| p.get_longitude())
+        );
+        assert!(p
+            .get_position(SolarTime::from_ymd_hms(1799, 12, 31, 23, 59, 59))
+            .is_err());
+        assert!(p
+            .get_position(SolarTime::from_ymd_hms(1800, 1, 1, 0, 0, 0))
+            .is_ok());
+        assert!(p
+            .get_events(
+                SolarDay::from_ymd(2050, 12, 1),
+                SolarDay::from_ymd(2051, 1, 31)
+            )
+            .is_err());
+        assert!(p
+            .get_conjunctions(
+                &Planet::from_name("镇星"),
+                SolarDay::from_ymd(1700, 1, 1),
+                SolarDay::from_ymd(1800, 12, 31)
+            )
+            .is_err());
+        assert!(PlanetPosition::try_from_zoned_solar_time(
+            p,
+            ZonedSolarTime::from_ymd_hms(2051, 1, 1, 0, 0, 0, UtcOffset::UTC)
+        )
+        .is_err());
+    }
+}
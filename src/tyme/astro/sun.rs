@@ -0,0 +1,343 @@
+//! 太阳出没
+use std::fmt::{Display, Formatter};
+
+use crate::tyme::astro::{
+    get_local_midnight, get_sidereal_time, get_sun_equatorial, normalize, to_solar_time,
+};
+use crate::tyme::enums::DaylightType;
+use crate::tyme::solar::{SolarDay, SolarTime};
+use crate::tyme::util::PI_2;
+use crate::tyme::zone::UtcOffset;
+use crate::tyme::Culture;
+
+/// 日出日落时太阳中心的高度（度），含大气折射34'和太阳视半径16'
+static SUNRISE_ALTITUDE: f64 = -50.0 / 60.0;
+
+/// 民用晨昏蒙影的太阳高度（度）
+static CIVIL_TWILIGHT_ALTITUDE: f64 = -6.0;
+
+/// 航海晨昏蒙影的太阳高度（度）
+static NAUTICAL_TWILIGHT_ALTITUDE: f64 = -12.0;
+
+/// 天文晨昏蒙影的太阳高度（度）
+static ASTRONOMICAL_TWILIGHT_ALTITUDE: f64 = -18.0;
+
+/// 太阳时角
+fn get_hour_angle(t: f64, longitude: f64) -> f64 {
+    let (ra, _) = get_sun_equatorial(t);
+    normalize(get_sidereal_time(t) + longitude - ra)
+}
+
+/// 中天（时角为0）的世界时
+fn get_transit(t: f64, longitude: f64) -> f64 {
+    let mut t: f64 = t;
+    for _ in 0..3 {
+        t -= get_hour_angle(t, longitude) / PI_2;
+    }
+    t
+}
+
+/// 中天前后太阳到达指定高度（度）的世界时，不能到达时为空
+fn get_altitude_time(
+    transit: f64,
+    latitude: f64,
+    longitude: f64,
+    altitude: f64,
+    rising: bool,
+) -> Option<f64> {
+    let (sin_h, sin_lat, cos_lat) = (altitude.to_radians().sin(), latitude.sin(), latitude.cos());
+    let mut t: f64 = transit;
+    for _ in 0..5 {
+        let (_, dec) = get_sun_equatorial(t);
+        let cos_h0: f64 = (sin_h - sin_lat * dec.sin()) / (cos_lat * dec.cos());
+        if !(-1.0..=1.0).contains(&cos_h0) {
+            return None;
+        }
+        let h0: f64 = cos_h0.acos();
+        let target: f64 = if rising { -h0 } else { h0 };
+        t += normalize(target - get_hour_angle(t, longitude)) / PI_2;
+    }
+    Some(t)
+}
+
+/// 日出日落、晨昏蒙影及昼长
+///
+/// 日出日落以太阳上边缘与地平线相切计（中心高度-50'），晨昏蒙影以太阳中心高度-6°（民用）、-12°（航海）、-18°（天文）计。
+/// 极昼、极夜时没有日出日落，但仍可能有晨昏蒙影。
+#[derive(Debug, Copy, Clone)]
+pub struct SunEvents {
+    solar_day: SolarDay,
+    latitude: f64,
+    longitude: f64,
+    offset: UtcOffset,
+    daylight_type: DaylightType,
+    transit: SolarTime,
+    sunrise: Option<SolarTime>,
+    sunset: Option<SolarTime>,
+    civil_dawn: Option<SolarTime>,
+    civil_dusk: Option<SolarTime>,
+    nautical_dawn: Option<SolarTime>,
+    nautical_dusk: Option<SolarTime>,
+    astronomical_dawn: Option<SolarTime>,
+    astronomical_dusk: Option<SolarTime>,
+    day_length: usize,
+}
+
+impl SunEvents {
+    /// 公历日、纬度（度，北纬为正）、经度（度，东经为正）、时间的UTC偏移
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::astro::sun::SunEvents;
+    /// use tyme4rs::tyme::solar::SolarDay;
+    /// use tyme4rs::tyme::zone::UtcOffset;
+    ///
+    /// // 北京夏至
+    /// let e: SunEvents = SunEvents::from_solar_day(SolarDay::from_ymd(2024, 6, 21), 39.9042, 116.4074, UtcOffset::BEIJING);
+    /// assert_eq!("2024年6月21日 04:46:02", e.get_sunrise().unwrap().to_string());
+    /// ```
+    pub fn from_solar_day(
+        solar_day: SolarDay,
+        latitude: f64,
+        longitude: f64,
+        offset: UtcOffset,
+    ) -> Self {
+        let lat: f64 = latitude.to_radians();
+        let lon: f64 = longitude.to_radians();
+        let transit: f64 = get_transit(
+            get_local_midnight(solar_day.get_julian_day(), offset) + 0.5,
+            lon,
+        );
+        let event = |altitude: f64, rising: bool| -> Option<SolarTime> {
+            get_altitude_time(transit, lat, lon, altitude, rising).map(|t| to_solar_time(t, offset))
+        };
+        let sunrise: Option<SolarTime> = event(SUNRISE_ALTITUDE, true);
+        let sunset: Option<SolarTime> = event(SUNRISE_ALTITUDE, false);
+
+        // 以中天时的赤纬判断极昼极夜
+        let (_, dec) = get_sun_equatorial(transit);
+        let daylight_type: DaylightType = match (sunrise, sunset) {
+            (Some(_), Some(_)) => DaylightType::Normal,
+            _ if 90.0 - (latitude + dec.to_degrees()).abs() < -SUNRISE_ALTITUDE => {
+                DaylightType::PolarDay
+            }
+            _ if 90.0 - (latitude - dec.to_degrees()).abs() < SUNRISE_ALTITUDE => {
+                DaylightType::PolarNight
+            }
+            _ => DaylightType::Normal,
+        };
+        let day_length: usize = match (sunrise, sunset) {
+            (Some(a), Some(b)) => b.subtract(a).max(0) as usize,
+            _ if DaylightType::PolarDay == daylight_type => 86400,
+            _ => 0,
+        };
+        Self {
+            solar_day,
+            latitude,
+            longitude,
+            offset,
+            daylight_type,
+            transit: to_solar_time(transit, offset),
+            sunrise,
+            sunset,
+            civil_dawn: event(CIVIL_TWILIGHT_ALTITUDE, true),
+            civil_dusk: event(CIVIL_TWILIGHT_ALTITUDE, false),
+            nautical_dawn: event(NAUTICAL_TWILIGHT_ALTITUDE, true),
+            nautical_dusk: event(NAUTICAL_TWILIGHT_ALTITUDE, false),
+            astronomical_dawn: event(ASTRONOMICAL_TWILIGHT_ALTITUDE, true),
+            astronomical_dusk: event(ASTRONOMICAL_TWILIGHT_ALTITUDE, false),
+            day_length,
+        }
+    }
+
+    /// 公历日
+    pub fn get_solar_day(&self) -> SolarDay {
+        self.solar_day
+    }
+
+    /// 纬度（度）
+    pub fn get_latitude(&self) -> f64 {
+        self.latitude
+    }
+
+    /// 经度（度）
+    pub fn get_longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    /// 时间的UTC偏移
+    pub fn get_offset(&self) -> UtcOffset {
+        self.offset
+    }
+
+    /// 日照类型
+    pub fn get_daylight_type(&self) -> DaylightType {
+        self.daylight_type
+    }
+
+    /// 太阳中天
+    pub fn get_transit(&self) -> SolarTime {
+        self.transit
+    }
+
+    /// 日出
+    pub fn get_sunrise(&self) -> Option<SolarTime> {
+        self.sunrise
+    }
+
+    /// 日落
+    pub fn get_sunset(&self) -> Option<SolarTime> {
+        self.sunset
+    }
+
+    /// 民用晨光始
+    pub fn get_civil_dawn(&self) -> Option<SolarTime> {
+        self.civil_dawn
+    }
+
+    /// 民用昏影终
+    pub fn get_civil_dusk(&self) -> Option<SolarTime> {
+        self.civil_dusk
+    }
+
+    /// 航海晨光始
+    pub fn get_nautical_dawn(&self) -> Option<SolarTime> {
+        self.nautical_dawn
+    }
+
+    /// 航海昏影终
+    pub fn get_nautical_dusk(&self) -> Option<SolarTime> {
+        self.nautical_dusk
+    }
+
+    /// 天文晨光始
+    pub fn get_astronomical_dawn(&self) -> Option<SolarTime> {
+        self.astronomical_dawn
+    }
+
+    /// 天文昏影终
+    pub fn get_astronomical_dusk(&self) -> Option<SolarTime> {
+        self.astronomical_dusk
+    }
+
+    /// 昼长（秒），极昼为86400，极夜为0
+    pub fn get_day_length(&self) -> usize {
+        self.day_length
+    }
+}
+
+impl Display for SunEvents {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = |t: Option<SolarTime>| t.map_or("--".to_string(), |t| t.get_name());
+        write!(
+            f,
+            "{} 日出{} 日落{} 中天{}",
+            self.solar_day,
+            name(self.sunrise),
+            name(self.sunset),
+            self.transit.get_name()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tyme::astro::sun::SunEvents;
+    use crate::tyme::enums::DaylightType;
+    use crate::tyme::solar::{SolarDay, SolarTime};
+    use crate::tyme::zone::UtcOffset;
+    use crate::tyme::Culture;
+
+    fn name(t: Option<SolarTime>) -> String {
+        t.unwrap().get_name()
+    }
+
+    #[test]
+    fn test0() {
+        // 北京
+        let e: SunEvents =
+            SolarDay::from_ymd(2024, 6, 21).get_sun_events(39.9042, 116.4074, UtcOffset::BEIJING);
+        assert_eq!(DaylightType::Normal, e.get_daylight_type());
+        assert_eq!("04:46:02", name(e.get_sunrise()));
+        assert_eq!("19:46:25", name(e.get_sunset()));
+        assert_eq!("12:16:13", e.get_transit().get_name());
+        assert_eq!("04:13:13", name(e.get_civil_dawn()));
+        assert_eq!("20:19:14", name(e.get_civil_dusk()));
+        assert_eq!("03:31:34", name(e.get_nautical_dawn()));
+        assert_eq!("21:00:52", name(e.get_nautical_dusk()));
+        assert_eq!("02:43:00", name(e.get_astronomical_dawn()));
+        assert_eq!("21:49:26", name(e.get_astronomical_dusk()));
+        assert_eq!(54023, e.get_day_length());
+
+        let e: SunEvents =
+            SolarDay::from_ymd(2024, 12, 21).get_sun_events(39.9042, 116.4074, UtcOffset::BEIJING);
+        assert_eq!(
+            "2024年12月21日 日出07:32:22 日落16:52:38 中天12:12:30",
+            e.to_string()
+        );
+        assert_eq!(33616, e.get_day_length());
+    }
+
+    #[test]
+    fn test1() {
+        // 格林尼治春分、纽约
+        let e: SunEvents =
+            SolarDay::from_ymd(2024, 3, 20).get_sun_events(51.4779, 0.0, UtcOffset::UTC);
+        assert_eq!("06:01:48", name(e.get_sunrise()));
+        assert_eq!("18:13:51", name(e.get_sunset()));
+        let e: SunEvents = SolarDay::from_ymd(2024, 1, 15).get_sun_events(
+            40.7128,
+            -74.006,
+            UtcOffset::from_hm(-5, 0),
+        );
+        assert_eq!("07:18:06", name(e.get_sunrise()));
+        assert_eq!("16:52:51", name(e.get_sunset()));
+    }
+
+    #[test]
+    fn test2() {
+        // 特罗姆瑟：夏至极昼，冬至极夜但有民用晨昏蒙影
+        let e: SunEvents = SolarDay::from_ymd(2024, 6, 21).get_sun_events(
+            69.6492,
+            18.9553,
+            UtcOffset::from_hm(2, 0),
+        );
+        assert_eq!(DaylightType::PolarDay, e.get_daylight_type());
+        assert_eq!(None, e.get_sunrise());
+        assert_eq!(None, e.get_civil_dusk());
+        assert_eq!(86400, e.get_day_length());
+        let e: SunEvents = SolarDay::from_ymd(2024, 12, 21).get_sun_events(
+            69.6492,
+            18.9553,
+            UtcOffset::from_hm(1, 0),
+        );
+        assert_eq!(DaylightType::PolarNight, e.get_daylight_type());
+        assert_eq!(None, e.get_sunset());
+        assert_eq!(0, e.get_day_length());
+        assert_eq!("09:31:29", name(e.get_civil_dawn()));
+        assert_eq!("13:53:25", name(e.get_civil_dusk()));
+        assert_eq!("2024年12月21日 日出-- 日落-- 中天11:42:27", e.to_string());
+    }
+
+    #[test]
+    fn test3() {
+        // 南极麦克默多站冬至只有航海晨昏蒙影
+        let e: SunEvents = SolarDay::from_ymd(2024, 6, 21).get_sun_events(
+            -77.85,
+            166.67,
+            UtcOffset::from_hm(12, 0),
+        );
+        assert_eq!(DaylightType::PolarNight, e.get_daylight_type());
+        assert_eq!(None, e.get_civil_dawn());
+        assert_eq!("11:33:22", name(e.get_nautical_dawn()));
+        // 北极点
+        let e: SunEvents =
+            SolarDay::from_ymd(2024, 6, 21).get_sun_events(90.0, 0.0, UtcOffset::UTC);
+        assert_eq!(DaylightType::PolarDay, e.get_daylight_type());
+        let e: SunEvents =
+            SolarDay::from_ymd(2024, 12, 21).get_sun_events(90.0, 0.0, UtcOffset::UTC);
+        assert_eq!(DaylightType::PolarNight, e.get_daylight_type());
+        assert_eq!(None, e.get_astronomical_dawn());
+    }
+}
@@ -1,3 +1,4 @@
+use crate::tyme::error::TymeError;
 use crate::tyme::{AbstractCulture, AbstractCultureDay, AbstractTyme, Culture, LoopTyme, Tyme};
 use std::fmt::{Display, Formatter};
 use std::ops::{Deref, DerefMut};
@@ -47,12 +48,16 @@ impl Dog {
     }
 
     pub fn from_name(name: &str) -> Self {
-        Self {
-            parent: LoopTyme::from_name(
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
                 DOG_NAMES.to_vec().iter().map(|x| x.to_string()).collect(),
                 name,
-            ),
-        }
+            )?,
+        })
     }
 }
 
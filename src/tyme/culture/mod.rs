@@ -2,11 +2,13 @@ use regex::Regex;
 use std::fmt::{Display, Formatter};
 use std::ops::{Deref, DerefMut};
 
+use crate::tyme::error::TymeError;
 use crate::tyme::jd::{JulianDay, J2000};
 use crate::tyme::lunar::{LunarDay, LunarMonth};
 use crate::tyme::sixtycycle::SixtyCycle;
 use crate::tyme::solar::{SolarDay, SolarTime};
 use crate::tyme::util::{ShouXingUtil, ONE_THIRD, PI_2};
+use crate::tyme::zone::{UtcOffset, ZonedSolarTime};
 use crate::tyme::{AbstractCulture, AbstractCultureDay, AbstractTyme, Culture, LoopTyme, Tyme};
 
 pub static ANIMAL_NAMES: [&str; 28] = [
@@ -61,16 +63,20 @@ impl Animal {
     }
 
     pub fn from_name(name: &str) -> Self {
-        Self {
-            parent: LoopTyme::from_name(
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
                 ANIMAL_NAMES
                     .to_vec()
                     .iter()
                     .map(|x| x.to_string())
                     .collect(),
                 name,
-            ),
-        }
+            )?,
+        })
     }
 }
 
@@ -139,12 +145,16 @@ impl Beast {
     }
 
     pub fn from_name(name: &str) -> Self {
-        Self {
-            parent: LoopTyme::from_name(
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
                 BEAST_NAMES.to_vec().iter().map(|x| x.to_string()).collect(),
                 name,
-            ),
-        }
+            )?,
+        })
     }
 }
 
@@ -219,16 +229,20 @@ impl Constellation {
     }
 
     pub fn from_name(name: &str) -> Self {
-        Self {
-            parent: LoopTyme::from_name(
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
                 CONSTELLATION_NAMES
                     .to_vec()
                     .iter()
                     .map(|x| x.to_string())
                     .collect(),
                 name,
-            ),
-        }
+            )?,
+        })
     }
 }
 
@@ -302,16 +316,20 @@ impl Direction {
     }
 
     pub fn from_name(name: &str) -> Self {
-        Self {
-            parent: LoopTyme::from_name(
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
                 DIRECTION_NAMES
                     .to_vec()
                     .iter()
                     .map(|x| x.to_string())
                     .collect(),
                 name,
-            ),
-        }
+            )?,
+        })
     }
 
     /// 五行
@@ -387,12 +405,16 @@ impl Duty {
     }
 
     pub fn from_name(name: &str) -> Self {
-        Self {
-            parent: LoopTyme::from_name(
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
                 DUTY_NAMES.to_vec().iter().map(|x| x.to_string()).collect(),
                 name,
-            ),
-        }
+            )?,
+        })
     }
 }
 
@@ -465,16 +487,20 @@ impl Element {
     }
 
     pub fn from_name(name: &str) -> Self {
-        Self {
-            parent: LoopTyme::from_name(
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
                 ELEMENT_NAMES
                     .to_vec()
                     .iter()
                     .map(|x| x.to_string())
                     .collect(),
                 name,
-            ),
-        }
+            )?,
+        })
     }
 
     /// 我生者
@@ -734,12 +760,16 @@ impl God {
     }
 
     pub fn from_name(name: &str) -> Self {
-        Self {
-            parent: LoopTyme::from_name(
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
                 GOD_NAMES.to_vec().iter().map(|x| x.to_string()).collect(),
                 name,
-            ),
-        }
+            )?,
+        })
     }
 
     pub fn get_luck(&self) -> Luck {
@@ -827,12 +857,16 @@ impl Land {
     }
 
     pub fn from_name(name: &str) -> Self {
-        Self {
-            parent: LoopTyme::from_name(
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
                 LAND_NAMES.to_vec().iter().map(|x| x.to_string()).collect(),
                 name,
-            ),
-        }
+            )?,
+        })
     }
 
     /// 方位
@@ -906,12 +940,16 @@ impl Luck {
     }
 
     pub fn from_name(name: &str) -> Self {
-        Self {
-            parent: LoopTyme::from_name(
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
                 LUCK_NAMES.to_vec().iter().map(|x| x.to_string()).collect(),
                 name,
-            ),
-        }
+            )?,
+        })
     }
 }
 
@@ -970,21 +1008,7 @@ impl DerefMut for Phase {
 
 impl Tyme for Phase {
     fn next(&self, n: isize) -> Self {
-        let size: isize = self.get_size() as isize;
-        let mut i: isize = self.get_index() as isize + n;
-        if i < 0 {
-            i -= size;
-        }
-        i /= size;
-        let mut m: LunarMonth = LunarMonth::from_ym(self.lunar_year, self.lunar_month);
-        if i != 0 {
-            m = m.next(i);
-        }
-        Self::from_index(
-            m.get_year(),
-            m.get_month_with_leap(),
-            self.parent.next_index(n) as isize,
-        )
+        self.try_next(n).unwrap()
     }
 }
 
@@ -996,27 +1020,73 @@ impl Culture for Phase {
 
 impl Phase {
     pub fn from_index(lunar_year: isize, lunar_month: isize, index: isize) -> Self {
-        let m: LunarMonth =
-            LunarMonth::from_ym(lunar_year, lunar_month).next(index / PHASE_NAMES.len() as isize);
-        Self {
+        Self::try_from_index(lunar_year, lunar_month, index).unwrap()
+    }
+
+    pub fn try_from_index(
+        lunar_year: isize,
+        lunar_month: isize,
+        index: isize,
+    ) -> Result<Self, TymeError> {
+        let m: LunarMonth = LunarMonth::new(lunar_year, lunar_month)?
+            .try_next(index / PHASE_NAMES.len() as isize)?;
+        Ok(Self {
             parent: LoopTyme::from_index(
                 PHASE_NAMES.to_vec().iter().map(|x| x.to_string()).collect(),
                 index,
             ),
             lunar_year: m.get_year(),
             lunar_month: m.get_month_with_leap(),
-        }
+        })
     }
 
     pub fn from_name(lunar_year: isize, lunar_month: isize, name: &str) -> Self {
-        Self {
-            parent: LoopTyme::from_name(
+        Self::try_from_name(lunar_year, lunar_month, name).unwrap()
+    }
+
+    pub fn try_from_name(
+        lunar_year: isize,
+        lunar_month: isize,
+        name: &str,
+    ) -> Result<Self, TymeError> {
+        LunarMonth::validate(lunar_year, lunar_month)?;
+        Ok(Self {
+            parent: LoopTyme::new(
                 PHASE_NAMES.to_vec().iter().map(|x| x.to_string()).collect(),
                 name,
-            ),
+            )?,
             lunar_year,
             lunar_month,
+        })
+    }
+
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        let size: isize = self.get_size() as isize;
+        let mut i: isize = self.get_index() as isize + n;
+        if i < 0 {
+            i -= size;
         }
+        i /= size;
+        let mut m: LunarMonth = LunarMonth::new(self.lunar_year, self.lunar_month)?;
+        if i != 0 {
+            m = m.try_next(i)?;
+        }
+        Self::try_from_index(
+            m.get_year(),
+            m.get_month_with_leap(),
+            self.parent.next_index(n) as isize,
+        )
+    }
+
+    /// 农历年
+    pub fn get_lunar_year(&self) -> isize {
+        self.lunar_year
+    }
+
+    /// 农历月，闰月为负数
+    pub fn get_lunar_month(&self) -> isize {
+        self.lunar_month
     }
 
     fn get_start_solar_time(&self) -> SolarTime {
@@ -1049,6 +1119,11 @@ impl Phase {
         }
     }
 
+    /// 指定UTC偏移的月相时刻
+    pub fn get_zoned_solar_time(&self, offset: UtcOffset) -> ZonedSolarTime {
+        ZonedSolarTime::new(self.get_solar_time(), UtcOffset::BEIJING).to_offset(offset)
+    }
+
     pub fn get_solar_day(&self) -> SolarDay {
         let d: SolarDay = self.get_start_solar_time().get_solar_day();
         if self.get_index() % 2 == 1 {
@@ -1187,12 +1262,16 @@ impl Sixty {
     }
 
     pub fn from_name(name: &str) -> Self {
-        Self {
-            parent: LoopTyme::from_name(
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
                 SIXTY_NAMES.to_vec().iter().map(|x| x.to_string()).collect(),
                 name,
-            ),
-        }
+            )?,
+        })
     }
 }
 
@@ -1292,12 +1371,16 @@ impl Sound {
     }
 
     pub fn from_name(name: &str) -> Self {
-        Self {
-            parent: LoopTyme::from_name(
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
                 SOUND_NAMES.to_vec().iter().map(|x| x.to_string()).collect(),
                 name,
-            ),
-        }
+            )?,
+        })
     }
 }
 
@@ -1535,12 +1618,16 @@ impl Taboo {
     }
 
     pub fn from_name(name: &str) -> Self {
-        Self {
-            parent: LoopTyme::from_name(
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
                 TABOO_NAMES.to_vec().iter().map(|x| x.to_string()).collect(),
                 name,
-            ),
-        }
+            )?,
+        })
     }
 
     fn get_taboos(data: [&str; 12], sup_index: usize, sub_index: usize, index: usize) -> Vec<Self> {
@@ -1657,12 +1744,16 @@ impl Ten {
     }
 
     pub fn from_name(name: &str) -> Self {
-        Self {
-            parent: LoopTyme::from_name(
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
                 TEN_NAMES.to_vec().iter().map(|x| x.to_string()).collect(),
                 name,
-            ),
-        }
+            )?,
+        })
     }
 }
 
@@ -1737,16 +1828,20 @@ impl Terrain {
     }
 
     pub fn from_name(name: &str) -> Self {
-        Self {
-            parent: LoopTyme::from_name(
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
                 TERRAIN_NAMES
                     .to_vec()
                     .iter()
                     .map(|x| x.to_string())
                     .collect(),
                 name,
-            ),
-        }
+            )?,
+        })
     }
 }
 
@@ -1821,16 +1916,20 @@ impl Twenty {
     }
 
     pub fn from_name(name: &str) -> Self {
-        Self {
-            parent: LoopTyme::from_name(
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
                 TWENTY_NAMES
                     .to_vec()
                     .iter()
                     .map(|x| x.to_string())
                     .collect(),
                 name,
-            ),
-        }
+            )?,
+        })
     }
 
     pub fn get_sixty(&self) -> Sixty {
@@ -1903,12 +2002,16 @@ impl Week {
     }
 
     pub fn from_name(name: &str) -> Self {
-        Self {
-            parent: LoopTyme::from_name(
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
                 WEEK_NAMES.to_vec().iter().map(|x| x.to_string()).collect(),
                 name,
-            ),
-        }
+            )?,
+        })
     }
 }
 
@@ -1936,6 +2039,11 @@ pub static ZODIAC_NAMES: [&str; 12] = [
     "鼠", "牛", "虎", "兔", "龙", "蛇", "马", "羊", "猴", "鸡", "狗", "猪",
 ];
 
+/// 越南生肖名称，以猫（Mèo）代替兔
+pub static VIETNAMESE_ZODIAC_NAMES: [&str; 12] = [
+    "Chuột", "Trâu", "Hổ", "Mèo", "Rồng", "Rắn", "Ngựa", "Dê", "Khỉ", "Gà", "Chó", "Lợn",
+];
+
 /// 生肖
 #[derive(Debug, Clone)]
 pub struct Zodiac {
@@ -1983,16 +2091,33 @@ impl Zodiac {
     }
 
     pub fn from_name(name: &str) -> Self {
-        Self {
-            parent: LoopTyme::from_name(
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
                 ZODIAC_NAMES
                     .to_vec()
                     .iter()
                     .map(|x| x.to_string())
                     .collect(),
                 name,
-            ),
-        }
+            )?,
+        })
+    }
+
+    /// 越南名称
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::culture::Zodiac;
+    ///
+    /// assert_eq!("Mèo", Zodiac::from_name("兔").get_vietnamese_name());
+    /// ```
+    pub fn get_vietnamese_name(&self) -> String {
+        VIETNAMESE_ZODIAC_NAMES[self.get_index()].to_string()
     }
 }
 
@@ -2061,12 +2186,16 @@ impl Zone {
     }
 
     pub fn from_name(name: &str) -> Self {
-        Self {
-            parent: LoopTyme::from_name(
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
                 ZONE_NAMES.to_vec().iter().map(|x| x.to_string()).collect(),
                 name,
-            ),
-        }
+            )?,
+        })
     }
 
     pub fn get_direction(&self) -> Direction {
@@ -2124,6 +2253,20 @@ impl KitchenGodSteed {
         Self::new(lunar_year)
     }
 
+    /// 由正月初一干支创建
+    #[cfg(feature = "serde")]
+    pub(crate) fn from_first_day_sixty_cycle(first_day_sixty_cycle: SixtyCycle) -> Self {
+        Self {
+            first_day_sixty_cycle,
+        }
+    }
+
+    /// 正月初一干支
+    #[cfg(feature = "serde")]
+    pub(crate) fn get_first_day_sixty_cycle(&self) -> SixtyCycle {
+        self.first_day_sixty_cycle.clone()
+    }
+
     fn by_heaven_stem(&self, n: isize) -> &str {
         let parent: LoopTyme = self.first_day_sixty_cycle.get_heaven_stem().into();
         NUMBERS[parent.steps_to(n)]
@@ -2209,6 +2352,7 @@ impl Eq for KitchenGodSteed {}
 pub mod dog;
 pub mod fetus;
 pub mod nine;
+pub mod panchang;
 pub mod peng_zu;
 pub mod phenology;
 pub mod plumrain;
@@ -2219,11 +2363,11 @@ pub mod star;
 mod tests {
     use crate::tyme::culture::dog::DogDay;
     use crate::tyme::culture::{
-        Animal, Beast, Constellation, Direction, Duty, Element, God, Land, Luck, Taboo,
+        Animal, Beast, Constellation, Direction, Duty, Element, God, Land, Luck, Taboo, Zodiac,
     };
     use crate::tyme::sixtycycle::{EarthBranch, HeavenStem};
     use crate::tyme::solar::{SolarDay, SolarTime};
-    use crate::tyme::Culture;
+    use crate::tyme::{Culture, Tyme};
 
     #[test]
     fn test1() {
@@ -2449,13 +2593,13 @@ mod tests {
     #[test]
     fn test26() {
         let d: Option<DogDay> = SolarDay::from_ymd(2011, 7, 13).get_dog_day();
-        assert_eq!(true, d.is_none());
+        assert!(d.is_none());
     }
 
     #[test]
     fn test27() {
         let d: Option<DogDay> = SolarDay::from_ymd(2011, 8, 23).get_dog_day();
-        assert_eq!(true, d.is_none());
+        assert!(d.is_none());
     }
 
     #[test]
@@ -2597,4 +2741,12 @@ mod tests {
             l
         );
     }
+
+    #[test]
+    fn test42() {
+        let zodiac: Zodiac = Zodiac::from_index(3);
+        assert_eq!("兔", zodiac.get_name());
+        assert_eq!("Mèo", zodiac.get_vietnamese_name());
+        assert_eq!("Lợn", zodiac.next(-4).get_vietnamese_name());
+    }
 }
@@ -1,3 +1,4 @@
+use crate::tyme::error::TymeError;
 use crate::tyme::{AbstractCulture, AbstractCultureDay, AbstractTyme, Culture, LoopTyme, Tyme};
 use std::fmt::{Display, Formatter};
 use std::ops::{Deref, DerefMut};
@@ -50,12 +51,16 @@ impl Nine {
     }
 
     pub fn from_name(name: &str) -> Self {
-        Self {
-            parent: LoopTyme::from_name(
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
                 NINE_NAMES.to_vec().iter().map(|x| x.to_string()).collect(),
                 name,
-            ),
-        }
+            )?,
+        })
     }
 }
 
@@ -200,6 +205,6 @@ mod tests {
     #[test]
     fn test6() {
         let d: Option<NineDay> = SolarDay::from_ymd(2021, 7, 5).get_nine_day();
-        assert_eq!(true, d.is_none());
+        assert!(d.is_none());
     }
 }
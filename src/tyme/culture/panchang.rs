@@ -0,0 +1,425 @@
+use crate::tyme::culture::star::twenty_eight::TwentyEightStar;
+use crate::tyme::error::TymeError;
+use crate::tyme::{Culture, LoopTyme, Tyme};
+use std::fmt::{Display, Formatter};
+use std::ops::{Deref, DerefMut};
+
+/// 二十七宿（从娄宿起，即Aśvinī）
+pub static NAKSHATRA_NAMES: [&str; 27] = [
+    "娄", "胃", "昴", "毕", "觜", "参", "井", "鬼", "柳", "星", "张", "翼", "轸", "角", "亢", "氐",
+    "房", "心", "尾", "箕", "斗", "女", "虚", "危", "室", "壁", "奎",
+];
+
+/// 二十七宿的IAST转写名称
+pub static NAKSHATRA_IAST_NAMES: [&str; 27] = [
+    "Aśvinī",
+    "Bharaṇī",
+    "Kṛttikā",
+    "Rohiṇī",
+    "Mṛgaśīrṣa",
+    "Ārdrā",
+    "Punarvasu",
+    "Puṣya",
+    "Āśleṣā",
+    "Maghā",
+    "Pūrva Phalgunī",
+    "Uttara Phalgunī",
+    "Hasta",
+    "Citrā",
+    "Svātī",
+    "Viśākhā",
+    "Anurādhā",
+    "Jyeṣṭhā",
+    "Mūla",
+    "Pūrva Āṣāḍhā",
+    "Uttara Āṣāḍhā",
+    "Śravaṇa",
+    "Dhaniṣṭhā",
+    "Śatabhiṣā",
+    "Pūrva Bhādrapadā",
+    "Uttara Bhādrapadā",
+    "Revatī",
+];
+
+/// 日月合宿名称（从支柱起，即Viṣkambha）
+pub static YOGA_NAMES: [&str; 27] = [
+    "支柱", "喜爱", "长寿", "好运", "光彩", "大险", "善业", "坚定", "尖刺", "险阻", "增长", "恒常",
+    "打击", "欢喜", "金刚", "成就", "灾祸", "安乐", "门闩", "吉祥", "圆成", "可成", "善妙", "白净",
+    "梵天", "帝释", "离持",
+];
+
+/// 日月合宿的IAST转写名称
+pub static YOGA_IAST_NAMES: [&str; 27] = [
+    "Viṣkambha",
+    "Prīti",
+    "Āyuṣmān",
+    "Saubhāgya",
+    "Śobhana",
+    "Atigaṇḍa",
+    "Sukarman",
+    "Dhṛti",
+    "Śūla",
+    "Gaṇḍa",
+    "Vṛddhi",
+    "Dhruva",
+    "Vyāghāta",
+    "Harṣaṇa",
+    "Vajra",
+    "Siddhi",
+    "Vyatīpāta",
+    "Varīyas",
+    "Parigha",
+    "Śiva",
+    "Siddha",
+    "Sādhya",
+    "Śubha",
+    "Śukla",
+    "Brahman",
+    "Indra",
+    "Vaidhṛti",
+];
+
+/// 卡拉纳名称，前7个为循环的动卡拉纳，后4个为固定卡拉纳
+pub static KARANA_NAMES: [&str; 11] = [
+    "婆婆",
+    "婆罗婆",
+    "憍罗婆",
+    "泰底罗",
+    "伽罗",
+    "伐尼阇",
+    "毗湿底",
+    "舍鸠尼",
+    "四足",
+    "那伽",
+    "紧斯图那",
+];
+
+/// 卡拉纳的IAST转写名称
+pub static KARANA_IAST_NAMES: [&str; 11] = [
+    "Bava",
+    "Bālava",
+    "Kaulava",
+    "Taitila",
+    "Gara",
+    "Vaṇija",
+    "Viṣṭi",
+    "Śakuni",
+    "Catuṣpada",
+    "Nāga",
+    "Kiṃstughna",
+];
+
+/// 二十七宿（月宿）
+#[derive(Debug, Clone)]
+pub struct Nakshatra {
+    parent: LoopTyme,
+}
+
+impl Deref for Nakshatra {
+    type Target = LoopTyme;
+
+    fn deref(&self) -> &Self::Target {
+        &self.parent
+    }
+}
+
+impl DerefMut for Nakshatra {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.parent
+    }
+}
+
+impl Tyme for Nakshatra {
+    fn next(&self, n: isize) -> Self {
+        Self::from_index(self.parent.next_index(n) as isize)
+    }
+}
+
+impl Culture for Nakshatra {
+    fn get_name(&self) -> String {
+        self.parent.get_name()
+    }
+}
+
+impl Nakshatra {
+    pub fn from_index(index: isize) -> Self {
+        Self {
+            parent: LoopTyme::from_index(
+                NAKSHATRA_NAMES
+                    .to_vec()
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect(),
+                index,
+            ),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Self {
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
+                NAKSHATRA_NAMES
+                    .to_vec()
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect(),
+                name,
+            )?,
+        })
+    }
+
+    /// 对应的二十八宿（二十七宿不含牛宿）
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::culture::panchang::Nakshatra;
+    /// use tyme4rs::tyme::Culture;
+    ///
+    /// assert_eq!("娄", Nakshatra::from_index(0).get_twenty_eight_star().get_name());
+    /// ```
+    pub fn get_twenty_eight_star(&self) -> TwentyEightStar {
+        TwentyEightStar::from_name(&self.get_name())
+    }
+
+    /// IAST转写名称
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::culture::panchang::Nakshatra;
+    ///
+    /// assert_eq!("Aśvinī", Nakshatra::from_name("娄").get_iast_name());
+    /// ```
+    pub fn get_iast_name(&self) -> String {
+        NAKSHATRA_IAST_NAMES[self.get_index()].to_string()
+    }
+}
+
+impl Display for Nakshatra {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.get_name())
+    }
+}
+
+impl PartialEq for Nakshatra {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for Nakshatra {}
+
+impl From<Nakshatra> for LoopTyme {
+    fn from(val: Nakshatra) -> Self {
+        val.parent
+    }
+}
+
+/// 日月合宿（Yoga，藏历称sbyor ba）
+#[derive(Debug, Clone)]
+pub struct Yoga {
+    parent: LoopTyme,
+}
+
+impl Deref for Yoga {
+    type Target = LoopTyme;
+
+    fn deref(&self) -> &Self::Target {
+        &self.parent
+    }
+}
+
+impl DerefMut for Yoga {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.parent
+    }
+}
+
+impl Tyme for Yoga {
+    fn next(&self, n: isize) -> Self {
+        Self::from_index(self.parent.next_index(n) as isize)
+    }
+}
+
+impl Culture for Yoga {
+    fn get_name(&self) -> String {
+        self.parent.get_name()
+    }
+}
+
+impl Yoga {
+    pub fn from_index(index: isize) -> Self {
+        Self {
+            parent: LoopTyme::from_index(
+                YOGA_NAMES.to_vec().iter().map(|x| x.to_string()).collect(),
+                index,
+            ),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Self {
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
+                YOGA_NAMES.to_vec().iter().map(|x| x.to_string()).collect(),
+                name,
+            )?,
+        })
+    }
+
+    /// IAST转写名称
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::culture::panchang::Yoga;
+    ///
+    /// assert_eq!("Siddhi", Yoga::from_name("成就").get_iast_name());
+    /// ```
+    pub fn get_iast_name(&self) -> String {
+        YOGA_IAST_NAMES[self.get_index()].to_string()
+    }
+}
+
+impl Display for Yoga {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.get_name())
+    }
+}
+
+impl PartialEq for Yoga {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for Yoga {}
+
+impl From<Yoga> for LoopTyme {
+    fn from(val: Yoga) -> Self {
+        val.parent
+    }
+}
+
+/// 半日（Karana，藏历称byed pa）
+#[derive(Debug, Clone)]
+pub struct Karana {
+    parent: LoopTyme,
+}
+
+impl Deref for Karana {
+    type Target = LoopTyme;
+
+    fn deref(&self) -> &Self::Target {
+        &self.parent
+    }
+}
+
+impl DerefMut for Karana {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.parent
+    }
+}
+
+impl Tyme for Karana {
+    fn next(&self, n: isize) -> Self {
+        Self::from_index(self.parent.next_index(n) as isize)
+    }
+}
+
+impl Culture for Karana {
+    fn get_name(&self) -> String {
+        self.parent.get_name()
+    }
+}
+
+impl Karana {
+    pub fn from_index(index: isize) -> Self {
+        Self {
+            parent: LoopTyme::from_index(
+                KARANA_NAMES
+                    .to_vec()
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect(),
+                index,
+            ),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Self {
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
+                KARANA_NAMES
+                    .to_vec()
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect(),
+                name,
+            )?,
+        })
+    }
+
+    /// 从朔望月中的半日序号（0-59）创建，0为紧斯图那，57-59为其余固定卡拉纳
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::culture::panchang::Karana;
+    /// use tyme4rs::tyme::Culture;
+    ///
+    /// assert_eq!("紧斯图那", Karana::from_half_day(0).get_name());
+    /// assert_eq!("婆婆", Karana::from_half_day(1).get_name());
+    /// assert_eq!("Nāga", Karana::from_half_day(59).get_iast_name());
+    /// ```
+    pub fn from_half_day(index: usize) -> Self {
+        let i: usize = index % 60;
+        Self::from_index(match i {
+            0 => 10,
+            57..=59 => i as isize - 50,
+            _ => (i as isize - 1) % 7,
+        })
+    }
+
+    /// 是否固定卡拉纳（每月只出现一次）
+    pub fn is_fixed(&self) -> bool {
+        self.get_index() > 6
+    }
+
+    /// IAST转写名称
+    pub fn get_iast_name(&self) -> String {
+        KARANA_IAST_NAMES[self.get_index()].to_string()
+    }
+}
+
+impl Display for Karana {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.get_name())
+    }
+}
+
+impl PartialEq for Karana {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for Karana {}
+
+impl From<Karana> for LoopTyme {
+    fn from(val: Karana) -> Self {
+        val.parent
+    }
+}
@@ -2,6 +2,7 @@ use std::fmt::{Display, Formatter};
 use std::ops::{Deref, DerefMut};
 use std::string::ToString;
 
+use crate::tyme::error::TymeError;
 use crate::tyme::sixtycycle::SixtyCycle;
 use crate::tyme::{Culture, LoopTyme, Tyme};
 
@@ -112,16 +113,20 @@ impl PengZuHeavenStem {
     }
 
     pub fn from_name(name: &str) -> Self {
-        Self {
-            parent: LoopTyme::from_name(
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
                 PENG_ZU_HEAVEN_STEM_NAMES
                     .to_vec()
                     .iter()
                     .map(|x| x.to_string())
                     .collect(),
                 name,
-            ),
-        }
+            )?,
+        })
     }
 }
 
@@ -207,16 +212,20 @@ impl PengZuEarthBranch {
     }
 
     pub fn from_name(name: &str) -> Self {
-        Self {
-            parent: LoopTyme::from_name(
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
                 PENG_ZU_EARTH_BRANCH_NAMES
                     .to_vec()
                     .iter()
                     .map(|x| x.to_string())
                     .collect(),
                 name,
-            ),
-        }
+            )?,
+        })
     }
 }
 
@@ -1,3 +1,4 @@
+use crate::tyme::error::TymeError;
 use crate::tyme::jd::{JulianDay, J2000};
 use crate::tyme::util::ShouXingUtil;
 use crate::tyme::{AbstractCulture, AbstractCultureDay, AbstractTyme, Culture, LoopTyme, Tyme};
@@ -136,17 +137,21 @@ impl Phenology {
     }
 
     pub fn from_name(year: isize, name: &str) -> Self {
-        Self {
-            parent: LoopTyme::from_name(
+        Self::try_from_name(year, name).unwrap()
+    }
+
+    pub fn try_from_name(year: isize, name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
                 PHENOLOGY_NAMES
                     .to_vec()
                     .iter()
                     .map(|x| x.to_string())
                     .collect(),
                 name,
-            ),
+            )?,
             year,
-        }
+        })
     }
 
     pub fn get_year(&self) -> isize {
@@ -241,16 +246,20 @@ impl ThreePhenology {
     }
 
     pub fn from_name(name: &str) -> Self {
-        Self {
-            parent: LoopTyme::from_name(
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
                 THREE_PHENOLOGY_NAMES
                     .to_vec()
                     .iter()
                     .map(|x| x.to_string())
                     .collect(),
                 name,
-            ),
-        }
+            )?,
+        })
     }
 }
 
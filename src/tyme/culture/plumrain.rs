@@ -1,3 +1,4 @@
+use crate::tyme::error::TymeError;
 use crate::tyme::{AbstractCulture, AbstractCultureDay, AbstractTyme, Culture, LoopTyme, Tyme};
 use std::fmt::{Display, Formatter};
 use std::ops::{Deref, DerefMut};
@@ -51,16 +52,20 @@ impl PlumRain {
     }
 
     pub fn from_name(name: &str) -> Self {
-        Self {
-            parent: LoopTyme::from_name(
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
                 PLUM_RAIN_NAMES
                     .to_vec()
                     .iter()
                     .map(|x| x.to_string())
                     .collect(),
                 name,
-            ),
-        }
+            )?,
+        })
     }
 }
 
@@ -159,12 +164,9 @@ mod tests {
 
     #[test]
     fn test1() {
-        assert_eq!(
-            true,
-            SolarDay::from_ymd(2024, 6, 10)
-                .get_plum_rain_day()
-                .is_none()
-        );
+        assert!(SolarDay::from_ymd(2024, 6, 10)
+            .get_plum_rain_day()
+            .is_none());
     }
 
     #[test]
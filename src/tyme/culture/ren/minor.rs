@@ -1,4 +1,5 @@
 use crate::tyme::culture::{Element, Luck};
+use crate::tyme::error::TymeError;
 use crate::tyme::{Culture, LoopTyme, Tyme};
 use std::fmt::{Display, Formatter};
 use std::ops::{Deref, DerefMut};
@@ -52,16 +53,20 @@ impl MinorRen {
     }
 
     pub fn from_name(name: &str) -> Self {
-        Self {
-            parent: LoopTyme::from_name(
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
                 SIX_STAR_NAMES
                     .to_vec()
                     .iter()
                     .map(|x| x.to_string())
                     .collect(),
                 name,
-            ),
-        }
+            )?,
+        })
     }
 
     /// 吉凶
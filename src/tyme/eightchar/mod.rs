@@ -6,9 +6,11 @@ use lazy_static::lazy_static;
 use crate::tyme::culture::Duty;
 use crate::tyme::eightchar::provider::{ChildLimitProvider, DefaultChildLimitProvider};
 use crate::tyme::enums::{Gender, YinYang};
+use crate::tyme::error::TymeError;
 use crate::tyme::lunar::LunarYear;
 use crate::tyme::sixtycycle::{HeavenStem, SixtyCycle, SixtyCycleYear, ThreePillars};
 use crate::tyme::solar::{SolarDay, SolarTerm, SolarTime};
+use crate::tyme::zone::{TrueSolarTime, UtcOffset, ZonedSolarTime};
 use crate::tyme::{Culture, Tyme};
 
 pub mod provider;
@@ -28,10 +30,14 @@ impl Culture for EightChar {
 
 impl EightChar {
     pub fn new(year: &str, month: &str, day: &str, hour: &str) -> Self {
-        Self {
-            three_pillars: ThreePillars::new(year, month, day),
-            hour: SixtyCycle::from_name(hour),
-        }
+        Self::try_new(year, month, day, hour).unwrap()
+    }
+
+    pub fn try_new(year: &str, month: &str, day: &str, hour: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            three_pillars: ThreePillars::try_new(year, month, day)?,
+            hour: SixtyCycle::try_from_name(hour)?,
+        })
     }
 
     pub fn from_sixty_cycle(
@@ -46,6 +52,23 @@ impl EightChar {
         }
     }
 
+    /// 按真太阳时排八字
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::eightchar::EightChar;
+    /// use tyme4rs::tyme::solar::SolarTime;
+    /// use tyme4rs::tyme::zone::TrueSolarTime;
+    ///
+    /// // 乌鲁木齐北京时间11:00出生，真太阳时为辰时
+    /// let t: TrueSolarTime = TrueSolarTime::from_solar_time(SolarTime::from_ymd_hms(2024, 2, 11, 11, 0, 0), 87.6, None);
+    /// assert_eq!("甲辰 丙寅 乙巳 庚辰", EightChar::from_true_solar_time(t).to_string());
+    /// ```
+    pub fn from_true_solar_time(true_solar_time: TrueSolarTime) -> Self {
+        true_solar_time.get_eight_char()
+    }
+
     pub fn get_year(&self) -> SixtyCycle {
         self.three_pillars.get_year()
     }
@@ -291,7 +314,23 @@ pub struct ChildLimit {
 
 impl ChildLimit {
     pub fn from_solar_time(birth_time: SolarTime, gender: Gender) -> Self {
-        let eight_char: EightChar = birth_time.get_lunar_hour().get_eight_char();
+        Self::from_eight_char(
+            birth_time.get_lunar_hour().get_eight_char(),
+            birth_time,
+            gender,
+        )
+    }
+
+    /// 从真太阳时创建，八字按真太阳时排定，起运按出生时刻（换算为北京时间）与节令的间隔推算
+    pub fn from_true_solar_time(birth_time: TrueSolarTime, gender: Gender) -> Self {
+        let clock_time: SolarTime =
+            ZonedSolarTime::new(birth_time.get_clock_time(), birth_time.get_offset())
+                .to_offset(UtcOffset::BEIJING)
+                .get_solar_time();
+        Self::from_eight_char(birth_time.get_eight_char(), clock_time, gender)
+    }
+
+    fn from_eight_char(eight_char: EightChar, birth_time: SolarTime, gender: Gender) -> Self {
         // 阳男阴女顺推，阴男阳女逆推
         let yang: bool = YinYang::YANG == eight_char.get_year().get_heaven_stem().get_yin_yang();
         let man: bool = Gender::MAN == gender;
@@ -611,6 +650,8 @@ mod tests {
     use crate::tyme::eightchar::{ChildLimit, CHILD_LIMIT_PROVIDER};
     use crate::tyme::enums::Gender;
     use crate::tyme::solar::SolarTime;
+    use crate::tyme::zone::{TrueSolarTime, UtcOffset, ZonedSolarTime};
+    use crate::tyme::Tyme;
     use std::sync::MutexGuard;
 
     #[test]
@@ -639,4 +680,29 @@ mod tests {
                 .to_string()
         );
     }
+
+    #[test]
+    fn test2() {
+        let birth_time: SolarTime = SolarTime::from_ymd_hms(1989, 12, 31, 23, 7, 17);
+        let t: TrueSolarTime = TrueSolarTime::from_solar_time(birth_time, 116.4, None);
+        let d: ChildLimit = ChildLimit::from_true_solar_time(t, Gender::MAN);
+        // 真太阳时在子时之前，日柱与时柱改变，起运仍按出生时刻推算
+        assert_eq!("1989年12月31日 22:49:46", t.get_solar_time().to_string());
+        assert_eq!("己巳 丙子 乙丑 丁亥", d.get_eight_char().to_string());
+        assert_eq!(
+            "己巳 丙子 丙寅 戊子",
+            ChildLimit::from_solar_time(birth_time, Gender::MAN)
+                .get_eight_char()
+                .to_string()
+        );
+        assert_eq!(birth_time, d.get_start_time());
+        let v: ChildLimit = ChildLimit::from_true_solar_time(
+            TrueSolarTime::from_zoned_solar_time(
+                ZonedSolarTime::new(birth_time.next(-3600), UtcOffset::from_hm(7, 0)),
+                116.4,
+            ),
+            Gender::MAN,
+        );
+        assert_eq!(d.get_end_time(), v.get_end_time());
+    }
 }
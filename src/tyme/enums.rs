@@ -1,3 +1,4 @@
+use crate::tyme::error::TymeError;
 use std::fmt::{Display, Formatter};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -8,21 +9,27 @@ pub enum FestivalType {
 }
 
 impl FestivalType {
-    pub fn from_code(code: usize) -> Result<Self, String> {
+    pub fn from_code(code: usize) -> Result<Self, TymeError> {
         match code {
             0 => Ok(Self::DAY),
             1 => Ok(Self::TERM),
             2 => Ok(Self::EVE),
-            _ => Err(format!("illegal FestivalType code: {}", code)),
+            _ => Err(TymeError::UnknownCode {
+                kind: "FestivalType",
+                code,
+            }),
         }
     }
 
-    pub fn from_name(name: &str) -> Result<Self, String> {
+    pub fn from_name(name: &str) -> Result<Self, TymeError> {
         match name {
             "日期" => Ok(Self::DAY),
             "节气" => Ok(Self::TERM),
             "除夕" => Ok(Self::EVE),
-            _ => Err(format!("illegal FestivalType name: {}", name)),
+            _ => Err(TymeError::UnknownName {
+                kind: "FestivalType",
+                name: name.to_string(),
+            }),
         }
     }
 
@@ -57,27 +64,41 @@ pub enum HideHeavenStemType {
 }
 
 impl HideHeavenStemType {
-    pub fn from_code(code: usize) -> Result<Self, String> {
+    pub fn from_code(code: usize) -> Result<Self, TymeError> {
         match code {
             0 => Ok(Self::RESIDUAL),
             1 => Ok(Self::MIDDLE),
             2 => Ok(Self::MAIN),
-            _ => Err(format!("illegal HideHeavenStemType code: {}", code)),
+            _ => Err(TymeError::UnknownCode {
+                kind: "HideHeavenStemType",
+                code,
+            }),
         }
     }
 
-    pub fn from_name(name: &str) -> Result<Self, String> {
+    pub fn from_name(name: &str) -> Result<Self, TymeError> {
         match name {
             "余气" => Ok(Self::RESIDUAL),
             "中气" => Ok(Self::MIDDLE),
             "本气" => Ok(Self::MAIN),
-            _ => Err(format!("illegal HideHeavenStemType name: {}", name)),
+            _ => Err(TymeError::UnknownName {
+                kind: "HideHeavenStemType",
+                name: name.to_string(),
+            }),
         }
     }
 
     pub fn get_name(&self) -> String {
         self.to_string()
     }
+
+    pub fn get_code(&self) -> usize {
+        match self {
+            Self::RESIDUAL => 0,
+            Self::MIDDLE => 1,
+            Self::MAIN => 2,
+        }
+    }
 }
 
 impl Display for HideHeavenStemType {
@@ -97,25 +118,38 @@ pub enum Gender {
 }
 
 impl Gender {
-    pub fn from_code(code: usize) -> Result<Self, String> {
+    pub fn from_code(code: usize) -> Result<Self, TymeError> {
         match code {
             0 => Ok(Self::WOMAN),
             1 => Ok(Self::MAN),
-            _ => Err(format!("illegal Gender code: {}", code)),
+            _ => Err(TymeError::UnknownCode {
+                kind: "Gender",
+                code,
+            }),
         }
     }
 
-    pub fn from_name(name: &str) -> Result<Self, String> {
+    pub fn from_name(name: &str) -> Result<Self, TymeError> {
         match name {
             "女" => Ok(Self::WOMAN),
             "男" => Ok(Self::MAN),
-            _ => Err(format!("illegal Gender name: {}", name)),
+            _ => Err(TymeError::UnknownName {
+                kind: "Gender",
+                name: name.to_string(),
+            }),
         }
     }
 
     pub fn get_name(&self) -> String {
         self.to_string()
     }
+
+    pub fn get_code(&self) -> usize {
+        match self {
+            Self::WOMAN => 0,
+            Self::MAN => 1,
+        }
+    }
 }
 
 impl Display for Gender {
@@ -134,25 +168,35 @@ pub enum Side {
 }
 
 impl Side {
-    pub fn from_code(code: usize) -> Result<Self, String> {
+    pub fn from_code(code: usize) -> Result<Self, TymeError> {
         match code {
             0 => Ok(Self::IN),
             1 => Ok(Self::OUT),
-            _ => Err(format!("illegal Side code: {}", code)),
+            _ => Err(TymeError::UnknownCode { kind: "Side", code }),
         }
     }
 
-    pub fn from_name(name: &str) -> Result<Self, String> {
+    pub fn from_name(name: &str) -> Result<Self, TymeError> {
         match name {
             "内" => Ok(Self::IN),
             "外" => Ok(Self::OUT),
-            _ => Err(format!("illegal Side name: {}", name)),
+            _ => Err(TymeError::UnknownName {
+                kind: "Side",
+                name: name.to_string(),
+            }),
         }
     }
 
     pub fn get_name(&self) -> String {
         self.to_string()
     }
+
+    pub fn get_code(&self) -> usize {
+        match self {
+            Self::IN => 0,
+            Self::OUT => 1,
+        }
+    }
 }
 
 impl Display for Side {
@@ -171,25 +215,38 @@ pub enum YinYang {
 }
 
 impl YinYang {
-    pub fn from_code(code: usize) -> Result<Self, String> {
+    pub fn from_code(code: usize) -> Result<Self, TymeError> {
         match code {
             0 => Ok(Self::YIN),
             1 => Ok(Self::YANG),
-            _ => Err(format!("illegal YinYang code: {}", code)),
+            _ => Err(TymeError::UnknownCode {
+                kind: "YinYang",
+                code,
+            }),
         }
     }
 
-    pub fn from_name(name: &str) -> Result<Self, String> {
+    pub fn from_name(name: &str) -> Result<Self, TymeError> {
         match name {
             "阴" => Ok(Self::YIN),
             "阳" => Ok(Self::YANG),
-            _ => Err(format!("illegal YinYang name: {}", name)),
+            _ => Err(TymeError::UnknownName {
+                kind: "YinYang",
+                name: name.to_string(),
+            }),
         }
     }
 
     pub fn get_name(&self) -> String {
         self.to_string()
     }
+
+    pub fn get_code(&self) -> usize {
+        match self {
+            Self::YIN => 0,
+            Self::YANG => 1,
+        }
+    }
 }
 
 impl Display for YinYang {
@@ -209,10 +266,11 @@ pub enum EventType {
     TermDay,
     TermHs,
     TermEb,
+    RabByungDay,
 }
 
 impl EventType {
-    pub fn from_code(code: usize) -> Result<Self, String> {
+    pub fn from_code(code: usize) -> Result<Self, TymeError> {
         match code {
             0 => Ok(Self::SolarDay),
             1 => Ok(Self::SolarWeek),
@@ -220,11 +278,15 @@ impl EventType {
             3 => Ok(Self::TermDay),
             4 => Ok(Self::TermHs),
             5 => Ok(Self::TermEb),
-            _ => Err(format!("illegal EventType code: {}", code)),
+            6 => Ok(Self::RabByungDay),
+            _ => Err(TymeError::UnknownCode {
+                kind: "EventType",
+                code,
+            }),
         }
     }
 
-    pub fn from_name(name: &str) -> Result<Self, String> {
+    pub fn from_name(name: &str) -> Result<Self, TymeError> {
         match name {
             "公历日期" => Ok(Self::SolarDay),
             "几月第几个星期几" => Ok(Self::SolarWeek),
@@ -232,7 +294,11 @@ impl EventType {
             "节气日期" => Ok(Self::TermDay),
             "节气天干" => Ok(Self::TermHs),
             "节气地支" => Ok(Self::TermEb),
-            _ => Err(format!("illegal EventType name: {}", name)),
+            "藏历日期" => Ok(Self::RabByungDay),
+            _ => Err(TymeError::UnknownName {
+                kind: "EventType",
+                name: name.to_string(),
+            }),
         }
     }
 
@@ -248,6 +314,7 @@ impl EventType {
             Self::TermDay => 3,
             Self::TermHs => 4,
             Self::TermEb => 5,
+            Self::RabByungDay => 6,
         }
     }
 }
@@ -261,6 +328,711 @@ impl Display for EventType {
             Self::TermDay => f.write_str("节气日期"),
             Self::TermHs => f.write_str("节气天干"),
             Self::TermEb => f.write_str("节气地支"),
+            Self::RabByungDay => f.write_str("藏历日期"),
+        }
+    }
+}
+
+/// 日照类型
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DaylightType {
+    /// 有日出日落
+    Normal,
+    /// 极昼
+    PolarDay,
+    /// 极夜
+    PolarNight,
+}
+
+impl DaylightType {
+    pub fn from_code(code: usize) -> Result<Self, TymeError> {
+        match code {
+            0 => Ok(Self::Normal),
+            1 => Ok(Self::PolarDay),
+            2 => Ok(Self::PolarNight),
+            _ => Err(TymeError::UnknownCode {
+                kind: "DaylightType",
+                code,
+            }),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self, TymeError> {
+        match name {
+            "正常" => Ok(Self::Normal),
+            "极昼" => Ok(Self::PolarDay),
+            "极夜" => Ok(Self::PolarNight),
+            _ => Err(TymeError::UnknownName {
+                kind: "DaylightType",
+                name: name.to_string(),
+            }),
+        }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.to_string()
+    }
+
+    pub fn get_code(&self) -> usize {
+        match self {
+            Self::Normal => 0,
+            Self::PolarDay => 1,
+            Self::PolarNight => 2,
+        }
+    }
+}
+
+impl Display for DaylightType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Normal => f.write_str("正常"),
+            Self::PolarDay => f.write_str("极昼"),
+            Self::PolarNight => f.write_str("极夜"),
+        }
+    }
+}
+
+/// 日月食类型
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EclipseType {
+    /// 日偏食
+    SolarPartial,
+    /// 日环食
+    SolarAnnular,
+    /// 日全食
+    SolarTotal,
+    /// 日全环食
+    SolarHybrid,
+    /// 半影月食
+    LunarPenumbral,
+    /// 月偏食
+    LunarPartial,
+    /// 月全食
+    LunarTotal,
+}
+
+impl EclipseType {
+    pub fn from_code(code: usize) -> Result<Self, TymeError> {
+        match code {
+            0 => Ok(Self::SolarPartial),
+            1 => Ok(Self::SolarAnnular),
+            2 => Ok(Self::SolarTotal),
+            3 => Ok(Self::SolarHybrid),
+            4 => Ok(Self::LunarPenumbral),
+            5 => Ok(Self::LunarPartial),
+            6 => Ok(Self::LunarTotal),
+            _ => Err(TymeError::UnknownCode {
+                kind: "EclipseType",
+                code,
+            }),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self, TymeError> {
+        match name {
+            "日偏食" => Ok(Self::SolarPartial),
+            "日环食" => Ok(Self::SolarAnnular),
+            "日全食" => Ok(Self::SolarTotal),
+            "日全环食" => Ok(Self::SolarHybrid),
+            "半影月食" => Ok(Self::LunarPenumbral),
+            "月偏食" => Ok(Self::LunarPartial),
+            "月全食" => Ok(Self::LunarTotal),
+            _ => Err(TymeError::UnknownName {
+                kind: "EclipseType",
+                name: name.to_string(),
+            }),
+        }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.to_string()
+    }
+
+    pub fn get_code(&self) -> usize {
+        match self {
+            Self::SolarPartial => 0,
+            Self::SolarAnnular => 1,
+            Self::SolarTotal => 2,
+            Self::SolarHybrid => 3,
+            Self::LunarPenumbral => 4,
+            Self::LunarPartial => 5,
+            Self::LunarTotal => 6,
+        }
+    }
+
+    /// 是否日食
+    pub fn is_solar(&self) -> bool {
+        self.get_code() < 4
+    }
+}
+
+impl Display for EclipseType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::SolarPartial => f.write_str("日偏食"),
+            Self::SolarAnnular => f.write_str("日环食"),
+            Self::SolarTotal => f.write_str("日全食"),
+            Self::SolarHybrid => f.write_str("日全环食"),
+            Self::LunarPenumbral => f.write_str("半影月食"),
+            Self::LunarPartial => f.write_str("月偏食"),
+            Self::LunarTotal => f.write_str("月全食"),
+        }
+    }
+}
+
+/// 行星动态
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PlanetEventType {
+    /// 合（外行星与太阳同黄经）
+    Conjunction,
+    /// 上合（内行星在太阳远侧与太阳同黄经）
+    SuperiorConjunction,
+    /// 下合（内行星在太阳近侧与太阳同黄经）
+    InferiorConjunction,
+    /// 冲（外行星与太阳黄经相差180°）
+    Opposition,
+    /// 顺留（由顺行转逆行）
+    StationaryRetrograde,
+    /// 逆留（由逆行转顺行）
+    StationaryDirect,
+}
+
+impl PlanetEventType {
+    pub fn from_code(code: usize) -> Result<Self, TymeError> {
+        match code {
+            0 => Ok(Self::Conjunction),
+            1 => Ok(Self::SuperiorConjunction),
+            2 => Ok(Self::InferiorConjunction),
+            3 => Ok(Self::Opposition),
+            4 => Ok(Self::StationaryRetrograde),
+            5 => Ok(Self::StationaryDirect),
+            _ => Err(TymeError::UnknownCode {
+                kind: "PlanetEventType",
+                code,
+            }),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self, TymeError> {
+        match name {
+            "合" => Ok(Self::Conjunction),
+            "上合" => Ok(Self::SuperiorConjunction),
+            "下合" => Ok(Self::InferiorConjunction),
+            "冲" => Ok(Self::Opposition),
+            "顺留" => Ok(Self::StationaryRetrograde),
+            "逆留" => Ok(Self::StationaryDirect),
+            _ => Err(TymeError::UnknownName {
+                kind: "PlanetEventType",
+                name: name.to_string(),
+            }),
+        }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.to_string()
+    }
+
+    pub fn get_code(&self) -> usize {
+        match self {
+            Self::Conjunction => 0,
+            Self::SuperiorConjunction => 1,
+            Self::InferiorConjunction => 2,
+            Self::Opposition => 3,
+            Self::StationaryRetrograde => 4,
+            Self::StationaryDirect => 5,
+        }
+    }
+}
+
+impl Display for PlanetEventType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Conjunction => f.write_str("合"),
+            Self::SuperiorConjunction => f.write_str("上合"),
+            Self::InferiorConjunction => f.write_str("下合"),
+            Self::Opposition => f.write_str("冲"),
+            Self::StationaryRetrograde => f.write_str("顺留"),
+            Self::StationaryDirect => f.write_str("逆留"),
+        }
+    }
+}
+
+/// 伊斯兰历类型
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum IslamicType {
+    /// 算术历（30年11闰的表格历法）
+    Tabular,
+    /// 乌姆库拉历（合朔在麦加日落前且月落晚于日落，则次日为1日，沙特阿拉伯官方历法）
+    UmmAlQura,
+    /// MABIMS历（吉隆坡日落时新月高度不低于3度且日月角距不低于6.4度，则次日为1日，东南亚各国通行）
+    Mabims,
+}
+
+impl IslamicType {
+    pub fn from_code(code: usize) -> Result<Self, TymeError> {
+        match code {
+            0 => Ok(Self::Tabular),
+            1 => Ok(Self::UmmAlQura),
+            2 => Ok(Self::Mabims),
+            _ => Err(TymeError::UnknownCode {
+                kind: "IslamicType",
+                code,
+            }),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self, TymeError> {
+        match name {
+            "算术历" => Ok(Self::Tabular),
+            "乌姆库拉历" => Ok(Self::UmmAlQura),
+            "MABIMS历" => Ok(Self::Mabims),
+            _ => Err(TymeError::UnknownName {
+                kind: "IslamicType",
+                name: name.to_string(),
+            }),
+        }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.to_string()
+    }
+
+    pub fn get_code(&self) -> usize {
+        match self {
+            Self::Tabular => 0,
+            Self::UmmAlQura => 1,
+            Self::Mabims => 2,
+        }
+    }
+}
+
+impl Display for IslamicType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Tabular => f.write_str("算术历"),
+            Self::UmmAlQura => f.write_str("乌姆库拉历"),
+            Self::Mabims => f.write_str("MABIMS历"),
+        }
+    }
+}
+
+/// 波斯历类型
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PersianType {
+    /// 算术历（33年8闰）
+    Arithmetic,
+    /// 天文历（春分在德黑兰正午前则当日为新年，伊朗官方历法）
+    Astronomical,
+}
+
+impl PersianType {
+    pub fn from_code(code: usize) -> Result<Self, TymeError> {
+        match code {
+            0 => Ok(Self::Arithmetic),
+            1 => Ok(Self::Astronomical),
+            _ => Err(TymeError::UnknownCode {
+                kind: "PersianType",
+                code,
+            }),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self, TymeError> {
+        match name {
+            "算术历" => Ok(Self::Arithmetic),
+            "天文历" => Ok(Self::Astronomical),
+            _ => Err(TymeError::UnknownName {
+                kind: "PersianType",
+                name: name.to_string(),
+            }),
+        }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.to_string()
+    }
+
+    pub fn get_code(&self) -> usize {
+        match self {
+            Self::Arithmetic => 0,
+            Self::Astronomical => 1,
+        }
+    }
+}
+
+impl Display for PersianType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Arithmetic => f.write_str("算术历"),
+            Self::Astronomical => f.write_str("天文历"),
+        }
+    }
+}
+
+/// 改历（由儒略历改用格里高利历）
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum CalendarReform {
+    /// 罗马（1582年10月4日的次日为10月15日）
+    Rome,
+    /// 英国（1752年9月2日的次日为9月14日）
+    Britain,
+    /// 俄国（1918年1月31日的次日为2月14日）
+    Russia,
+}
+
+impl CalendarReform {
+    pub fn from_code(code: usize) -> Result<Self, TymeError> {
+        match code {
+            0 => Ok(Self::Rome),
+            1 => Ok(Self::Britain),
+            2 => Ok(Self::Russia),
+            _ => Err(TymeError::UnknownCode {
+                kind: "CalendarReform",
+                code,
+            }),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self, TymeError> {
+        match name {
+            "罗马" => Ok(Self::Rome),
+            "英国" => Ok(Self::Britain),
+            "俄国" => Ok(Self::Russia),
+            _ => Err(TymeError::UnknownName {
+                kind: "CalendarReform",
+                name: name.to_string(),
+            }),
+        }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.to_string()
+    }
+
+    pub fn get_code(&self) -> usize {
+        match self {
+            Self::Rome => 0,
+            Self::Britain => 1,
+            Self::Russia => 2,
+        }
+    }
+}
+
+impl Display for CalendarReform {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Rome => f.write_str("罗马"),
+            Self::Britain => f.write_str("英国"),
+            Self::Russia => f.write_str("俄国"),
+        }
+    }
+}
+
+/// 日本年号类型
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum JapaneseEraType {
+    /// 近代（明治以后）
+    Modern,
+    /// 历史（庆长以后，含江户时代年号）
+    Historical,
+}
+
+impl JapaneseEraType {
+    pub fn from_code(code: usize) -> Result<Self, TymeError> {
+        match code {
+            0 => Ok(Self::Modern),
+            1 => Ok(Self::Historical),
+            _ => Err(TymeError::UnknownCode {
+                kind: "JapaneseEraType",
+                code,
+            }),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self, TymeError> {
+        match name {
+            "近代" => Ok(Self::Modern),
+            "历史" => Ok(Self::Historical),
+            _ => Err(TymeError::UnknownName {
+                kind: "JapaneseEraType",
+                name: name.to_string(),
+            }),
+        }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.to_string()
+    }
+
+    pub fn get_code(&self) -> usize {
+        match self {
+            Self::Modern => 0,
+            Self::Historical => 1,
+        }
+    }
+}
+
+impl Display for JapaneseEraType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Modern => f.write_str("近代"),
+            Self::Historical => f.write_str("历史"),
+        }
+    }
+}
+
+/// 节假日地区
+///
+/// 中国大陆的数据自2001年12月29日起；其他地区的数据仅覆盖2024至2025年（台湾另含2023年末的补行上班日），超出范围时查不到假日。
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Region {
+    /// 中国大陆（国务院公布的放假安排）
+    Mainland,
+    /// 香港（2024至2025年）
+    HongKong,
+    /// 澳门（2024至2025年）
+    Macau,
+    /// 台湾（2024至2025年）
+    Taiwan,
+    /// 新加坡（2024至2025年）
+    Singapore,
+    /// 泰国（2024至2025年）
+    Thailand,
+}
+
+impl Region {
+    pub fn from_code(code: usize) -> Result<Self, TymeError> {
+        match code {
+            0 => Ok(Self::Mainland),
+            1 => Ok(Self::HongKong),
+            2 => Ok(Self::Macau),
+            3 => Ok(Self::Taiwan),
+            4 => Ok(Self::Singapore),
+            5 => Ok(Self::Thailand),
+            _ => Err(TymeError::UnknownCode {
+                kind: "Region",
+                code,
+            }),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self, TymeError> {
+        match name {
+            "中国大陆" => Ok(Self::Mainland),
+            "香港" => Ok(Self::HongKong),
+            "澳门" => Ok(Self::Macau),
+            "台湾" => Ok(Self::Taiwan),
+            "新加坡" => Ok(Self::Singapore),
+            "泰国" => Ok(Self::Thailand),
+            _ => Err(TymeError::UnknownName {
+                kind: "Region",
+                name: name.to_string(),
+            }),
+        }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.to_string()
+    }
+
+    pub fn get_code(&self) -> usize {
+        match self {
+            Self::Mainland => 0,
+            Self::HongKong => 1,
+            Self::Macau => 2,
+            Self::Taiwan => 3,
+            Self::Singapore => 4,
+            Self::Thailand => 5,
+        }
+    }
+}
+
+impl Display for Region {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Mainland => f.write_str("中国大陆"),
+            Self::HongKong => f.write_str("香港"),
+            Self::Macau => f.write_str("澳门"),
+            Self::Taiwan => f.write_str("台湾"),
+            Self::Singapore => f.write_str("新加坡"),
+            Self::Thailand => f.write_str("泰国"),
+        }
+    }
+}
+
+/// 藏历流派
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Tradition {
+    /// 浦派（西藏通行的历书）
+    Phugpa,
+    /// 蒙古（查干萨日所用历书）
+    Mongolian,
+    /// 粗卜派（噶玛噶举派所用历书），历元常数尚未与原始文献核对
+    Tsurphu,
+    /// 不丹，历元常数尚未与原始文献核对
+    Bhutanese,
+}
+
+impl Tradition {
+    pub fn from_code(code: usize) -> Result<Self, TymeError> {
+        match code {
+            0 => Ok(Self::Phugpa),
+            1 => Ok(Self::Mongolian),
+            2 => Ok(Self::Tsurphu),
+            3 => Ok(Self::Bhutanese),
+            _ => Err(TymeError::UnknownCode {
+                kind: "Tradition",
+                code,
+            }),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self, TymeError> {
+        match name {
+            "浦派" => Ok(Self::Phugpa),
+            "蒙古" => Ok(Self::Mongolian),
+            "粗卜派" => Ok(Self::Tsurphu),
+            "不丹" => Ok(Self::Bhutanese),
+            _ => Err(TymeError::UnknownName {
+                kind: "Tradition",
+                name: name.to_string(),
+            }),
+        }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.to_string()
+    }
+
+    pub fn get_code(&self) -> usize {
+        match self {
+            Self::Phugpa => 0,
+            Self::Mongolian => 1,
+            Self::Tsurphu => 2,
+            Self::Bhutanese => 3,
+        }
+    }
+}
+
+impl Display for Tradition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Phugpa => f.write_str("浦派"),
+            Self::Mongolian => f.write_str("蒙古"),
+            Self::Tsurphu => f.write_str("粗卜派"),
+            Self::Bhutanese => f.write_str("不丹"),
+        }
+    }
+}
+
+/// 月半（印度历）
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Paksha {
+    /// 白分（新月到满月）
+    Shukla,
+    /// 黑分（满月到新月）
+    Krishna,
+}
+
+impl Paksha {
+    pub fn from_code(code: usize) -> Result<Self, TymeError> {
+        match code {
+            0 => Ok(Self::Shukla),
+            1 => Ok(Self::Krishna),
+            _ => Err(TymeError::UnknownCode {
+                kind: "Paksha",
+                code,
+            }),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self, TymeError> {
+        match name {
+            "白分" => Ok(Self::Shukla),
+            "黑分" => Ok(Self::Krishna),
+            _ => Err(TymeError::UnknownName {
+                kind: "Paksha",
+                name: name.to_string(),
+            }),
+        }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.to_string()
+    }
+
+    pub fn get_code(&self) -> usize {
+        match self {
+            Self::Shukla => 0,
+            Self::Krishna => 1,
+        }
+    }
+}
+
+impl Display for Paksha {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Shukla => f.write_str("白分"),
+            Self::Krishna => f.write_str("黑分"),
+        }
+    }
+}
+
+/// 岁差（恒星黄道与回归黄道起点之差）
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Ayanamsa {
+    /// 拉希里（印度历书委员会标准）
+    Lahiri,
+    /// 拉曼
+    Raman,
+    /// 克里希纳穆提
+    Krishnamurti,
+}
+
+impl Ayanamsa {
+    pub fn from_code(code: usize) -> Result<Self, TymeError> {
+        match code {
+            0 => Ok(Self::Lahiri),
+            1 => Ok(Self::Raman),
+            2 => Ok(Self::Krishnamurti),
+            _ => Err(TymeError::UnknownCode {
+                kind: "Ayanamsa",
+                code,
+            }),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Result<Self, TymeError> {
+        match name {
+            "拉希里" => Ok(Self::Lahiri),
+            "拉曼" => Ok(Self::Raman),
+            "克里希纳穆提" => Ok(Self::Krishnamurti),
+            _ => Err(TymeError::UnknownName {
+                kind: "Ayanamsa",
+                name: name.to_string(),
+            }),
+        }
+    }
+
+    pub fn get_name(&self) -> String {
+        self.to_string()
+    }
+
+    pub fn get_code(&self) -> usize {
+        match self {
+            Self::Lahiri => 0,
+            Self::Raman => 1,
+            Self::Krishnamurti => 2,
+        }
+    }
+}
+
+impl Display for Ayanamsa {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Lahiri => f.write_str("拉希里"),
+            Self::Raman => f.write_str("拉曼"),
+            Self::Krishnamurti => f.write_str("克里希纳穆提"),
         }
     }
 }
@@ -268,6 +1040,7 @@ impl Display for EventType {
 #[cfg(test)]
 mod tests {
     use crate::tyme::enums::Gender;
+    use crate::tyme::error::TymeError;
 
     #[test]
     fn test1() {
@@ -283,4 +1056,15 @@ mod tests {
     fn test3() {
         assert!(Gender::from_name("未知").is_err());
     }
+
+    #[test]
+    fn test4() {
+        assert_eq!(
+            Err(TymeError::UnknownCode {
+                kind: "Gender",
+                code: 2
+            }),
+            Gender::from_code(2)
+        );
+    }
 }
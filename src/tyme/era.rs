@@ -0,0 +1,273 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::tyme::error::TymeError;
+use crate::tyme::solar::SolarYear;
+use crate::tyme::{Culture, Tyme};
+
+/// 民国元年对应的公历年
+static MINGUO_OFFSET: isize = 1911;
+
+/// 佛历与公历的年差
+static BUDDHIST_OFFSET: isize = 543;
+
+/// 民国纪年（民国元年即公历1912年）
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct MinguoYear {
+    year: isize,
+}
+
+impl Tyme for MinguoYear {
+    fn next(&self, n: isize) -> Self {
+        self.try_next(n).unwrap()
+    }
+}
+
+impl Culture for MinguoYear {
+    fn get_name(&self) -> String {
+        match self.year {
+            1 => "民国元年".to_string(),
+            y => format!("民国{}年", y),
+        }
+    }
+}
+
+impl MinguoYear {
+    pub fn new(year: isize) -> Result<Self, TymeError> {
+        Self::validate(year)?;
+        Ok(Self { year })
+    }
+
+    pub fn validate(year: isize) -> Result<(), TymeError> {
+        if !(1..=9999 - MINGUO_OFFSET).contains(&year) {
+            return Err(TymeError::YearOutOfRange {
+                calendar: "minguo",
+                year,
+                min: 1,
+                max: 9999 - MINGUO_OFFSET,
+            });
+        }
+        Ok(())
+    }
+
+    pub fn from_year(year: isize) -> Self {
+        Self::new(year).unwrap()
+    }
+
+    pub fn try_from_year(year: isize) -> Result<Self, TymeError> {
+        Self::new(year)
+    }
+
+    /// 从公历年转换，早于1912年时返回错误
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::era::MinguoYear;
+    /// use tyme4rs::tyme::solar::SolarYear;
+    ///
+    /// assert_eq!("民国113年", MinguoYear::try_from_solar_year(SolarYear::from_year(2024)).unwrap().to_string());
+    /// ```
+    pub fn try_from_solar_year(solar_year: SolarYear) -> Result<Self, TymeError> {
+        Self::new(solar_year.get_year() - MINGUO_OFFSET)
+    }
+
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        Self::new(self.year + n)
+    }
+
+    /// 民国年数（从1开始）
+    pub fn get_year(&self) -> isize {
+        self.year
+    }
+
+    /// 公历年
+    pub fn get_solar_year(&self) -> SolarYear {
+        SolarYear::from_year(self.year + MINGUO_OFFSET)
+    }
+}
+
+impl Display for MinguoYear {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.get_name())
+    }
+}
+
+impl FromStr for MinguoYear {
+    type Err = TymeError;
+
+    /// 解析民国纪年（如民国113年、民国元年，也可用繁体“民國”）
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || TymeError::IllegalFormat {
+            kind: "minguo year",
+            text: s.to_string(),
+        };
+        let year: &str = s
+            .strip_prefix("民国")
+            .or_else(|| s.strip_prefix("民國"))
+            .and_then(|t| t.strip_suffix('年'))
+            .ok_or_else(err)?;
+        if "元" == year {
+            return Self::new(1);
+        }
+        if year.is_empty() || !year.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(err());
+        }
+        Self::new(isize::from_str(year).map_err(|_| err())?)
+    }
+}
+
+/// 佛历纪年（泰国），按公历年换算，以1月1日为岁首
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct BuddhistYear {
+    year: isize,
+}
+
+impl Tyme for BuddhistYear {
+    fn next(&self, n: isize) -> Self {
+        self.try_next(n).unwrap()
+    }
+}
+
+impl Culture for BuddhistYear {
+    fn get_name(&self) -> String {
+        format!("พ.ศ. {}", self.year)
+    }
+}
+
+impl BuddhistYear {
+    pub fn new(year: isize) -> Result<Self, TymeError> {
+        Self::validate(year)?;
+        Ok(Self { year })
+    }
+
+    pub fn validate(year: isize) -> Result<(), TymeError> {
+        if !(1 + BUDDHIST_OFFSET..=9999 + BUDDHIST_OFFSET).contains(&year) {
+            return Err(TymeError::YearOutOfRange {
+                calendar: "buddhist",
+                year,
+                min: 1 + BUDDHIST_OFFSET,
+                max: 9999 + BUDDHIST_OFFSET,
+            });
+        }
+        Ok(())
+    }
+
+    pub fn from_year(year: isize) -> Self {
+        Self::new(year).unwrap()
+    }
+
+    pub fn try_from_year(year: isize) -> Result<Self, TymeError> {
+        Self::new(year)
+    }
+
+    /// 从公历年转换
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::era::BuddhistYear;
+    /// use tyme4rs::tyme::solar::SolarYear;
+    ///
+    /// assert_eq!("พ.ศ. 2567", BuddhistYear::from_solar_year(SolarYear::from_year(2024)).to_string());
+    /// ```
+    pub fn from_solar_year(solar_year: SolarYear) -> Self {
+        Self::from_year(solar_year.get_year() + BUDDHIST_OFFSET)
+    }
+
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        Self::new(self.year + n)
+    }
+
+    /// 佛历年数
+    pub fn get_year(&self) -> isize {
+        self.year
+    }
+
+    /// 公历年
+    pub fn get_solar_year(&self) -> SolarYear {
+        SolarYear::from_year(self.year - BUDDHIST_OFFSET)
+    }
+}
+
+impl Display for BuddhistYear {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.get_name())
+    }
+}
+
+impl FromStr for BuddhistYear {
+    type Err = TymeError;
+
+    /// 解析佛历纪年（如พ.ศ. 2567，空格可省略）
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || TymeError::IllegalFormat {
+            kind: "buddhist year",
+            text: s.to_string(),
+        };
+        let year: &str = s.strip_prefix("พ.ศ.").ok_or_else(err)?.trim_start();
+        if year.is_empty() || !year.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(err());
+        }
+        Self::new(isize::from_str(year).map_err(|_| err())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::tyme::era::{BuddhistYear, MinguoYear};
+    use crate::tyme::error::TymeError;
+    use crate::tyme::solar::SolarYear;
+    use crate::tyme::{Culture, Tyme};
+
+    #[test]
+    fn test0() {
+        let y: MinguoYear = MinguoYear::from_year(113);
+        assert_eq!("民国113年", y.to_string());
+        assert_eq!(2024, y.get_solar_year().get_year());
+        assert_eq!("民国元年", y.next(-112).get_name());
+        assert_eq!(y, SolarYear::from_year(2024).get_minguo_year().unwrap());
+        assert_eq!(
+            Err(TymeError::YearOutOfRange {
+                calendar: "minguo",
+                year: 0,
+                min: 1,
+                max: 8088
+            }),
+            SolarYear::from_year(1911).get_minguo_year()
+        );
+    }
+
+    #[test]
+    fn test1() {
+        assert_eq!(113, MinguoYear::from_str("民国113年").unwrap().get_year());
+        assert_eq!(113, MinguoYear::from_str("民國113年").unwrap().get_year());
+        assert_eq!(1, MinguoYear::from_str("民国元年").unwrap().get_year());
+        assert_eq!(
+            Err(TymeError::IllegalFormat {
+                kind: "minguo year",
+                text: "民国+1年".to_string()
+            }),
+            MinguoYear::from_str("民国+1年")
+        );
+        assert!(MinguoYear::from_str("民国0年").is_err());
+        assert!(MinguoYear::from_str("113年").is_err());
+    }
+
+    #[test]
+    fn test2() {
+        let y: BuddhistYear = SolarYear::from_year(2024).get_buddhist_year();
+        assert_eq!("พ.ศ. 2567", y.to_string());
+        assert_eq!(2567, y.get_year());
+        assert_eq!(2025, y.next(1).get_solar_year().get_year());
+        assert_eq!(y, BuddhistYear::from_str("พ.ศ. 2567").unwrap());
+        assert_eq!(y, BuddhistYear::from_str("พ.ศ.2567").unwrap());
+        assert!(BuddhistYear::from_str("2567").is_err());
+        assert!(BuddhistYear::from_str("พ.ศ. 543").is_err());
+        assert!(BuddhistYear::try_from_year(10543).is_err());
+    }
+}
@@ -0,0 +1,205 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// 错误
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TymeError {
+    /// 年超出范围
+    YearOutOfRange {
+        /// 历法
+        calendar: &'static str,
+        year: isize,
+        min: isize,
+        max: isize,
+    },
+    /// 非法月
+    IllegalMonth {
+        /// 历法
+        calendar: &'static str,
+        year: isize,
+        month: isize,
+    },
+    /// 非法闰月
+    IllegalLeapMonth {
+        /// 历法
+        calendar: &'static str,
+        year: isize,
+        month: isize,
+    },
+    /// 非法日
+    IllegalDay {
+        /// 历法
+        calendar: &'static str,
+        year: isize,
+        month: isize,
+        day: isize,
+    },
+    /// 非法闰日
+    IllegalLeapDay {
+        /// 历法
+        calendar: &'static str,
+        year: isize,
+        month: isize,
+        day: isize,
+    },
+    /// 格里高利历改革时删除的日期（如1582年10月5日至14日）
+    GregorianGap {
+        year: isize,
+        month: isize,
+        day: isize,
+    },
+    /// 非法时刻
+    IllegalTime {
+        hour: usize,
+        minute: usize,
+        second: usize,
+    },
+    /// 索引超出范围
+    IndexOutOfRange {
+        /// 类别
+        kind: &'static str,
+        index: isize,
+        min: isize,
+        max: isize,
+    },
+    /// 未知名称
+    UnknownName {
+        /// 类别，为空时表示未指定
+        kind: &'static str,
+        name: String,
+    },
+    /// 未知代码
+    UnknownCode {
+        /// 类别
+        kind: &'static str,
+        code: usize,
+    },
+    /// 非法事件数据
+    IllegalEventData { data: String },
+    /// 非法格式
+    IllegalFormat {
+        /// 类别
+        kind: &'static str,
+        text: String,
+    },
+    /// 非法UTC偏移
+    IllegalUtcOffset {
+        /// 秒数
+        seconds: isize,
+    },
+    /// 非法经度（非有限数或不在-180至180度之间）
+    IllegalLongitude {
+        /// 经度的文本形式
+        longitude: String,
+    },
+}
+
+impl Display for TymeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::YearOutOfRange {
+                calendar,
+                year,
+                min,
+                max,
+            } => write!(
+                f,
+                "illegal {} year: {}, must be between {} and {}",
+                calendar, year, min, max
+            ),
+            Self::IllegalMonth {
+                calendar,
+                year,
+                month,
+            } => write!(f, "illegal {} month {} in year {}", calendar, month, year),
+            Self::IllegalLeapMonth {
+                calendar,
+                year,
+                month,
+            } => write!(
+                f,
+                "illegal leap month {} in {} year {}",
+                month, calendar, year
+            ),
+            Self::IllegalDay {
+                calendar,
+                year,
+                month,
+                day,
+            } => write!(f, "illegal {} day: {}-{}-{}", calendar, year, month, day),
+            Self::IllegalLeapDay {
+                calendar,
+                year,
+                month,
+                day,
+            } => write!(
+                f,
+                "illegal {} leap day: {}-{}-{}",
+                calendar, year, month, day
+            ),
+            Self::GregorianGap { year, month, day } => write!(
+                f,
+                "illegal solar day: {}-{}-{}, dropped by the gregorian reform",
+                year, month, day
+            ),
+            Self::IllegalTime {
+                hour,
+                minute,
+                second,
+            } => write!(f, "illegal time: {}:{}:{}", hour, minute, second),
+            Self::IndexOutOfRange {
+                kind,
+                index,
+                min,
+                max,
+            } => write!(
+                f,
+                "illegal {} index: {}, must be between {} and {}",
+                kind, index, min, max
+            ),
+            Self::UnknownName { kind, name } => match kind.is_empty() {
+                true => write!(f, "illegal name: {}", name),
+                false => write!(f, "illegal {} name: {}", kind, name),
+            },
+            Self::UnknownCode { kind, code } => write!(f, "illegal {} code: {}", kind, code),
+            Self::IllegalEventData { data } => write!(f, "illegal event data: {}", data),
+            Self::IllegalFormat { kind, text } => write!(f, "illegal {} format: {}", kind, text),
+            Self::IllegalUtcOffset { seconds } => write!(
+                f,
+                "illegal utc offset: {} seconds, must be between -64800 and 64800",
+                seconds
+            ),
+            Self::IllegalLongitude { longitude } => write!(
+                f,
+                "illegal longitude: {}, must be between -180 and 180",
+                longitude
+            ),
+        }
+    }
+}
+
+impl Error for TymeError {}
+
+#[cfg(test)]
+mod tests {
+    use crate::tyme::error::TymeError;
+
+    #[test]
+    fn test0() {
+        let e: TymeError = TymeError::IllegalLeapMonth {
+            calendar: "lunar",
+            year: 2023,
+            month: 6,
+        };
+        assert_eq!("illegal leap month 6 in lunar year 2023", e.to_string());
+    }
+
+    #[test]
+    fn test1() {
+        let e: TymeError = TymeError::UnknownName {
+            kind: "",
+            name: "子".to_string(),
+        };
+        assert_eq!("illegal name: 子", e.to_string());
+    }
+}
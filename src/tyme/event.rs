@@ -1,8 +1,10 @@
 use crate::tyme::enums::EventType;
+use crate::tyme::error::TymeError;
 use crate::tyme::lunar::LunarDay;
 use crate::tyme::lunar::LunarMonth;
+use crate::tyme::rabbyung::{RabByungDay, RabByungMonth};
 use crate::tyme::solar::{SolarDay, SolarMonth, SolarTerm};
-use crate::tyme::{AbstractCulture, Culture, Tyme};
+use crate::tyme::{AbstractCulture, Culture};
 use lazy_static::lazy_static;
 use regex::Regex;
 use std::fmt::{Display, Formatter};
@@ -52,7 +54,7 @@ impl Culture for Event {
 }
 
 impl Event {
-    fn new(name: &str, data: &str) -> Result<Self, String> {
+    pub(crate) fn new(name: &str, data: &str) -> Result<Self, TymeError> {
         Self::validate(data)?;
         Ok(Self {
             parent: AbstractCulture::new(),
@@ -61,25 +63,31 @@ impl Event {
         })
     }
 
-    pub fn validate(data: &str) -> Result<(), String> {
-        if data.len() != 9 {
-            Err(format!("illegal event data: {}", data))
-        } else {
+    pub fn validate(data: &str) -> Result<(), TymeError> {
+        let chars: Vec<char> = data.chars().collect();
+        let valid: bool = chars.len() == 9
+            && chars[0] == '@'
+            && chars[1..].iter().all(|c| EVENT_MANAGER_CHARS.contains(c))
+            && EVENT_MANAGER_CHARS
+                .iter()
+                .position(|&c| c == chars[1])
+                .is_some_and(|i| EventType::from_code(i).is_ok());
+        if valid {
             Ok(())
+        } else {
+            Err(TymeError::IllegalEventData {
+                data: data.to_string(),
+            })
         }
     }
 
     pub fn from_name(name: &str) -> Option<Self> {
-        let reg: Regex = Regex::new(format!("{}({})", EVENT_MANAGER_REGEX, name).as_str()).unwrap();
-        if let Some(caps) = reg.captures(EVENT_MANAGER_DATA.lock().unwrap().as_str()) {
-            let data: String = caps.get(1).unwrap().as_str().to_string();
-            return Some(Self {
-                parent: AbstractCulture::new(),
-                name: name.to_string(),
-                data,
-            });
-        }
-        None
+        let reg: Regex =
+            Regex::new(format!("{}({})", EVENT_MANAGER_REGEX, regex::escape(name)).as_str())
+                .ok()?;
+        let s: MutexGuard<String> = EventManager::lock();
+        let caps = reg.captures(s.as_str())?;
+        Self::new(name, caps.get(1)?.as_str()).ok()
     }
 
     pub fn builder() -> EventBuilder {
@@ -94,19 +102,24 @@ impl Event {
         self.data.clone()
     }
 
+    /// 数据中指定位置字符的编码值（数据在创建时已校验）
+    fn value_at(&self, index: usize) -> isize {
+        self.data
+            .chars()
+            .nth(index)
+            .and_then(|t| EVENT_MANAGER_CHARS.iter().position(|&c| c == t))
+            .unwrap_or(0) as isize
+    }
+
     pub fn get_type(&self) -> EventType {
-        let t: char = self.data.chars().nth(1).unwrap();
-        let i: usize = EVENT_MANAGER_CHARS.iter().position(|&c| c == t).unwrap();
-        EventType::from_code(i).unwrap()
+        EventType::from_code(self.value_at(1) as usize).unwrap_or(EventType::SolarDay)
     }
 
     pub fn get_start_year(&self) -> isize {
         let mut n: isize = 0;
         let size: isize = EVENT_MANAGER_CHARS.len() as isize;
-        let chars: Vec<char> = self.data.chars().collect();
         for i in 0..3 {
-            let t: char = chars[6 + i];
-            n = n * size + EVENT_MANAGER_CHARS.iter().position(|&c| c == t).unwrap() as isize;
+            n = n * size + self.value_at(6 + i);
         }
         n
     }
@@ -114,14 +127,12 @@ impl Event {
     pub fn all() -> Vec<Event> {
         let mut l: Vec<Self> = Vec::new();
         let reg: Regex = Regex::new(format!("{}(.[^@]+)", EVENT_MANAGER_REGEX).as_str()).unwrap();
-        for caps in reg.captures_iter(EVENT_MANAGER_DATA.lock().unwrap().as_str()) {
-            let data: String = caps.get(1).unwrap().as_str().to_string();
-            let name: String = caps.get(2).unwrap().as_str().to_string();
-            l.push(Self {
-                parent: AbstractCulture::new(),
-                name,
-                data,
-            });
+        for caps in reg.captures_iter(EventManager::lock().as_str()) {
+            if let (Some(data), Some(name)) = (caps.get(1), caps.get(2)) {
+                if let Ok(e) = Self::new(name.as_str(), data.as_str()) {
+                    l.push(e);
+                }
+            }
         }
         l
     }
@@ -143,198 +154,172 @@ impl Event {
         if year < self.get_start_year() {
             return None;
         }
-        let d: Option<SolarDay> = match t {
+        let d: SolarDay = match t {
             EventType::SolarDay => self.get_solar_day_by_solar_day(year),
             EventType::LunarDay => self.get_solar_day_by_lunar_day(year),
             EventType::SolarWeek => self.get_solar_day_by_week(year),
             EventType::TermDay => self.get_solar_day_by_term(year),
             EventType::TermHs => self.get_solar_day_by_term_heaven_stem(year),
             EventType::TermEb => self.get_solar_day_by_term_earth_branch(year),
-        };
-        d?;
-        let chars: Vec<char> = self.data.chars().collect();
-        let offset: isize = EVENT_MANAGER_CHARS
-            .iter()
-            .position(|&c| c == chars[5])
-            .unwrap() as isize
-            - 31;
+            EventType::RabByungDay => self.get_solar_day_by_rab_byung_day(year),
+        }?;
+        let offset: isize = self.value_at(5) - 31;
         if offset != 0 {
-            return Some(d.unwrap().next(offset));
+            return d.try_next(offset).ok();
         }
-        d
+        Some(d)
     }
 
     fn get_solar_day_by_solar_day(&self, year: isize) -> Option<SolarDay> {
         let mut y: isize = year;
-        let chars: Vec<char> = self.data.chars().collect();
-        let mut m: usize = EVENT_MANAGER_CHARS
-            .iter()
-            .position(|&c| c == chars[2])
-            .unwrap()
-            - 31;
+        let mut m: isize = self.value_at(2) - 31;
         if m > 12 {
             m = 1;
             y += 1;
         }
-        let d: usize = EVENT_MANAGER_CHARS
-            .iter()
-            .position(|&c| c == chars[3])
-            .unwrap()
-            - 31;
-        let delay: isize = EVENT_MANAGER_CHARS
-            .iter()
-            .position(|&c| c == chars[4])
-            .unwrap() as isize
-            - 31;
-        let month: SolarMonth = SolarMonth::from_ym(y, m);
-        let last_day: usize = month.get_day_count();
+        let d: isize = self.value_at(3) - 31;
+        let delay: isize = self.value_at(4) - 31;
+        if m < 1 || d < 1 {
+            return None;
+        }
+        let m: usize = m as usize;
+        let month: SolarMonth = SolarMonth::try_from_ym(y, m).ok()?;
+        let last_day: isize = month.get_day_count() as isize;
         if d > last_day {
             if 0 == delay {
                 return None;
             } else if delay < 0 {
-                return Some(SolarDay::from_ymd(y, m, (d as isize + delay) as usize));
+                return SolarDay::try_from_ymd(y, m, (d + delay).max(0) as usize).ok();
             }
-            return Some(SolarDay::from_ymd(y, m, last_day).next(delay));
+            return SolarDay::try_from_ymd(y, m, last_day as usize)
+                .ok()?
+                .try_next(delay)
+                .ok();
         }
-        Some(SolarDay::from_ymd(y, m, d))
+        SolarDay::try_from_ymd(y, m, d as usize).ok()
     }
 
     fn get_solar_day_by_lunar_day(&self, year: isize) -> Option<SolarDay> {
         let mut y: isize = year;
-        let chars: Vec<char> = self.data.chars().collect();
-        let mut m: isize = EVENT_MANAGER_CHARS
-            .iter()
-            .position(|&c| c == chars[2])
-            .unwrap() as isize
-            - 31;
+        let mut m: isize = self.value_at(2) - 31;
         if m > 12 {
             m = 1;
             y += 1;
         }
-        let d: usize = EVENT_MANAGER_CHARS
-            .iter()
-            .position(|&c| c == chars[3])
-            .unwrap()
-            - 31;
-        let delay: isize = EVENT_MANAGER_CHARS
-            .iter()
-            .position(|&c| c == chars[4])
-            .unwrap() as isize
-            - 31;
-        let month: LunarMonth = LunarMonth::from_ym(y, m);
-        let last_day: usize = month.get_day_count();
+        let d: isize = self.value_at(3) - 31;
+        let delay: isize = self.value_at(4) - 31;
+        if d < 1 {
+            return None;
+        }
+        let month: LunarMonth = LunarMonth::try_from_ym(y, m).ok()?;
+        let last_day: isize = month.get_day_count() as isize;
         if d > last_day {
             if 0 == delay {
                 return None;
             } else if delay < 0 {
                 return Some(
-                    LunarDay::from_ymd(y, m, (d as isize + delay) as usize).get_solar_day(),
+                    LunarDay::try_from_ymd(y, m, (d + delay).max(0) as usize)
+                        .ok()?
+                        .get_solar_day(),
                 );
             }
-            return Some(
-                LunarDay::from_ymd(y, m, last_day)
-                    .get_solar_day()
-                    .next(delay),
-            );
+            return LunarDay::try_from_ymd(y, m, last_day as usize)
+                .ok()?
+                .get_solar_day()
+                .try_next(delay)
+                .ok();
         }
-        Some(LunarDay::from_ymd(y, m, d).get_solar_day())
+        Some(
+            LunarDay::try_from_ymd(y, m, d as usize)
+                .ok()?
+                .get_solar_day(),
+        )
+    }
+
+    /// 藏历日期，缺日时偏移为0则按习俗取前一日（初一取当月首日），否则自前一日起偏移
+    fn get_solar_day_by_rab_byung_day(&self, year: isize) -> Option<SolarDay> {
+        let mut y: isize = year;
+        let mut m: isize = self.value_at(2) - 31;
+        if m > 12 {
+            m = 1;
+            y += 1;
+        }
+        let d: isize = self.value_at(3) - 31;
+        let delay: isize = self.value_at(4) - 31;
+        if !(1..=30).contains(&d) {
+            return None;
+        }
+        let month: RabByungMonth = RabByungMonth::from_ym(y, m).ok()?;
+        let day: RabByungDay = month.get_observed_day(d as usize).ok()?;
+        if delay == 0 || !month.get_miss_days().contains(&d) {
+            return Some(day.get_solar_day());
+        }
+        // 缺日并入的前一日
+        let prev: SolarDay = match d {
+            1 => day.get_solar_day().try_next(-1).ok()?,
+            _ => day.get_solar_day(),
+        };
+        prev.try_next(delay).ok()
     }
 
     fn get_solar_day_by_week(&self, year: isize) -> Option<SolarDay> {
-        let chars: Vec<char> = self.data.chars().collect();
         // 第几个星期
-        let n: isize = EVENT_MANAGER_CHARS
-            .iter()
-            .position(|&c| c == chars[3])
-            .unwrap() as isize
-            - 31;
+        let n: isize = self.value_at(3) - 31;
         if n == 0 {
             return None;
         }
-        let m: SolarMonth = SolarMonth::from_ym(
-            year,
-            (EVENT_MANAGER_CHARS
-                .iter()
-                .position(|&c| c == chars[2])
-                .unwrap() as isize
-                - 31) as usize,
-        );
+        let m: isize = self.value_at(2) - 31;
+        if m < 1 {
+            return None;
+        }
+        let m: SolarMonth = SolarMonth::try_from_ym(year, m as usize).ok()?;
         // 星期几
-        let w: isize = EVENT_MANAGER_CHARS
-            .iter()
-            .position(|&c| c == chars[4])
-            .unwrap() as isize
-            - 31;
+        let w: isize = self.value_at(4) - 31;
         if n > 0 {
             // 当月第1天
             let d: SolarDay = m.get_first_day();
             // 往后找第几个星期几
-            return Some(d.next(d.get_week().steps_to(w) as isize + 7 * n - 7));
+            return d
+                .try_next(d.get_week().steps_to(w) as isize + 7 * n - 7)
+                .ok();
         }
         // 当月最后一天
-        let d: SolarDay = SolarDay::from_ymd(year, m.get_month(), m.get_day_count());
+        let d: SolarDay = SolarDay::try_from_ymd(year, m.get_month(), m.get_day_count()).ok()?;
         // 往前找第几个星期几
-        Some(d.next(d.get_week().steps_back_to(w) + 7 * n + 7))
+        d.try_next(d.get_week().steps_back_to(w) + 7 * n + 7).ok()
     }
 
     fn get_solar_day_by_term(&self, year: isize) -> Option<SolarDay> {
-        let chars: Vec<char> = self.data.chars().collect();
-        let offset: isize = EVENT_MANAGER_CHARS
-            .iter()
-            .position(|&c| c == chars[4])
-            .unwrap() as isize
-            - 31;
-        let d: SolarDay = SolarTerm::from_index(
-            year,
-            EVENT_MANAGER_CHARS
-                .iter()
-                .position(|&c| c == chars[2])
-                .unwrap() as isize
-                - 31,
-        )
-        .get_solar_day();
+        let offset: isize = self.value_at(4) - 31;
+        let d: SolarDay = SolarTerm::from_index(year, self.value_at(2) - 31)
+            .try_get_solar_day()
+            .ok()?;
         if offset != 0 {
-            return Some(d.next(offset));
+            return d.try_next(offset).ok();
         }
         Some(d)
     }
 
     fn get_solar_day_by_term_heaven_stem(&self, year: isize) -> Option<SolarDay> {
         let d: SolarDay = self.get_solar_day_by_term(year)?;
-        let chars: Vec<char> = self.data.chars().collect();
-        Some(
-            d.next(
-                d.get_lunar_day()
-                    .get_sixty_cycle()
-                    .get_heaven_stem()
-                    .steps_to(
-                        EVENT_MANAGER_CHARS
-                            .iter()
-                            .position(|&c| c == chars[3])
-                            .unwrap() as isize
-                            - 31,
-                    ) as isize,
-            ),
+        d.try_next(
+            d.get_lunar_day()
+                .get_sixty_cycle()
+                .get_heaven_stem()
+                .steps_to(self.value_at(3) - 31) as isize,
         )
+        .ok()
     }
 
     fn get_solar_day_by_term_earth_branch(&self, year: isize) -> Option<SolarDay> {
         let d: SolarDay = self.get_solar_day_by_term(year)?;
-        let chars: Vec<char> = self.data.chars().collect();
-        Some(
-            d.next(
-                d.get_lunar_day()
-                    .get_sixty_cycle()
-                    .get_earth_branch()
-                    .steps_to(
-                        EVENT_MANAGER_CHARS
-                            .iter()
-                            .position(|&c| c == chars[3])
-                            .unwrap() as isize
-                            - 31,
-                    ) as isize,
-            ),
+        d.try_next(
+            d.get_lunar_day()
+                .get_sixty_cycle()
+                .get_earth_branch()
+                .steps_to(self.value_at(3) - 31) as isize,
         )
+        .ok()
     }
 }
 
@@ -375,11 +360,22 @@ impl EventBuilder {
         }
     }
 
+    /// 编码，超出范围时使用非法字符，在构建时校验失败
+    fn encode(n: isize) -> char {
+        match usize::try_from(n)
+            .ok()
+            .and_then(|i| EVENT_MANAGER_CHARS.get(i))
+        {
+            Some(c) => *c,
+            None => '-',
+        }
+    }
+
     fn content(mut self, t: EventType, a: isize, b: isize, c: isize) -> Self {
         self.data[1] = Self::encode_type(t);
-        self.data[2] = *EVENT_MANAGER_CHARS.get((31 + a) as usize).unwrap();
-        self.data[3] = *EVENT_MANAGER_CHARS.get((31 + b) as usize).unwrap();
-        self.data[4] = *EVENT_MANAGER_CHARS.get((31 + c) as usize).unwrap();
+        self.data[2] = Self::encode(31 + a);
+        self.data[3] = Self::encode(31 + b);
+        self.data[4] = Self::encode(31 + c);
         self
     }
 
@@ -406,6 +402,21 @@ impl EventBuilder {
         )
     }
 
+    /// 藏历日期（浦派），闰月为负数，delay_days为该日缺日时自前一日起的偏移天数，0为按习俗取前一日
+    pub fn rab_byung_day(
+        self,
+        rab_byung_month: isize,
+        rab_byung_day: usize,
+        delay_days: isize,
+    ) -> Self {
+        self.content(
+            EventType::RabByungDay,
+            rab_byung_month,
+            rab_byung_day as isize,
+            delay_days,
+        )
+    }
+
     pub fn solar_week(self, solar_month: usize, week_index: isize, week: usize) -> Self {
         self.content(
             EventType::SolarWeek,
@@ -451,22 +462,25 @@ impl EventBuilder {
         let size: usize = EVENT_MANAGER_CHARS.len();
         let mut n: isize = year;
         for i in 0..3 {
-            self.data[8 - i] = *EVENT_MANAGER_CHARS
-                .get((n % size as isize) as usize)
-                .unwrap();
+            self.data[8 - i] = Self::encode(n % size as isize);
             n /= size as isize;
         }
         self
     }
 
     pub fn offset(mut self, days: isize) -> Self {
-        self.data[5] = *EVENT_MANAGER_CHARS.get((31 + days) as usize).unwrap();
+        self.data[5] = Self::encode(31 + days);
         self
     }
 
     pub fn build(self) -> Event {
+        self.try_build().unwrap()
+    }
+
+    /// 构建，数据超出范围时返回错误
+    pub fn try_build(self) -> Result<Event, TymeError> {
         let s: String = self.data.iter().collect();
-        Event::new(self.name.as_str(), s.as_str()).unwrap()
+        Event::new(self.name.as_str(), s.as_str())
     }
 }
 
@@ -474,16 +488,24 @@ impl EventBuilder {
 pub struct EventManager {}
 
 impl EventManager {
+    fn lock() -> MutexGuard<'static, String> {
+        EVENT_MANAGER_DATA.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn name_regex(name: &str) -> Regex {
+        Regex::new(format!("{}{}", EVENT_MANAGER_REGEX, regex::escape(name)).as_str()).unwrap()
+    }
+
     pub fn remove(name: &str) {
-        let reg: Regex = Regex::new(format!("{}{}", EVENT_MANAGER_REGEX, name).as_str()).unwrap();
-        let mut s: MutexGuard<String> = EVENT_MANAGER_DATA.lock().unwrap();
+        let reg: Regex = Self::name_regex(name);
+        let mut s: MutexGuard<String> = Self::lock();
         let str: &str = s.as_str();
         *s = reg.replace_all(str, "").to_string();
     }
 
     fn save_or_update(name: &str, data: &str) {
-        let reg: Regex = Regex::new(format!("{}{}", EVENT_MANAGER_REGEX, name).as_str()).unwrap();
-        let mut s: MutexGuard<String> = EVENT_MANAGER_DATA.lock().unwrap();
+        let reg: Regex = Self::name_regex(name);
+        let mut s: MutexGuard<String> = Self::lock();
         let str: &str = s.as_str();
         if reg.is_match(str) {
             *s = reg.replace_all(str, data).to_string();
@@ -492,7 +514,7 @@ impl EventManager {
         }
     }
 
-    pub fn update_data(name: &str, data: &str) -> Result<(), String> {
+    pub fn update_data(name: &str, data: &str) -> Result<(), TymeError> {
         Event::validate(data)?;
         Self::save_or_update(name, data);
         Ok(())
@@ -510,6 +532,7 @@ impl EventManager {
 
 #[cfg(test)]
 mod tests {
+    use crate::tyme::enums::EventType;
     use crate::tyme::event::{Event, EventManager};
     use crate::tyme::solar::SolarDay;
 
@@ -775,4 +798,53 @@ mod tests {
         let d: SolarDay = e.get_solar_day(2024).unwrap();
         assert_eq!("2024年3月25日", d.to_string());
     }
+
+    #[test]
+    fn test4() {
+        assert!(Event::from_name("(未注册").is_none());
+        assert!(Event::builder()
+            .name("非法偏移")
+            .solar_day(1, 1, 0)
+            .offset(100)
+            .try_build()
+            .is_err());
+        assert!(Event::validate("@9VV__0Ux").is_err());
+    }
+
+    #[test]
+    fn test5() {
+        EventManager::update(
+            "萨嘎达瓦节",
+            Event::builder().rab_byung_day(4, 15, 0).build(),
+        );
+        let e: Event = Event::from_name("萨嘎达瓦节").unwrap();
+        assert_eq!(EventType::RabByungDay, e.get_type());
+        assert_eq!("2024年5月23日", e.get_solar_day(2024).unwrap().to_string());
+
+        // 2008年正月十五缺日，按习俗在前一日，或指定自前一日起的偏移
+        let e: Event = Event::builder()
+            .name("神变节")
+            .rab_byung_day(1, 15, 0)
+            .build();
+        assert_eq!("2008年2月20日", e.get_solar_day(2008).unwrap().to_string());
+        let e: Event = Event::builder()
+            .name("神变节")
+            .rab_byung_day(1, 15, 1)
+            .build();
+        assert_eq!("2008年2月21日", e.get_solar_day(2008).unwrap().to_string());
+        assert_eq!("2024年2月24日", e.get_solar_day(2024).unwrap().to_string());
+
+        // 藏历除夕
+        let e: Event = Event::builder()
+            .name("藏历除夕")
+            .rab_byung_day(13, 1, 0)
+            .offset(-1)
+            .build();
+        assert_eq!("2024年2月9日", e.get_solar_day(2023).unwrap().to_string());
+        assert!(Event::builder()
+            .rab_byung_day(1, 31, 0)
+            .build()
+            .get_solar_day(2024)
+            .is_none());
+    }
 }
@@ -4,9 +4,11 @@ use std::str::FromStr;
 use regex::Regex;
 
 use crate::tyme::enums::FestivalType;
+use crate::tyme::hebrew::{HebrewDay, HebrewYear};
 use crate::tyme::lunar::LunarDay;
-use crate::tyme::solar::{SolarDay, SolarTerm};
-use crate::tyme::{AbstractCulture, Culture, Tyme};
+use crate::tyme::rabbyung::{RabByungDay, RabByungMonth};
+use crate::tyme::solar::{SolarDay, SolarTerm, SolarYear};
+use crate::tyme::{AbstractCulture, Culture};
 
 pub static SOLAR_FESTIVAL_NAMES: [&str; 10] = [
     "元旦",
@@ -54,17 +56,17 @@ impl SolarFestival {
             )
             .as_str(),
         )
-        .unwrap();
+        .ok()?;
         if reg.is_match(SOLAR_FESTIVAL_DATA) {
-            let data: &str = reg.find(SOLAR_FESTIVAL_DATA).unwrap().as_str();
+            let data: &str = reg.find(SOLAR_FESTIVAL_DATA)?.as_str();
             let dy: &str = &data[8..data.len()];
-            let start_year: isize = isize::from_str(dy).unwrap();
+            let start_year: isize = isize::from_str(dy).ok()?;
             if year < start_year {
                 return None;
             }
-            let day: SolarDay = SolarDay::from_ymd(year, month, day);
+            let day: SolarDay = SolarDay::try_from_ymd(year, month, day).ok()?;
             let di: &str = &data[1..3];
-            let index: usize = usize::from_str(di).unwrap();
+            let index: usize = usize::from_str(di).ok()?;
             Some(Self {
                 festival_type: FestivalType::DAY,
                 day,
@@ -81,27 +83,27 @@ impl SolarFestival {
             return None;
         }
         let reg: Regex =
-            Regex::new(format!("{}{:0>two$}{}", r"@", index, r"\d+", two = 2).as_str()).unwrap();
+            Regex::new(format!("{}{:0>two$}{}", r"@", index, r"\d+", two = 2).as_str()).ok()?;
         if reg.is_match(SOLAR_FESTIVAL_DATA) {
-            let data: &str = reg.find(SOLAR_FESTIVAL_DATA).unwrap().as_str();
-            let dt: usize = (data.chars().nth(3).unwrap() as usize) - ('0' as usize);
-            let festival_type: FestivalType = FestivalType::from_code(dt).unwrap();
+            let data: &str = reg.find(SOLAR_FESTIVAL_DATA)?.as_str();
+            let dt: usize = (data.chars().nth(3)? as usize) - ('0' as usize);
+            let festival_type: FestivalType = FestivalType::from_code(dt).ok()?;
             if festival_type != FestivalType::DAY {
                 return None;
             }
             let dy: &str = &data[8..data.len()];
-            let start_year: isize = isize::from_str(dy).unwrap();
+            let start_year: isize = isize::from_str(dy).ok()?;
             if year < start_year {
                 return None;
             }
             let dm: &str = &data[4..6];
             let dd: &str = &data[6..8];
-            let month: usize = usize::from_str(dm).unwrap();
-            let day: usize = usize::from_str(dd).unwrap();
+            let month: usize = usize::from_str(dm).ok()?;
+            let day: usize = usize::from_str(dd).ok()?;
 
-            let day: SolarDay = SolarDay::from_ymd(year, month, day);
+            let day: SolarDay = SolarDay::try_from_ymd(year, month, day).ok()?;
             let di: &str = &data[1..3];
-            let index: usize = usize::from_str(di).unwrap();
+            let index: usize = usize::from_str(di).ok()?;
             Some(Self {
                 festival_type: FestivalType::DAY,
                 day,
@@ -193,12 +195,12 @@ impl LunarFestival {
     pub fn from_ymd(year: isize, month: isize, day: usize) -> Option<Self> {
         let mut reg: Regex =
             Regex::new(format!("{}{:0>two$}{:0>two$}", r"@\d{2}0", month, day, two = 2).as_str())
-                .unwrap();
+                .ok()?;
         if reg.is_match(LUNAR_FESTIVAL_DATA) {
-            let data: &str = reg.find(LUNAR_FESTIVAL_DATA).unwrap().as_str();
-            let day: LunarDay = LunarDay::from_ymd(year, month, day);
+            let data: &str = reg.find(LUNAR_FESTIVAL_DATA)?.as_str();
+            let day: LunarDay = LunarDay::try_from_ymd(year, month, day).ok()?;
             let di: &str = &data[1..3];
-            let index: usize = usize::from_str(di).unwrap();
+            let index: usize = usize::from_str(di).ok()?;
             return Some(Self {
                 festival_type: FestivalType::DAY,
                 day,
@@ -206,17 +208,18 @@ impl LunarFestival {
                 solar_term: None,
             });
         }
-        let lunar_day: LunarDay = LunarDay::from_ymd(year, month, day);
+        let lunar_day: LunarDay = LunarDay::try_from_ymd(year, month, day).ok()?;
         let solar_day: SolarDay = lunar_day.get_solar_day();
-        reg = Regex::new(r"@\d{2}1\d{2}").unwrap();
+        reg = Regex::new(r"@\d{2}1\d{2}").ok()?;
         if reg.is_match(LUNAR_FESTIVAL_DATA) {
-            let data: &str = reg.find(LUNAR_FESTIVAL_DATA).unwrap().as_str();
+            let data: &str = reg.find(LUNAR_FESTIVAL_DATA)?.as_str();
             let di: &str = &data[4..data.len()];
-            let term_index: usize = usize::from_str(di).unwrap();
+            let term_index: usize = usize::from_str(di).ok()?;
+            SolarYear::validate(year).ok()?;
             let term: SolarTerm = SolarTerm::from_index(year, term_index as isize);
             let term_day: SolarDay = term.get_solar_day();
             let di: &str = &data[1..3];
-            let index: usize = usize::from_str(di).unwrap();
+            let index: usize = usize::from_str(di).ok()?;
             let lunar_day: LunarDay = term.get_solar_day().get_lunar_day();
             if term_day.get_year() == solar_day.get_year()
                 && term_day.get_month() == solar_day.get_month()
@@ -231,13 +234,17 @@ impl LunarFestival {
             }
         }
         if month.abs() == 12 && day > 28 {
-            reg = Regex::new(r"@\d{2}2").unwrap();
+            reg = Regex::new(r"@\d{2}2").ok()?;
             if reg.is_match(LUNAR_FESTIVAL_DATA) {
-                let data: &str = reg.find(LUNAR_FESTIVAL_DATA).unwrap().as_str();
+                let data: &str = reg.find(LUNAR_FESTIVAL_DATA)?.as_str();
                 let di: &str = &data[1..3];
-                let index: usize = usize::from_str(di).unwrap();
+                let index: usize = usize::from_str(di).ok()?;
 
-                if lunar_day.next(1).get_year() != year {
+                let is_eve: bool = match lunar_day.try_next(1) {
+                    Ok(d) => d.get_year() != year,
+                    Err(_) => true,
+                };
+                if is_eve {
                     return Some(Self {
                         festival_type: FestivalType::EVE,
                         day: lunar_day,
@@ -255,32 +262,33 @@ impl LunarFestival {
             return None;
         }
         let reg: Regex =
-            Regex::new(format!("{}{:0>two$}{}", r"@", index, r"\d+", two = 2).as_str()).unwrap();
+            Regex::new(format!("{}{:0>two$}{}", r"@", index, r"\d+", two = 2).as_str()).ok()?;
         if reg.is_match(LUNAR_FESTIVAL_DATA) {
-            let data: &str = reg.find(LUNAR_FESTIVAL_DATA).unwrap().as_str();
-            let dt: usize = (data.chars().nth(3).unwrap() as usize) - ('0' as usize);
-            let festival_type: FestivalType = FestivalType::from_code(dt).unwrap();
+            let data: &str = reg.find(LUNAR_FESTIVAL_DATA)?.as_str();
+            let dt: usize = (data.chars().nth(3)? as usize) - ('0' as usize);
+            let festival_type: FestivalType = FestivalType::from_code(dt).ok()?;
             return match festival_type {
                 FestivalType::DAY => {
                     let dm: &str = &data[4..6];
                     let dd: &str = &data[6..8];
-                    let month: usize = usize::from_str(dm).unwrap();
-                    let day: usize = usize::from_str(dd).unwrap();
+                    let month: usize = usize::from_str(dm).ok()?;
+                    let day: usize = usize::from_str(dd).ok()?;
                     let di: &str = &data[1..3];
-                    let index: usize = usize::from_str(di).unwrap();
+                    let index: usize = usize::from_str(di).ok()?;
                     Some(Self {
                         festival_type: FestivalType::DAY,
-                        day: LunarDay::from_ymd(year, month as isize, day),
+                        day: LunarDay::try_from_ymd(year, month as isize, day).ok()?,
                         index,
                         solar_term: None,
                     })
                 }
                 FestivalType::TERM => {
                     let ti: &str = &data[4..data.len()];
-                    let term_index: usize = usize::from_str(ti).unwrap();
+                    let term_index: usize = usize::from_str(ti).ok()?;
+                    SolarYear::validate(year).ok()?;
                     let solar_term: SolarTerm = SolarTerm::from_index(year, term_index as isize);
                     let di: &str = &data[1..3];
-                    let index: usize = usize::from_str(di).unwrap();
+                    let index: usize = usize::from_str(di).ok()?;
                     let lunar_day: LunarDay = solar_term.get_solar_day().get_lunar_day();
                     Some(Self {
                         festival_type: FestivalType::TERM,
@@ -291,10 +299,13 @@ impl LunarFestival {
                 }
                 FestivalType::EVE => {
                     let di: &str = &data[1..3];
-                    let index: usize = usize::from_str(di).unwrap();
+                    let index: usize = usize::from_str(di).ok()?;
                     Some(Self {
                         festival_type: FestivalType::EVE,
-                        day: LunarDay::from_ymd(year + 1, 1, 1).next(-1),
+                        day: LunarDay::try_from_ymd(year + 1, 1, 1)
+                            .ok()?
+                            .try_next(-1)
+                            .ok()?,
                         index,
                         solar_term: None,
                     })
@@ -344,11 +355,280 @@ impl PartialEq for LunarFestival {
 
 impl Eq for LunarFestival {}
 
+pub static HEBREW_FESTIVAL_NAMES: [&str; 11] = [
+    "犹太新年",
+    "赎罪日",
+    "住棚节",
+    "圣会节",
+    "诵经节",
+    "光明节",
+    "树木新年",
+    "普珥节",
+    "逾越节",
+    "七七节",
+    "圣殿被毁日",
+];
+pub static HEBREW_FESTIVAL_DATA: &str =
+    "@0000701@0100710@0200715@0300722@0400723@0500925@0601115@0701214@0800115@0900306@1000509";
+
+/// 希伯来历节日（按犹太新年起的顺序，诵经节采用以色列以外的日期，普珥节在闰年为亚达二月14日，圣殿被毁日逢安息日时推迟至埃波月10日）
+#[derive(Debug, Copy, Clone)]
+pub struct HebrewFestival {
+    /// 类型
+    festival_type: FestivalType,
+    /// 希伯来历日
+    day: HebrewDay,
+    /// 索引
+    index: usize,
+}
+
+impl Culture for HebrewFestival {
+    fn get_name(&self) -> String {
+        HEBREW_FESTIVAL_NAMES[self.index].to_string()
+    }
+}
+
+impl HebrewFestival {
+    pub fn from_ymd(year: isize, month: usize, day: usize) -> Option<Self> {
+        let leap: bool = HebrewYear::try_from_year(year).ok()?.is_leap();
+        // 节日数据中的亚达月，闰年对应亚达二月
+        let m: usize = match month {
+            12 if leap => return None,
+            13 => 12,
+            _ => month,
+        };
+        let d: HebrewDay = HebrewDay::try_from_ymd(year, month, day).ok()?;
+        let f: Self = if m == 5 && day == 10 {
+            // 推迟的圣殿被毁日
+            Self::from_index(year, 10)?
+        } else {
+            let reg: Regex =
+                Regex::new(format!("{}{:0>two$}{:0>two$}", r"@\d{2}0", m, day, two = 2).as_str())
+                    .ok()?;
+            let data: &str = reg.find(HEBREW_FESTIVAL_DATA)?.as_str();
+            let di: &str = &data[1..3];
+            Self::from_index(year, usize::from_str(di).ok()?)?
+        };
+        if f.day != d {
+            return None;
+        }
+        Some(f)
+    }
+
+    pub fn from_index(year: isize, index: usize) -> Option<Self> {
+        if index >= HEBREW_FESTIVAL_NAMES.len() {
+            return None;
+        }
+        let reg: Regex =
+            Regex::new(format!("{}{:0>two$}{}", r"@", index, r"\d+", two = 2).as_str()).ok()?;
+        let data: &str = reg.find(HEBREW_FESTIVAL_DATA)?.as_str();
+        let dm: &str = &data[4..6];
+        let dd: &str = &data[6..8];
+        let mut month: usize = usize::from_str(dm).ok()?;
+        let day: usize = usize::from_str(dd).ok()?;
+        if month == 12 && HebrewYear::try_from_year(year).ok()?.is_leap() {
+            month = 13;
+        }
+        let mut d: HebrewDay = HebrewDay::try_from_ymd(year, month, day).ok()?;
+        // 圣殿被毁日逢安息日时推迟一日
+        if index == 10 && 6 == d.get_solar_day().get_week().get_index() {
+            d = d.try_next(1).ok()?;
+        }
+        Some(Self {
+            festival_type: FestivalType::DAY,
+            day: d,
+            index,
+        })
+    }
+
+    pub fn get_type(&self) -> FestivalType {
+        self.festival_type
+    }
+
+    pub fn get_index(&self) -> usize {
+        self.index
+    }
+
+    pub fn get_day(&self) -> HebrewDay {
+        self.day
+    }
+
+    pub fn next(&self, n: isize) -> Option<Self> {
+        let size: isize = HEBREW_FESTIVAL_NAMES.len() as isize;
+        let i: isize = self.get_index() as isize + n;
+        Self::from_index(
+            (self.day.get_year() * size + i) / size,
+            AbstractCulture::new().index_of(i, size as usize),
+        )
+    }
+}
+
+impl Display for HebrewFestival {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.get_day(), self.get_name())
+    }
+}
+
+impl PartialEq for HebrewFestival {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for HebrewFestival {}
+
+pub static RAB_BYUNG_FESTIVAL_NAMES: [&str; 9] = [
+    "藏历新年",
+    "神变节",
+    "萨嘎达瓦节",
+    "转法轮节",
+    "天降节",
+    "莲师荟供日",
+    "阿弥陀佛日",
+    "空行母荟供日",
+    "释迦牟尼佛日",
+];
+/// 月份为00的为每月的节日
+pub static RAB_BYUNG_FESTIVAL_DATA: &str =
+    "@0000101@0100115@0200415@0300604@0400922@0500010@0600015@0700025@0800030";
+
+/// 藏历节日（前5个为每年一次的节日，后4个为每月的节日，闰月只有每月的节日）
+#[derive(Debug, Clone)]
+pub struct RabByungFestival {
+    /// 类型
+    festival_type: FestivalType,
+    /// 节日所属的藏历月
+    month: RabByungMonth,
+    /// 过节的藏历日
+    day: RabByungDay,
+    /// 索引
+    index: usize,
+}
+
+impl Culture for RabByungFestival {
+    fn get_name(&self) -> String {
+        RAB_BYUNG_FESTIVAL_NAMES[self.index].to_string()
+    }
+}
+
+impl RabByungFestival {
+    /// 从藏历年月日（浦派）创建，日期为缺日时在前一日过节（藏历新年在当月首日），重日时在平日过节
+    pub fn from_ymd(year: isize, month: isize, day: usize) -> Option<Self> {
+        Self::from_month_day(RabByungMonth::from_ym(year, month).ok()?, day)
+    }
+
+    pub(crate) fn from_month_day(month: RabByungMonth, day: usize) -> Option<Self> {
+        let m: isize = month.get_month_with_leap();
+        let pattern: String = match m > 0 {
+            true => format!("(?:{:0>2}|00)", m),
+            false => "00".to_string(),
+        };
+        let reg: Regex =
+            Regex::new(format!("{}{}{:0>two$}", r"@\d{2}0", pattern, day, two = 2).as_str())
+                .ok()?;
+        let data: &str = reg.find(RAB_BYUNG_FESTIVAL_DATA)?.as_str();
+        let di: &str = &data[1..3];
+        Self::from_month_index(month, usize::from_str(di).ok()?)
+    }
+
+    pub(crate) fn from_month_index(month: RabByungMonth, index: usize) -> Option<Self> {
+        let (m, d): (isize, usize) = Self::get_month_day(index)?;
+        if m > 0 && m != month.get_month_with_leap() {
+            return None;
+        }
+        Some(Self {
+            festival_type: FestivalType::DAY,
+            day: month.get_observed_day(d).ok()?,
+            month,
+            index,
+        })
+    }
+
+    /// 从藏历年和索引（浦派）创建，每月的节日取正月
+    pub fn from_index(year: isize, index: usize) -> Option<Self> {
+        if index >= RAB_BYUNG_FESTIVAL_NAMES.len() {
+            return None;
+        }
+        let (month, _): (isize, usize) = Self::get_month_day(index)?;
+        Self::from_month_index(RabByungMonth::from_ym(year, month.max(1)).ok()?, index)
+    }
+
+    /// 数据中的月（每月的节日为0）和日
+    fn get_month_day(index: usize) -> Option<(isize, usize)> {
+        let reg: Regex =
+            Regex::new(format!("{}{:0>two$}{}", r"@", index, r"\d+", two = 2).as_str()).ok()?;
+        let data: &str = reg.find(RAB_BYUNG_FESTIVAL_DATA)?.as_str();
+        let dm: &str = &data[4..6];
+        let dd: &str = &data[6..8];
+        Some((isize::from_str(dm).ok()?, usize::from_str(dd).ok()?))
+    }
+
+    pub fn get_type(&self) -> FestivalType {
+        self.festival_type
+    }
+
+    pub fn get_index(&self) -> usize {
+        self.index
+    }
+
+    /// 过节的藏历日
+    pub fn get_day(&self) -> RabByungDay {
+        self.day.clone()
+    }
+
+    /// 节日所属的藏历月
+    pub fn get_rab_byung_month(&self) -> RabByungMonth {
+        self.month.clone()
+    }
+
+    /// 是否每月的节日
+    pub fn is_monthly(&self) -> bool {
+        self.index >= 5
+    }
+
+    /// 推移，每年的节日按年推移，每月的节日按月（含闰月）推移
+    pub fn next(&self, n: isize) -> Option<Self> {
+        let (size, offset): (isize, isize) = match self.is_monthly() {
+            true => (4, 5),
+            false => (5, 0),
+        };
+        let i: isize = self.index as isize - offset + n;
+        let index: usize =
+            (AbstractCulture::new().index_of(i, size as usize) as isize + offset) as usize;
+        let (month, _): (isize, usize) = Self::get_month_day(index)?;
+        let m: RabByungMonth = match self.is_monthly() {
+            true => self.month.next(i.div_euclid(size)).ok()?,
+            false => {
+                let y: isize = self.month.get_year() + i.div_euclid(size);
+                RabByungMonth::from_ym_with(self.month.get_tradition(), y, month).ok()?
+            }
+        };
+        Self::from_month_index(m, index)
+    }
+}
+
+impl Display for RabByungFestival {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.get_day(), self.get_name())
+    }
+}
+
+impl PartialEq for RabByungFestival {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string() && self.month == other.month
+    }
+}
+
+impl Eq for RabByungFestival {}
+
 #[cfg(test)]
 mod tests {
-    use crate::tyme::festival::{LunarFestival, SolarFestival};
+    use crate::tyme::festival::{HebrewFestival, LunarFestival, RabByungFestival, SolarFestival};
+    use crate::tyme::hebrew::HebrewDay;
     use crate::tyme::lunar::LunarDay;
+    use crate::tyme::rabbyung::RabByungDay;
     use crate::tyme::solar::SolarDay;
+    use crate::tyme::Culture;
 
     #[test]
     fn test1() {
@@ -381,7 +661,7 @@ mod tests {
     #[test]
     fn test5() {
         let f: Option<SolarFestival> = SolarFestival::from_index(2023, 0);
-        assert_eq!(false, f.is_none());
+        assert!(f.is_some());
         assert_eq!(
             "2024年5月1日 劳动节",
             f.unwrap().next(13).unwrap().to_string()
@@ -395,7 +675,7 @@ mod tests {
     #[test]
     fn test6() {
         let f: Option<SolarFestival> = SolarFestival::from_index(2023, 0);
-        assert_eq!(false, f.is_none());
+        assert!(f.is_some());
         assert_eq!(
             "2022年3月8日 妇女节",
             f.unwrap().next(-9).unwrap().to_string()
@@ -405,20 +685,211 @@ mod tests {
     #[test]
     fn test7() {
         let f: Option<SolarFestival> = SolarDay::from_ymd(2010, 1, 1).get_festival();
-        assert_eq!(false, f.is_none());
+        assert!(f.is_some());
         assert_eq!("2010年1月1日 元旦", f.unwrap().to_string());
     }
 
     #[test]
     fn test8() {
         let f: Option<SolarFestival> = SolarDay::from_ymd(2021, 5, 4).get_festival();
-        assert_eq!(false, f.is_none());
+        assert!(f.is_some());
         assert_eq!("2021年5月4日 青年节", f.unwrap().to_string());
     }
 
     #[test]
     fn test9() {
         let f: Option<SolarFestival> = SolarDay::from_ymd(1939, 5, 4).get_festival();
-        assert_eq!(true, f.is_none());
+        assert!(f.is_none());
+    }
+
+    #[test]
+    fn test10() {
+        assert!(LunarFestival::from_ymd(2023, 1, 30).is_none());
+        assert!(LunarFestival::from_index(10000, 0).is_none());
+        assert!(SolarFestival::from_ymd(2023, 2, 30).is_none());
+    }
+
+    #[test]
+    fn test11() {
+        let f: HebrewFestival = HebrewFestival::from_index(5785, 0).unwrap();
+        assert_eq!("希伯来历5785年提斯利月1日 犹太新年", f.to_string());
+        assert_eq!("2024年10月3日", f.get_day().get_solar_day().to_string());
+        assert_eq!(
+            "希伯来历5785年尼散月15日 逾越节",
+            f.next(8).unwrap().to_string()
+        );
+        assert_eq!(
+            "希伯来历5786年提斯利月10日 赎罪日",
+            f.next(12).unwrap().to_string()
+        );
+        assert_eq!(
+            "希伯来历5784年埃波月9日 圣殿被毁日",
+            f.next(-1).unwrap().to_string()
+        );
+    }
+
+    #[test]
+    fn test12() {
+        // 闰年的普珥节在亚达二月
+        let f: HebrewFestival = HebrewFestival::from_index(5784, 7).unwrap();
+        assert_eq!("希伯来历5784年亚达二月14日 普珥节", f.to_string());
+        assert_eq!("2024年3月24日", f.get_day().get_solar_day().to_string());
+        assert!(HebrewDay::from_ymd(5784, 12, 14).get_festival().is_none());
+        assert_eq!(
+            "2025年3月14日",
+            HebrewFestival::from_index(5785, 7)
+                .unwrap()
+                .get_day()
+                .get_solar_day()
+                .to_string()
+        );
+        assert_eq!(
+            "光明节",
+            SolarDay::from_ymd(2024, 12, 26)
+                .get_hebrew_day()
+                .unwrap()
+                .get_festival()
+                .unwrap()
+                .get_name()
+        );
+        assert!(HebrewFestival::from_ymd(5785, 7, 2).is_none());
+    }
+
+    #[test]
+    fn test13() {
+        let f: RabByungFestival = RabByungFestival::from_index(2024, 0).unwrap();
+        assert_eq!("第十七饶迥木龙年正月初一 藏历新年", f.to_string());
+        assert_eq!("2024年2月10日", f.get_day().get_solar_day().to_string());
+        assert_eq!(
+            "第十七饶迥木蛇年正月初一 藏历新年",
+            f.next(5).unwrap().to_string()
+        );
+        assert_eq!(
+            "第十七饶迥水兔年九月廿二 天降节",
+            f.next(-1).unwrap().to_string()
+        );
+        assert_eq!(
+            "2024年5月23日",
+            RabByungFestival::from_index(2024, 2)
+                .unwrap()
+                .get_day()
+                .get_solar_day()
+                .to_string()
+        );
+        // 闰九月不过天降节
+        assert_eq!(
+            "2021年10月27日",
+            RabByungFestival::from_index(2021, 4)
+                .unwrap()
+                .get_day()
+                .get_solar_day()
+                .to_string()
+        );
+        assert!(RabByungFestival::from_ymd(2021, -9, 22).is_none());
+        assert!(RabByungFestival::from_index(2024, 9).is_none());
+    }
+
+    #[test]
+    fn test14() {
+        // 每月的节日按月推移，与年节同日时不混入年节
+        let f: RabByungFestival = RabByungFestival::from_index(2024, 5).unwrap();
+        assert!(f.is_monthly());
+        assert_eq!(
+            "第十七饶迥木龙年正月十五 阿弥陀佛日",
+            f.next(1).unwrap().to_string()
+        );
+        assert_eq!(
+            "第十七饶迥木龙年二月初十 莲师荟供日",
+            f.next(4).unwrap().to_string()
+        );
+        assert_eq!(
+            "第十七饶迥水兔年十二月三十 释迦牟尼佛日",
+            f.next(-1).unwrap().to_string()
+        );
+        assert_eq!(
+            "第十七饶迥铁牛年闰九月初十 莲师荟供日",
+            RabByungFestival::from_index(2021, 8)
+                .unwrap()
+                .next(33)
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            "神变节",
+            RabByungDay::from_ymd(2024, 1, 15)
+                .unwrap()
+                .get_festival()
+                .unwrap()
+                .get_name()
+        );
+    }
+
+    #[test]
+    fn test15() {
+        // 缺日在前一日过节
+        let f: RabByungFestival = RabByungFestival::from_index(2008, 1).unwrap();
+        assert_eq!("第十七饶迥土鼠年正月十四 神变节", f.to_string());
+        assert_eq!(
+            f,
+            RabByungDay::from_ymd(2008, 1, 14)
+                .unwrap()
+                .get_festival()
+                .unwrap()
+        );
+        // 初一缺日时藏历新年在当月首日
+        let f: RabByungFestival = RabByungFestival::from_index(1977, 0).unwrap();
+        assert_eq!("第十六饶迥火蛇年正月初二 藏历新年", f.to_string());
+        assert_eq!(
+            f,
+            RabByungDay::from_ymd(1977, 1, 2)
+                .unwrap()
+                .get_festival()
+                .unwrap()
+        );
+        assert!(RabByungDay::from_ymd(1976, 12, 30)
+            .unwrap()
+            .get_festival()
+            .unwrap()
+            .is_monthly());
+        // 重日在平日过节
+        let d: RabByungDay = RabByungDay::from_ymd(2015, 3, 15).unwrap();
+        assert_eq!("阿弥陀佛日", d.get_festival().unwrap().get_name());
+        assert!(d.next(1).unwrap().is_leap());
+        assert!(d.next(1).unwrap().get_festival().is_none());
+    }
+
+    #[test]
+    fn test16() {
+        // 5782年埃波月9日逢安息日，圣殿被毁日推迟至10日
+        assert_eq!(
+            "2022年8月6日",
+            HebrewDay::from_ymd(5782, 5, 9).get_solar_day().to_string()
+        );
+        let f: HebrewFestival = HebrewFestival::from_index(5782, 10).unwrap();
+        assert_eq!("希伯来历5782年埃波月10日 圣殿被毁日", f.to_string());
+        assert_eq!("2022年8月7日", f.get_day().get_solar_day().to_string());
+        assert_eq!(f, HebrewFestival::from_ymd(5782, 5, 10).unwrap());
+        assert!(HebrewFestival::from_ymd(5782, 5, 9).is_none());
+        assert_eq!(
+            "圣殿被毁日",
+            SolarDay::from_ymd(2022, 8, 7)
+                .get_hebrew_day()
+                .unwrap()
+                .get_festival()
+                .unwrap()
+                .get_name()
+        );
+        assert_eq!(
+            f,
+            HebrewFestival::from_index(5783, 0)
+                .unwrap()
+                .next(-1)
+                .unwrap()
+        );
+        // 5785年埃波月9日为星期日，不推迟
+        let f: HebrewFestival = HebrewFestival::from_ymd(5785, 5, 9).unwrap();
+        assert_eq!("2025年8月3日", f.get_day().get_solar_day().to_string());
+        assert_eq!(f, HebrewFestival::from_index(5785, 10).unwrap());
+        assert!(HebrewFestival::from_ymd(5785, 5, 10).is_none());
     }
 }
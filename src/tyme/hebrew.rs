@@ -0,0 +1,601 @@
+use std::fmt::{Display, Formatter};
+
+use crate::tyme::error::TymeError;
+use crate::tyme::festival::HebrewFestival;
+use crate::tyme::jd::{JulianDay, J2000};
+use crate::tyme::solar::SolarDay;
+use crate::tyme::zone::{UtcOffset, ZonedSolarTime};
+use crate::tyme::{Culture, Tyme};
+
+/// 希伯来历月名称（按圣经顺序，尼散月为1月，闰年的亚达二月为13月）
+pub static HEBREW_MONTH_NAMES: [&str; 13] = [
+    "尼散月",
+    "以珥月",
+    "西弯月",
+    "搭模斯月",
+    "埃波月",
+    "以禄月",
+    "提斯利月",
+    "玛西班月",
+    "基斯流月",
+    "提别月",
+    "细罢特月",
+    "亚达月",
+    "亚达二月",
+];
+
+/// 创世纪元（公元前3761年10月7日）的日序号（J2000起算）
+static EPOCH: isize = -2103547;
+
+/// 每日的分（希伯来历1小时为1080分）
+static PARTS_PER_DAY: isize = 25920;
+
+/// 耶路撒冷的经度
+static JERUSALEM: f64 = 35.2338;
+
+/// 截至year年提斯利月的朔望月数
+fn get_months_elapsed(year: isize) -> isize {
+    (235 * year - 234).div_euclid(19)
+}
+
+/// 创世纪元至year年提斯利月1日的天数（已含“朔日不在周日、周三、周五”的推迟规则）
+fn get_elapsed_days(year: isize) -> isize {
+    let months: isize = get_months_elapsed(year);
+    let parts: isize = 12084 + 13753 * months;
+    let day: isize = 29 * months + parts.div_euclid(PARTS_PER_DAY);
+    if (3 * (day + 1)).rem_euclid(7) < 3 {
+        day + 1
+    } else {
+        day
+    }
+}
+
+/// 新年的推迟天数，避免相邻年份出现356天或382天
+fn get_year_length_correction(year: isize) -> isize {
+    let ny0: isize = get_elapsed_days(year - 1);
+    let ny1: isize = get_elapsed_days(year);
+    let ny2: isize = get_elapsed_days(year + 1);
+    if ny2 - ny1 == 356 {
+        2
+    } else if ny1 - ny0 == 382 {
+        1
+    } else {
+        0
+    }
+}
+
+/// year年提斯利月1日的日序号（J2000起算）
+fn get_new_year(year: isize) -> isize {
+    EPOCH + get_elapsed_days(year) + get_year_length_correction(year)
+}
+
+/// 希伯来历年，以提斯利月1日（犹太新年）为岁首
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct HebrewYear {
+    year: isize,
+}
+
+impl Tyme for HebrewYear {
+    fn next(&self, n: isize) -> Self {
+        self.try_next(n).unwrap()
+    }
+}
+
+impl Culture for HebrewYear {
+    fn get_name(&self) -> String {
+        format!("希伯来历{}年", self.year)
+    }
+}
+
+impl HebrewYear {
+    pub fn new(year: isize) -> Result<Self, TymeError> {
+        Self::validate(year)?;
+        Ok(Self { year })
+    }
+
+    /// 校验年，支持3762年至13759年（公元1年至9999年）
+    pub fn validate(year: isize) -> Result<(), TymeError> {
+        if !(3762..=13759).contains(&year) {
+            return Err(TymeError::YearOutOfRange {
+                calendar: "hebrew",
+                year,
+                min: 3762,
+                max: 13759,
+            });
+        }
+        Ok(())
+    }
+
+    pub fn from_year(year: isize) -> Self {
+        Self::new(year).unwrap()
+    }
+
+    pub fn try_from_year(year: isize) -> Result<Self, TymeError> {
+        Self::new(year)
+    }
+
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        Self::new(self.year + n)
+    }
+
+    pub fn get_year(&self) -> isize {
+        self.year
+    }
+
+    /// 是否闰年（19年7闰，闰年有亚达一月和亚达二月）
+    pub fn is_leap(&self) -> bool {
+        (7 * self.year + 1).rem_euclid(19) < 7
+    }
+
+    pub fn get_month_count(&self) -> usize {
+        if self.is_leap() {
+            13
+        } else {
+            12
+        }
+    }
+
+    /// 天数（353、354、355、383、384或385）
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::hebrew::HebrewYear;
+    ///
+    /// assert_eq!(383, HebrewYear::from_year(5784).get_day_count());
+    /// assert_eq!(355, HebrewYear::from_year(5785).get_day_count());
+    /// ```
+    pub fn get_day_count(&self) -> usize {
+        (get_new_year(self.year + 1) - get_new_year(self.year)) as usize
+    }
+
+    /// 月列表，从提斯利月开始
+    pub fn get_months(&self) -> Vec<HebrewMonth> {
+        let count: usize = self.get_month_count();
+        (7..=count)
+            .chain(1..7)
+            .map(|month| HebrewMonth {
+                year: self.year,
+                month,
+            })
+            .collect()
+    }
+
+    pub fn get_first_month(&self) -> HebrewMonth {
+        HebrewMonth {
+            year: self.year,
+            month: 7,
+        }
+    }
+}
+
+impl Display for HebrewYear {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.get_name())
+    }
+}
+
+/// 希伯来历月
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct HebrewMonth {
+    year: isize,
+    month: usize,
+}
+
+impl Tyme for HebrewMonth {
+    fn next(&self, n: isize) -> Self {
+        self.try_next(n).unwrap()
+    }
+}
+
+impl Culture for HebrewMonth {
+    fn get_name(&self) -> String {
+        if self.month == 12 && self.get_hebrew_year().is_leap() {
+            "亚达一月".to_string()
+        } else {
+            HEBREW_MONTH_NAMES[self.month - 1].to_string()
+        }
+    }
+}
+
+impl HebrewMonth {
+    /// 月按圣经顺序：1为尼散月，7为提斯利月，13为闰年的亚达二月
+    pub fn new(year: isize, month: usize) -> Result<Self, TymeError> {
+        let y: HebrewYear = HebrewYear::new(year)?;
+        if !(1..=13).contains(&month) {
+            return Err(TymeError::IllegalMonth {
+                calendar: "hebrew",
+                year,
+                month: month as isize,
+            });
+        }
+        if month > y.get_month_count() {
+            return Err(TymeError::IllegalLeapMonth {
+                calendar: "hebrew",
+                year,
+                month: month as isize,
+            });
+        }
+        Ok(Self { year, month })
+    }
+
+    pub fn from_ym(year: isize, month: usize) -> Self {
+        Self::new(year, month).unwrap()
+    }
+
+    pub fn try_from_ym(year: isize, month: usize) -> Result<Self, TymeError> {
+        Self::new(year, month)
+    }
+
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        if n == 0 {
+            return Ok(*self);
+        }
+        let mut y: HebrewYear = self.get_hebrew_year();
+        let mut i: isize = self.get_index_in_year() as isize + n;
+        while i < 0 {
+            y = y.try_next(-1)?;
+            i += y.get_month_count() as isize;
+        }
+        while i >= y.get_month_count() as isize {
+            i -= y.get_month_count() as isize;
+            y = y.try_next(1)?;
+        }
+        Ok(y.get_months()[i as usize])
+    }
+
+    pub fn get_hebrew_year(&self) -> HebrewYear {
+        HebrewYear { year: self.year }
+    }
+
+    pub fn get_year(&self) -> isize {
+        self.year
+    }
+
+    pub fn get_month(&self) -> usize {
+        self.month
+    }
+
+    /// 位于当年的索引，提斯利月为0
+    pub fn get_index_in_year(&self) -> usize {
+        if self.month >= 7 {
+            self.month - 7
+        } else {
+            self.month + self.get_hebrew_year().get_month_count() - 7
+        }
+    }
+
+    /// 天数（29或30），玛西班月与基斯流月随年长变化
+    pub fn get_day_count(&self) -> usize {
+        let y: HebrewYear = self.get_hebrew_year();
+        match self.month {
+            2 | 4 | 6 | 10 | 13 => 29,
+            8 if y.get_day_count() % 10 != 5 => 29,
+            9 if y.get_day_count() % 10 == 3 => 29,
+            12 if !y.is_leap() => 29,
+            _ => 30,
+        }
+    }
+
+    /// 1日的日序号（J2000起算）
+    fn get_first_day_index(&self) -> isize {
+        let days: usize = self
+            .get_hebrew_year()
+            .get_months()
+            .iter()
+            .take(self.get_index_in_year())
+            .map(|m| m.get_day_count())
+            .sum();
+        get_new_year(self.year) + days as isize
+    }
+
+    pub fn get_first_day(&self) -> HebrewDay {
+        HebrewDay {
+            month: *self,
+            day: 1,
+        }
+    }
+
+    pub fn get_days(&self) -> Vec<HebrewDay> {
+        (1..=self.get_day_count())
+            .map(|day| HebrewDay { month: *self, day })
+            .collect()
+    }
+
+    /// 合会（平朔的时刻，耶路撒冷地方平时），每月相隔29日12小时793分
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::hebrew::HebrewMonth;
+    ///
+    /// // 5784年提斯利月的合会在周五5时49分
+    /// assert_eq!("2023年9月15日 05:49:00 +02:20:56", HebrewMonth::from_ym(5784, 7).get_molad().to_string());
+    /// ```
+    pub fn get_molad(&self) -> ZonedSolarTime {
+        let year: isize = if self.month < 7 {
+            self.year + 1
+        } else {
+            self.year
+        };
+        let months: isize = self.month as isize - 7 + get_months_elapsed(year);
+        let parts: isize = -876 + months * (29 * PARTS_PER_DAY + 12 * 1080 + 793);
+        let offset: UtcOffset = UtcOffset::from_meridian(JERUSALEM);
+        ZonedSolarTime::from_julian_day(
+            JulianDay::from_julian_day(
+                J2000 - 0.5
+                    + (EPOCH + parts.div_euclid(PARTS_PER_DAY)) as f64
+                    + parts.rem_euclid(PARTS_PER_DAY) as f64 / PARTS_PER_DAY as f64
+                    - offset.get_days(),
+            ),
+            offset,
+        )
+    }
+}
+
+impl Display for HebrewMonth {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.get_hebrew_year(), self.get_name())
+    }
+}
+
+/// 希伯来历日
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct HebrewDay {
+    month: HebrewMonth,
+    day: usize,
+}
+
+impl Tyme for HebrewDay {
+    fn next(&self, n: isize) -> Self {
+        self.try_next(n).unwrap()
+    }
+}
+
+impl Culture for HebrewDay {
+    fn get_name(&self) -> String {
+        format!("{}日", self.day)
+    }
+}
+
+impl HebrewDay {
+    pub fn new(year: isize, month: usize, day: usize) -> Result<Self, TymeError> {
+        let m: HebrewMonth = HebrewMonth::new(year, month)?;
+        if day < 1 || day > m.get_day_count() {
+            return Err(TymeError::IllegalDay {
+                calendar: "hebrew",
+                year,
+                month: month as isize,
+                day: day as isize,
+            });
+        }
+        Ok(Self { month: m, day })
+    }
+
+    pub fn from_ymd(year: isize, month: usize, day: usize) -> Self {
+        Self::new(year, month, day).unwrap()
+    }
+
+    pub fn try_from_ymd(year: isize, month: usize, day: usize) -> Result<Self, TymeError> {
+        Self::new(year, month, day)
+    }
+
+    /// 从公历日创建
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::hebrew::HebrewDay;
+    /// use tyme4rs::tyme::solar::SolarDay;
+    ///
+    /// let d: HebrewDay = HebrewDay::from_solar_day(SolarDay::from_ymd(2024, 10, 3));
+    /// assert_eq!("希伯来历5785年提斯利月1日", d.to_string());
+    /// ```
+    pub fn from_solar_day(solar_day: SolarDay) -> Self {
+        Self::try_from_solar_day(solar_day).unwrap()
+    }
+
+    /// 从公历日创建，超出范围时返回错误
+    pub fn try_from_solar_day(solar_day: SolarDay) -> Result<Self, TymeError> {
+        Self::try_from_julian_day(solar_day.get_julian_day())
+    }
+
+    /// 从儒略日创建，超出范围时返回错误
+    pub fn try_from_julian_day(julian_day: JulianDay) -> Result<Self, TymeError> {
+        let d: isize = (julian_day.get_day() - J2000 + 0.5).floor() as isize;
+        let mut year: isize = ((d - EPOCH) as f64 / 365.2468).floor() as isize + 1;
+        while get_new_year(year) > d {
+            year -= 1;
+        }
+        while get_new_year(year + 1) <= d {
+            year += 1;
+        }
+        let y: HebrewYear = HebrewYear::new(year)?;
+        let mut first: isize = get_new_year(year);
+        for m in y.get_months() {
+            let count: isize = m.get_day_count() as isize;
+            if d < first + count {
+                return Ok(Self {
+                    month: m,
+                    day: (d - first) as usize + 1,
+                });
+            }
+            first += count;
+        }
+        unreachable!()
+    }
+
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        Self::try_from_solar_day(self.get_solar_day().try_next(n)?)
+    }
+
+    pub fn get_hebrew_month(&self) -> HebrewMonth {
+        self.month
+    }
+
+    pub fn get_year(&self) -> isize {
+        self.month.year
+    }
+
+    pub fn get_month(&self) -> usize {
+        self.month.month
+    }
+
+    pub fn get_day(&self) -> usize {
+        self.day
+    }
+
+    pub fn get_julian_day(&self) -> JulianDay {
+        JulianDay::from_julian_day(
+            J2000 + (self.month.get_first_day_index() + self.day as isize - 1) as f64,
+        )
+    }
+
+    pub fn get_solar_day(&self) -> SolarDay {
+        self.get_julian_day().get_solar_day()
+    }
+
+    pub fn subtract(&self, other: Self) -> isize {
+        self.get_solar_day().subtract(other.get_solar_day())
+    }
+
+    /// 希伯来历节日
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::festival::HebrewFestival;
+    /// use tyme4rs::tyme::hebrew::HebrewDay;
+    /// use tyme4rs::tyme::Culture;
+    ///
+    /// let festival: Option<HebrewFestival> = HebrewDay::from_ymd(5785, 7, 10).get_festival();
+    /// assert_eq!("赎罪日", festival.unwrap().get_name());
+    /// ```
+    pub fn get_festival(&self) -> Option<HebrewFestival> {
+        HebrewFestival::from_ymd(self.get_year(), self.get_month(), self.day)
+    }
+}
+
+impl Display for HebrewDay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.month, self.get_name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tyme::error::TymeError;
+    use crate::tyme::hebrew::{HebrewDay, HebrewMonth, HebrewYear};
+    use crate::tyme::solar::SolarDay;
+    use crate::tyme::{Culture, Tyme};
+
+    #[test]
+    fn test0() {
+        // 犹太新年
+        let fixtures: [(isize, [usize; 3]); 8] = [
+            (5760, [1999, 9, 11]),
+            (5770, [2009, 9, 19]),
+            (5781, [2020, 9, 19]),
+            (5782, [2021, 9, 7]),
+            (5783, [2022, 9, 26]),
+            (5784, [2023, 9, 16]),
+            (5785, [2024, 10, 3]),
+            (5786, [2025, 9, 23]),
+        ];
+        for (year, [y, m, d]) in fixtures {
+            let day: HebrewDay = HebrewYear::from_year(year)
+                .get_first_month()
+                .get_first_day();
+            assert_eq!(SolarDay::from_ymd(y as isize, m, d), day.get_solar_day());
+            assert_eq!(
+                day,
+                SolarDay::from_ymd(y as isize, m, d)
+                    .get_hebrew_day()
+                    .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test1() {
+        let y: HebrewYear = HebrewYear::from_year(5784);
+        assert!(y.is_leap());
+        assert_eq!(13, y.get_month_count());
+        let months: Vec<HebrewMonth> = y.get_months();
+        assert_eq!("提斯利月", months[0].get_name());
+        assert_eq!("亚达一月", months[5].get_name());
+        assert_eq!("亚达二月", months[6].get_name());
+        assert_eq!("以禄月", months[12].get_name());
+        assert_eq!(383, months.iter().map(|m| m.get_day_count()).sum::<usize>());
+        assert_eq!("亚达月", HebrewMonth::from_ym(5785, 12).get_name());
+        assert_eq!(
+            Err(TymeError::IllegalLeapMonth {
+                calendar: "hebrew",
+                year: 5785,
+                month: 13
+            }),
+            HebrewMonth::try_from_ym(5785, 13)
+        );
+    }
+
+    #[test]
+    fn test2() {
+        // 年长与推迟规则
+        let lengths: [(isize, usize); 6] = [
+            (5779, 385),
+            (5780, 355),
+            (5781, 353),
+            (5782, 384),
+            (5783, 355),
+            (5785, 355),
+        ];
+        for (year, length) in lengths {
+            assert_eq!(length, HebrewYear::from_year(year).get_day_count());
+        }
+    }
+
+    #[test]
+    fn test3() {
+        let d: HebrewDay = HebrewDay::from_ymd(5784, 1, 15);
+        assert_eq!("希伯来历5784年尼散月15日", d.to_string());
+        assert_eq!("2024年4月23日", d.get_solar_day().to_string());
+        assert_eq!("希伯来历5784年以禄月29日", d.next(162).to_string());
+        assert_eq!("希伯来历5785年提斯利月1日", d.next(163).to_string());
+        assert_eq!(163, d.next(163).subtract(d));
+        assert_eq!(
+            "希伯来历5785年提斯利月",
+            HebrewMonth::from_ym(5784, 6).next(1).to_string()
+        );
+        assert_eq!(
+            "希伯来历5784年亚达二月",
+            HebrewMonth::from_ym(5785, 7).next(-7).to_string()
+        );
+    }
+
+    #[test]
+    fn test4() {
+        // 往返转换
+        let mut solar_day: SolarDay = SolarDay::from_ymd(2023, 1, 1);
+        for _ in 0..1200 {
+            let d: HebrewDay = solar_day.get_hebrew_day().unwrap();
+            assert_eq!(solar_day, d.get_solar_day());
+            assert_eq!(
+                d,
+                HebrewDay::from_ymd(d.get_year(), d.get_month(), d.get_day())
+            );
+            solar_day = solar_day.next(1);
+        }
+    }
+
+    #[test]
+    fn test5() {
+        assert_eq!(
+            "2023年9月15日 05:49:00 +02:20:56",
+            HebrewMonth::from_ym(5784, 7).get_molad().to_string()
+        );
+        assert!(HebrewDay::try_from_solar_day(SolarDay::from_ymd(1, 1, 1)).is_err());
+    }
+}
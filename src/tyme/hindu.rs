@@ -0,0 +1,761 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Deref, DerefMut};
+
+use crate::tyme::astro::sun::SunEvents;
+use crate::tyme::culture::panchang::{Karana, Nakshatra, Yoga};
+use crate::tyme::culture::star::seven::SevenStar;
+use crate::tyme::enums::{Ayanamsa, Paksha};
+use crate::tyme::error::TymeError;
+use crate::tyme::jd::J2000;
+use crate::tyme::solar::{SolarDay, SolarTime};
+use crate::tyme::util::ShouXingUtil;
+use crate::tyme::zone::{get_new_moon, UtcOffset, ZonedSolarTime, SYNODIC_MONTH};
+use crate::tyme::{Culture, LoopTyme, Tyme};
+
+/// 太阴日名称（白分15个，黑分15个）
+pub static TITHI_NAMES: [&str; 30] = [
+    "白分初一",
+    "白分初二",
+    "白分初三",
+    "白分初四",
+    "白分初五",
+    "白分初六",
+    "白分初七",
+    "白分初八",
+    "白分初九",
+    "白分初十",
+    "白分十一",
+    "白分十二",
+    "白分十三",
+    "白分十四",
+    "望日",
+    "黑分初一",
+    "黑分初二",
+    "黑分初三",
+    "黑分初四",
+    "黑分初五",
+    "黑分初六",
+    "黑分初七",
+    "黑分初八",
+    "黑分初九",
+    "黑分初十",
+    "黑分十一",
+    "黑分十二",
+    "黑分十三",
+    "黑分十四",
+    "晦日",
+];
+
+/// 太阴日的IAST转写名称
+pub static TITHI_IAST_NAMES: [&str; 30] = [
+    "Śukla Pratipadā",
+    "Śukla Dvitīyā",
+    "Śukla Tṛtīyā",
+    "Śukla Caturthī",
+    "Śukla Pañcamī",
+    "Śukla Ṣaṣṭhī",
+    "Śukla Saptamī",
+    "Śukla Aṣṭamī",
+    "Śukla Navamī",
+    "Śukla Daśamī",
+    "Śukla Ekādaśī",
+    "Śukla Dvādaśī",
+    "Śukla Trayodaśī",
+    "Śukla Caturdaśī",
+    "Pūrṇimā",
+    "Kṛṣṇa Pratipadā",
+    "Kṛṣṇa Dvitīyā",
+    "Kṛṣṇa Tṛtīyā",
+    "Kṛṣṇa Caturthī",
+    "Kṛṣṇa Pañcamī",
+    "Kṛṣṇa Ṣaṣṭhī",
+    "Kṛṣṇa Saptamī",
+    "Kṛṣṇa Aṣṭamī",
+    "Kṛṣṇa Navamī",
+    "Kṛṣṇa Daśamī",
+    "Kṛṣṇa Ekādaśī",
+    "Kṛṣṇa Dvādaśī",
+    "Kṛṣṇa Trayodaśī",
+    "Kṛṣṇa Caturdaśī",
+    "Amāvasyā",
+];
+
+/// 星期名称（从星期日起）
+pub static VARA_NAMES: [&str; 7] = [
+    "日曜日",
+    "月曜日",
+    "火曜日",
+    "水曜日",
+    "木曜日",
+    "金曜日",
+    "土曜日",
+];
+
+/// 星期的IAST转写名称
+pub static VARA_IAST_NAMES: [&str; 7] = [
+    "Ravivāra",
+    "Somavāra",
+    "Maṅgalavāra",
+    "Budhavāra",
+    "Guruvāra",
+    "Śukravāra",
+    "Śanivāra",
+];
+
+/// 月名称（从制呾罗月起，据《大唐西域记》）
+pub static MASA_NAMES: [&str; 12] = [
+    "制呾罗月",
+    "吠舍佉月",
+    "逝瑟吒月",
+    "頞沙荼月",
+    "室罗伐拏月",
+    "婆罗钵陀月",
+    "頞湿缚庾阇月",
+    "迦剌底迦月",
+    "末伽始罗月",
+    "报沙月",
+    "磨祛月",
+    "颇勒窭拏月",
+];
+
+/// 月的IAST转写名称
+pub static MASA_IAST_NAMES: [&str; 12] = [
+    "Caitra",
+    "Vaiśākha",
+    "Jyeṣṭha",
+    "Āṣāḍha",
+    "Śrāvaṇa",
+    "Bhādrapada",
+    "Āśvina",
+    "Kārttika",
+    "Mārgaśīrṣa",
+    "Pauṣa",
+    "Māgha",
+    "Phālguna",
+];
+
+/// 贝塞尔年1956.0的日序号（J2000起算）
+static B1956: f64 = 2435473.87665 - J2000;
+
+/// 回归年长度（日）
+static TROPICAL_YEAR: f64 = 365.242198781;
+
+/// 平岁差（度），t为J2000起算的日数
+///
+/// 拉希里岁差按《印度天文星历》的定义：1956.0时为23°15′00.658″，每年50.2564″加0.000222″t²（t为年数）。
+/// 其余岁差按瑞士星历表J2000时的历元值推得，之后按IAU 2006黄经总岁差推移。
+fn get_ayanamsa_degrees(ayanamsa: Ayanamsa, t: f64) -> f64 {
+    let a: f64 = match ayanamsa {
+        Ayanamsa::Lahiri => {
+            let y: f64 = (t - B1956) / TROPICAL_YEAR;
+            return 23.250182778 + (50.2564 * y + 0.000222 * y * y) / 3600.0;
+        }
+        Ayanamsa::Raman => 22.411021,
+        Ayanamsa::Krishnamurti => 23.760470,
+    };
+    let c: f64 = t / 36525.0;
+    a + (5028.796195 * c + 1.1054348 * c * c) / 3600.0
+}
+
+/// 太阳、月亮的恒星黄经（度），t为J2000起算的世界时日数
+fn get_sidereal_longitudes(t: f64, ayanamsa: Ayanamsa) -> (f64, f64) {
+    let c: f64 = (t + ShouXingUtil::dtt(t)) / 36525.0;
+    let a: f64 = get_ayanamsa_degrees(ayanamsa, t);
+    // 岁差以平春分点起算，故不含章动
+    let sun: f64 = (ShouXingUtil::sa_lon(c, -1) - ShouXingUtil::nutation_lon2(c)).to_degrees();
+    let moon: f64 = (ShouXingUtil::mlon(c, -1) - 3.4E-6).to_degrees();
+    ((sun - a).rem_euclid(360.0), (moon - a).rem_euclid(360.0))
+}
+
+/// 世界时所在太阴月的朔序号
+fn get_lunation(t: f64) -> isize {
+    let mut k: isize = ((t - get_new_moon(0)) / SYNODIC_MONTH).floor() as isize;
+    while get_new_moon(k) > t {
+        k -= 1;
+    }
+    while get_new_moon(k + 1) <= t {
+        k += 1;
+    }
+    k
+}
+
+/// 太阴日（Tithi），日月黄经差每12度为一个太阴日
+#[derive(Debug, Clone)]
+pub struct Tithi {
+    parent: LoopTyme,
+}
+
+impl Deref for Tithi {
+    type Target = LoopTyme;
+
+    fn deref(&self) -> &Self::Target {
+        &self.parent
+    }
+}
+
+impl DerefMut for Tithi {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.parent
+    }
+}
+
+impl Tyme for Tithi {
+    fn next(&self, n: isize) -> Self {
+        Self::from_index(self.parent.next_index(n) as isize)
+    }
+}
+
+impl Culture for Tithi {
+    fn get_name(&self) -> String {
+        self.parent.get_name()
+    }
+}
+
+impl Tithi {
+    pub fn from_index(index: isize) -> Self {
+        Self {
+            parent: LoopTyme::from_index(
+                TITHI_NAMES.to_vec().iter().map(|x| x.to_string()).collect(),
+                index,
+            ),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Self {
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
+                TITHI_NAMES.to_vec().iter().map(|x| x.to_string()).collect(),
+                name,
+            )?,
+        })
+    }
+
+    /// 月半
+    pub fn get_paksha(&self) -> Paksha {
+        match self.get_index() < 15 {
+            true => Paksha::Shukla,
+            false => Paksha::Krishna,
+        }
+    }
+
+    /// 在月半中的序号（1-15）
+    pub fn get_day(&self) -> usize {
+        self.get_index() % 15 + 1
+    }
+
+    /// IAST转写名称
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::hindu::Tithi;
+    ///
+    /// assert_eq!("Pūrṇimā", Tithi::from_name("望日").get_iast_name());
+    /// ```
+    pub fn get_iast_name(&self) -> String {
+        TITHI_IAST_NAMES[self.get_index()].to_string()
+    }
+}
+
+impl Display for Tithi {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.get_name())
+    }
+}
+
+impl PartialEq for Tithi {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for Tithi {}
+
+impl From<Tithi> for LoopTyme {
+    fn from(val: Tithi) -> Self {
+        val.parent
+    }
+}
+
+/// 星期（Vara）
+#[derive(Debug, Clone)]
+pub struct Vara {
+    parent: LoopTyme,
+}
+
+impl Deref for Vara {
+    type Target = LoopTyme;
+
+    fn deref(&self) -> &Self::Target {
+        &self.parent
+    }
+}
+
+impl DerefMut for Vara {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.parent
+    }
+}
+
+impl Tyme for Vara {
+    fn next(&self, n: isize) -> Self {
+        Self::from_index(self.parent.next_index(n) as isize)
+    }
+}
+
+impl Culture for Vara {
+    fn get_name(&self) -> String {
+        self.parent.get_name()
+    }
+}
+
+impl Vara {
+    pub fn from_index(index: isize) -> Self {
+        Self {
+            parent: LoopTyme::from_index(
+                VARA_NAMES.to_vec().iter().map(|x| x.to_string()).collect(),
+                index,
+            ),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Self {
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
+                VARA_NAMES.to_vec().iter().map(|x| x.to_string()).collect(),
+                name,
+            )?,
+        })
+    }
+
+    /// 七曜
+    pub fn get_seven_star(&self) -> SevenStar {
+        SevenStar::from_index(self.get_index() as isize)
+    }
+
+    /// IAST转写名称
+    pub fn get_iast_name(&self) -> String {
+        VARA_IAST_NAMES[self.get_index()].to_string()
+    }
+}
+
+impl Display for Vara {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.get_name())
+    }
+}
+
+impl PartialEq for Vara {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for Vara {}
+
+impl From<Vara> for LoopTyme {
+    fn from(val: Vara) -> Self {
+        val.parent
+    }
+}
+
+/// 印度历月名（Masa）
+#[derive(Debug, Clone)]
+pub struct Masa {
+    parent: LoopTyme,
+}
+
+impl Deref for Masa {
+    type Target = LoopTyme;
+
+    fn deref(&self) -> &Self::Target {
+        &self.parent
+    }
+}
+
+impl DerefMut for Masa {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.parent
+    }
+}
+
+impl Tyme for Masa {
+    fn next(&self, n: isize) -> Self {
+        Self::from_index(self.parent.next_index(n) as isize)
+    }
+}
+
+impl Culture for Masa {
+    fn get_name(&self) -> String {
+        self.parent.get_name()
+    }
+}
+
+impl Masa {
+    pub fn from_index(index: isize) -> Self {
+        Self {
+            parent: LoopTyme::from_index(
+                MASA_NAMES.to_vec().iter().map(|x| x.to_string()).collect(),
+                index,
+            ),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Self {
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
+                MASA_NAMES.to_vec().iter().map(|x| x.to_string()).collect(),
+                name,
+            )?,
+        })
+    }
+
+    /// IAST转写名称
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::hindu::Masa;
+    ///
+    /// assert_eq!("Caitra", Masa::from_index(0).get_iast_name());
+    /// ```
+    pub fn get_iast_name(&self) -> String {
+        MASA_IAST_NAMES[self.get_index()].to_string()
+    }
+}
+
+impl Display for Masa {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.get_name())
+    }
+}
+
+impl PartialEq for Masa {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for Masa {}
+
+impl From<Masa> for LoopTyme {
+    fn from(val: Masa) -> Self {
+        val.parent
+    }
+}
+
+/// 印度历书（Panchang）：某一时刻的太阴日、月宿、日月合宿、半日、星期与月名
+///
+/// 恒星黄经 = 视黄经 - 岁差。太阴月以朔为界（Amanta），以太阳在朔时所在的恒星宫定名：
+/// 朔时太阳在双鱼宫的月为制呾罗月，月内无太阳入宫时为闰月（Adhika）。
+#[derive(Debug, Copy, Clone)]
+pub struct Panchang {
+    /// 当地时刻
+    zoned_solar_time: ZonedSolarTime,
+    /// 岁差
+    ayanamsa: Ayanamsa,
+    /// 太阳恒星黄经（度）
+    sun: f64,
+    /// 月亮恒星黄经（度）
+    moon: f64,
+    /// 朔望月月名索引
+    month: usize,
+    /// 是否闰月
+    adhika: bool,
+}
+
+impl Panchang {
+    /// 从公历时刻（印度标准时间）按拉希里岁差计算
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::hindu::Panchang;
+    /// use tyme4rs::tyme::solar::SolarTime;
+    /// use tyme4rs::tyme::Culture;
+    ///
+    /// let p: Panchang = Panchang::from_solar_time(SolarTime::from_ymd_hms(2024, 1, 22, 12, 29, 0));
+    /// assert_eq!("白分十二", p.get_tithi().get_name());
+    /// assert_eq!("报沙月", p.get_amanta_month().get_name());
+    /// ```
+    pub fn from_solar_time(solar_time: SolarTime) -> Self {
+        Self::from_zoned_solar_time(
+            ZonedSolarTime::new(solar_time, UtcOffset::KOLKATA),
+            Ayanamsa::Lahiri,
+        )
+    }
+
+    /// 从带UTC偏移的公历时刻按指定岁差计算
+    pub fn from_zoned_solar_time(zoned_solar_time: ZonedSolarTime, ayanamsa: Ayanamsa) -> Self {
+        let t: f64 = zoned_solar_time.get_julian_day().get_day() - J2000;
+        let (sun, moon): (f64, f64) = get_sidereal_longitudes(t, ayanamsa);
+        let k: isize = get_lunation(t);
+        let (start, _): (f64, f64) = get_sidereal_longitudes(get_new_moon(k), ayanamsa);
+        let (end, _): (f64, f64) = get_sidereal_longitudes(get_new_moon(k + 1), ayanamsa);
+        let sign: usize = (start / 30.0).floor() as usize;
+        Self {
+            zoned_solar_time,
+            ayanamsa,
+            sun,
+            moon,
+            month: (sign + 1) % 12,
+            adhika: sign == (end / 30.0).floor() as usize,
+        }
+    }
+
+    /// 当地日出时刻的历书，印度历以日出为日界，历书通常列日出时的值；极昼极夜无日出时为空
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::enums::Ayanamsa;
+    /// use tyme4rs::tyme::hindu::Panchang;
+    /// use tyme4rs::tyme::solar::SolarDay;
+    /// use tyme4rs::tyme::zone::UtcOffset;
+    /// use tyme4rs::tyme::Culture;
+    ///
+    /// // 新德里
+    /// let p: Panchang = Panchang::from_sunrise(SolarDay::from_ymd(2024, 11, 1), 28.6139, 77.209, UtcOffset::KOLKATA, Ayanamsa::Lahiri).unwrap();
+    /// assert_eq!("晦日", p.get_tithi().get_name());
+    /// assert_eq!("金曜日", p.get_vara().get_name());
+    /// ```
+    pub fn from_sunrise(
+        solar_day: SolarDay,
+        latitude: f64,
+        longitude: f64,
+        offset: UtcOffset,
+        ayanamsa: Ayanamsa,
+    ) -> Option<Self> {
+        let sunrise: SolarTime =
+            SunEvents::from_solar_day(solar_day, latitude, longitude, offset).get_sunrise()?;
+        Some(Self::from_zoned_solar_time(
+            ZonedSolarTime::new(sunrise, offset),
+            ayanamsa,
+        ))
+    }
+
+    /// 当地时刻
+    pub fn get_zoned_solar_time(&self) -> ZonedSolarTime {
+        self.zoned_solar_time
+    }
+
+    /// 岁差
+    pub fn get_ayanamsa(&self) -> Ayanamsa {
+        self.ayanamsa
+    }
+
+    /// 岁差值（度）
+    pub fn get_ayanamsa_degrees(&self) -> f64 {
+        get_ayanamsa_degrees(
+            self.ayanamsa,
+            self.zoned_solar_time.get_julian_day().get_day() - J2000,
+        )
+    }
+
+    /// 太阳恒星黄经（度）
+    pub fn get_sun_longitude(&self) -> f64 {
+        self.sun
+    }
+
+    /// 月亮恒星黄经（度）
+    pub fn get_moon_longitude(&self) -> f64 {
+        self.moon
+    }
+
+    /// 日月黄经差（度）
+    fn get_elongation(&self) -> f64 {
+        (self.moon - self.sun).rem_euclid(360.0)
+    }
+
+    /// 太阴日
+    pub fn get_tithi(&self) -> Tithi {
+        Tithi::from_index((self.get_elongation() / 12.0).floor() as isize)
+    }
+
+    /// 月半
+    pub fn get_paksha(&self) -> Paksha {
+        self.get_tithi().get_paksha()
+    }
+
+    /// 月宿
+    pub fn get_nakshatra(&self) -> Nakshatra {
+        Nakshatra::from_index((self.moon * 27.0 / 360.0).floor() as isize)
+    }
+
+    /// 日月合宿
+    pub fn get_yoga(&self) -> Yoga {
+        Yoga::from_index(((self.sun + self.moon).rem_euclid(360.0) * 27.0 / 360.0).floor() as isize)
+    }
+
+    /// 半日（半个太阴日）
+    pub fn get_karana(&self) -> Karana {
+        Karana::from_half_day((self.get_elongation() / 6.0).floor() as usize)
+    }
+
+    /// 星期，以当地时刻所在的公历日计
+    pub fn get_vara(&self) -> Vara {
+        Vara::from_index(
+            self.zoned_solar_time
+                .get_solar_time()
+                .get_solar_day()
+                .get_week()
+                .get_index() as isize,
+        )
+    }
+
+    /// 以朔为月首（Amanta）的月名
+    pub fn get_amanta_month(&self) -> Masa {
+        Masa::from_index(self.month as isize)
+    }
+
+    /// 以望为月末（Purnimanta）的月名，黑分属于下个月；闰月仍以朔为界
+    pub fn get_purnimanta_month(&self) -> Masa {
+        let m: Masa = self.get_amanta_month();
+        match !self.adhika && Paksha::Krishna == self.get_paksha() {
+            true => m.next(1),
+            false => m,
+        }
+    }
+
+    /// 是否闰月（Adhika Masa）
+    pub fn is_adhika(&self) -> bool {
+        self.adhika
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tyme::enums::{Ayanamsa, Paksha};
+    use crate::tyme::hindu::{Masa, Panchang, Tithi, Vara};
+    use crate::tyme::solar::{SolarDay, SolarTime};
+    use crate::tyme::zone::{UtcOffset, ZonedSolarTime};
+    use crate::tyme::{Culture, Tyme};
+
+    #[test]
+    fn test1() {
+        // 2024年1月22日，白分第12日，觜宿（Mṛgaśirā）
+        let p: Panchang =
+            Panchang::from_solar_time(SolarTime::from_ymd_hms(2024, 1, 22, 12, 29, 0));
+        assert_eq!("白分十二", p.get_tithi().get_name());
+        assert_eq!(Paksha::Shukla, p.get_paksha());
+        assert_eq!("觜", p.get_nakshatra().get_name());
+        assert_eq!("月曜日", p.get_vara().get_name());
+        assert_eq!("月", p.get_vara().get_seven_star().get_name());
+        assert_eq!("报沙月", p.get_amanta_month().get_name());
+        assert_eq!("报沙月", p.get_purnimanta_month().get_name());
+        assert!(!p.is_adhika());
+    }
+
+    #[test]
+    fn test2() {
+        // 摩羯宫入宫（Makara Saṅkrānti）：2024年1月15日02:54
+        let a: Panchang = Panchang::from_solar_time(SolarTime::from_ymd_hms(2024, 1, 15, 2, 53, 0));
+        let b: Panchang = Panchang::from_solar_time(SolarTime::from_ymd_hms(2024, 1, 15, 2, 55, 0));
+        assert!(a.get_sun_longitude() < 270.0);
+        assert!(b.get_sun_longitude() > 270.0);
+        assert_eq!(24, a.get_ayanamsa_degrees().floor() as usize);
+        // 白羊宫入宫（Meṣa Saṅkrānti）：2024年4月13日21:15
+        let a: Panchang =
+            Panchang::from_solar_time(SolarTime::from_ymd_hms(2024, 4, 13, 21, 14, 0));
+        let b: Panchang =
+            Panchang::from_solar_time(SolarTime::from_ymd_hms(2024, 4, 13, 21, 16, 0));
+        assert!(a.get_sun_longitude() > 359.0);
+        assert!(b.get_sun_longitude() < 1.0);
+    }
+
+    #[test]
+    fn test3() {
+        // 2023年闰室罗伐拏月（7月18日至8月16日）
+        let p: Panchang = Panchang::from_solar_time(SolarTime::from_ymd_hms(2023, 8, 1, 12, 0, 0));
+        assert!(p.is_adhika());
+        assert_eq!("室罗伐拏月", p.get_amanta_month().get_name());
+        let p: Panchang = Panchang::from_solar_time(SolarTime::from_ymd_hms(2023, 8, 10, 12, 0, 0));
+        assert_eq!(Paksha::Krishna, p.get_paksha());
+        assert_eq!("室罗伐拏月", p.get_purnimanta_month().get_name());
+        let p: Panchang = Panchang::from_solar_time(SolarTime::from_ymd_hms(2023, 8, 20, 12, 0, 0));
+        assert!(!p.is_adhika());
+        assert_eq!("室罗伐拏月", p.get_amanta_month().get_name());
+        // 闰月前的黑分在满月月制中属下个月
+        let p: Panchang = Panchang::from_solar_time(SolarTime::from_ymd_hms(2023, 7, 10, 12, 0, 0));
+        assert_eq!("頞沙荼月", p.get_amanta_month().get_name());
+        assert_eq!("室罗伐拏月", p.get_purnimanta_month().get_name());
+        // 2026年闰逝瑟吒月
+        let p: Panchang = Panchang::from_solar_time(SolarTime::from_ymd_hms(2026, 6, 1, 12, 0, 0));
+        assert!(p.is_adhika());
+        assert_eq!("逝瑟吒月", p.get_amanta_month().get_name());
+    }
+
+    #[test]
+    fn test4() {
+        // 2024年排灯节（頞湿缚庾阇月晦日，满月月制为迦剌底迦月）
+        let p: Panchang = Panchang::from_sunrise(
+            SolarDay::from_ymd(2024, 11, 1),
+            28.6139,
+            77.209,
+            UtcOffset::KOLKATA,
+            Ayanamsa::Lahiri,
+        )
+        .unwrap();
+        assert_eq!("晦日", p.get_tithi().get_name());
+        assert_eq!("頞湿缚庾阇月", p.get_amanta_month().get_name());
+        assert_eq!("迦剌底迦月", p.get_purnimanta_month().get_name());
+        assert_eq!("金曜日", p.get_vara().get_name());
+        // 极夜无日出
+        assert!(Panchang::from_sunrise(
+            SolarDay::from_ymd(2024, 12, 21),
+            80.0,
+            0.0,
+            UtcOffset::UTC,
+            Ayanamsa::Lahiri
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test5() {
+        // 半日每6度一个，太阴日内的两个半日相邻
+        let t: ZonedSolarTime = ZonedSolarTime::from_ymd_hms(2024, 3, 1, 0, 0, 0, UtcOffset::UTC);
+        for i in 0..60 {
+            let p: Panchang =
+                Panchang::from_zoned_solar_time(t.try_next(i * 43200).unwrap(), Ayanamsa::Raman);
+            let tithi: usize = p.get_tithi().get_index();
+            let karana: usize = p.get_karana().get_index();
+            match tithi {
+                0 => assert!(karana == 10 || karana == 0),
+                _ => assert!(karana < 10),
+            }
+            assert_eq!(Ayanamsa::Raman, p.get_ayanamsa());
+        }
+        // 拉曼岁差比拉希里小约1.45度
+        let zt: ZonedSolarTime = ZonedSolarTime::from_ymd_hms(2024, 3, 1, 0, 0, 0, UtcOffset::UTC);
+        let a: Panchang = Panchang::from_zoned_solar_time(zt, Ayanamsa::Lahiri);
+        let b: Panchang = Panchang::from_zoned_solar_time(zt, Ayanamsa::Raman);
+        let d: f64 = b.get_sun_longitude() - a.get_sun_longitude();
+        assert!((d - 1.446).abs() < 0.01);
+    }
+
+    #[test]
+    fn test6() {
+        assert_eq!(Paksha::Krishna, Tithi::from_index(15).get_paksha());
+        assert_eq!(15, Tithi::from_name("晦日").get_day());
+        assert_eq!("日曜日", Vara::from_index(7).get_name());
+        assert_eq!("制呾罗月", Masa::from_name("颇勒窭拏月").next(1).get_name());
+        assert!(Masa::try_from_name("Chaitra").is_err());
+        assert!(Masa::try_from_name("Caitra").is_err());
+        assert_eq!("Amāvasyā", Tithi::from_index(29).get_iast_name());
+        assert_eq!("Ravivāra", Vara::from_index(7).get_iast_name());
+        assert_eq!("Phālguna", Masa::from_index(-1).get_iast_name());
+    }
+}
@@ -2,8 +2,10 @@ use std::fmt::{Display, Formatter};
 
 use regex::Regex;
 
+use crate::tyme::enums::Region;
 use crate::tyme::solar::SolarDay;
-use crate::tyme::Culture;
+use crate::tyme::zone::{UtcOffset, ZonedLunarMonth, ZonedLunarYear};
+use crate::tyme::{Culture, Tyme};
 
 pub static LEGAL_HOLIDAY_NAMES: [&str; 9] = [
     "元旦",
@@ -18,7 +20,121 @@ pub static LEGAL_HOLIDAY_NAMES: [&str; 9] = [
 ];
 pub static LEGAL_HOLIDAY_DATA: &str = "2001122900+032001123000+022002010110+002002010210-012002010310-022002020901+032002021001+022002021211+002002021311-012002021411-022002021511-032002021611-042002021711-052002021811-062002042703+042002042803+032002050113+002002050213-012002050313-022002050413-032002050513-042002050613-052002050713-062002092806+032002092906+022002100116+002002100216-012002100316-022002100416-032002100516-042002100616-052002100716-062003010110+002003020111+002003020211-012003020311-022003020411-032003020511-042003020611-052003020711-062003020801-072003020901-082003042603+052003042703+042003050113+002003050213-012003050313-022003050413-032003050513-042003050613-052003050713-062003092706+042003092806+032003100116+002003100216-012003100316-022003100416-032003100516-042003100616-052003100716-062004010110+002004011701+052004011801+042004012211+002004012311-012004012411-022004012511-032004012611-042004012711-052004012811-062004050113+002004050213-012004050313-022004050413-032004050513-042004050613-052004050713-062004050803-072004050903-082004100116+002004100216-012004100316-022004100416-032004100516-042004100616-052004100716-062004100906-082004101006-092005010110+002005010210-012005010310-022005020501+042005020601+032005020911+002005021011-012005021111-022005021211-032005021311-042005021411-052005021511-062005043003+012005050113+002005050213-012005050313-022005050413-032005050513-042005050613-052005050713-062005050803-072005100116+002005100216-012005100316-022005100416-032005100516-042005100616-052005100716-062005100806-072005100906-082005123100+012006010110+002006010210-012006010310-022006012801+012006012911+002006013011-012006013111-022006020111-032006020211-042006020311-052006020411-062006020501-072006042903+022006043003+012006050113+002006050213-012006050313-022006050413-032006050513-042006050613-052006050713-062006093006+012006100116+002006100216-012006100316-022006100416-032006100516-042006100616-052006100716-062006100806-072006123000+022006123100+012007010110+002007010210-012007010310-022007021701+012007021811+002007021911-012007022011-022007022111-032007022211-042007022311-052007022411-062007022501-072007042803+032007042903+022007050113+002007050213-012007050313-022007050413-032007050513-042007050613-052007050713-062007092906+022007093006+012007100116+002007100216-012007100316-022007100416-032007100516-042007100616-052007100716-062007122900+032007123010+022007123110+012008010110+002008020201+042008020301+032008020611+002008020711-012008020811-022008020911-032008021011-042008021111-052008021211-062008040412+002008040512-012008040612-022008050113+002008050213-012008050313-022008050403-032008060714+012008060814+002008060914-012008091315+012008091415+002008091515-012008092706+042008092806+032008092916+022008093016+012008100116+002008100216-012008100316-022008100416-032008100516-042009010110+002009010210-012009010310-022009010400-032009012401+012009012511+002009012611-012009012711-022009012811-032009012911-042009013011-052009013111-062009020101-072009040412+002009040512-012009040612-022009050113+002009050213-012009050313-022009052814+002009052914-012009053014-022009053104-032009092706+042009100116+002009100216-012009100316-022009100416-032009100515-022009100615-032009100715-042009100815-052009101005-072010010110+002010010210-012010010310-022010021311+002010021411-012010021511-022010021611-032010021711-042010021811-052010021911-062010022001-072010022101-082010040312+022010040412+012010040512+002010050113+002010050213-012010050313-022010061204+042010061304+032010061414+022010061514+012010061614+002010091905+032010092215+002010092315-012010092415-022010092505-032010092606+052010100116+002010100216-012010100316-022010100416-032010100516-042010100616-052010100716-062010100906-082011010110+002011010210-012011010310-022011013001+042011020211+012011020311+002011020411-012011020511-022011020611-032011020711-042011020811-052011021201-092011040202+032011040312+022011040412+012011040512+002011043013+012011050113+002011050213-012011060414+022011060514+012011060614+002011091015+022011091115+012011091215+002011100116+002011100216-012011100316-022011100416-032011100516-042011100616-052011100716-062011100806-072011100906-082011123100+012012010110+002012010210-012012010310-022012012101+022012012211+012012012311+002012012411-012012012511-022012012611-032012012711-042012012811-052012012901-062012033102+042012040102+032012040212+022012040312+012012040412+002012042803+032012042913+022012043013+012012050113+002012050203-012012062214+012012062314+002012062414-012012092905+012012093015+002012100116+002012100216-012012100316-022012100416-032012100516-042012100616-052012100716-062012100806-072013010110+002013010210-012013010310-022013010500-042013010600-052013020911+012013021011+002013021111-012013021211-022013021311-032013021411-042013021511-052013021601-062013021701-072013040412+002013040512-012013040612-022013042703+042013042803+032013042913+022013043013+012013050113+002013060804+042013060904+032013061014+022013061114+012013061214+002013091915+002013092015-012013092115-022013092205-032013092906+022013100116+002013100216-012013100316-022013100416-032013100516-042013100616-052013100716-062014010110+002014012601+052014013111+002014020111-012014020211-022014020311-032014020411-042014020511-052014020611-062014020801-082014040512+002014040612-012014040712-022014050113+002014050213-012014050313-022014050403-032014053114+022014060114+012014060214+002014090615+022014090715+012014090815+002014092806+032014100116+002014100216-012014100316-022014100416+002014100516-042014100616-052014100716-062014101106-102015010110+002015010210-012015010310-022015010400-032015021501+042015021811+012015021911+002015022011-012015022111-022015022211-032015022311-042015022411-052015022801-092015040412+012015040512+002015040612-012015050113+002015050213-012015050313-022015062014+002015062114-012015062214-022015090318+002015090418-012015090518-022015090608-032015092615+012015092715+002015100116+002015100216-012015100316-022015100416+002015100516-042015100616-052015100716-062015101006-092016010110+002016010210-012016010310-022016020601+022016020711+012016020811+002016020911-012016021011-022016021111-032016021211-042016021311-052016021401-062016040212+022016040312+012016040412+002016043013+012016050113+002016050213-012016060914+002016061014-012016061114-022016061204-032016091515+002016091615-012016091715-022016091805-032016100116+002016100216-012016100316-022016100416-032016100516-042016100616-052016100716-062016100806-072016100906-082016123110+012017010110+002017010210-012017012201+062017012711+012017012811+002017012911-012017013011-022017013111-032017020111-042017020211-052017020401-072017040102+032017040212+022017040312+012017040412+002017042913+022017043013+012017050113+002017052704+032017052814+022017052914+012017053014+002017093006+012017100116+002017100216-012017100316-022017100415+002017100516-042017100616-052017100716-062017100816-072017123010+022017123110+012018010110+002018021101+052018021511+012018021611+002018021711-012018021811-022018021911-032018022011-042018022111-052018022401-082018040512+002018040612-012018040712-022018040802-032018042803+032018042913+022018043013+012018050113+002018061614+022018061714+012018061814+002018092215+022018092315+012018092415+002018092906+022018093006+012018100116+002018100216-012018100316-022018100416-032018100516-042018100616-052018100716-062018122900+032018123010+022018123110+012019010110+002019020201+032019020301+022019020411+012019020511+002019020611-012019020711-022019020811-032019020911-042019021011-052019040512+002019040612-012019040712-022019042803+032019050113+002019050213-012019050313-022019050413-032019050503-042019060714+002019060814-012019060914-022019091315+002019091415-012019091515-022019092906+022019100116+002019100216-012019100316-022019100416-032019100516-042019100616-052019100716-062019101206-112020010110+002020011901+062020012411+012020012511+002020012611-012020012711-022020012811-032020012911-042020013011-052020013111-062020020111-072020020211-082020040412+002020040512-012020040612-022020042603+052020050113+002020050213-012020050313-022020050413-032020050513-042020050903-082020062514+002020062614-012020062714-022020062804-032020092707+042020100117+002020100216-012020100316-022020100416-032020100516-042020100616-052020100716-062020100816-072020101006-092021010110+002021010210-012021010310-022021020701+052021021111+012021021211+002021021311-012021021411-022021021511-032021021611-042021021711-052021022001-082021040312+012021040412+002021040512-012021042503+062021050113+002021050213-012021050313-022021050413-032021050513-042021050803-072021061214+022021061314+012021061414+002021091805+032021091915+022021092015+012021092115+002021092606+052021100116+002021100216-012021100316-022021100416-032021100516-042021100616-052021100716-062021100906-082022010110+002022010210-012022010310-022022012901+032022013001+022022013111+012022020111+002022020211-012022020311-022022020411-032022020511-042022020611-052022040202+032022040312+022022040412+012022040512+002022042403+072022043013+012022050113+002022050213-012022050313-022022050413-032022050703-062022060314+002022060414-012022060514-022022091015+002022091115-012022091215-022022100116+002022100216-012022100316-022022100416-032022100516-042022100616-052022100716-062022100806-072022100906-082022123110+012023010110+002023010210-012023012111+012023012211+002023012311-012023012411-022023012511-032023012611-042023012711-052023012801-062023012901-072023040512+002023042303+082023042913+022023043013+012023050113+002023050213-012023050313-022023050603-052023062214+002023062314-012023062414-022023062504-032023092915+002023093016+012023100116+002023100216-012023100316-022023100416-032023100516-042023100616-052023100706-062023100806-072023123010+022023123110+012024010110+002024020401+062024021011+002024021111-012024021211-022024021311-032024021411-042024021511-052024021611-062024021711-072024021801-082024040412+002024040512-012024040612-022024040702-032024042803+032024050113+002024050213-012024050313-022024050413-032024050513-042024051103-102024060814+022024060914+012024061014+002024091405+032024091515+022024091615+012024091715+002024092906+022024100116+002024100216-012024100316-022024100416-032024100516-042024100616-052024100716-062024101206-112025010110+002025012601+032025012811+012025012911+002025013011-012025013111-022025020111-032025020211-032025020311-042025020411-052025020801-092025040412+002025040512-012025040612-022025042703+042025050113+002025050213-012025050313-022025050413-032025050513-042025053114+002025060114-012025060214-022025092807+032025100117+002025100217-012025100317-022025100417-032025100517-042025100617-052025100717-062025100817-072025101107-102026010110+002026010210-012026010310-022026010400-032026021401+032026021511+022026021611+012026021711+002026021811-012026021911-022026022011-032026022111-042026022211-052026022311-062026022801-112026040412+012026040512+002026040612-012026050113+002026050213-012026050313-022026050413-032026050513-042026050903-082026061914+002026062014-012026062114-022026092006+112026092515+002026092615-012026092715-022026100116+002026100216-012026100316-022026100416-032026100516-042026100616-052026100716-062026101006-09";
 
-/// 法定假日（自2001-12-29起）
+/// 香港公众假期名称（《公众假期条例》列明的公众假期）
+pub static HONG_KONG_HOLIDAY_NAMES: [&str; 18] = [
+    "元旦",
+    "农历年初一",
+    "农历年初二",
+    "农历年初三",
+    "农历年初四",
+    "耶稣受难节",
+    "耶稣受难节翌日",
+    "复活节星期一",
+    "清明节",
+    "劳动节",
+    "佛诞",
+    "端午节",
+    "香港特别行政区成立纪念日",
+    "中秋节翌日",
+    "国庆日",
+    "重阳节",
+    "圣诞节",
+    "圣诞节后第一个周日",
+];
+pub static HONG_KONG_HOLIDAY_DATA: &str = "2024010110+002024021011+002024021213-022024021314-032024032915+002024033016-012024040117-032024040418+002024050119+00202405151a+00202406101b+00202407011c+00202409181d+00202410011e+00202410111f+00202412251g+00202412261h-012025010110+002025012911+002025013012-012025013113-022025040418+002025041815+002025041916-012025042117-032025050119+00202505051a+00202505311b+00202507011c+00202510011e+00202510071d+00202510291f+00202512251g+00202512261h-01";
+
+/// 澳门公众假期名称（行政长官批示公布的公众假期）
+pub static MACAU_HOLIDAY_NAMES: [&str; 17] = [
+    "元旦",
+    "农历新年",
+    "耶稣受难日",
+    "复活节前日",
+    "清明节",
+    "劳动节",
+    "佛诞节",
+    "端午节",
+    "中秋节翌日",
+    "国庆日",
+    "重阳节",
+    "追思节",
+    "圣母无原罪瞻礼",
+    "澳门特别行政区成立纪念日",
+    "冬至",
+    "圣诞节前夕",
+    "圣诞节",
+];
+pub static MACAU_HOLIDAY_DATA: &str = "2024010110+002024021011+002024021111-012024021211-022024032912+002024033013-012024040414+002024050115+002024051516+002024061017+002024091818+002024100119+002024100219-01202410111a+00202411021b+00202412081c+00202412201d+00202412211e+00202412241f+01202412251g+002025010110+002025012911+002025013011-012025013111-022025040414+002025041812+002025041913-012025050115+002025050516+002025053117+002025100119+002025100219-012025100718+00202510291a+00202511021b+00202512081c+00202512201d+00202512211e+00202512241f+01202512251g+00";
+
+/// 台湾放假日名称（行政院人事行政总处公布的政府行政机关办公日历表，含补行上班日）
+pub static TAIWAN_HOLIDAY_NAMES: [&str; 11] = [
+    "开国纪念日",
+    "春节",
+    "和平纪念日",
+    "儿童节",
+    "清明节",
+    "端午节",
+    "中秋节",
+    "国庆日",
+    "教师节",
+    "光复节",
+    "行宪纪念日",
+];
+pub static TAIWAN_HOLIDAY_DATA: &str = "2023123010+022023123110+012024010110+002024020811+022024020911+012024021011+002024021111-012024021211-022024021311-032024021411-042024021701-072024022812+002024040414+002024040513-012024040614-022024040714-032024060815+022024060915+012024061015+002024091716+002024101017+002025010110+002025012511+042025012611+032025012711+022025012811+012025012911+002025013011-012025013111-022025020111-032025020211-042025020801-102025022812+002025030112-012025030212-022025040313+012025040414+002025040514-012025040614-022025053015+012025053115+002025060115-012025092718+012025092818+002025092918-012025100416+022025100516+012025100616+002025101017+002025101117-012025101217-022025102419+012025102519+002025102619-01202512251a+00";
+
+/// 新加坡公共假日名称（人力部公布的公共假日）
+pub static SINGAPORE_HOLIDAY_NAMES: [&str; 12] = [
+    "新年",
+    "春节",
+    "耶稣受难日",
+    "开斋节",
+    "劳动节",
+    "卫塞节",
+    "哈芝节",
+    "国庆日",
+    "屠妖节",
+    "圣诞节",
+    "补假",
+    "投票日",
+];
+pub static SINGAPORE_HOLIDAY_DATA: &str = "2024010110+002024021011+002024021111-01202402121a-012024032912+002024041013+002024050114+002024052215+002024061716+002024080917+002024103118+002024122519+002025010110+002025012911+002025013011-012025033113+002025041812+002025050114+00202505031b+002025051215+002025060716+002025080917+002025102018+002025122519+00";
+
+/// 泰国公共假日名称（含内阁公布的特别假日和补假）
+pub static THAI_HOLIDAY_NAMES: [&str; 19] = [
+    "新年",
+    "万佛节",
+    "却克里王朝纪念日",
+    "宋干节",
+    "加冕纪念日",
+    "春耕节",
+    "卫塞节",
+    "王后诞辰",
+    "三宝佛节",
+    "守夏节",
+    "国王诞辰",
+    "母亲节",
+    "九世王纪念日",
+    "五世王纪念日",
+    "父亲节",
+    "宪法日",
+    "除夕",
+    "补假",
+    "特别假日",
+];
+pub static THAI_HOLIDAY_DATA: &str = "2024010110+002024022411+00202402261h-022024040612+00202404081h-02202404121i+002024041313+002024041413-012024041513-02202404161h-032024050414+00202405061h-022024051015+002024052216+002024060317+002024072018+002024072119+00202407221h-02202407281a+00202407291h-01202408121b+00202410131c+00202410141h-01202410231d+00202412051e+00202412101f+00202412301i+00202412311g+002025010110+002025021211+002025040612+00202504071h-012025041313+002025041413-012025041513-02202504161h-032025050414+00202505051h-012025050915+002025051116+00202505121h-01202506021i+002025060317+002025071018+002025071119+00202507281a+00202508121b+00202510131c+00202510231d+00202512051e+00202512101f+00202512311g+00";
+
+/// 地区的假日名称和数据
+fn get_region_data(region: Region) -> (&'static [&'static str], &'static str) {
+    match region {
+        Region::Mainland => (&LEGAL_HOLIDAY_NAMES, LEGAL_HOLIDAY_DATA),
+        Region::HongKong => (&HONG_KONG_HOLIDAY_NAMES, HONG_KONG_HOLIDAY_DATA),
+        Region::Macau => (&MACAU_HOLIDAY_NAMES, MACAU_HOLIDAY_DATA),
+        Region::Taiwan => (&TAIWAN_HOLIDAY_NAMES, TAIWAN_HOLIDAY_DATA),
+        Region::Singapore => (&SINGAPORE_HOLIDAY_NAMES, SINGAPORE_HOLIDAY_DATA),
+        Region::Thailand => (&THAI_HOLIDAY_NAMES, THAI_HOLIDAY_DATA),
+    }
+}
+
+/// 法定假日（中国大陆自2001-12-29起，其他地区仅有2024至2025年的数据）
 #[derive(Debug, Copy, Clone)]
 pub struct LegalHoliday {
     /// 公历日
@@ -27,135 +143,204 @@ pub struct LegalHoliday {
     index: usize,
     /// 是否上班
     work: bool,
+    /// 地区
+    region: Region,
 }
 
 impl Culture for LegalHoliday {
     fn get_name(&self) -> String {
-        LEGAL_HOLIDAY_NAMES[self.index].to_string()
+        get_region_data(self.region).0[self.index].to_string()
     }
 }
 
-impl LegalHoliday {
-    pub fn from_ymd(year: isize, month: usize, day: usize) -> Option<Self> {
+/// 从假日数据中查找公历日，返回名称索引和是否上班
+///
+/// 每条数据13位：年月日8位、是否放假1位（0为上班）、名称索引1位（0-9、a-z）、距节日当天的天数3位。
+fn find_holiday(
+    data: &str,
+    year: isize,
+    month: usize,
+    day: usize,
+) -> Option<(SolarDay, usize, bool)> {
+    let day: SolarDay = SolarDay::try_from_ymd(year, month, day).ok()?;
+    let reg: Regex = Regex::new(
+        format!(
+            "{:0>four$}{:0>two$}{:0>two$}{}",
+            year,
+            month,
+            day.get_day(),
+            r"[0-1][0-9a-z][\+|-]\d{2}",
+            four = 4,
+            two = 2
+        )
+        .as_str(),
+    )
+    .ok()?;
+    let data: &str = reg.find(data)?.as_str();
+    let chars: Vec<char> = data.chars().collect();
+    let index: usize = chars.get(9)?.to_digit(36)? as usize;
+    let work: bool = '0' == *chars.get(8)?;
+    Some((day, index, work))
+}
+
+/// 从农历月列表中取指定月日的公历日，同一年只需推算一次月列表
+fn get_zoned_lunar_solar_day(months: &[ZonedLunarMonth], month: isize, day: usize) -> SolarDay {
+    months
+        .iter()
+        .find(|m| m.get_month_with_leap() == month)
+        .unwrap()
+        .get_first_julian_day()
+        .next(day as isize - 1)
+        .get_solar_day()
+}
+
+/// 从按年推算的节假日列表中查找公历日
+pub(crate) fn find_year_holiday<T>(
+    from_year: fn(isize) -> Vec<T>,
+    get_day: fn(&T) -> SolarDay,
+    year: isize,
+    month: usize,
+    day: usize,
+) -> Option<T> {
+    let d: SolarDay = SolarDay::try_from_ymd(year, month, day).ok()?;
+    from_year(year).into_iter().find(|h| get_day(h) == d)
+}
+
+/// 在按年推算的节假日列表中推移，跨年时顺延至相邻年份，超出范围时返回None
+pub(crate) fn next_year_holiday<T: Copy + PartialEq>(
+    from_year: fn(isize) -> Vec<T>,
+    holiday: &T,
+    mut year: isize,
+    n: isize,
+) -> Option<T> {
+    let mut l: Vec<T> = from_year(year);
+    let mut index: isize = l.iter().position(|h| h == holiday)? as isize + n;
+    while index < 0 {
+        year -= 1;
+        l = from_year(year);
+        if l.is_empty() {
+            return None;
+        }
+        index += l.len() as isize;
+    }
+    while index >= l.len() as isize {
+        index -= l.len() as isize;
+        year += 1;
+        l = from_year(year);
+        if l.is_empty() {
+            return None;
+        }
+    }
+    l.get(index as usize).copied()
+}
+
+/// 从假日数据中查找公历日之后（n为负数时之前）第n条数据的公历日
+fn next_holiday(data: &str, day: SolarDay, n: isize) -> Option<SolarDay> {
+    let year: isize = day.get_year();
+    let find = |y: isize| -> Option<Vec<String>> {
         let reg: Regex = Regex::new(
             format!(
-                "{:0>four$}{:0>two$}{:0>two$}{}",
-                year,
-                month,
-                day,
-                r"[0-1][0-8][\+|-]\d{2}",
-                four = 4,
-                two = 2
+                "{:0>four$}{}",
+                y,
+                r"\d{4}[0-1][0-9a-z][\+|-]\d{2}",
+                four = 4
             )
             .as_str(),
         )
-        .unwrap();
-        if reg.is_match(LEGAL_HOLIDAY_DATA) {
-            let data: &str = reg.find(LEGAL_HOLIDAY_DATA).unwrap().as_str();
-            let chars: Vec<char> = data.chars().collect();
-            let day: SolarDay = SolarDay::from_ymd(year, month, day);
-            let index: usize = *chars.get(9).unwrap() as usize - 48;
-            let work: bool = '0' == (*chars.get(8).unwrap());
-            Some(Self { day, index, work })
-        } else {
-            None
+        .ok()?;
+        Some(
+            reg.find_iter(data)
+                .map(|matcher| matcher.as_str().to_string())
+                .collect(),
+        )
+    };
+    let today: String = format!(
+        "{:0>four$}{:0>two$}{:0>two$}",
+        year,
+        day.get_month(),
+        day.get_day(),
+        four = 4,
+        two = 2
+    );
+    let mut l: Vec<String> = find(year)?;
+    let mut index: isize = l.iter().position(|d| d.starts_with(today.as_str()))? as isize + n;
+    let mut y: isize = year;
+    while index >= l.len() as isize {
+        index -= l.len() as isize;
+        y += 1;
+        l = find(y)?;
+        if l.is_empty() {
+            return None;
         }
     }
+    while index < 0 {
+        y -= 1;
+        l = find(y)?;
+        if l.is_empty() {
+            return None;
+        }
+        index += l.len() as isize;
+    }
+    let d: &str = l.get(index as usize)?;
+    SolarDay::try_from_ymd(
+        d[0..4].parse().ok()?,
+        d[4..6].parse().ok()?,
+        d[6..8].parse().ok()?,
+    )
+    .ok()
+}
+
+impl LegalHoliday {
+    pub fn from_ymd(year: isize, month: usize, day: usize) -> Option<Self> {
+        Self::from_ymd_in(Region::Mainland, year, month, day)
+    }
+
+    /// 指定地区的法定假日，不是假日或调休上班日时返回None
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::enums::Region;
+    /// use tyme4rs::tyme::holiday::LegalHoliday;
+    ///
+    /// let holiday: LegalHoliday = LegalHoliday::from_ymd_in(Region::HongKong, 2024, 9, 18).unwrap();
+    /// assert_eq!("2024年9月18日 中秋节翌日(休)", holiday.to_string());
+    /// assert!(LegalHoliday::from_ymd(2024, 9, 18).is_none());
+    /// ```
+    pub fn from_ymd_in(region: Region, year: isize, month: usize, day: usize) -> Option<Self> {
+        let (day, index, work) = find_holiday(get_region_data(region).1, year, month, day)?;
+        Some(Self {
+            day,
+            index,
+            work,
+            region,
+        })
+    }
 
     pub fn get_day(&self) -> SolarDay {
         self.day
     }
 
+    /// 索引，对应地区的假日名称
+    pub fn get_index(&self) -> usize {
+        self.index
+    }
+
     pub fn is_work(&self) -> bool {
         self.work
     }
 
+    /// 地区
+    pub fn get_region(&self) -> Region {
+        self.region
+    }
+
     pub fn next(&self, n: isize) -> Option<Self> {
         if n == 0 {
             return Some(*self);
         }
-        let year: isize = self.day.get_year();
-        let month: usize = self.day.get_month();
-        let mut data: Vec<String> = vec![];
-        let today: String = format!(
-            "{:0>four$}{:0>two$}{:0>two$}",
-            year,
-            month,
-            self.day.get_day(),
-            four = 4,
-            two = 2
-        );
-        let mut reg: Regex = Regex::new(
-            format!(
-                "{:0>four$}{}",
-                year,
-                r"\d{4}[0-1][0-8][\+|-]\d{2}",
-                four = 4
-            )
-            .as_str(),
-        )
-        .unwrap();
-        for matcher in reg.find_iter(LEGAL_HOLIDAY_DATA) {
-            data.push(matcher.as_str().to_string());
-        }
-
-        let mut index: isize = -1;
-        let mut size: isize = data.len() as isize;
-        for i in 0..size {
-            let d: String = data.get(i as usize).unwrap().to_string();
-            if d.starts_with(today.as_str()) {
-                index = i;
-                break;
-            }
-        }
-        if index == -1 {
-            return None;
-        }
-        index += n;
-        let mut y: isize = year;
-        if n > 0 {
-            while index >= size {
-                index -= size;
-                y += 1;
-                data.clear();
-                reg = Regex::new(
-                    format!("{:0>four$}{}", y, r"\d{4}[0-1][0-8][\+|-]\d{2}", four = 4).as_str(),
-                )
-                .unwrap();
-                for matcher in reg.find_iter(LEGAL_HOLIDAY_DATA) {
-                    data.push(matcher.as_str().to_string());
-                }
-                size = data.len() as isize;
-                if size < 1 {
-                    return None;
-                }
-            }
-        } else {
-            while index < 0 {
-                y -= 1;
-                data.clear();
-                reg = Regex::new(
-                    format!("{:0>four$}{}", y, r"\d{4}[0-1][0-8][\+|-]\d{2}", four = 4).as_str(),
-                )
-                .unwrap();
-                for matcher in reg.find_iter(LEGAL_HOLIDAY_DATA) {
-                    data.push(matcher.as_str().to_string());
-                }
-                size = data.len() as isize;
-                if size < 1 {
-                    return None;
-                }
-                index += size;
-            }
-        }
-        let d: String = data.get(index as usize).unwrap().to_string();
-        let dy: &str = &d[0..4];
-        let dm: &str = &d[4..6];
-        let dd: &str = &d[6..8];
-        let year: isize = dy.parse().unwrap();
-        let month: usize = dm.parse().unwrap();
-        let day: usize = dd.parse().unwrap();
-        Self::from_ymd(year, month, day)
+        let d: SolarDay = next_holiday(get_region_data(self.region).1, self.day, n)?;
+        Self::from_ymd_in(self.region, d.get_year(), d.get_month(), d.get_day())
     }
 }
 
@@ -173,15 +358,357 @@ impl Display for LegalHoliday {
 
 impl PartialEq for LegalHoliday {
     fn eq(&self, other: &Self) -> bool {
-        self.to_string() == other.to_string()
+        self.region == other.region && self.to_string() == other.to_string()
     }
 }
 
 impl Eq for LegalHoliday {}
 
+/// 韩国节假日名称
+pub static KOREAN_HOLIDAY_NAMES: [&str; 14] = [
+    "新正",
+    "春节",
+    "三一节",
+    "儿童节",
+    "佛诞日",
+    "显忠日",
+    "光复节",
+    "中秋节",
+    "开天节",
+    "韩文日",
+    "圣诞节",
+    "代替公休日",
+    "选举日",
+    "临时公休日",
+];
+
+/// 代替公休日的索引
+static KOREAN_SUBSTITUTE: usize = 11;
+
+/// 韩国的选举日和临时公休日：名称索引、年、月、日
+static KOREAN_SPECIAL_HOLIDAYS: [(usize, isize, usize, usize); 20] = [
+    (12, 2008, 4, 9),
+    (12, 2010, 6, 2),
+    (12, 2012, 4, 11),
+    (12, 2012, 12, 19),
+    (12, 2014, 6, 4),
+    (13, 2015, 8, 14),
+    (12, 2016, 4, 13),
+    (13, 2016, 5, 6),
+    (12, 2017, 5, 9),
+    (13, 2017, 10, 2),
+    (12, 2018, 6, 13),
+    (12, 2020, 4, 15),
+    (13, 2020, 8, 17),
+    (12, 2022, 3, 9),
+    (12, 2022, 6, 1),
+    (13, 2023, 10, 2),
+    (12, 2024, 4, 10),
+    (13, 2024, 10, 1),
+    (13, 2025, 1, 27),
+    (12, 2025, 6, 3),
+];
+
+/// 代替公休日的适用规则：起始日期，以及星期六是否适用（春节、中秋节仅星期日适用）
+fn get_korean_substitute_rule(index: usize) -> Option<(SolarDay, bool)> {
+    match index {
+        1 | 7 => Some((SolarDay::from_ymd(2014, 1, 1), false)),
+        3 => Some((SolarDay::from_ymd(2014, 1, 1), true)),
+        2 | 6 | 8 | 9 => Some((SolarDay::from_ymd(2021, 8, 4), true)),
+        4 | 10 => Some((SolarDay::from_ymd(2023, 5, 4), true)),
+        _ => None,
+    }
+}
+
+/// 韩国节假日（自2008年起）
+///
+/// 春节、中秋节、佛诞日按韩国标准时间（东经135度）的农历推算，春节和中秋节各连休3天；节日逢周末或与其他节日重叠时，按规定顺延代替公休日。
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct KoreanHoliday {
+    /// 公历日
+    day: SolarDay,
+    /// 索引
+    index: usize,
+}
+
+impl Culture for KoreanHoliday {
+    fn get_name(&self) -> String {
+        KOREAN_HOLIDAY_NAMES[self.index].to_string()
+    }
+}
+
+impl KoreanHoliday {
+    pub fn from_ymd(year: isize, month: usize, day: usize) -> Option<Self> {
+        find_year_holiday(Self::from_year, Self::get_day, year, month, day)
+    }
+
+    /// 当年的节假日列表
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::holiday::KoreanHoliday;
+    ///
+    /// // 春节逢星期日，2月12日为代替公休日
+    /// let l: Vec<String> = KoreanHoliday::from_year(2024).iter().map(|h| h.to_string()).collect();
+    /// assert_eq!("2024年2月12日 代替公休日(休)", l[4]);
+    /// ```
+    pub fn from_year(year: isize) -> Vec<Self> {
+        if !(2008..=9999).contains(&year) {
+            return vec![];
+        }
+        let months: Vec<ZonedLunarMonth> =
+            ZonedLunarYear::from_year(year, UtcOffset::SEOUL).get_months();
+        let lunar = |m: isize, d: usize| get_zoned_lunar_solar_day(&months, m, d);
+        let solar = |m: usize, d: usize| vec![SolarDay::from_ymd(year, m, d)];
+        let span = |d: SolarDay| vec![d.next(-1), d, d.next(1)];
+        let mut units: Vec<(usize, Vec<SolarDay>)> = vec![
+            (0, solar(1, 1)),
+            (1, span(lunar(1, 1))),
+            (2, solar(3, 1)),
+            (3, solar(5, 5)),
+            (4, vec![lunar(4, 8)]),
+            (5, solar(6, 6)),
+            (6, solar(8, 15)),
+            (7, span(lunar(8, 15))),
+            (8, solar(10, 3)),
+            (10, solar(12, 25)),
+        ];
+        // 韩文日自2013年起恢复为公休日
+        if year >= 2013 {
+            units.push((9, solar(10, 9)));
+        }
+        units.sort_by_key(|(index, days)| {
+            (days[0].subtract(SolarDay::from_ymd(year, 1, 1)), *index)
+        });
+
+        let mut l: Vec<Self> = Vec::new();
+        for (index, days) in units.iter() {
+            for d in days.iter() {
+                l.push(Self {
+                    day: *d,
+                    index: *index,
+                });
+            }
+        }
+        for (index, y, m, d) in KOREAN_SPECIAL_HOLIDAYS.iter() {
+            if year == *y {
+                l.push(Self {
+                    day: SolarDay::from_ymd(*y, *m, *d),
+                    index: *index,
+                });
+            }
+        }
+
+        // 逢周末或与先前的节日重叠时，由适用代替公休日的节日取得代替公休日
+        let mut triggered: Vec<bool> = vec![false; units.len()];
+        for i in 0..units.len() {
+            let (index, days) = &units[i];
+            let rule: Option<(SolarDay, bool)> =
+                get_korean_substitute_rule(*index).filter(|(start, _)| !days[0].is_before(*start));
+            if let Some((_, saturday)) = rule {
+                if days.iter().any(|d| {
+                    let w: usize = d.get_week().get_index();
+                    0 == w || (saturday && 6 == w)
+                }) {
+                    triggered[i] = true;
+                }
+            }
+            for j in 0..i {
+                if !units[j].1.iter().any(|d| days.contains(d)) {
+                    continue;
+                }
+                if rule.is_some() {
+                    triggered[i] = true;
+                } else if get_korean_substitute_rule(units[j].0)
+                    .is_some_and(|(start, _)| !units[j].1[0].is_before(start))
+                {
+                    triggered[j] = true;
+                }
+            }
+        }
+        for (i, (_, days)) in units.iter().enumerate() {
+            if !triggered[i] {
+                continue;
+            }
+            let mut d: SolarDay = days[days.len() - 1].next(1);
+            while matches!(d.get_week().get_index(), 0 | 6) || l.iter().any(|h| h.day == d) {
+                d = d.next(1);
+            }
+            l.push(Self {
+                day: d,
+                index: KOREAN_SUBSTITUTE,
+            });
+        }
+        l.sort_by_key(|h| h.day.subtract(SolarDay::from_ymd(year, 1, 1)));
+        l
+    }
+
+    pub fn get_day(&self) -> SolarDay {
+        self.day
+    }
+
+    /// 索引，对应节假日名称
+    pub fn get_index(&self) -> usize {
+        self.index
+    }
+
+    /// 是否上班，韩国节假日没有调休上班日，始终为false
+    pub fn is_work(&self) -> bool {
+        false
+    }
+
+    /// 推移，跨年时顺延至相邻年份，超出范围时返回None
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::holiday::KoreanHoliday;
+    ///
+    /// let h: KoreanHoliday = KoreanHoliday::from_ymd(2025, 10, 5).unwrap();
+    /// assert_eq!("2025年10月8日 代替公休日(休)", h.next(3).unwrap().to_string());
+    /// ```
+    pub fn next(&self, n: isize) -> Option<Self> {
+        next_year_holiday(Self::from_year, self, self.day.get_year(), n)
+    }
+}
+
+impl Display for KoreanHoliday {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}(休)", self.day, self.get_name())
+    }
+}
+
+/// 越南节假日名称
+pub static VIETNAMESE_HOLIDAY_NAMES: [&str; 7] = [
+    "元旦",
+    "春节",
+    "雄王忌日",
+    "南方解放日",
+    "劳动节",
+    "国庆节",
+    "补休日",
+];
+
+/// 补休日的索引
+static VIETNAMESE_SUBSTITUTE: usize = 6;
+
+/// 越南节假日（自2013年起）
+///
+/// 春节、雄王忌日按越南标准时间（东经105度）的农历推算，春节为除夕至正月初四共5天；国庆节自2021年起连休2天；节日逢周末时，顺延至之后的工作日补休。
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct VietnameseHoliday {
+    /// 公历日
+    day: SolarDay,
+    /// 索引
+    index: usize,
+}
+
+impl Culture for VietnameseHoliday {
+    fn get_name(&self) -> String {
+        VIETNAMESE_HOLIDAY_NAMES[self.index].to_string()
+    }
+}
+
+impl VietnameseHoliday {
+    pub fn from_ymd(year: isize, month: usize, day: usize) -> Option<Self> {
+        find_year_holiday(Self::from_year, Self::get_day, year, month, day)
+    }
+
+    /// 当年的节假日列表
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::holiday::VietnameseHoliday;
+    ///
+    /// // 雄王忌日为农历三月初十
+    /// let l: Vec<String> = VietnameseHoliday::from_year(2024).iter().map(|h| h.to_string()).collect();
+    /// assert_eq!("2024年4月18日 雄王忌日(休)", l[8]);
+    /// ```
+    pub fn from_year(year: isize) -> Vec<Self> {
+        if !(2013..=9999).contains(&year) {
+            return vec![];
+        }
+        let months: Vec<ZonedLunarMonth> =
+            ZonedLunarYear::from_year(year, UtcOffset::HANOI).get_months();
+        let new_year: SolarDay = get_zoned_lunar_solar_day(&months, 1, 1);
+        let mut days: Vec<(usize, SolarDay)> = vec![(0, SolarDay::from_ymd(year, 1, 1))];
+        for i in -1..4 {
+            days.push((1, new_year.next(i)));
+        }
+        days.push((2, get_zoned_lunar_solar_day(&months, 3, 10)));
+        days.push((3, SolarDay::from_ymd(year, 4, 30)));
+        days.push((4, SolarDay::from_ymd(year, 5, 1)));
+        let national: SolarDay = SolarDay::from_ymd(year, 9, 2);
+        days.push((5, national));
+        // 2021年起国庆节另加相邻的1天，取前一天或后一天以连接周末
+        if year >= 2021 {
+            days.push((
+                5,
+                match national.get_week().get_index() {
+                    2 | 5 | 6 => national.next(-1),
+                    _ => national.next(1),
+                },
+            ));
+        }
+        days.sort_by_key(|(_, d)| d.subtract(SolarDay::from_ymd(year, 1, 1)));
+
+        let mut l: Vec<Self> = days
+            .iter()
+            .map(|(index, day)| Self {
+                day: *day,
+                index: *index,
+            })
+            .collect();
+        for (_, day) in days.iter() {
+            if !matches!(day.get_week().get_index(), 0 | 6) {
+                continue;
+            }
+            let mut d: SolarDay = day.next(1);
+            while matches!(d.get_week().get_index(), 0 | 6) || l.iter().any(|h| h.day == d) {
+                d = d.next(1);
+            }
+            l.push(Self {
+                day: d,
+                index: VIETNAMESE_SUBSTITUTE,
+            });
+        }
+        l.sort_by_key(|h| h.day.subtract(SolarDay::from_ymd(year, 1, 1)));
+        l
+    }
+
+    pub fn get_day(&self) -> SolarDay {
+        self.day
+    }
+
+    /// 索引，对应节假日名称
+    pub fn get_index(&self) -> usize {
+        self.index
+    }
+
+    /// 是否上班，不含政府另行公布的调休上班日，始终为false
+    pub fn is_work(&self) -> bool {
+        false
+    }
+
+    /// 推移，跨年时顺延至相邻年份，超出范围时返回None
+    pub fn next(&self, n: isize) -> Option<Self> {
+        next_year_holiday(Self::from_year, self, self.day.get_year(), n)
+    }
+}
+
+impl Display for VietnameseHoliday {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}(休)", self.day, self.get_name())
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::tyme::holiday::LegalHoliday;
+    use crate::tyme::enums::Region;
+    use crate::tyme::holiday::{KoreanHoliday, LegalHoliday, VietnameseHoliday};
+    use crate::tyme::Culture;
 
     #[test]
     fn test1() {
@@ -197,7 +724,7 @@ mod tests {
     fn test2() {
         let d: LegalHoliday = LegalHoliday::from_ymd(2001, 12, 29).unwrap();
         assert_eq!("2001年12月29日 元旦(班)", d.to_string());
-        assert_eq!(true, d.next(-1).is_none());
+        assert!(d.next(-1).is_none());
     }
 
     #[test]
@@ -213,4 +740,325 @@ mod tests {
         let d: LegalHoliday = LegalHoliday::from_ymd(2010, 10, 1).unwrap();
         assert_eq!("2010年10月1日 国庆节(休)", d.to_string());
     }
+
+    #[test]
+    fn test5() {
+        assert!(LegalHoliday::from_ymd(2011, 13, 1).is_none());
+        assert!(LegalHoliday::from_ymd(2011, 2, 30).is_none());
+    }
+
+    fn korean(year: isize) -> Vec<String> {
+        KoreanHoliday::from_year(year)
+            .iter()
+            .map(|h| h.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test6() {
+        assert_eq!(
+            vec![
+                "2025年1月1日 新正(休)",
+                "2025年1月27日 临时公休日(休)",
+                "2025年1月28日 春节(休)",
+                "2025年1月29日 春节(休)",
+                "2025年1月30日 春节(休)",
+                "2025年3月1日 三一节(休)",
+                "2025年3月3日 代替公休日(休)",
+                "2025年5月5日 儿童节(休)",
+                "2025年5月5日 佛诞日(休)",
+                "2025年5月6日 代替公休日(休)",
+                "2025年6月3日 选举日(休)",
+                "2025年6月6日 显忠日(休)",
+                "2025年8月15日 光复节(休)",
+                "2025年10月3日 开天节(休)",
+                "2025年10月5日 中秋节(休)",
+                "2025年10月6日 中秋节(休)",
+                "2025年10月7日 中秋节(休)",
+                "2025年10月8日 代替公休日(休)",
+                "2025年10月9日 韩文日(休)",
+                "2025年12月25日 圣诞节(休)",
+            ],
+            korean(2025)
+        );
+    }
+
+    #[test]
+    fn test7() {
+        assert_eq!(19, korean(2024).len());
+        // 2014年起春节逢星期日才有代替公休日，此前没有
+        assert!(KoreanHoliday::from_ymd(2010, 2, 16).is_none());
+        // 2021年8月起光复节逢周末有代替公休日
+        assert_eq!(
+            "2021年8月16日 代替公休日(休)",
+            KoreanHoliday::from_ymd(2021, 8, 16).unwrap().to_string()
+        );
+        // 2023年5月起佛诞日逢周末有代替公休日
+        assert_eq!(
+            "2023年5月29日 代替公休日(休)",
+            KoreanHoliday::from_ymd(2023, 5, 29).unwrap().to_string()
+        );
+        // 2022年圣诞节逢星期日，尚未适用
+        assert!(KoreanHoliday::from_ymd(2022, 12, 26).is_none());
+        // 2012年韩文日尚不是公休日
+        assert!(KoreanHoliday::from_ymd(2012, 10, 9).is_none());
+        // 按东九区推算，2027年韩国春节比中国晚一天
+        assert_eq!(
+            vec![
+                "2027年2月6日 春节(休)",
+                "2027年2月7日 春节(休)",
+                "2027年2月8日 春节(休)",
+                "2027年2月9日 代替公休日(休)",
+            ],
+            korean(2027)[1..5].to_vec()
+        );
+    }
+
+    #[test]
+    fn test8() {
+        let d: KoreanHoliday = KoreanHoliday::from_ymd(2025, 5, 5).unwrap();
+        assert_eq!("儿童节", d.get_name());
+        assert!(!d.is_work());
+        assert_eq!("2025年5月5日 佛诞日(休)", d.next(1).unwrap().to_string());
+        assert_eq!(
+            "2024年12月25日 圣诞节(休)",
+            KoreanHoliday::from_ymd(2025, 1, 1)
+                .unwrap()
+                .next(-1)
+                .unwrap()
+                .to_string()
+        );
+        assert!(KoreanHoliday::from_ymd(2008, 1, 1)
+            .unwrap()
+            .next(-1)
+            .is_none());
+    }
+
+    #[test]
+    fn test9() {
+        let l: Vec<String> = VietnameseHoliday::from_year(2023)
+            .iter()
+            .map(|h| h.to_string())
+            .collect();
+        assert_eq!(
+            vec![
+                "2023年1月1日 元旦(休)",
+                "2023年1月2日 补休日(休)",
+                "2023年1月21日 春节(休)",
+                "2023年1月22日 春节(休)",
+                "2023年1月23日 春节(休)",
+                "2023年1月24日 春节(休)",
+                "2023年1月25日 春节(休)",
+                "2023年1月26日 补休日(休)",
+                "2023年1月27日 补休日(休)",
+                "2023年4月29日 雄王忌日(休)",
+                "2023年4月30日 南方解放日(休)",
+                "2023年5月1日 劳动节(休)",
+                "2023年5月2日 补休日(休)",
+                "2023年5月3日 补休日(休)",
+                "2023年9月1日 国庆节(休)",
+                "2023年9月2日 国庆节(休)",
+                "2023年9月4日 补休日(休)",
+            ],
+            l
+        );
+    }
+
+    #[test]
+    fn test10() {
+        // 2021年以前国庆节只有1天
+        assert!(VietnameseHoliday::from_ymd(2020, 9, 3).is_none());
+        assert_eq!(
+            "2024年9月3日 国庆节(休)",
+            VietnameseHoliday::from_ymd(2024, 9, 3).unwrap().to_string()
+        );
+        let d: VietnameseHoliday = VietnameseHoliday::from_ymd(2025, 1, 1).unwrap();
+        assert_eq!("元旦", d.get_name());
+        assert!(!d.is_work());
+        assert_eq!("2025年1月28日 春节(休)", d.next(1).unwrap().to_string());
+        assert_eq!("2024年9月3日 国庆节(休)", d.next(-1).unwrap().to_string());
+        assert!(VietnameseHoliday::from_ymd(2013, 1, 1)
+            .unwrap()
+            .next(-1)
+            .is_none());
+    }
+
+    #[test]
+    fn test11() {
+        let d: LegalHoliday = LegalHoliday::from_ymd_in(Region::Taiwan, 2025, 1, 25).unwrap();
+        assert_eq!("春节", d.get_name());
+        assert!(!d.is_work());
+        assert_eq!("2025年2月2日 春节(休)", d.next(8).unwrap().to_string());
+        assert_eq!("2025年2月8日 春节(班)", d.next(9).unwrap().to_string());
+        assert_eq!("2024年10月10日 国庆日(休)", d.next(-2).unwrap().to_string());
+        // 儿童节与清明节同日，逢星期四时顺延至星期五
+        assert_eq!(
+            "2024年4月5日 儿童节(休)",
+            LegalHoliday::from_ymd_in(Region::Taiwan, 2024, 4, 5)
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            "2025年4月3日 儿童节(休)",
+            LegalHoliday::from_ymd_in(Region::Taiwan, 2025, 4, 3)
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            "2025年12月25日 行宪纪念日(休)",
+            LegalHoliday::from_ymd_in(Region::Taiwan, 2025, 12, 25)
+                .unwrap()
+                .to_string()
+        );
+        assert!(LegalHoliday::from_ymd_in(Region::Taiwan, 2024, 9, 16).is_none());
+        assert!(LegalHoliday::from_ymd_in(Region::Taiwan, 2025, 12, 25)
+            .unwrap()
+            .next(1)
+            .is_none());
+    }
+
+    #[test]
+    fn test12() {
+        let d: LegalHoliday = LegalHoliday::from_ymd_in(Region::Thailand, 2024, 4, 13).unwrap();
+        assert_eq!("宋干节", d.get_name());
+        assert_eq!(
+            vec![
+                "2024年4月12日 特别假日(休)",
+                "2024年4月13日 宋干节(休)",
+                "2024年4月14日 宋干节(休)",
+                "2024年4月15日 宋干节(休)",
+                "2024年4月16日 补假(休)",
+            ],
+            (-1..4)
+                .map(|i| d.next(i).unwrap().to_string())
+                .collect::<Vec<String>>()
+        );
+        assert_eq!(
+            "2025年1月1日 新年(休)",
+            LegalHoliday::from_ymd_in(Region::Thailand, 2024, 12, 31)
+                .unwrap()
+                .next(1)
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            18,
+            LegalHoliday::from_ymd_in(Region::Thailand, 2025, 6, 2)
+                .unwrap()
+                .get_index()
+        );
+        assert!(LegalHoliday::from_ymd_in(Region::Thailand, 2024, 5, 1).is_none());
+        // 泰国的数据仅覆盖2024至2025年
+        assert!(LegalHoliday::from_ymd_in(Region::Thailand, 2023, 12, 5).is_none());
+        assert!(LegalHoliday::from_ymd_in(Region::Thailand, 2026, 1, 1).is_none());
+        assert!(LegalHoliday::from_ymd_in(Region::Thailand, 2025, 12, 31)
+            .unwrap()
+            .next(1)
+            .is_none());
+    }
+
+    fn region_year(region: Region, year: isize) -> Vec<String> {
+        let mut l: Vec<String> = Vec::new();
+        let mut h: Option<LegalHoliday> = LegalHoliday::from_ymd_in(region, year, 1, 1);
+        while let Some(d) = h.filter(|d| d.get_day().get_year() == year) {
+            l.push(format!(
+                "{}-{} {}",
+                d.get_day().get_month(),
+                d.get_day().get_day(),
+                d.get_name()
+            ));
+            h = d.next(1);
+        }
+        l
+    }
+
+    #[test]
+    fn test13() {
+        assert_eq!(
+            vec![
+                "1-1 元旦",
+                "2-10 农历年初一",
+                "2-12 农历年初三",
+                "2-13 农历年初四",
+                "3-29 耶稣受难节",
+                "3-30 耶稣受难节翌日",
+                "4-1 复活节星期一",
+                "4-4 清明节",
+                "5-1 劳动节",
+                "5-15 佛诞",
+                "6-10 端午节",
+                "7-1 香港特别行政区成立纪念日",
+                "9-18 中秋节翌日",
+                "10-1 国庆日",
+                "10-11 重阳节",
+                "12-25 圣诞节",
+                "12-26 圣诞节后第一个周日",
+            ],
+            region_year(Region::HongKong, 2024)
+        );
+        assert_eq!(17, region_year(Region::HongKong, 2025).len());
+        let d: LegalHoliday = LegalHoliday::from_ymd_in(Region::HongKong, 2025, 12, 26).unwrap();
+        assert_eq!(Region::HongKong, d.get_region());
+        assert!(d.next(1).is_none());
+        assert_ne!(
+            LegalHoliday::from_ymd(2024, 10, 1),
+            LegalHoliday::from_ymd_in(Region::Macau, 2024, 10, 1)
+        );
+    }
+
+    #[test]
+    fn test14() {
+        assert_eq!(
+            vec![
+                "1-1 元旦",
+                "1-29 农历新年",
+                "1-30 农历新年",
+                "1-31 农历新年",
+                "4-4 清明节",
+                "4-18 耶稣受难日",
+                "4-19 复活节前日",
+                "5-1 劳动节",
+                "5-5 佛诞节",
+                "5-31 端午节",
+                "10-1 国庆日",
+                "10-2 国庆日",
+                "10-7 中秋节翌日",
+                "10-29 重阳节",
+                "11-2 追思节",
+                "12-8 圣母无原罪瞻礼",
+                "12-20 澳门特别行政区成立纪念日",
+                "12-21 冬至",
+                "12-24 圣诞节前夕",
+                "12-25 圣诞节",
+            ],
+            region_year(Region::Macau, 2025)
+        );
+    }
+
+    #[test]
+    fn test15() {
+        assert_eq!(
+            vec![
+                "1-1 新年",
+                "2-10 春节",
+                "2-11 春节",
+                "2-12 补假",
+                "3-29 耶稣受难日",
+                "4-10 开斋节",
+                "5-1 劳动节",
+                "5-22 卫塞节",
+                "6-17 哈芝节",
+                "8-9 国庆日",
+                "10-31 屠妖节",
+                "12-25 圣诞节",
+            ],
+            region_year(Region::Singapore, 2024)
+        );
+        assert_eq!(
+            "2025年5月3日 投票日(休)",
+            LegalHoliday::from_ymd_in(Region::Singapore, 2025, 5, 3)
+                .unwrap()
+                .to_string()
+        );
+    }
 }
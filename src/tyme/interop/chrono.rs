@@ -0,0 +1,201 @@
+use ::chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+
+use crate::tyme::error::TymeError;
+use crate::tyme::interop::{from_gregorian, gregorian_julian_day, to_gregorian};
+use crate::tyme::jd::JulianDay;
+use crate::tyme::lunar::{LunarDay, LunarHour};
+use crate::tyme::solar::{SolarDay, SolarTime};
+
+impl TryFrom<NaiveDate> for SolarDay {
+    type Error = TymeError;
+
+    fn try_from(date: NaiveDate) -> Result<Self, Self::Error> {
+        from_gregorian(date.year() as i64, date.month() as i64, date.day() as i64)
+    }
+}
+
+impl From<SolarDay> for NaiveDate {
+    fn from(solar_day: SolarDay) -> Self {
+        let (y, m, d) = to_gregorian(solar_day);
+        NaiveDate::from_ymd_opt(y as i32, m as u32, d as u32).unwrap()
+    }
+}
+
+impl TryFrom<NaiveDateTime> for SolarTime {
+    type Error = TymeError;
+
+    fn try_from(date_time: NaiveDateTime) -> Result<Self, Self::Error> {
+        let d: SolarDay = SolarDay::try_from(date_time.date())?;
+        SolarTime::new(
+            d.get_year(),
+            d.get_month(),
+            d.get_day(),
+            date_time.hour() as usize,
+            date_time.minute() as usize,
+            date_time.second() as usize,
+        )
+    }
+}
+
+impl From<SolarTime> for NaiveDateTime {
+    fn from(solar_time: SolarTime) -> Self {
+        NaiveDate::from(solar_time.get_solar_day()).and_time(
+            NaiveTime::from_hms_opt(
+                solar_time.get_hour() as u32,
+                solar_time.get_minute() as u32,
+                solar_time.get_second() as u32,
+            )
+            .unwrap(),
+        )
+    }
+}
+
+impl From<NaiveDateTime> for JulianDay {
+    fn from(date_time: NaiveDateTime) -> Self {
+        gregorian_julian_day(
+            date_time.year() as i64,
+            date_time.month() as i64,
+            date_time.day() as i64,
+            date_time.num_seconds_from_midnight() as f64
+                + date_time.nanosecond() as f64 / 1_000_000_000.0,
+        )
+    }
+}
+
+impl TryFrom<JulianDay> for NaiveDateTime {
+    type Error = TymeError;
+
+    fn try_from(julian_day: JulianDay) -> Result<Self, Self::Error> {
+        Ok(julian_day.try_get_solar_time()?.into())
+    }
+}
+
+impl LunarDay {
+    /// 从chrono日期创建
+    ///
+    /// # 示例
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use tyme4rs::tyme::lunar::LunarDay;
+    ///
+    /// let day: LunarDay = LunarDay::from_chrono(NaiveDate::from_ymd_opt(2024, 2, 10).unwrap()).unwrap();
+    /// assert_eq!("农历甲辰年正月初一", day.to_string());
+    /// ```
+    pub fn from_chrono(date: NaiveDate) -> Result<Self, TymeError> {
+        Ok(SolarDay::try_from(date)?.get_lunar_day())
+    }
+
+    /// 转为chrono日期
+    pub fn to_chrono(&self) -> NaiveDate {
+        self.get_solar_day().into()
+    }
+}
+
+impl LunarHour {
+    /// 从chrono日期时间创建
+    pub fn from_chrono(date_time: NaiveDateTime) -> Result<Self, TymeError> {
+        Ok(SolarTime::try_from(date_time)?.get_lunar_hour())
+    }
+
+    /// 转为chrono日期时间
+    pub fn to_chrono(&self) -> NaiveDateTime {
+        self.get_solar_time().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, NaiveDateTime};
+
+    use crate::tyme::jd::JulianDay;
+    use crate::tyme::lunar::{LunarDay, LunarHour};
+    use crate::tyme::solar::{SolarDay, SolarTime};
+    use crate::tyme::Tyme;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test0() {
+        assert_eq!(
+            "2024年2月10日",
+            SolarDay::try_from(date(2024, 2, 10)).unwrap().to_string()
+        );
+        assert_eq!(date(2024, 2, 10), SolarDay::from_ymd(2024, 2, 10).into());
+        let t: NaiveDateTime = date(2024, 2, 10).and_hms_opt(23, 59, 58).unwrap();
+        assert_eq!(
+            "2024年2月10日 23:59:58",
+            SolarTime::try_from(t).unwrap().to_string()
+        );
+        assert_eq!(
+            t,
+            NaiveDateTime::from(SolarTime::from_ymd_hms(2024, 2, 10, 23, 59, 58))
+        );
+    }
+
+    #[test]
+    fn test1() {
+        // 格里高利历改革前后
+        assert_eq!(
+            "1582年10月15日",
+            SolarDay::try_from(date(1582, 10, 15)).unwrap().to_string()
+        );
+        assert_eq!(
+            "1582年10月4日",
+            SolarDay::try_from(date(1582, 10, 14)).unwrap().to_string()
+        );
+        assert_eq!(
+            "1582年9月25日",
+            SolarDay::try_from(date(1582, 10, 5)).unwrap().to_string()
+        );
+        assert_eq!(date(1582, 10, 14), SolarDay::from_ymd(1582, 10, 4).into());
+        assert_eq!(date(1000, 1, 6), SolarDay::from_ymd(1000, 1, 1).into());
+        assert_eq!(date(0, 12, 30), SolarDay::from_ymd(1, 1, 1).into());
+        assert_eq!(
+            SolarDay::from_ymd(1582, 10, 4).next(1),
+            SolarDay::try_from(date(1582, 10, 15)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test2() {
+        assert!(SolarDay::try_from(date(0, 12, 29)).is_err());
+        assert!(SolarDay::try_from(date(10000, 1, 1)).is_err());
+    }
+
+    #[test]
+    fn test3() {
+        let t: NaiveDateTime = date(2000, 1, 1).and_hms_opt(12, 0, 0).unwrap();
+        assert_eq!(2451545.0, JulianDay::from(t).get_day());
+        assert_eq!(
+            t,
+            NaiveDateTime::try_from(JulianDay::from_julian_day(2451545.0)).unwrap()
+        );
+        let t: NaiveDateTime = date(1582, 10, 14).and_hms_opt(6, 0, 0).unwrap();
+        assert_eq!(
+            SolarTime::from_ymd_hms(1582, 10, 4, 6, 0, 0)
+                .get_julian_day()
+                .get_day(),
+            JulianDay::from(t).get_day()
+        );
+        assert_eq!(
+            t,
+            NaiveDateTime::try_from(JulianDay::from_julian_day(2299159.75)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test4() {
+        let d: LunarDay = LunarDay::from_chrono(date(2024, 2, 10)).unwrap();
+        assert_eq!("农历甲辰年正月初一", d.to_string());
+        assert_eq!(date(2024, 2, 10), d.to_chrono());
+        let h: LunarHour =
+            LunarHour::from_chrono(date(2024, 2, 10).and_hms_opt(23, 30, 0).unwrap()).unwrap();
+        assert_eq!("农历甲辰年正月初一丙子时", h.to_string());
+        assert_eq!(
+            date(2024, 2, 10).and_hms_opt(23, 30, 0).unwrap(),
+            h.to_chrono()
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use ::jiff::civil::{Date, DateTime};
+
+use crate::tyme::error::TymeError;
+use crate::tyme::interop::{from_gregorian, gregorian_julian_day, to_gregorian};
+use crate::tyme::jd::JulianDay;
+use crate::tyme::lunar::{LunarDay, LunarHour};
+use crate::tyme::solar::{SolarDay, SolarTime};
+
+impl TryFrom<Date> for SolarDay {
+    type Error = TymeError;
+
+    fn try_from(date: Date) -> Result<Self, Self::Error> {
+        from_gregorian(date.year() as i64, date.month() as i64, date.day() as i64)
+    }
+}
+
+impl From<SolarDay> for Date {
+    fn from(solar_day: SolarDay) -> Self {
+        let (y, m, d) = to_gregorian(solar_day);
+        Date::new(y as i16, m as i8, d as i8).unwrap()
+    }
+}
+
+impl TryFrom<DateTime> for SolarTime {
+    type Error = TymeError;
+
+    fn try_from(date_time: DateTime) -> Result<Self, Self::Error> {
+        let d: SolarDay = SolarDay::try_from(date_time.date())?;
+        SolarTime::new(
+            d.get_year(),
+            d.get_month(),
+            d.get_day(),
+            date_time.hour() as usize,
+            date_time.minute() as usize,
+            date_time.second() as usize,
+        )
+    }
+}
+
+impl From<SolarTime> for DateTime {
+    fn from(solar_time: SolarTime) -> Self {
+        Date::from(solar_time.get_solar_day()).at(
+            solar_time.get_hour() as i8,
+            solar_time.get_minute() as i8,
+            solar_time.get_second() as i8,
+            0,
+        )
+    }
+}
+
+impl From<DateTime> for JulianDay {
+    fn from(date_time: DateTime) -> Self {
+        gregorian_julian_day(
+            date_time.year() as i64,
+            date_time.month() as i64,
+            date_time.day() as i64,
+            (date_time.hour() as i32 * 3600
+                + date_time.minute() as i32 * 60
+                + date_time.second() as i32) as f64
+                + date_time.subsec_nanosecond() as f64 / 1_000_000_000.0,
+        )
+    }
+}
+
+impl TryFrom<JulianDay> for DateTime {
+    type Error = TymeError;
+
+    fn try_from(julian_day: JulianDay) -> Result<Self, Self::Error> {
+        Ok(julian_day.try_get_solar_time()?.into())
+    }
+}
+
+impl LunarDay {
+    /// 从jiff日期创建
+    ///
+    /// # 示例
+    /// ```
+    /// use jiff::civil::date;
+    /// use tyme4rs::tyme::lunar::LunarDay;
+    ///
+    /// let day: LunarDay = LunarDay::from_jiff(date(2024, 2, 10)).unwrap();
+    /// assert_eq!("农历甲辰年正月初一", day.to_string());
+    /// ```
+    pub fn from_jiff(date: Date) -> Result<Self, TymeError> {
+        Ok(SolarDay::try_from(date)?.get_lunar_day())
+    }
+
+    /// 转为jiff日期
+    pub fn to_jiff(&self) -> Date {
+        self.get_solar_day().into()
+    }
+}
+
+impl LunarHour {
+    /// 从jiff日期时间创建
+    pub fn from_jiff(date_time: DateTime) -> Result<Self, TymeError> {
+        Ok(SolarTime::try_from(date_time)?.get_lunar_hour())
+    }
+
+    /// 转为jiff日期时间
+    pub fn to_jiff(&self) -> DateTime {
+        self.get_solar_time().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::civil::{date, datetime, DateTime};
+
+    use crate::tyme::jd::JulianDay;
+    use crate::tyme::lunar::{LunarDay, LunarHour};
+    use crate::tyme::solar::{SolarDay, SolarTime};
+
+    #[test]
+    fn test0() {
+        assert_eq!(
+            "2024年2月10日 23:59:58",
+            SolarTime::try_from(datetime(2024, 2, 10, 23, 59, 58, 0))
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            datetime(2024, 2, 10, 23, 59, 58, 0),
+            DateTime::from(SolarTime::from_ymd_hms(2024, 2, 10, 23, 59, 58))
+        );
+    }
+
+    #[test]
+    fn test1() {
+        assert_eq!(
+            "1582年10月4日",
+            SolarDay::try_from(date(1582, 10, 14)).unwrap().to_string()
+        );
+        assert_eq!(
+            "1582年10月15日",
+            SolarDay::try_from(date(1582, 10, 15)).unwrap().to_string()
+        );
+        assert_eq!(date(1582, 10, 14), SolarDay::from_ymd(1582, 10, 4).into());
+        assert_eq!(date(9999, 12, 31), SolarDay::from_ymd(9999, 12, 31).into());
+        assert!(SolarDay::try_from(date(0, 12, 29)).is_err());
+    }
+
+    #[test]
+    fn test2() {
+        let t: DateTime = datetime(2000, 1, 1, 12, 0, 0, 0);
+        assert_eq!(2451545.0, JulianDay::from(t).get_day());
+        assert_eq!(
+            t,
+            DateTime::try_from(JulianDay::from_julian_day(2451545.0)).unwrap()
+        );
+        let d: LunarDay = LunarDay::from_jiff(date(2024, 2, 10)).unwrap();
+        assert_eq!("农历甲辰年正月初一", d.to_string());
+        assert_eq!(date(2024, 2, 10), d.to_jiff());
+        let h: LunarHour = LunarHour::from_jiff(datetime(2024, 2, 10, 23, 30, 0, 0)).unwrap();
+        assert_eq!("农历甲辰年正月初一丙子时", h.to_string());
+    }
+}
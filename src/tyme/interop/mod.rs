@@ -0,0 +1,73 @@
+//! 与第三方日期时间库互转（需启用 `chrono`、`time` 或 `jiff` 特性）
+//!
+//! 第三方库均使用外推的格里高利历，而本库的公历在1582年10月15日之前为儒略历。互转按儒略日数对齐，
+//! 因此转换前后表示的是同一天，例如 `chrono` 的1582-10-14即本库的1582年10月4日。
+use crate::tyme::error::TymeError;
+use crate::tyme::jd::JulianDay;
+use crate::tyme::solar::SolarDay;
+
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(feature = "jiff")]
+mod jiff;
+#[cfg(feature = "time")]
+mod time;
+
+/// 外推格里高利历的年月日转儒略日数（当日正午）
+fn gregorian_to_jdn(year: i64, month: i64, day: i64) -> i64 {
+    let a: i64 = (14 - month).div_euclid(12);
+    let y: i64 = year + 4800 - a;
+    let m: i64 = month + 12 * a - 3;
+    day + (153 * m + 2).div_euclid(5) + 365 * y + y.div_euclid(4) - y.div_euclid(100)
+        + y.div_euclid(400)
+        - 32045
+}
+
+/// 儒略日数转外推格里高利历的年月日
+fn jdn_to_gregorian(jdn: i64) -> (i64, i64, i64) {
+    let a: i64 = jdn + 32044;
+    let b: i64 = (4 * a + 3).div_euclid(146097);
+    let c: i64 = a - (146097 * b).div_euclid(4);
+    let d: i64 = (4 * c + 3).div_euclid(1461);
+    let e: i64 = c - (1461 * d).div_euclid(4);
+    let m: i64 = (5 * e + 2).div_euclid(153);
+    (
+        100 * b + d - 4800 + m.div_euclid(10),
+        m + 3 - 12 * m.div_euclid(10),
+        e - (153 * m + 2).div_euclid(5) + 1,
+    )
+}
+
+/// 公历日转外推格里高利历的年月日
+fn to_gregorian(solar_day: SolarDay) -> (i64, i64, i64) {
+    jdn_to_gregorian((solar_day.get_julian_day().get_day() + 0.5).floor() as i64)
+}
+
+/// 外推格里高利历的年月日转公历日
+fn from_gregorian(year: i64, month: i64, day: i64) -> Result<SolarDay, TymeError> {
+    JulianDay::from_julian_day(gregorian_to_jdn(year, month, day) as f64).try_get_solar_day()
+}
+
+/// 外推格里高利历的日期时间转儒略日
+fn gregorian_julian_day(year: i64, month: i64, day: i64, seconds: f64) -> JulianDay {
+    JulianDay::from_julian_day(gregorian_to_jdn(year, month, day) as f64 - 0.5 + seconds / 86400.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tyme::interop::{gregorian_to_jdn, jdn_to_gregorian};
+
+    #[test]
+    fn test0() {
+        assert_eq!(2451545, gregorian_to_jdn(2000, 1, 1));
+        assert_eq!(2299161, gregorian_to_jdn(1582, 10, 15));
+        assert_eq!(1721426, gregorian_to_jdn(1, 1, 1));
+        assert_eq!((2000, 1, 1), jdn_to_gregorian(2451545));
+        assert_eq!((1582, 10, 14), jdn_to_gregorian(2299160));
+        assert_eq!((0, 12, 30), jdn_to_gregorian(1721424));
+        for jdn in [0, 1721424, 2299160, 2460000, 5373484] {
+            let (y, m, d) = jdn_to_gregorian(jdn);
+            assert_eq!(jdn, gregorian_to_jdn(y, m, d));
+        }
+    }
+}
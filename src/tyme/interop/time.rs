@@ -0,0 +1,177 @@
+use ::time::{Date, Month, PrimitiveDateTime, Time};
+
+use crate::tyme::error::TymeError;
+use crate::tyme::interop::{from_gregorian, gregorian_julian_day, to_gregorian};
+use crate::tyme::jd::JulianDay;
+use crate::tyme::lunar::{LunarDay, LunarHour};
+use crate::tyme::solar::{SolarDay, SolarTime};
+
+impl TryFrom<Date> for SolarDay {
+    type Error = TymeError;
+
+    fn try_from(date: Date) -> Result<Self, Self::Error> {
+        from_gregorian(
+            date.year() as i64,
+            u8::from(date.month()) as i64,
+            date.day() as i64,
+        )
+    }
+}
+
+impl From<SolarDay> for Date {
+    fn from(solar_day: SolarDay) -> Self {
+        let (y, m, d) = to_gregorian(solar_day);
+        Date::from_calendar_date(y as i32, Month::try_from(m as u8).unwrap(), d as u8).unwrap()
+    }
+}
+
+impl TryFrom<PrimitiveDateTime> for SolarTime {
+    type Error = TymeError;
+
+    fn try_from(date_time: PrimitiveDateTime) -> Result<Self, Self::Error> {
+        let d: SolarDay = SolarDay::try_from(date_time.date())?;
+        SolarTime::new(
+            d.get_year(),
+            d.get_month(),
+            d.get_day(),
+            date_time.hour() as usize,
+            date_time.minute() as usize,
+            date_time.second() as usize,
+        )
+    }
+}
+
+impl From<SolarTime> for PrimitiveDateTime {
+    fn from(solar_time: SolarTime) -> Self {
+        PrimitiveDateTime::new(
+            solar_time.get_solar_day().into(),
+            Time::from_hms(
+                solar_time.get_hour() as u8,
+                solar_time.get_minute() as u8,
+                solar_time.get_second() as u8,
+            )
+            .unwrap(),
+        )
+    }
+}
+
+impl From<PrimitiveDateTime> for JulianDay {
+    fn from(date_time: PrimitiveDateTime) -> Self {
+        gregorian_julian_day(
+            date_time.year() as i64,
+            u8::from(date_time.month()) as i64,
+            date_time.day() as i64,
+            (date_time.hour() as u32 * 3600
+                + date_time.minute() as u32 * 60
+                + date_time.second() as u32) as f64
+                + date_time.nanosecond() as f64 / 1_000_000_000.0,
+        )
+    }
+}
+
+impl TryFrom<JulianDay> for PrimitiveDateTime {
+    type Error = TymeError;
+
+    fn try_from(julian_day: JulianDay) -> Result<Self, Self::Error> {
+        Ok(julian_day.try_get_solar_time()?.into())
+    }
+}
+
+impl LunarDay {
+    /// 从time日期创建
+    ///
+    /// # 示例
+    /// ```
+    /// use time::{Date, Month};
+    /// use tyme4rs::tyme::lunar::LunarDay;
+    ///
+    /// let day: LunarDay =
+    ///     LunarDay::from_time(Date::from_calendar_date(2024, Month::February, 10).unwrap()).unwrap();
+    /// assert_eq!("农历甲辰年正月初一", day.to_string());
+    /// ```
+    pub fn from_time(date: Date) -> Result<Self, TymeError> {
+        Ok(SolarDay::try_from(date)?.get_lunar_day())
+    }
+
+    /// 转为time日期
+    pub fn to_time(&self) -> Date {
+        self.get_solar_day().into()
+    }
+}
+
+impl LunarHour {
+    /// 从time日期时间创建
+    pub fn from_time(date_time: PrimitiveDateTime) -> Result<Self, TymeError> {
+        Ok(SolarTime::try_from(date_time)?.get_lunar_hour())
+    }
+
+    /// 转为time日期时间
+    pub fn to_time(&self) -> PrimitiveDateTime {
+        self.get_solar_time().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{Date, Month, PrimitiveDateTime, Time};
+
+    use crate::tyme::jd::JulianDay;
+    use crate::tyme::lunar::{LunarDay, LunarHour};
+    use crate::tyme::solar::{SolarDay, SolarTime};
+
+    fn date(y: i32, m: u8, d: u8) -> Date {
+        Date::from_calendar_date(y, Month::try_from(m).unwrap(), d).unwrap()
+    }
+
+    fn date_time(y: i32, m: u8, d: u8, hour: u8, minute: u8, second: u8) -> PrimitiveDateTime {
+        PrimitiveDateTime::new(date(y, m, d), Time::from_hms(hour, minute, second).unwrap())
+    }
+
+    #[test]
+    fn test0() {
+        assert_eq!(
+            "2024年2月10日 23:59:58",
+            SolarTime::try_from(date_time(2024, 2, 10, 23, 59, 58))
+                .unwrap()
+                .to_string()
+        );
+        assert_eq!(
+            date_time(2024, 2, 10, 23, 59, 58),
+            PrimitiveDateTime::from(SolarTime::from_ymd_hms(2024, 2, 10, 23, 59, 58))
+        );
+    }
+
+    #[test]
+    fn test1() {
+        assert_eq!(
+            "1582年10月4日",
+            SolarDay::try_from(date(1582, 10, 14)).unwrap().to_string()
+        );
+        assert_eq!(
+            "1582年10月15日",
+            SolarDay::try_from(date(1582, 10, 15)).unwrap().to_string()
+        );
+        assert_eq!(date(1582, 10, 14), SolarDay::from_ymd(1582, 10, 4).into());
+        assert_eq!(date(0, 12, 30), SolarDay::from_ymd(1, 1, 1).into());
+        assert!(SolarDay::try_from(date(0, 12, 29)).is_err());
+        assert_eq!(
+            date(1582, 10, 14).to_julian_day(),
+            (SolarDay::from_ymd(1582, 10, 4).get_julian_day().get_day() + 0.5) as i32
+        );
+    }
+
+    #[test]
+    fn test2() {
+        let t: PrimitiveDateTime = date_time(2000, 1, 1, 12, 0, 0);
+        assert_eq!(2451545.0, JulianDay::from(t).get_day());
+        assert_eq!(
+            t,
+            PrimitiveDateTime::try_from(JulianDay::from_julian_day(2451545.0)).unwrap()
+        );
+        let d: LunarDay = LunarDay::from_time(date(2024, 2, 10)).unwrap();
+        assert_eq!("农历甲辰年正月初一", d.to_string());
+        assert_eq!(date(2024, 2, 10), d.to_time());
+        let h: LunarHour = LunarHour::from_time(date_time(2024, 2, 10, 23, 30, 0)).unwrap();
+        assert_eq!("农历甲辰年正月初一丙子时", h.to_string());
+    }
+}
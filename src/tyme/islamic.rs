@@ -0,0 +1,598 @@
+use std::fmt::{Display, Formatter};
+
+use crate::tyme::astro::moon::get_crescent;
+use crate::tyme::enums::IslamicType;
+use crate::tyme::error::TymeError;
+use crate::tyme::jd::{JulianDay, J2000};
+use crate::tyme::solar::{SolarDay, SolarTime};
+use crate::tyme::zone::{get_local_day, get_new_moon, UtcOffset, SYNODIC_MONTH};
+use crate::tyme::{Culture, Tyme};
+
+/// 伊斯兰历月名称
+pub static ISLAMIC_MONTH_NAMES: [&str; 12] = [
+    "穆哈兰姆月",
+    "色法尔月",
+    "赖比尔·敖外鲁月",
+    "赖比尔·阿色尼月",
+    "主马达·敖外鲁月",
+    "主马达·阿色尼月",
+    "赖哲卜月",
+    "舍尔邦月",
+    "赖买丹月",
+    "闪瓦鲁月",
+    "都尔喀尔德月",
+    "都尔黑哲月",
+];
+
+/// 伊斯兰历元年穆哈兰姆月1日（公元622年7月16日）的日序号（J2000起算）
+static EPOCH: isize = -503105;
+
+/// 2000年1月6日的朔所在月（伊斯兰历1420年闪瓦鲁月）的月序号
+static NEW_MOON_MONTH: isize = 17037;
+
+/// 麦加的纬度、经度
+static MECCA: (f64, f64) = (21.4225, 39.8262);
+
+/// 吉隆坡的纬度、经度
+static KUALA_LUMPUR: (f64, f64) = (3.139, 101.6869);
+
+/// 第n个月（伊斯兰历元年穆哈兰姆月为0）1日的日序号（J2000起算）
+fn get_month_start(n: isize, islamic_type: IslamicType) -> isize {
+    match islamic_type {
+        IslamicType::Tabular => {
+            let y: isize = n.div_euclid(12) + 1;
+            let m: isize = n.rem_euclid(12);
+            EPOCH + (59 * m + 1) / 2 + (y - 1) * 354 + (3 + 11 * y).div_euclid(30)
+        }
+        IslamicType::UmmAlQura => {
+            // 合朔在麦加日落前且月落晚于日落，则次日为1日，否则推迟一日
+            let offset: UtcOffset = UtcOffset::from_hm(3, 0);
+            let t: f64 = get_new_moon(n - NEW_MOON_MONTH);
+            let d: isize = get_local_day(t, offset);
+            let solar_day: SolarDay = JulianDay::from_julian_day(J2000 + d as f64).get_solar_day();
+            let sunset: f64 = solar_day
+                .get_sun_events(MECCA.0, MECCA.1, offset)
+                .get_sunset()
+                .map_or(f64::MAX, |t: SolarTime| t.get_julian_day().get_day());
+            let visible: bool = J2000 + t + offset.get_days() < sunset
+                && solar_day
+                    .get_moon_events(MECCA.0, MECCA.1, offset)
+                    .get_moonset()
+                    .is_none_or(|t: SolarTime| t.get_julian_day().get_day() > sunset);
+            d + if visible { 1 } else { 2 }
+        }
+        IslamicType::Mabims => {
+            // 合朔在吉隆坡日落前，且日落时月球高度不低于3度、日月角距不低于6.4度，则次日为1日，否则推迟一日
+            let offset: UtcOffset = UtcOffset::from_hm(8, 0);
+            let t: f64 = get_new_moon(n - NEW_MOON_MONTH);
+            let d: isize = get_local_day(t, offset);
+            let solar_day: SolarDay = JulianDay::from_julian_day(J2000 + d as f64).get_solar_day();
+            let visible: bool = solar_day
+                .get_sun_events(KUALA_LUMPUR.0, KUALA_LUMPUR.1, offset)
+                .get_sunset()
+                .is_some_and(|sunset: SolarTime| {
+                    let s: f64 = sunset.get_julian_day().get_day() - J2000 - offset.get_days();
+                    let (altitude, elongation) =
+                        get_crescent(s, KUALA_LUMPUR.0.to_radians(), KUALA_LUMPUR.1.to_radians());
+                    t < s && altitude >= 3.0 && elongation >= 6.4
+                });
+            d + if visible { 1 } else { 2 }
+        }
+    }
+}
+
+/// 伊斯兰历年
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct IslamicYear {
+    year: isize,
+    islamic_type: IslamicType,
+}
+
+impl Tyme for IslamicYear {
+    fn next(&self, n: isize) -> Self {
+        self.try_next(n).unwrap()
+    }
+}
+
+impl Culture for IslamicYear {
+    fn get_name(&self) -> String {
+        format!("伊斯兰历{}年", self.year)
+    }
+}
+
+impl IslamicYear {
+    pub fn new(year: isize, islamic_type: IslamicType) -> Result<Self, TymeError> {
+        Self::validate(year)?;
+        Ok(Self { year, islamic_type })
+    }
+
+    /// 校验年，支持1年至9665年（公元622年至9999年）
+    pub fn validate(year: isize) -> Result<(), TymeError> {
+        if !(1..=9665).contains(&year) {
+            return Err(TymeError::YearOutOfRange {
+                calendar: "islamic",
+                year,
+                min: 1,
+                max: 9665,
+            });
+        }
+        Ok(())
+    }
+
+    pub fn from_year(year: isize, islamic_type: IslamicType) -> Self {
+        Self::new(year, islamic_type).unwrap()
+    }
+
+    pub fn try_from_year(year: isize, islamic_type: IslamicType) -> Result<Self, TymeError> {
+        Self::new(year, islamic_type)
+    }
+
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        Self::new(self.year + n, self.islamic_type)
+    }
+
+    pub fn get_year(&self) -> isize {
+        self.year
+    }
+
+    /// 类型
+    pub fn get_type(&self) -> IslamicType {
+        self.islamic_type
+    }
+
+    /// 天数
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::enums::IslamicType;
+    /// use tyme4rs::tyme::islamic::IslamicYear;
+    ///
+    /// assert_eq!(355, IslamicYear::from_year(1445, IslamicType::Tabular).get_day_count());
+    /// assert_eq!(354, IslamicYear::from_year(1446, IslamicType::Tabular).get_day_count());
+    /// ```
+    pub fn get_day_count(&self) -> usize {
+        let n: isize = (self.year - 1) * 12;
+        (get_month_start(n + 12, self.islamic_type) - get_month_start(n, self.islamic_type))
+            as usize
+    }
+
+    /// 是否闰年（355天及以上）
+    pub fn is_leap(&self) -> bool {
+        self.get_day_count() > 354
+    }
+
+    pub fn get_first_month(&self) -> IslamicMonth {
+        IslamicMonth::from_ym(self.year, 1, self.islamic_type)
+    }
+
+    pub fn get_months(&self) -> Vec<IslamicMonth> {
+        (1..=12)
+            .map(|m| IslamicMonth::from_ym(self.year, m, self.islamic_type))
+            .collect()
+    }
+}
+
+impl Display for IslamicYear {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.get_name())
+    }
+}
+
+/// 伊斯兰历月
+#[derive(Debug, Copy, Clone)]
+pub struct IslamicMonth {
+    year: isize,
+    month: usize,
+    islamic_type: IslamicType,
+    /// 1日（J2000起算的日序号）
+    first_day: isize,
+    day_count: usize,
+}
+
+impl Tyme for IslamicMonth {
+    fn next(&self, n: isize) -> Self {
+        self.try_next(n).unwrap()
+    }
+}
+
+impl Culture for IslamicMonth {
+    fn get_name(&self) -> String {
+        ISLAMIC_MONTH_NAMES[self.month - 1].to_string()
+    }
+}
+
+impl IslamicMonth {
+    pub fn new(year: isize, month: usize, islamic_type: IslamicType) -> Result<Self, TymeError> {
+        IslamicYear::validate(year)?;
+        if !(1..=12).contains(&month) {
+            return Err(TymeError::IllegalMonth {
+                calendar: "islamic",
+                year,
+                month: month as isize,
+            });
+        }
+        let n: isize = (year - 1) * 12 + month as isize - 1;
+        let first_day: isize = get_month_start(n, islamic_type);
+        Ok(Self {
+            year,
+            month,
+            islamic_type,
+            first_day,
+            day_count: (get_month_start(n + 1, islamic_type) - first_day) as usize,
+        })
+    }
+
+    pub fn from_ym(year: isize, month: usize, islamic_type: IslamicType) -> Self {
+        Self::new(year, month, islamic_type).unwrap()
+    }
+
+    pub fn try_from_ym(
+        year: isize,
+        month: usize,
+        islamic_type: IslamicType,
+    ) -> Result<Self, TymeError> {
+        Self::new(year, month, islamic_type)
+    }
+
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        let i: isize = (self.year - 1) * 12 + self.month as isize - 1 + n;
+        Self::new(
+            i.div_euclid(12) + 1,
+            (i.rem_euclid(12) + 1) as usize,
+            self.islamic_type,
+        )
+    }
+
+    pub fn get_islamic_year(&self) -> IslamicYear {
+        IslamicYear {
+            year: self.year,
+            islamic_type: self.islamic_type,
+        }
+    }
+
+    pub fn get_year(&self) -> isize {
+        self.year
+    }
+
+    pub fn get_month(&self) -> usize {
+        self.month
+    }
+
+    /// 类型
+    pub fn get_type(&self) -> IslamicType {
+        self.islamic_type
+    }
+
+    /// 位于当年的索引(0-11)
+    pub fn get_index_in_year(&self) -> usize {
+        self.month - 1
+    }
+
+    /// 天数（29或30）
+    pub fn get_day_count(&self) -> usize {
+        self.day_count
+    }
+
+    pub fn get_first_day(&self) -> IslamicDay {
+        IslamicDay {
+            month: *self,
+            day: 1,
+        }
+    }
+
+    pub fn get_days(&self) -> Vec<IslamicDay> {
+        (1..=self.day_count)
+            .map(|day| IslamicDay { month: *self, day })
+            .collect()
+    }
+}
+
+impl Display for IslamicMonth {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.get_islamic_year(), self.get_name())
+    }
+}
+
+impl PartialEq for IslamicMonth {
+    fn eq(&self, other: &Self) -> bool {
+        self.year == other.year
+            && self.month == other.month
+            && self.islamic_type == other.islamic_type
+    }
+}
+
+impl Eq for IslamicMonth {}
+
+/// 伊斯兰历日
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct IslamicDay {
+    month: IslamicMonth,
+    day: usize,
+}
+
+impl Tyme for IslamicDay {
+    fn next(&self, n: isize) -> Self {
+        self.try_next(n).unwrap()
+    }
+}
+
+impl Culture for IslamicDay {
+    fn get_name(&self) -> String {
+        format!("{}日", self.day)
+    }
+}
+
+impl IslamicDay {
+    pub fn new(
+        year: isize,
+        month: usize,
+        day: usize,
+        islamic_type: IslamicType,
+    ) -> Result<Self, TymeError> {
+        let m: IslamicMonth = IslamicMonth::new(year, month, islamic_type)?;
+        if day < 1 || day > m.get_day_count() {
+            return Err(TymeError::IllegalDay {
+                calendar: "islamic",
+                year,
+                month: month as isize,
+                day: day as isize,
+            });
+        }
+        Ok(Self { month: m, day })
+    }
+
+    pub fn from_ymd(year: isize, month: usize, day: usize, islamic_type: IslamicType) -> Self {
+        Self::new(year, month, day, islamic_type).unwrap()
+    }
+
+    pub fn try_from_ymd(
+        year: isize,
+        month: usize,
+        day: usize,
+        islamic_type: IslamicType,
+    ) -> Result<Self, TymeError> {
+        Self::new(year, month, day, islamic_type)
+    }
+
+    /// 从公历日创建
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::enums::IslamicType;
+    /// use tyme4rs::tyme::islamic::IslamicDay;
+    /// use tyme4rs::tyme::solar::SolarDay;
+    ///
+    /// let d: IslamicDay = IslamicDay::from_solar_day(SolarDay::from_ymd(2024, 3, 11), IslamicType::UmmAlQura);
+    /// assert_eq!("伊斯兰历1445年赖买丹月1日", d.to_string());
+    /// // 按MABIMS标准，3月10日日落时新月高度不足3度，赖买丹月推迟一日
+    /// let d: IslamicDay = IslamicDay::from_solar_day(SolarDay::from_ymd(2024, 3, 12), IslamicType::Mabims);
+    /// assert_eq!("伊斯兰历1445年赖买丹月1日", d.to_string());
+    /// ```
+    pub fn from_solar_day(solar_day: SolarDay, islamic_type: IslamicType) -> Self {
+        Self::try_from_solar_day(solar_day, islamic_type).unwrap()
+    }
+
+    /// 从公历日创建，早于伊斯兰历元年时返回错误
+    pub fn try_from_solar_day(
+        solar_day: SolarDay,
+        islamic_type: IslamicType,
+    ) -> Result<Self, TymeError> {
+        let d: isize = (solar_day.get_julian_day().get_day() - J2000 + 0.5).floor() as isize;
+        let mut n: isize = ((d - EPOCH) as f64 / SYNODIC_MONTH).floor() as isize;
+        while get_month_start(n, islamic_type) > d {
+            n -= 1;
+        }
+        while get_month_start(n + 1, islamic_type) <= d {
+            n += 1;
+        }
+        let m: IslamicMonth = IslamicMonth::new(
+            n.div_euclid(12) + 1,
+            (n.rem_euclid(12) + 1) as usize,
+            islamic_type,
+        )?;
+        Ok(Self {
+            month: m,
+            day: (d - m.first_day) as usize + 1,
+        })
+    }
+
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        Self::try_from_solar_day(self.get_solar_day().try_next(n)?, self.month.islamic_type)
+    }
+
+    pub fn get_islamic_month(&self) -> IslamicMonth {
+        self.month
+    }
+
+    pub fn get_year(&self) -> isize {
+        self.month.year
+    }
+
+    pub fn get_month(&self) -> usize {
+        self.month.month
+    }
+
+    pub fn get_day(&self) -> usize {
+        self.day
+    }
+
+    /// 类型
+    pub fn get_type(&self) -> IslamicType {
+        self.month.islamic_type
+    }
+
+    pub fn get_solar_day(&self) -> SolarDay {
+        JulianDay::from_julian_day(J2000 + (self.month.first_day + self.day as isize - 1) as f64)
+            .get_solar_day()
+    }
+
+    pub fn subtract(&self, other: Self) -> isize {
+        self.month.first_day + self.day as isize - other.month.first_day - other.day as isize
+    }
+}
+
+impl Display for IslamicDay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.month, self.get_name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tyme::enums::IslamicType;
+    use crate::tyme::error::TymeError;
+    use crate::tyme::islamic::{IslamicDay, IslamicMonth, IslamicYear};
+    use crate::tyme::solar::SolarDay;
+    use crate::tyme::{Culture, Tyme};
+
+    #[test]
+    fn test0() {
+        let d: IslamicDay = IslamicDay::from_ymd(1, 1, 1, IslamicType::Tabular);
+        assert_eq!("伊斯兰历1年穆哈兰姆月1日", d.to_string());
+        assert_eq!("622年7月16日", d.get_solar_day().to_string());
+        assert_eq!(
+            d,
+            IslamicDay::from_solar_day(SolarDay::from_ymd(622, 7, 16), IslamicType::Tabular)
+        );
+        assert_eq!(
+            Err(TymeError::YearOutOfRange {
+                calendar: "islamic",
+                year: 0,
+                min: 1,
+                max: 9665
+            }),
+            IslamicDay::try_from_solar_day(SolarDay::from_ymd(622, 7, 15), IslamicType::Tabular)
+        );
+    }
+
+    #[test]
+    fn test1() {
+        let y: IslamicYear = IslamicYear::from_year(1445, IslamicType::Tabular);
+        assert!(y.is_leap());
+        assert!(!y.next(1).is_leap());
+        let months: Vec<IslamicMonth> = y.get_months();
+        assert_eq!(12, months.len());
+        assert_eq!(30, months[0].get_day_count());
+        assert_eq!(29, months[1].get_day_count());
+        assert_eq!(30, months[11].get_day_count());
+        assert_eq!(29, y.next(1).get_months()[11].get_day_count());
+    }
+
+    #[test]
+    fn test2() {
+        let d: IslamicDay = IslamicDay::from_ymd(1445, 9, 1, IslamicType::Tabular);
+        assert_eq!("赖买丹月", d.get_islamic_month().get_name());
+        assert_eq!("1日", d.get_name());
+        assert_eq!(
+            "伊斯兰历1445年",
+            d.get_islamic_month().get_islamic_year().get_name()
+        );
+        assert_eq!(
+            d,
+            SolarDay::from_ymd(2024, 3, 11).get_islamic_day().unwrap()
+        );
+    }
+
+    #[test]
+    fn test3() {
+        // 乌姆库拉历
+        let fixtures: [(isize, usize, [usize; 3]); 6] = [
+            (1445, 9, [2024, 3, 11]),
+            (1445, 10, [2024, 4, 10]),
+            (1445, 12, [2024, 6, 7]),
+            (1446, 1, [2024, 7, 7]),
+            (1446, 9, [2025, 3, 1]),
+            (1446, 10, [2025, 3, 30]),
+        ];
+        for (year, month, [y, m, d]) in fixtures {
+            let day: IslamicDay = IslamicDay::from_ymd(year, month, 1, IslamicType::UmmAlQura);
+            assert_eq!(SolarDay::from_ymd(y as isize, m, d), day.get_solar_day());
+            assert_eq!(
+                day,
+                IslamicDay::from_solar_day(day.get_solar_day(), IslamicType::UmmAlQura)
+            );
+        }
+    }
+
+    #[test]
+    fn test4() {
+        // 往返转换
+        let mut solar_day: SolarDay = SolarDay::from_ymd(2023, 1, 1);
+        for _ in 0..800 {
+            let d: IslamicDay = solar_day.get_islamic_day().unwrap();
+            assert_eq!(solar_day, d.get_solar_day());
+            assert_eq!(
+                d,
+                IslamicDay::from_ymd(d.get_year(), d.get_month(), d.get_day(), d.get_type())
+            );
+            solar_day = solar_day.next(1);
+        }
+    }
+
+    #[test]
+    fn test5() {
+        let d: IslamicDay = IslamicDay::from_ymd(1445, 12, 30, IslamicType::Tabular);
+        assert_eq!("伊斯兰历1446年穆哈兰姆月1日", d.next(1).to_string());
+        assert_eq!(1, d.next(1).subtract(d));
+        assert_eq!(
+            "伊斯兰历1444年都尔黑哲月",
+            d.get_islamic_month().next(-12).to_string()
+        );
+        assert_eq!(
+            Err(TymeError::IllegalDay {
+                calendar: "islamic",
+                year: 1446,
+                month: 12,
+                day: 30
+            }),
+            IslamicDay::try_from_ymd(1446, 12, 30, IslamicType::Tabular)
+        );
+    }
+
+    #[test]
+    fn test6() {
+        let mut m: IslamicMonth = IslamicMonth::from_ym(1445, 1, IslamicType::UmmAlQura);
+        let mut solar_day: SolarDay = m.get_first_day().get_solar_day();
+        for _ in 0..24 {
+            assert!(m.get_day_count() == 29 || m.get_day_count() == 30);
+            for d in m.get_days() {
+                assert_eq!(solar_day, d.get_solar_day());
+                solar_day = solar_day.next(1);
+            }
+            m = m.next(1);
+        }
+    }
+    #[test]
+    fn test7() {
+        // MABIMS标准，与马来西亚官方公布的日期一致
+        let fixtures: [(isize, usize, [usize; 3]); 9] = [
+            (1444, 9, [2023, 3, 23]),
+            (1444, 10, [2023, 4, 22]),
+            (1445, 9, [2024, 3, 12]),
+            (1445, 10, [2024, 4, 10]),
+            (1445, 12, [2024, 6, 8]),
+            (1446, 1, [2024, 7, 7]),
+            (1446, 3, [2024, 9, 5]),
+            (1446, 9, [2025, 3, 2]),
+            (1446, 10, [2025, 3, 31]),
+        ];
+        for (year, month, [y, m, d]) in fixtures {
+            let day: IslamicDay = IslamicDay::from_ymd(year, month, 1, IslamicType::Mabims);
+            assert_eq!(SolarDay::from_ymd(y as isize, m, d), day.get_solar_day());
+            assert_eq!(
+                day,
+                IslamicDay::from_solar_day(day.get_solar_day(), IslamicType::Mabims)
+            );
+        }
+        let mut m: IslamicMonth = IslamicMonth::from_ym(1445, 1, IslamicType::Mabims);
+        for _ in 0..24 {
+            assert!(m.get_day_count() == 29 || m.get_day_count() == 30);
+            m = m.next(1);
+        }
+    }
+}
@@ -0,0 +1,746 @@
+use std::fmt::{Display, Formatter};
+
+use crate::tyme::enums::JapaneseEraType;
+use crate::tyme::error::TymeError;
+use crate::tyme::event::Event;
+use crate::tyme::holiday::{find_year_holiday, next_year_holiday};
+use crate::tyme::solar::{SolarDay, SolarTerm};
+use crate::tyme::zone::UtcOffset;
+use crate::tyme::{Culture, Tyme};
+
+/// 日本年号名称（庆长以后）
+pub static JAPANESE_ERA_NAMES: [&str; 41] = [
+    "庆长", "元和", "宽永", "正保", "庆安", "承应", "明历", "万治", "宽文", "延宝", "天和", "贞享",
+    "元禄", "宝永", "正德", "享保", "元文", "宽保", "延享", "宽延", "宝历", "明和", "安永", "天明",
+    "宽政", "享和", "文化", "文政", "天保", "弘化", "嘉永", "安政", "万延", "文久", "元治", "庆应",
+    "明治", "大正", "昭和", "平成", "令和",
+];
+
+/// 年号的改元日（公历）
+static JAPANESE_ERA_START_DAYS: [(isize, usize, usize); 41] = [
+    (1596, 12, 16),
+    (1615, 9, 5),
+    (1624, 4, 17),
+    (1645, 1, 13),
+    (1648, 4, 7),
+    (1652, 10, 20),
+    (1655, 5, 18),
+    (1658, 8, 21),
+    (1661, 5, 23),
+    (1673, 10, 30),
+    (1681, 11, 9),
+    (1684, 4, 5),
+    (1688, 10, 23),
+    (1704, 4, 16),
+    (1711, 6, 11),
+    (1716, 8, 9),
+    (1736, 6, 7),
+    (1741, 4, 12),
+    (1744, 4, 3),
+    (1748, 8, 5),
+    (1751, 12, 14),
+    (1764, 6, 30),
+    (1772, 12, 10),
+    (1781, 4, 25),
+    (1789, 2, 19),
+    (1801, 3, 19),
+    (1804, 3, 22),
+    (1818, 5, 26),
+    (1831, 1, 23),
+    (1845, 1, 9),
+    (1848, 4, 1),
+    (1855, 1, 15),
+    (1860, 4, 8),
+    (1861, 3, 29),
+    (1864, 3, 27),
+    (1865, 5, 1),
+    // 明治改元时追溯至庆应4年1月1日
+    (1868, 1, 25),
+    (1912, 7, 30),
+    (1926, 12, 25),
+    (1989, 1, 8),
+    (2019, 5, 1),
+];
+
+/// 明治的索引
+static MEIJI: usize = 36;
+
+/// 日本年号
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct JapaneseEra {
+    index: usize,
+}
+
+impl Tyme for JapaneseEra {
+    fn next(&self, n: isize) -> Self {
+        self.try_next(n).unwrap()
+    }
+}
+
+impl Culture for JapaneseEra {
+    fn get_name(&self) -> String {
+        JAPANESE_ERA_NAMES[self.index].to_string()
+    }
+}
+
+impl JapaneseEra {
+    pub fn from_index(index: usize) -> Self {
+        Self::try_from_index(index).unwrap()
+    }
+
+    pub fn try_from_index(index: usize) -> Result<Self, TymeError> {
+        if index >= JAPANESE_ERA_NAMES.len() {
+            return Err(TymeError::IndexOutOfRange {
+                kind: "japanese era",
+                index: index as isize,
+                min: 0,
+                max: JAPANESE_ERA_NAMES.len() as isize - 1,
+            });
+        }
+        Ok(Self { index })
+    }
+
+    pub fn from_name(name: &str) -> Self {
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        match JAPANESE_ERA_NAMES.iter().position(|n| *n == name) {
+            Some(index) => Ok(Self { index }),
+            None => Err(TymeError::UnknownName {
+                kind: "japanese era",
+                name: name.to_string(),
+            }),
+        }
+    }
+
+    /// 公历日所在的年号，早于支持范围时返回错误
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::enums::JapaneseEraType;
+    /// use tyme4rs::tyme::japanese::JapaneseEra;
+    /// use tyme4rs::tyme::solar::SolarDay;
+    /// use tyme4rs::tyme::Culture;
+    ///
+    /// let day: SolarDay = SolarDay::from_ymd(1860, 1, 1);
+    /// assert_eq!("安政", JapaneseEra::try_from_solar_day(day, JapaneseEraType::Historical).unwrap().get_name());
+    /// assert!(JapaneseEra::try_from_solar_day(day, JapaneseEraType::Modern).is_err());
+    /// ```
+    pub fn try_from_solar_day(
+        solar_day: SolarDay,
+        era_type: JapaneseEraType,
+    ) -> Result<Self, TymeError> {
+        let first: usize = match era_type {
+            JapaneseEraType::Modern => MEIJI,
+            JapaneseEraType::Historical => 0,
+        };
+        let era: Self = Self { index: first };
+        if solar_day.is_before(era.get_start_day()) {
+            return Err(TymeError::YearOutOfRange {
+                calendar: "japanese",
+                year: solar_day.get_year(),
+                min: era.get_start_day().get_year(),
+                max: 9999,
+            });
+        }
+        let mut index: usize = JAPANESE_ERA_NAMES.len() - 1;
+        while Self::from_index(index).get_start_day().is_after(solar_day) {
+            index -= 1;
+        }
+        Self::try_from_index(index)
+    }
+
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        let index: isize = self.index as isize + n;
+        if index < 0 {
+            return Err(TymeError::IndexOutOfRange {
+                kind: "japanese era",
+                index,
+                min: 0,
+                max: JAPANESE_ERA_NAMES.len() as isize - 1,
+            });
+        }
+        Self::try_from_index(index as usize)
+    }
+
+    pub fn get_index(&self) -> usize {
+        self.index
+    }
+
+    /// 是否近代年号（明治以后）
+    pub fn is_modern(&self) -> bool {
+        self.index >= MEIJI
+    }
+
+    /// 改元日
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::japanese::JapaneseEra;
+    ///
+    /// assert_eq!("2019年5月1日", JapaneseEra::from_name("令和").get_start_day().to_string());
+    /// ```
+    pub fn get_start_day(&self) -> SolarDay {
+        let (y, m, d) = JAPANESE_ERA_START_DAYS[self.index];
+        SolarDay::from_ymd(y, m, d)
+    }
+
+    /// 公历日在本年号中的年数，明治以后按公历年计，之前按农历年计
+    fn get_year_of(&self, solar_day: SolarDay) -> usize {
+        let start: SolarDay = self.get_start_day();
+        if self.is_modern() {
+            (solar_day.get_year() - start.get_year() + 1) as usize
+        } else {
+            (solar_day.get_lunar_day().get_year() - start.get_lunar_day().get_year() + 1) as usize
+        }
+    }
+
+    /// 年数
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::japanese::JapaneseEra;
+    ///
+    /// assert_eq!(64, JapaneseEra::from_name("昭和").get_year_count());
+    /// ```
+    pub fn get_year_count(&self) -> usize {
+        match self.try_next(1) {
+            Ok(e) => self.get_year_of(e.get_start_day().next(-1)),
+            Err(_) => (9999 - self.get_start_day().get_year() + 1) as usize,
+        }
+    }
+}
+
+impl Display for JapaneseEra {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.get_name())
+    }
+}
+
+/// 日本年号纪年（和历），如令和6年
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct JapaneseYear {
+    era: JapaneseEra,
+    year: usize,
+}
+
+impl Tyme for JapaneseYear {
+    fn next(&self, n: isize) -> Self {
+        self.try_next(n).unwrap()
+    }
+}
+
+impl Culture for JapaneseYear {
+    fn get_name(&self) -> String {
+        match self.year {
+            1 => format!("{}元年", self.era),
+            y => format!("{}{}年", self.era, y),
+        }
+    }
+}
+
+impl JapaneseYear {
+    pub fn new(era: JapaneseEra, year: usize) -> Result<Self, TymeError> {
+        Self::validate(era, year)?;
+        Ok(Self { era, year })
+    }
+
+    pub fn validate(era: JapaneseEra, year: usize) -> Result<(), TymeError> {
+        let count: usize = era.get_year_count();
+        if !(1..=count).contains(&year) {
+            return Err(TymeError::YearOutOfRange {
+                calendar: "japanese",
+                year: year as isize,
+                min: 1,
+                max: count as isize,
+            });
+        }
+        Ok(())
+    }
+
+    pub fn from_ey(era: &str, year: usize) -> Self {
+        Self::try_from_ey(era, year).unwrap()
+    }
+
+    pub fn try_from_ey(era: &str, year: usize) -> Result<Self, TymeError> {
+        Self::new(JapaneseEra::try_from_name(era)?, year)
+    }
+
+    /// 从公历日转换，早于支持范围时返回错误
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::enums::JapaneseEraType;
+    /// use tyme4rs::tyme::japanese::JapaneseYear;
+    /// use tyme4rs::tyme::solar::SolarDay;
+    /// use tyme4rs::tyme::Culture;
+    ///
+    /// let day: SolarDay = SolarDay::from_ymd(2019, 4, 30);
+    /// assert_eq!("平成31年", JapaneseYear::try_from_solar_day(day, JapaneseEraType::Modern).unwrap().get_name());
+    /// assert_eq!("令和元年", JapaneseYear::try_from_solar_day(SolarDay::from_ymd(2019, 5, 1), JapaneseEraType::Modern).unwrap().get_name());
+    /// ```
+    pub fn try_from_solar_day(
+        solar_day: SolarDay,
+        era_type: JapaneseEraType,
+    ) -> Result<Self, TymeError> {
+        let era: JapaneseEra = JapaneseEra::try_from_solar_day(solar_day, era_type)?;
+        Self::new(era, era.get_year_of(solar_day))
+    }
+
+    /// 推移（同一年号内），超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        let year: isize = self.year as isize + n;
+        if year < 1 {
+            return Err(TymeError::YearOutOfRange {
+                calendar: "japanese",
+                year,
+                min: 1,
+                max: self.era.get_year_count() as isize,
+            });
+        }
+        Self::new(self.era, year as usize)
+    }
+
+    /// 年号
+    pub fn get_era(&self) -> JapaneseEra {
+        self.era
+    }
+
+    /// 年号中的年数（从1开始）
+    pub fn get_year(&self) -> usize {
+        self.year
+    }
+}
+
+impl Display for JapaneseYear {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.get_name())
+    }
+}
+
+/// 日本节假日名称
+pub static JAPANESE_HOLIDAY_NAMES: [&str; 23] = [
+    "元日",
+    "成人之日",
+    "建国纪念之日",
+    "天皇诞生日",
+    "春分之日",
+    "昭和之日",
+    "宪法纪念日",
+    "绿之日",
+    "儿童之日",
+    "海之日",
+    "山之日",
+    "敬老之日",
+    "秋分之日",
+    "体育之日",
+    "运动之日",
+    "文化之日",
+    "勤劳感谢之日",
+    "振替休日",
+    "国民休日",
+    "结婚之仪",
+    "大丧之礼",
+    "即位礼正殿之仪",
+    "即位之日",
+];
+
+/// 振替休日的索引
+static SUBSTITUTE: usize = 17;
+
+/// 国民休日的索引
+static CITIZENS: usize = 18;
+
+/// 国民祝日的日期规则
+enum JapaneseHolidayRule {
+    /// 固定日期：月、日
+    Day(usize, usize),
+    /// 幸福星期一（Happy Monday）：月、第几个星期一
+    Monday(usize, isize),
+    /// 节气（按日本标准时间）：节气索引
+    Term(isize),
+}
+
+/// 国民祝日：名称索引、起始年、结束年、规则
+static JAPANESE_HOLIDAY_RULES: [(usize, isize, isize, JapaneseHolidayRule); 32] = [
+    (0, 1949, 9999, JapaneseHolidayRule::Day(1, 1)),
+    (1, 1949, 1999, JapaneseHolidayRule::Day(1, 15)),
+    (1, 2000, 9999, JapaneseHolidayRule::Monday(1, 2)),
+    (2, 1967, 9999, JapaneseHolidayRule::Day(2, 11)),
+    (3, 1949, 1988, JapaneseHolidayRule::Day(4, 29)),
+    (3, 1989, 2018, JapaneseHolidayRule::Day(12, 23)),
+    (3, 2020, 9999, JapaneseHolidayRule::Day(2, 23)),
+    (4, 1949, 9999, JapaneseHolidayRule::Term(6)),
+    (5, 2007, 9999, JapaneseHolidayRule::Day(4, 29)),
+    (6, 1949, 9999, JapaneseHolidayRule::Day(5, 3)),
+    (7, 1989, 2006, JapaneseHolidayRule::Day(4, 29)),
+    (7, 2007, 9999, JapaneseHolidayRule::Day(5, 4)),
+    (8, 1949, 9999, JapaneseHolidayRule::Day(5, 5)),
+    (9, 1996, 2002, JapaneseHolidayRule::Day(7, 20)),
+    (9, 2003, 2019, JapaneseHolidayRule::Monday(7, 3)),
+    (9, 2020, 2020, JapaneseHolidayRule::Day(7, 23)),
+    (9, 2021, 2021, JapaneseHolidayRule::Day(7, 22)),
+    (9, 2022, 9999, JapaneseHolidayRule::Monday(7, 3)),
+    (10, 2016, 2019, JapaneseHolidayRule::Day(8, 11)),
+    (10, 2020, 2020, JapaneseHolidayRule::Day(8, 10)),
+    (10, 2021, 2021, JapaneseHolidayRule::Day(8, 8)),
+    (10, 2022, 9999, JapaneseHolidayRule::Day(8, 11)),
+    (11, 1966, 2002, JapaneseHolidayRule::Day(9, 15)),
+    (11, 2003, 9999, JapaneseHolidayRule::Monday(9, 3)),
+    (12, 1949, 9999, JapaneseHolidayRule::Term(18)),
+    (13, 1966, 1999, JapaneseHolidayRule::Day(10, 10)),
+    (13, 2000, 2019, JapaneseHolidayRule::Monday(10, 2)),
+    (14, 2020, 2020, JapaneseHolidayRule::Day(7, 24)),
+    (14, 2021, 2021, JapaneseHolidayRule::Day(7, 23)),
+    (14, 2022, 9999, JapaneseHolidayRule::Monday(10, 2)),
+    (15, 1949, 9999, JapaneseHolidayRule::Day(11, 3)),
+    (16, 1949, 9999, JapaneseHolidayRule::Day(11, 23)),
+];
+
+/// 依特别法设立的休日：名称索引、年、月、日
+static JAPANESE_SPECIAL_HOLIDAYS: [(usize, isize, usize, usize); 6] = [
+    (19, 1959, 4, 10),
+    (20, 1989, 2, 24),
+    (21, 1990, 11, 12),
+    (19, 1993, 6, 9),
+    (22, 2019, 5, 1),
+    (21, 2019, 10, 22),
+];
+
+/// 日本节假日（自1949年起），含幸福星期一、振替休日和国民休日
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct JapaneseHoliday {
+    /// 公历日
+    day: SolarDay,
+    /// 索引
+    index: usize,
+}
+
+impl Culture for JapaneseHoliday {
+    fn get_name(&self) -> String {
+        JAPANESE_HOLIDAY_NAMES[self.index].to_string()
+    }
+}
+
+impl JapaneseHoliday {
+    pub fn from_ymd(year: isize, month: usize, day: usize) -> Option<Self> {
+        find_year_holiday(Self::from_year, Self::get_day, year, month, day)
+    }
+
+    /// 当年的节假日列表
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::japanese::JapaneseHoliday;
+    /// use tyme4rs::tyme::Culture;
+    ///
+    /// let names: Vec<String> = JapaneseHoliday::from_year(2024).iter().map(|h| h.get_name()).collect();
+    /// assert_eq!(21, names.len());
+    /// ```
+    pub fn from_year(year: isize) -> Vec<Self> {
+        if !(1949..=9999).contains(&year) {
+            return vec![];
+        }
+        // 国民祝日
+        let mut l: Vec<Self> = Vec::new();
+        for (index, start, end, rule) in JAPANESE_HOLIDAY_RULES.iter() {
+            if year < *start || year > *end {
+                continue;
+            }
+            let day: Option<SolarDay> = match rule {
+                JapaneseHolidayRule::Day(m, d) => Event::builder()
+                    .solar_day(*m, *d, 0)
+                    .build()
+                    .get_solar_day(year),
+                JapaneseHolidayRule::Monday(m, n) => Event::builder()
+                    .solar_week(*m, *n, 1)
+                    .build()
+                    .get_solar_day(year),
+                JapaneseHolidayRule::Term(i) => Some(
+                    SolarTerm::from_index(year, *i)
+                        .get_zoned_solar_time(UtcOffset::TOKYO)
+                        .get_solar_time()
+                        .get_solar_day(),
+                ),
+            };
+            if let Some(day) = day {
+                l.push(Self { day, index: *index });
+            }
+        }
+        for (index, y, m, d) in JAPANESE_SPECIAL_HOLIDAYS.iter() {
+            if year == *y {
+                l.push(Self {
+                    day: SolarDay::from_ymd(*y, *m, *d),
+                    index: *index,
+                });
+            }
+        }
+        l.sort_by(|a, b| a.day.subtract(b.day).cmp(&0));
+        let is_holiday = |l: &Vec<Self>, d: SolarDay| l.iter().any(|h| h.day == d);
+
+        // 振替休日：1973年4月12日起，祝日为星期日时顺延至最近的非祝日（2007年前仅限次日）
+        let mut substitutes: Vec<Self> = Vec::new();
+        for h in l.iter() {
+            if h.day.get_week().get_index() != 0 || h.day.is_before(SolarDay::from_ymd(1973, 4, 12))
+            {
+                continue;
+            }
+            let mut d: SolarDay = h.day.next(1);
+            if year >= 2007 {
+                while is_holiday(&l, d) {
+                    d = d.next(1);
+                }
+            } else if is_holiday(&l, d) {
+                continue;
+            }
+            substitutes.push(Self {
+                day: d,
+                index: SUBSTITUTE,
+            });
+        }
+
+        // 国民休日：1985年12月27日起，前后两天均为祝日的平日
+        let mut citizens: Vec<Self> = Vec::new();
+        if year >= 1986 {
+            for h in l.iter() {
+                let d: SolarDay = h.day.next(1);
+                if d.get_week().get_index() != 0
+                    && !is_holiday(&l, d)
+                    && is_holiday(&l, d.next(1))
+                    && !is_holiday(&substitutes, d)
+                {
+                    citizens.push(Self {
+                        day: d,
+                        index: CITIZENS,
+                    });
+                }
+            }
+        }
+        l.extend(substitutes);
+        l.extend(citizens);
+        l.sort_by(|a, b| a.day.subtract(b.day).cmp(&0));
+        l
+    }
+
+    pub fn get_day(&self) -> SolarDay {
+        self.day
+    }
+
+    /// 索引，对应节假日名称
+    pub fn get_index(&self) -> usize {
+        self.index
+    }
+
+    /// 推移，跨年时顺延至相邻年份，超出范围时返回None
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::japanese::JapaneseHoliday;
+    ///
+    /// let h: JapaneseHoliday = JapaneseHoliday::from_ymd(2024, 11, 23).unwrap();
+    /// assert_eq!("2025年1月1日 元日", h.next(1).unwrap().to_string());
+    /// ```
+    pub fn next(&self, n: isize) -> Option<Self> {
+        next_year_holiday(Self::from_year, self, self.day.get_year(), n)
+    }
+}
+
+impl Display for JapaneseHoliday {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.day, self.get_name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tyme::enums::JapaneseEraType;
+    use crate::tyme::error::TymeError;
+    use crate::tyme::japanese::{JapaneseEra, JapaneseHoliday, JapaneseYear};
+    use crate::tyme::solar::SolarDay;
+    use crate::tyme::{Culture, Tyme};
+
+    fn holidays(year: isize) -> Vec<String> {
+        JapaneseHoliday::from_year(year)
+            .iter()
+            .map(|h| h.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test0() {
+        let day: SolarDay = SolarDay::from_ymd(2024, 5, 1);
+        assert_eq!(
+            "令和6年",
+            JapaneseYear::try_from_solar_day(day, JapaneseEraType::Modern)
+                .unwrap()
+                .get_name()
+        );
+        assert_eq!(
+            "昭和64年",
+            JapaneseYear::try_from_solar_day(
+                SolarDay::from_ymd(1989, 1, 7),
+                JapaneseEraType::Modern
+            )
+            .unwrap()
+            .get_name()
+        );
+        assert_eq!(
+            "平成元年",
+            JapaneseYear::try_from_solar_day(
+                SolarDay::from_ymd(1989, 1, 8),
+                JapaneseEraType::Modern
+            )
+            .unwrap()
+            .get_name()
+        );
+        assert_eq!(
+            "明治45年",
+            JapaneseYear::try_from_solar_day(
+                SolarDay::from_ymd(1912, 7, 29),
+                JapaneseEraType::Modern
+            )
+            .unwrap()
+            .get_name()
+        );
+        assert_eq!(
+            "大正元年",
+            JapaneseYear::try_from_solar_day(
+                SolarDay::from_ymd(1912, 7, 30),
+                JapaneseEraType::Historical
+            )
+            .unwrap()
+            .get_name()
+        );
+    }
+
+    #[test]
+    fn test1() {
+        // 江户时代按农历年计
+        let f = |y: isize, m: usize, d: usize| {
+            JapaneseYear::try_from_solar_day(
+                SolarDay::from_ymd(y, m, d),
+                JapaneseEraType::Historical,
+            )
+            .unwrap()
+            .get_name()
+        };
+        assert_eq!("庆长元年", f(1596, 12, 16));
+        assert_eq!("元禄元年", f(1688, 10, 23));
+        assert_eq!("庆应3年", f(1868, 1, 24));
+        assert_eq!("明治元年", f(1868, 1, 25));
+        assert_eq!("天保元年", f(1831, 1, 23));
+        assert_eq!("天保2年", f(1831, 2, 13));
+        assert!(JapaneseYear::try_from_solar_day(
+            SolarDay::from_ymd(1596, 12, 15),
+            JapaneseEraType::Historical
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(45, JapaneseEra::from_name("明治").get_year_count());
+        assert_eq!(15, JapaneseEra::from_name("大正").get_year_count());
+        assert_eq!(31, JapaneseEra::from_name("平成").get_year_count());
+        // 明治追溯至庆应4年1月1日，故庆应只有3年
+        assert_eq!(3, JapaneseEra::from_name("庆应").get_year_count());
+        assert_eq!(
+            "平成31年",
+            JapaneseYear::from_ey("平成", 30).next(1).get_name()
+        );
+        assert!(JapaneseYear::from_ey("平成", 31).try_next(1).is_err());
+        assert_eq!(
+            Err(TymeError::YearOutOfRange {
+                calendar: "japanese",
+                year: 16,
+                min: 1,
+                max: 15,
+            }),
+            JapaneseYear::try_from_ey("大正", 16)
+        );
+        assert_eq!("令和", JapaneseEra::from_name("平成").next(1).get_name());
+        assert!(JapaneseEra::from_name("令和").try_next(1).is_err());
+        assert_eq!("令和", JapaneseEra::from_index(40).get_name());
+        assert!(JapaneseEra::try_from_index(41).is_err());
+        assert!(JapaneseEra::try_from_name("光文").is_err());
+    }
+
+    #[test]
+    fn test3() {
+        assert_eq!(
+            vec![
+                "2019年1月1日 元日",
+                "2019年1月14日 成人之日",
+                "2019年2月11日 建国纪念之日",
+                "2019年3月21日 春分之日",
+                "2019年4月29日 昭和之日",
+                "2019年4月30日 国民休日",
+                "2019年5月1日 即位之日",
+                "2019年5月2日 国民休日",
+                "2019年5月3日 宪法纪念日",
+                "2019年5月4日 绿之日",
+                "2019年5月5日 儿童之日",
+                "2019年5月6日 振替休日",
+                "2019年7月15日 海之日",
+                "2019年8月11日 山之日",
+                "2019年8月12日 振替休日",
+                "2019年9月16日 敬老之日",
+                "2019年9月23日 秋分之日",
+                "2019年10月14日 体育之日",
+                "2019年10月22日 即位礼正殿之仪",
+                "2019年11月3日 文化之日",
+                "2019年11月4日 振替休日",
+                "2019年11月23日 勤劳感谢之日",
+            ],
+            holidays(2019)
+        );
+    }
+
+    #[test]
+    fn test4() {
+        // 东京奥运会调整
+        let l: Vec<String> = holidays(2020);
+        assert!(l.contains(&"2020年7月23日 海之日".to_string()));
+        assert!(l.contains(&"2020年7月24日 运动之日".to_string()));
+        assert!(l.contains(&"2020年8月10日 山之日".to_string()));
+        assert!(l.contains(&"2020年2月24日 振替休日".to_string()));
+        // 2015年白银周
+        assert_eq!(
+            "2015年9月22日 国民休日",
+            JapaneseHoliday::from_ymd(2015, 9, 22).unwrap().to_string()
+        );
+        // 2009年宪法纪念日为星期日，振替休日顺延至5月6日
+        assert_eq!(
+            "2009年5月6日 振替休日",
+            JapaneseHoliday::from_ymd(2009, 5, 6).unwrap().to_string()
+        );
+        // 2007年前的国民休日
+        assert_eq!(
+            "1988年5月4日 国民休日",
+            JapaneseHoliday::from_ymd(1988, 5, 4).unwrap().to_string()
+        );
+        assert!(JapaneseHoliday::from_ymd(2024, 5, 7).is_none());
+    }
+
+    #[test]
+    fn test5() {
+        let h: JapaneseHoliday = JapaneseHoliday::from_ymd(2025, 1, 1).unwrap();
+        assert_eq!(
+            "2024年11月23日 勤劳感谢之日",
+            h.next(-1).unwrap().to_string()
+        );
+        assert_eq!("2025年1月13日 成人之日", h.next(1).unwrap().to_string());
+        assert!(JapaneseHoliday::from_ymd(1949, 1, 1)
+            .unwrap()
+            .next(-1)
+            .is_none());
+    }
+}
@@ -1,4 +1,5 @@
 use crate::tyme::culture::Week;
+use crate::tyme::error::TymeError;
 use crate::tyme::solar::{SolarDay, SolarTime};
 use crate::tyme::{Culture, Tyme};
 use std::fmt::{Display, Formatter};
@@ -73,7 +74,17 @@ impl JulianDay {
         self.get_solar_time().get_solar_day()
     }
 
+    /// 公历日，超出公历范围时返回错误
+    pub fn try_get_solar_day(&self) -> Result<SolarDay, TymeError> {
+        Ok(self.try_get_solar_time()?.get_solar_day())
+    }
+
     pub fn get_solar_time(&self) -> SolarTime {
+        self.try_get_solar_time().unwrap()
+    }
+
+    /// 公历时刻，超出公历范围时返回错误
+    pub fn try_get_solar_time(&self) -> Result<SolarTime, TymeError> {
         let mut d: isize = (self.day + 0.5) as isize;
         let mut f: f64 = self.day + 0.5 - (d as f64);
 
@@ -105,7 +116,7 @@ impl JulianDay {
         f *= 60.0;
         let second: isize = f.round() as isize;
         if second < 60 {
-            return SolarTime::from_ymd_hms(
+            return SolarTime::new(
                 year,
                 month as usize,
                 day as usize,
@@ -114,15 +125,15 @@ impl JulianDay {
                 second as usize,
             );
         }
-        SolarTime::from_ymd_hms(
+        SolarTime::new(
             year,
             month as usize,
             day as usize,
             hour as usize,
             minute as usize,
             second as usize - 60,
-        )
-        .next(60)
+        )?
+        .try_next(60)
     }
 
     /// 儒略日相减
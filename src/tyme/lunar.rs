@@ -14,6 +14,7 @@ use crate::tyme::culture::{
 };
 use crate::tyme::eightchar::provider::{DefaultEightCharProvider, EightCharProvider};
 use crate::tyme::eightchar::EightChar;
+use crate::tyme::error::TymeError;
 use crate::tyme::festival::LunarFestival;
 use crate::tyme::jd::{JulianDay, J2000};
 use crate::tyme::sixtycycle::{
@@ -75,7 +76,7 @@ pub struct LunarYear {
 
 impl Tyme for LunarYear {
     fn next(&self, n: isize) -> Self {
-        Self::from_year(self.get_year() + n)
+        self.try_next(n).unwrap()
     }
 }
 
@@ -100,16 +101,21 @@ impl DerefMut for LunarYear {
 }
 
 impl LunarYear {
-    pub fn new(year: isize) -> Result<Self, String> {
+    pub fn new(year: isize) -> Result<Self, TymeError> {
         Self::validate(year)?;
         Ok(Self {
             parent: YearUnit::new(year),
         })
     }
 
-    pub fn validate(year: isize) -> Result<(), String> {
+    pub fn validate(year: isize) -> Result<(), TymeError> {
         if !(-1..=9999).contains(&year) {
-            Err(format!("illegal lunar year: {}", year))
+            Err(TymeError::YearOutOfRange {
+                calendar: "lunar",
+                year,
+                min: -1,
+                max: 9999,
+            })
         } else {
             Ok(())
         }
@@ -119,6 +125,15 @@ impl LunarYear {
         Self::new(year).unwrap()
     }
 
+    pub fn try_from_year(year: isize) -> Result<Self, TymeError> {
+        Self::new(year)
+    }
+
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        Self::new(self.get_year() + n)
+    }
+
     pub fn get_day_count(&self) -> usize {
         let mut n: usize = 0;
         for m in self.get_months() {
@@ -237,36 +252,7 @@ pub struct LunarMonth {
 
 impl Tyme for LunarMonth {
     fn next(&self, n: isize) -> Self {
-        if n == 0 {
-            return *self;
-        }
-        let mut m: isize = self.get_index_in_year() as isize + 1 + n;
-        let mut y: LunarYear = self.get_lunar_year();
-        let mut month_size: isize = y.get_month_count() as isize;
-        let forward: bool = n > 0;
-        let add: isize = if forward { 1 } else { -1 };
-        while if forward { m > month_size } else { m <= 0 } {
-            if forward {
-                m -= month_size;
-            }
-            y = y.next(add);
-            month_size = y.get_month_count() as isize;
-            if !forward {
-                m += month_size;
-            }
-        }
-        let mut leap: bool = false;
-        let leap_month: usize = y.get_leap_month();
-        if leap_month > 0 {
-            if m == leap_month as isize + 1 {
-                leap = true;
-            }
-            if m > leap_month as isize {
-                m -= 1;
-            }
-        }
-        let month: isize = if leap { -m } else { m };
-        Self::from_ym(y.get_year(), month)
+        self.try_next(n).unwrap()
     }
 }
 
@@ -292,7 +278,7 @@ impl Culture for LunarMonth {
 }
 
 impl LunarMonth {
-    pub fn new(year: isize, month: isize) -> Result<Self, String> {
+    pub fn new(year: isize, month: isize) -> Result<Self, TymeError> {
         Self::validate(year, month)?;
         Ok(Self {
             parent: MonthUnit::new(year, month.abs()),
@@ -300,13 +286,22 @@ impl LunarMonth {
         })
     }
 
-    pub fn validate(year: isize, month: isize) -> Result<(), String> {
+    pub fn validate(year: isize, month: isize) -> Result<(), TymeError> {
         if month == 0 || !(-12..=12).contains(&month) {
-            Err(format!("illegal lunar month: {}", month))
+            Err(TymeError::IllegalMonth {
+                calendar: "lunar",
+                year,
+                month,
+            })
         } else {
+            let y: LunarYear = LunarYear::new(year)?;
             let m: usize = month.unsigned_abs();
-            if month < 0 && m != LunarYear::from_year(year).get_leap_month() {
-                return Err(format!("illegal leap month {} in lunar year {}", m, year));
+            if month < 0 && m != y.get_leap_month() {
+                return Err(TymeError::IllegalLeapMonth {
+                    calendar: "lunar",
+                    year,
+                    month: m as isize,
+                });
             }
             Ok(())
         }
@@ -316,6 +311,44 @@ impl LunarMonth {
         Self::new(year, month).unwrap()
     }
 
+    pub fn try_from_ym(year: isize, month: isize) -> Result<Self, TymeError> {
+        Self::new(year, month)
+    }
+
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        if n == 0 {
+            return Ok(*self);
+        }
+        let mut m: isize = self.get_index_in_year() as isize + 1 + n;
+        let mut y: LunarYear = self.get_lunar_year();
+        let mut month_size: isize = y.get_month_count() as isize;
+        let forward: bool = n > 0;
+        let add: isize = if forward { 1 } else { -1 };
+        while if forward { m > month_size } else { m <= 0 } {
+            if forward {
+                m -= month_size;
+            }
+            y = y.try_next(add)?;
+            month_size = y.get_month_count() as isize;
+            if !forward {
+                m += month_size;
+            }
+        }
+        let mut leap: bool = false;
+        let leap_month: usize = y.get_leap_month();
+        if leap_month > 0 {
+            if m == leap_month as isize + 1 {
+                leap = true;
+            }
+            if m > leap_month as isize {
+                m -= 1;
+            }
+        }
+        let month: isize = if leap { -m } else { m };
+        Self::new(y.get_year(), month)
+    }
+
     pub fn get_lunar_year(&self) -> LunarYear {
         LunarYear::from_year(self.get_year())
     }
@@ -533,16 +566,20 @@ impl LunarSeason {
     }
 
     pub fn from_name(name: &str) -> Self {
-        Self {
-            parent: LoopTyme::from_name(
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
                 LUNAR_SEASON_NAMES
                     .to_vec()
                     .iter()
                     .map(|x| x.to_string())
                     .collect(),
                 name,
-            ),
-        }
+            )?,
+        })
     }
 }
 
@@ -592,34 +629,7 @@ impl DerefMut for LunarWeek {
 
 impl Tyme for LunarWeek {
     fn next(&self, n: isize) -> Self {
-        let mut d: isize = (self.get_index() as isize) + n;
-        let mut m: LunarMonth = self.get_lunar_month();
-        let start_index: usize = self.get_start();
-        if n > 0 {
-            let mut week_count: isize = m.get_week_count(start_index) as isize;
-            while d >= week_count {
-                d -= week_count;
-                m = m.next(1);
-                if m.get_first_day().get_week().get_index() != start_index {
-                    d += 1;
-                }
-                week_count = m.get_week_count(start_index) as isize;
-            }
-        } else {
-            while d < 0 {
-                if m.get_first_day().get_week().get_index() != start_index {
-                    d -= 1;
-                }
-                m = m.next(-1);
-                d += m.get_week_count(start_index) as isize;
-            }
-        }
-        Self::from_ym(
-            m.get_year(),
-            m.get_month_with_leap(),
-            d as usize,
-            start_index,
-        )
+        self.try_next(n).unwrap()
     }
 }
 
@@ -630,21 +640,28 @@ impl Culture for LunarWeek {
 }
 
 impl LunarWeek {
-    pub fn new(year: isize, month: isize, index: usize, start: usize) -> Result<Self, String> {
+    pub fn new(year: isize, month: isize, index: usize, start: usize) -> Result<Self, TymeError> {
         Self::validate(year, month, index, start)?;
         Ok(Self {
             parent: WeekUnit::new(year, month, index, start),
         })
     }
 
-    pub fn validate(year: isize, month: isize, index: usize, start: usize) -> Result<(), String> {
+    pub fn validate(
+        year: isize,
+        month: isize,
+        index: usize,
+        start: usize,
+    ) -> Result<(), TymeError> {
         WeekUnit::validate(index, start)?;
-        let m: LunarMonth = LunarMonth::from_ym(year, month);
+        let m: LunarMonth = LunarMonth::new(year, month)?;
         if index >= m.get_week_count(start) {
-            Err(format!(
-                "illegal lunar week index: {} in month: {}",
-                index, m
-            ))
+            Err(TymeError::IndexOutOfRange {
+                kind: "lunar week",
+                index: index as isize,
+                min: 0,
+                max: m.get_week_count(start) as isize - 1,
+            })
         } else {
             Ok(())
         }
@@ -654,6 +671,47 @@ impl LunarWeek {
         Self::new(year, month, index, start).unwrap()
     }
 
+    pub fn try_from_ym(
+        year: isize,
+        month: isize,
+        index: usize,
+        start: usize,
+    ) -> Result<Self, TymeError> {
+        Self::new(year, month, index, start)
+    }
+
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        let mut d: isize = (self.get_index() as isize) + n;
+        let mut m: LunarMonth = self.get_lunar_month();
+        let start_index: usize = self.get_start();
+        if n > 0 {
+            let mut week_count: isize = m.get_week_count(start_index) as isize;
+            while d >= week_count {
+                d -= week_count;
+                m = m.try_next(1)?;
+                if m.get_first_day().get_week().get_index() != start_index {
+                    d += 1;
+                }
+                week_count = m.get_week_count(start_index) as isize;
+            }
+        } else {
+            while d < 0 {
+                if m.get_first_day().get_week().get_index() != start_index {
+                    d -= 1;
+                }
+                m = m.try_next(-1)?;
+                d += m.get_week_count(start_index) as isize;
+            }
+        }
+        Self::new(
+            m.get_year(),
+            m.get_month_with_leap(),
+            d as usize,
+            start_index,
+        )
+    }
+
     pub fn get_lunar_month(&self) -> LunarMonth {
         LunarMonth::from_ym(self.get_year(), self.get_month())
     }
@@ -710,11 +768,7 @@ pub struct LunarDay {
 
 impl Tyme for LunarDay {
     fn next(&self, n: isize) -> Self {
-        if n == 0 {
-            self.clone()
-        } else {
-            self.get_solar_day().next(n).get_lunar_day()
-        }
+        self.try_next(n).unwrap()
     }
 }
 
@@ -739,20 +793,26 @@ impl DerefMut for LunarDay {
 }
 
 impl LunarDay {
-    pub fn new(year: isize, month: isize, day: usize) -> Result<Self, String> {
+    pub fn new(year: isize, month: isize, day: usize) -> Result<Self, TymeError> {
         Self::validate(year, month, day)?;
         Ok(Self {
             parent: DayUnit::new(year, month, day as isize),
         })
     }
 
-    pub fn validate(year: isize, month: isize, day: usize) -> Result<(), String> {
+    pub fn validate(year: isize, month: isize, day: usize) -> Result<(), TymeError> {
+        let err: TymeError = TymeError::IllegalDay {
+            calendar: "lunar",
+            year,
+            month,
+            day: day as isize,
+        };
         if day < 1 {
-            Err(format!("illegal lunar day {}", day))
+            Err(err)
         } else {
-            let m: LunarMonth = LunarMonth::from_ym(year, month);
+            let m: LunarMonth = LunarMonth::new(year, month)?;
             if day > m.get_day_count() {
-                return Err(format!("illegal day {} in {}", day, m));
+                return Err(err);
             }
             Ok(())
         }
@@ -762,6 +822,19 @@ impl LunarDay {
         Self::new(year, month, day).unwrap()
     }
 
+    pub fn try_from_ymd(year: isize, month: isize, day: usize) -> Result<Self, TymeError> {
+        Self::new(year, month, day)
+    }
+
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        if n == 0 {
+            Ok(self.clone())
+        } else {
+            Ok(self.get_solar_day().try_next(n)?.get_lunar_day())
+        }
+    }
+
     /// 农历月
     ///
     /// # 示例
@@ -1057,28 +1130,7 @@ pub struct LunarHour {
 
 impl Tyme for LunarHour {
     fn next(&self, n: isize) -> Self {
-        if n == 0 {
-            self.clone()
-        } else {
-            let h: isize = (self.get_hour() as isize) + n * 2;
-            let diff: isize = if h < 0 { -1 } else { 1 };
-            let mut hour: isize = h.abs();
-            let mut days: isize = hour / 24 * diff;
-            hour = (hour % 24) * diff;
-            if hour < 0 {
-                hour += 24;
-                days -= 1;
-            }
-            let d: LunarDay = self.get_lunar_day().next(days);
-            Self::from_ymd_hms(
-                d.get_year(),
-                d.get_month(),
-                d.get_day(),
-                hour as usize,
-                self.get_minute(),
-                self.get_second(),
-            )
-        }
+        self.try_next(n).unwrap()
     }
 }
 
@@ -1113,7 +1165,7 @@ impl LunarHour {
         hour: usize,
         minute: usize,
         second: usize,
-    ) -> Result<Self, String> {
+    ) -> Result<Self, TymeError> {
         Self::validate(year, month, day, hour, minute, second)?;
         Ok(Self {
             parent: SecondUnit::new(year, month, day as isize, hour, minute, second),
@@ -1127,7 +1179,7 @@ impl LunarHour {
         hour: usize,
         minute: usize,
         second: usize,
-    ) -> Result<(), String> {
+    ) -> Result<(), TymeError> {
         SecondUnit::validate(hour, minute, second)?;
         LunarDay::validate(year, month, day)?;
         Ok(())
@@ -1144,6 +1196,42 @@ impl LunarHour {
         Self::new(year, month, day, hour, minute, second).unwrap()
     }
 
+    pub fn try_from_ymd_hms(
+        year: isize,
+        month: isize,
+        day: usize,
+        hour: usize,
+        minute: usize,
+        second: usize,
+    ) -> Result<Self, TymeError> {
+        Self::new(year, month, day, hour, minute, second)
+    }
+
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        if n == 0 {
+            return Ok(self.clone());
+        }
+        let h: isize = (self.get_hour() as isize) + n * 2;
+        let diff: isize = if h < 0 { -1 } else { 1 };
+        let mut hour: isize = h.abs();
+        let mut days: isize = hour / 24 * diff;
+        hour = (hour % 24) * diff;
+        if hour < 0 {
+            hour += 24;
+            days -= 1;
+        }
+        let d: LunarDay = self.get_lunar_day().try_next(days)?;
+        Self::new(
+            d.get_year(),
+            d.get_month(),
+            d.get_day(),
+            hour as usize,
+            self.get_minute(),
+            self.get_second(),
+        )
+    }
+
     pub fn get_lunar_day(&self) -> LunarDay {
         LunarDay::from_ymd(self.get_year(), self.get_month(), self.get_day())
     }
@@ -1324,6 +1412,7 @@ impl Eq for LunarHour {}
 #[cfg(test)]
 mod tests {
     use crate::tyme::culture::star::twenty_eight::TwentyEightStar;
+    use crate::tyme::error::TymeError;
     use crate::tyme::lunar::{LunarDay, LunarHour, LunarMonth, LunarYear};
     use crate::tyme::solar::SolarDay;
     use crate::tyme::{Culture, Tyme};
@@ -2179,4 +2268,47 @@ mod tests {
         assert_eq!("九运", year.get_twenty().get_name());
         assert_eq!("下元", year.get_twenty().get_sixty().get_name());
     }
+
+    #[test]
+    fn test101() {
+        assert_eq!(
+            Err(TymeError::IllegalLeapMonth {
+                calendar: "lunar",
+                year: 2023,
+                month: 6
+            }),
+            LunarMonth::new(2023, -6)
+        );
+        assert_eq!(
+            "illegal leap month 6 in lunar year 2023",
+            LunarMonth::new(2023, -6).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test102() {
+        assert_eq!(
+            Err(TymeError::IllegalDay {
+                calendar: "lunar",
+                year: 2023,
+                month: 1,
+                day: 30
+            }),
+            LunarDay::new(2023, 1, 30)
+        );
+    }
+
+    #[test]
+    fn test103() {
+        assert!(LunarDay::try_from_ymd(2023, 1, 30).is_err());
+        assert!(LunarYear::from_year(9999).try_next(1).is_err());
+        assert!(LunarHour::try_from_ymd_hms(2023, 1, 1, 24, 0, 0).is_err());
+        assert_eq!(
+            "农历癸卯年闰二月",
+            LunarMonth::from_ym(2023, 2)
+                .try_next(1)
+                .unwrap()
+                .to_string()
+        );
+    }
 }
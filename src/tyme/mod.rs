@@ -1,3 +1,4 @@
+use crate::tyme::error::TymeError;
 use std::fmt::{Display, Formatter};
 use std::ops::{Deref, DerefMut};
 
@@ -184,9 +185,12 @@ impl DerefMut for LoopTyme {
 }
 
 impl LoopTyme {
-    pub fn new(names: Vec<String>, name: &str) -> Result<Self, String> {
+    pub fn new(names: Vec<String>, name: &str) -> Result<Self, TymeError> {
         match names.iter().position(|x| x == name) {
-            None => Err(format!("illegal name: {}", name)),
+            None => Err(TymeError::UnknownName {
+                kind: "",
+                name: name.to_string(),
+            }),
             Some(n) => Ok(Self {
                 parent: AbstractTyme::new(),
                 names,
@@ -265,16 +269,30 @@ impl From<LoopTyme> for AbstractTyme {
     }
 }
 
+pub mod astro;
 pub mod culture;
 pub mod eightchar;
 pub mod enums;
+pub mod era;
+pub mod error;
 pub mod event;
 pub mod festival;
+pub mod hebrew;
+pub mod hindu;
 pub mod holiday;
+#[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
+pub mod interop;
+pub mod islamic;
+pub mod japanese;
 pub mod jd;
 pub mod lunar;
+pub mod persian;
 pub mod rabbyung;
+pub mod reform;
+#[cfg(feature = "serde")]
+pub mod serialize;
 pub mod sixtycycle;
 pub mod solar;
 pub mod unit;
 pub mod util;
+pub mod zone;
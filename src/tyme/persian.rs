@@ -0,0 +1,528 @@
+use std::fmt::{Display, Formatter};
+
+use crate::tyme::enums::PersianType;
+use crate::tyme::error::TymeError;
+use crate::tyme::jd::{JulianDay, J2000};
+use crate::tyme::solar::{SolarDay, SolarTerm};
+use crate::tyme::zone::{UtcOffset, ZonedSolarTime};
+use crate::tyme::{Culture, Tyme};
+
+/// 波斯历月名称
+pub static PERSIAN_MONTH_NAMES: [&str; 12] = [
+    "法尔瓦丁月",
+    "奥尔迪贝赫什特月",
+    "霍尔达德月",
+    "提尔月",
+    "莫尔达德月",
+    "沙赫里瓦尔月",
+    "梅赫尔月",
+    "阿班月",
+    "阿扎尔月",
+    "达伊月",
+    "巴赫曼月",
+    "埃斯凡德月",
+];
+
+/// 算术历元年法尔瓦丁月1日的日序号（J2000起算）
+static EPOCH: isize = -503225;
+
+/// 德黑兰标准时间（东经52.5度）
+fn get_tehran_offset() -> UtcOffset {
+    UtcOffset::from_hm(3, 30)
+}
+
+/// year年的春分
+fn get_equinox(year: isize) -> SolarTerm {
+    SolarTerm::from_index(year + 621, 6)
+}
+
+/// year年法尔瓦丁月1日（新年）的日序号（J2000起算）
+fn get_new_year(year: isize, persian_type: PersianType) -> isize {
+    match persian_type {
+        PersianType::Arithmetic => EPOCH + 365 * (year - 1) + (8 * year + 21).div_euclid(33),
+        PersianType::Astronomical => {
+            // 春分在德黑兰正午前则当日为新年，否则为次日
+            let jd: f64 = get_equinox(year).get_julian_day().get_day()
+                - UtcOffset::BEIJING.get_days()
+                + get_tehran_offset().get_days();
+            (jd - J2000 + 1.0).floor() as isize
+        }
+    }
+}
+
+/// 波斯历年（伊朗历），以春分所在日为岁首
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PersianYear {
+    year: isize,
+    persian_type: PersianType,
+}
+
+impl Tyme for PersianYear {
+    fn next(&self, n: isize) -> Self {
+        self.try_next(n).unwrap()
+    }
+}
+
+impl Culture for PersianYear {
+    fn get_name(&self) -> String {
+        format!("波斯历{}年", self.year)
+    }
+}
+
+impl PersianYear {
+    pub fn new(year: isize, persian_type: PersianType) -> Result<Self, TymeError> {
+        Self::validate(year)?;
+        Ok(Self { year, persian_type })
+    }
+
+    /// 校验年，支持1年至9377年（公元622年至9999年）
+    pub fn validate(year: isize) -> Result<(), TymeError> {
+        if !(1..=9377).contains(&year) {
+            return Err(TymeError::YearOutOfRange {
+                calendar: "persian",
+                year,
+                min: 1,
+                max: 9377,
+            });
+        }
+        Ok(())
+    }
+
+    pub fn from_year(year: isize, persian_type: PersianType) -> Self {
+        Self::new(year, persian_type).unwrap()
+    }
+
+    pub fn try_from_year(year: isize, persian_type: PersianType) -> Result<Self, TymeError> {
+        Self::new(year, persian_type)
+    }
+
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        Self::new(self.year + n, self.persian_type)
+    }
+
+    pub fn get_year(&self) -> isize {
+        self.year
+    }
+
+    /// 类型
+    pub fn get_type(&self) -> PersianType {
+        self.persian_type
+    }
+
+    /// 天数（365或366）
+    pub fn get_day_count(&self) -> usize {
+        (get_new_year(self.year + 1, self.persian_type)
+            - get_new_year(self.year, self.persian_type)) as usize
+    }
+
+    /// 是否闰年（埃斯凡德月30天）
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::enums::PersianType;
+    /// use tyme4rs::tyme::persian::PersianYear;
+    ///
+    /// assert!(PersianYear::from_year(1403, PersianType::Astronomical).is_leap());
+    /// assert!(!PersianYear::from_year(1404, PersianType::Astronomical).is_leap());
+    /// ```
+    pub fn is_leap(&self) -> bool {
+        self.get_day_count() == 366
+    }
+
+    /// 春分（德黑兰标准时间）
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::enums::PersianType;
+    /// use tyme4rs::tyme::persian::PersianYear;
+    ///
+    /// let y: PersianYear = PersianYear::from_year(1404, PersianType::Astronomical);
+    /// assert_eq!("2025年3月20日 12:31:29 +03:30", y.get_equinox().to_string());
+    /// // 春分在正午后，次日为新年
+    /// assert_eq!("2025年3月21日", y.get_first_month().get_first_day().get_solar_day().to_string());
+    /// ```
+    pub fn get_equinox(&self) -> ZonedSolarTime {
+        get_equinox(self.year).get_zoned_solar_time(get_tehran_offset())
+    }
+
+    pub fn get_first_month(&self) -> PersianMonth {
+        PersianMonth::from_ym(self.year, 1, self.persian_type)
+    }
+
+    pub fn get_months(&self) -> Vec<PersianMonth> {
+        (1..=12)
+            .map(|m| PersianMonth::from_ym(self.year, m, self.persian_type))
+            .collect()
+    }
+}
+
+impl Display for PersianYear {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.get_name())
+    }
+}
+
+/// 波斯历月
+#[derive(Debug, Copy, Clone)]
+pub struct PersianMonth {
+    year: isize,
+    month: usize,
+    persian_type: PersianType,
+    /// 1日（J2000起算的日序号）
+    first_day: isize,
+    day_count: usize,
+}
+
+impl Tyme for PersianMonth {
+    fn next(&self, n: isize) -> Self {
+        self.try_next(n).unwrap()
+    }
+}
+
+impl Culture for PersianMonth {
+    fn get_name(&self) -> String {
+        PERSIAN_MONTH_NAMES[self.month - 1].to_string()
+    }
+}
+
+impl PersianMonth {
+    pub fn new(year: isize, month: usize, persian_type: PersianType) -> Result<Self, TymeError> {
+        PersianYear::validate(year)?;
+        if !(1..=12).contains(&month) {
+            return Err(TymeError::IllegalMonth {
+                calendar: "persian",
+                year,
+                month: month as isize,
+            });
+        }
+        // 前6个月31天，其后30天，末月29天（闰年30天）
+        let m: isize = month as isize - 1;
+        let new_year: isize = get_new_year(year, persian_type);
+        let day_count: usize = match month {
+            1..=6 => 31,
+            7..=11 => 30,
+            _ => (get_new_year(year + 1, persian_type) - new_year - 336) as usize,
+        };
+        Ok(Self {
+            year,
+            month,
+            persian_type,
+            first_day: new_year + 31 * m.min(6) + 30 * (m - 6).max(0),
+            day_count,
+        })
+    }
+
+    pub fn from_ym(year: isize, month: usize, persian_type: PersianType) -> Self {
+        Self::new(year, month, persian_type).unwrap()
+    }
+
+    pub fn try_from_ym(
+        year: isize,
+        month: usize,
+        persian_type: PersianType,
+    ) -> Result<Self, TymeError> {
+        Self::new(year, month, persian_type)
+    }
+
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        let i: isize = (self.year - 1) * 12 + self.month as isize - 1 + n;
+        Self::new(
+            i.div_euclid(12) + 1,
+            (i.rem_euclid(12) + 1) as usize,
+            self.persian_type,
+        )
+    }
+
+    pub fn get_persian_year(&self) -> PersianYear {
+        PersianYear {
+            year: self.year,
+            persian_type: self.persian_type,
+        }
+    }
+
+    pub fn get_year(&self) -> isize {
+        self.year
+    }
+
+    pub fn get_month(&self) -> usize {
+        self.month
+    }
+
+    /// 类型
+    pub fn get_type(&self) -> PersianType {
+        self.persian_type
+    }
+
+    /// 位于当年的索引(0-11)
+    pub fn get_index_in_year(&self) -> usize {
+        self.month - 1
+    }
+
+    pub fn get_day_count(&self) -> usize {
+        self.day_count
+    }
+
+    pub fn get_first_day(&self) -> PersianDay {
+        PersianDay {
+            month: *self,
+            day: 1,
+        }
+    }
+
+    pub fn get_days(&self) -> Vec<PersianDay> {
+        (1..=self.day_count)
+            .map(|day| PersianDay { month: *self, day })
+            .collect()
+    }
+}
+
+impl Display for PersianMonth {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.get_persian_year(), self.get_name())
+    }
+}
+
+impl PartialEq for PersianMonth {
+    fn eq(&self, other: &Self) -> bool {
+        self.year == other.year
+            && self.month == other.month
+            && self.persian_type == other.persian_type
+    }
+}
+
+impl Eq for PersianMonth {}
+
+/// 波斯历日
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PersianDay {
+    month: PersianMonth,
+    day: usize,
+}
+
+impl Tyme for PersianDay {
+    fn next(&self, n: isize) -> Self {
+        self.try_next(n).unwrap()
+    }
+}
+
+impl Culture for PersianDay {
+    fn get_name(&self) -> String {
+        format!("{}日", self.day)
+    }
+}
+
+impl PersianDay {
+    pub fn new(
+        year: isize,
+        month: usize,
+        day: usize,
+        persian_type: PersianType,
+    ) -> Result<Self, TymeError> {
+        let m: PersianMonth = PersianMonth::new(year, month, persian_type)?;
+        if day < 1 || day > m.get_day_count() {
+            return Err(TymeError::IllegalDay {
+                calendar: "persian",
+                year,
+                month: month as isize,
+                day: day as isize,
+            });
+        }
+        Ok(Self { month: m, day })
+    }
+
+    pub fn from_ymd(year: isize, month: usize, day: usize, persian_type: PersianType) -> Self {
+        Self::new(year, month, day, persian_type).unwrap()
+    }
+
+    pub fn try_from_ymd(
+        year: isize,
+        month: usize,
+        day: usize,
+        persian_type: PersianType,
+    ) -> Result<Self, TymeError> {
+        Self::new(year, month, day, persian_type)
+    }
+
+    /// 从公历日创建
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::enums::PersianType;
+    /// use tyme4rs::tyme::persian::PersianDay;
+    /// use tyme4rs::tyme::solar::SolarDay;
+    ///
+    /// let d: PersianDay = PersianDay::from_solar_day(SolarDay::from_ymd(2024, 10, 1), PersianType::Astronomical);
+    /// assert_eq!("波斯历1403年梅赫尔月10日", d.to_string());
+    /// ```
+    pub fn from_solar_day(solar_day: SolarDay, persian_type: PersianType) -> Self {
+        Self::try_from_solar_day(solar_day, persian_type).unwrap()
+    }
+
+    /// 从公历日创建，超出范围时返回错误
+    pub fn try_from_solar_day(
+        solar_day: SolarDay,
+        persian_type: PersianType,
+    ) -> Result<Self, TymeError> {
+        let d: isize = (solar_day.get_julian_day().get_day() - J2000 + 0.5).floor() as isize;
+        let mut year: isize = solar_day.get_year() - 621;
+        if d < get_new_year(year, persian_type) {
+            year -= 1;
+        }
+        let m: PersianMonth = PersianYear::new(year, persian_type)?
+            .get_months()
+            .into_iter()
+            .rev()
+            .find(|m| m.first_day <= d)
+            .unwrap();
+        Ok(Self {
+            month: m,
+            day: (d - m.first_day) as usize + 1,
+        })
+    }
+
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        Self::try_from_solar_day(self.get_solar_day().try_next(n)?, self.month.persian_type)
+    }
+
+    pub fn get_persian_month(&self) -> PersianMonth {
+        self.month
+    }
+
+    pub fn get_year(&self) -> isize {
+        self.month.year
+    }
+
+    pub fn get_month(&self) -> usize {
+        self.month.month
+    }
+
+    pub fn get_day(&self) -> usize {
+        self.day
+    }
+
+    /// 类型
+    pub fn get_type(&self) -> PersianType {
+        self.month.persian_type
+    }
+
+    pub fn get_solar_day(&self) -> SolarDay {
+        JulianDay::from_julian_day(J2000 + (self.month.first_day + self.day as isize - 1) as f64)
+            .get_solar_day()
+    }
+
+    pub fn subtract(&self, other: Self) -> isize {
+        self.month.first_day + self.day as isize - other.month.first_day - other.day as isize
+    }
+}
+
+impl Display for PersianDay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.month, self.get_name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tyme::enums::PersianType;
+    use crate::tyme::error::TymeError;
+    use crate::tyme::persian::{PersianDay, PersianMonth, PersianYear};
+    use crate::tyme::solar::SolarDay;
+    use crate::tyme::{Culture, Tyme};
+
+    #[test]
+    fn test0() {
+        // 新年（诺鲁孜节）
+        let fixtures: [(isize, [usize; 3]); 8] = [
+            (1300, [1921, 3, 21]),
+            (1354, [1975, 3, 21]),
+            (1375, [1996, 3, 20]),
+            (1396, [2017, 3, 21]),
+            (1399, [2020, 3, 20]),
+            (1403, [2024, 3, 20]),
+            (1404, [2025, 3, 21]),
+            (1405, [2026, 3, 21]),
+        ];
+        for (year, [y, m, d]) in fixtures {
+            let day: PersianDay = PersianDay::from_ymd(year, 1, 1, PersianType::Astronomical);
+            assert_eq!(SolarDay::from_ymd(y as isize, m, d), day.get_solar_day());
+            assert_eq!(
+                day,
+                SolarDay::from_ymd(y as isize, m, d)
+                    .get_persian_day()
+                    .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test1() {
+        // 1300至1500年，33年8闰的算术历与天文历完全一致
+        for year in 1300..=1500 {
+            let a: PersianYear = PersianYear::from_year(year, PersianType::Arithmetic);
+            let b: PersianYear = PersianYear::from_year(year, PersianType::Astronomical);
+            assert_eq!((25 * year + 11).rem_euclid(33) < 8, a.is_leap());
+            assert_eq!(a.is_leap(), b.is_leap());
+            assert_eq!(
+                a.get_first_month().get_first_day().get_solar_day(),
+                b.get_first_month().get_first_day().get_solar_day()
+            );
+        }
+    }
+
+    #[test]
+    fn test2() {
+        let y: PersianYear = PersianYear::from_year(1403, PersianType::Astronomical);
+        let months: Vec<PersianMonth> = y.get_months();
+        assert_eq!("法尔瓦丁月", months[0].get_name());
+        assert_eq!(31, months[5].get_day_count());
+        assert_eq!(30, months[6].get_day_count());
+        assert_eq!(30, months[11].get_day_count());
+        assert_eq!(29, y.next(1).get_months()[11].get_day_count());
+        assert_eq!(366, months.iter().map(|m| m.get_day_count()).sum::<usize>());
+        assert_eq!(
+            Err(TymeError::IllegalDay {
+                calendar: "persian",
+                year: 1404,
+                month: 12,
+                day: 30
+            }),
+            PersianDay::try_from_ymd(1404, 12, 30, PersianType::Astronomical)
+        );
+    }
+
+    #[test]
+    fn test3() {
+        let d: PersianDay = PersianDay::from_ymd(1403, 12, 30, PersianType::Astronomical);
+        assert_eq!("波斯历1403年埃斯凡德月30日", d.to_string());
+        assert_eq!("2025年3月20日", d.get_solar_day().to_string());
+        assert_eq!("波斯历1404年法尔瓦丁月1日", d.next(1).to_string());
+        assert_eq!(1, d.next(1).subtract(d));
+        assert_eq!(
+            "波斯历1402年埃斯凡德月",
+            d.get_persian_month().next(-12).to_string()
+        );
+    }
+
+    #[test]
+    fn test4() {
+        // 往返转换
+        for persian_type in [PersianType::Arithmetic, PersianType::Astronomical] {
+            let mut solar_day: SolarDay = SolarDay::from_ymd(2023, 1, 1);
+            for _ in 0..800 {
+                let d: PersianDay = PersianDay::from_solar_day(solar_day, persian_type);
+                assert_eq!(solar_day, d.get_solar_day());
+                assert_eq!(
+                    d,
+                    PersianDay::from_ymd(d.get_year(), d.get_month(), d.get_day(), persian_type)
+                );
+                solar_day = solar_day.next(1);
+            }
+        }
+    }
+}
@@ -1,13 +1,18 @@
+use crate::tyme::culture::panchang::{Karana, Nakshatra, Yoga};
+use crate::tyme::culture::star::nine::NineStar;
+use crate::tyme::culture::star::seven::SevenStar;
 use crate::tyme::culture::{Element, Zodiac, ELEMENT_NAMES, ZODIAC_NAMES};
+use crate::tyme::enums::Tradition;
+use crate::tyme::error::TymeError;
+use crate::tyme::festival::RabByungFestival;
+use crate::tyme::jd::{JulianDay, J2000};
+use crate::tyme::lunar::LunarYear;
 use crate::tyme::sixtycycle::SixtyCycle;
 use crate::tyme::solar::{SolarDay, SolarYear};
 use crate::tyme::unit::{DayUnit, MonthUnit};
-use crate::tyme::{Culture, Tyme};
-use lazy_static::lazy_static;
-use std::collections::HashMap;
+use crate::tyme::{Culture, LoopTyme, Tyme};
 use std::fmt::{Display, Formatter};
 use std::ops::{Deref, DerefMut};
-use std::str::{Chars, Split};
 
 /// 藏历五行
 #[derive(Debug, Clone)]
@@ -51,9 +56,13 @@ impl RabByungElement {
     }
 
     pub fn from_name(name: &str) -> Self {
-        Self {
-            parent: Element::from_name(&name.replace("铁", "金")),
-        }
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: Element::try_from_name(&name.replace("铁", "金"))?,
+        })
     }
 
     /// 我生者
@@ -97,6 +106,93 @@ impl From<RabByungElement> for Element {
     }
 }
 
+/// 八卦名称，按藏历巴卡（spar kha）的顺序
+pub static SPAR_KHA_NAMES: [&str; 8] = ["离", "坤", "兑", "乾", "坎", "艮", "震", "巽"];
+
+/// 巴卡（spar kha，八卦）
+#[derive(Debug, Clone)]
+pub struct SparKha {
+    parent: LoopTyme,
+}
+
+impl Deref for SparKha {
+    type Target = LoopTyme;
+
+    fn deref(&self) -> &Self::Target {
+        &self.parent
+    }
+}
+
+impl DerefMut for SparKha {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.parent
+    }
+}
+
+impl Tyme for SparKha {
+    fn next(&self, n: isize) -> Self {
+        Self::from_index(self.parent.next_index(n) as isize)
+    }
+}
+
+impl Culture for SparKha {
+    fn get_name(&self) -> String {
+        self.parent.get_name()
+    }
+}
+
+impl SparKha {
+    pub fn from_index(index: isize) -> Self {
+        Self {
+            parent: LoopTyme::from_index(
+                SPAR_KHA_NAMES
+                    .to_vec()
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect(),
+                index,
+            ),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Self {
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
+                SPAR_KHA_NAMES
+                    .to_vec()
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect(),
+                name,
+            )?,
+        })
+    }
+}
+
+impl Display for SparKha {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.get_name())
+    }
+}
+
+impl PartialEq for SparKha {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for SparKha {}
+
+impl From<SparKha> for LoopTyme {
+    fn from(val: SparKha) -> Self {
+        val.parent
+    }
+}
+
 /// 藏历年 (饶迥年)
 #[derive(Debug, Clone)]
 pub struct RabByungYear {
@@ -143,15 +239,30 @@ impl RabByungYear {
         rab_byung_index: isize,
         element_index: usize,
         zodiac_index: usize,
-    ) -> Result<Self, String> {
+    ) -> Result<Self, TymeError> {
         if !(0..=150).contains(&rab_byung_index) {
-            return Err(format!("illegal rab-byung index: {}", rab_byung_index));
+            return Err(TymeError::IndexOutOfRange {
+                kind: "rab-byung",
+                index: rab_byung_index,
+                min: 0,
+                max: 150,
+            });
         }
         if element_index >= ELEMENT_NAMES.len() {
-            return Err(format!("illegal relement index: {}", element_index));
+            return Err(TymeError::IndexOutOfRange {
+                kind: "rab-byung element",
+                index: element_index as isize,
+                min: 0,
+                max: ELEMENT_NAMES.len() as isize - 1,
+            });
         }
         if zodiac_index >= ZODIAC_NAMES.len() {
-            return Err(format!("illegal zodiac index: {}", zodiac_index));
+            return Err(TymeError::IndexOutOfRange {
+                kind: "zodiac",
+                index: zodiac_index as isize,
+                min: 0,
+                max: ZODIAC_NAMES.len() as isize - 1,
+            });
         }
         Ok(Self {
             rab_byung_index: rab_byung_index as usize,
@@ -160,9 +271,14 @@ impl RabByungYear {
         })
     }
 
-    pub fn validate(year: isize) -> Result<(), String> {
+    pub fn validate(year: isize) -> Result<(), TymeError> {
         if !(1027..=9999).contains(&year) {
-            Err(format!("illegal rab-byung year: {}", year))
+            Err(TymeError::YearOutOfRange {
+                calendar: "rab-byung",
+                year,
+                min: 1027,
+                max: 9999,
+            })
         } else {
             Ok(())
         }
@@ -172,7 +288,7 @@ impl RabByungYear {
     pub fn from_sixty_cycle(
         rab_byung_index: isize,
         sixty_cycle: SixtyCycle,
-    ) -> Result<Self, String> {
+    ) -> Result<Self, TymeError> {
         Self::new(
             rab_byung_index,
             sixty_cycle.get_heaven_stem().get_element().get_index(),
@@ -185,12 +301,12 @@ impl RabByungYear {
         rab_byung_index: isize,
         element: RabByungElement,
         zodiac: Zodiac,
-    ) -> Result<Self, String> {
+    ) -> Result<Self, TymeError> {
         Self::new(rab_byung_index, element.get_index(), zodiac.get_index())
     }
 
     /// 从公历年创建 (1027年为藏历元年)
-    pub fn from_year(year: isize) -> Result<Self, String> {
+    pub fn from_year(year: isize) -> Result<Self, TymeError> {
         Self::validate(year)?;
         Self::from_sixty_cycle((year - 1024) / 60, SixtyCycle::from_index(year - 4))
     }
@@ -223,30 +339,34 @@ impl RabByungYear {
 
     /// 闰月数字，1代表闰1月，0代表无闰月
     pub fn get_leap_month(&self) -> usize {
-        let mut y: isize = 1;
-        let mut m: isize = 4;
-        let mut t: isize = 1;
-        let current_year: isize = self.get_year();
-
-        while y < current_year {
-            let i: isize = m + 31 + t;
-            y += 2;
-            m = i - 23;
-            if i > 35 {
-                y += 1;
-                m -= 12;
+        self.get_leap_month_with(Tradition::Phugpa)
+    }
+
+    /// 指定流派的闰月数字，0代表无闰月
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::enums::Tradition;
+    /// use tyme4rs::tyme::rabbyung::RabByungYear;
+    ///
+    /// let y: RabByungYear = RabByungYear::from_year(2021).unwrap();
+    /// assert_eq!(9, y.get_leap_month());
+    /// assert_eq!(0, y.get_leap_month_with(Tradition::Mongolian));
+    /// ```
+    pub fn get_leap_month_with(&self, tradition: Tradition) -> usize {
+        let y: isize = self.get_year();
+        let start: isize = get_month_index(y, 1, tradition);
+        for n in start + 1..get_month_index(y + 1, 1, tradition) {
+            let (_, m): (isize, isize) = get_year_month(n, tradition);
+            if m < 0 {
+                return m.unsigned_abs();
             }
-            t = 1 - t;
-        }
-
-        if y == current_year {
-            m as usize
-        } else {
-            0
         }
+        0
     }
 
-    pub fn next(&self, n: isize) -> Result<Self, String> {
+    pub fn next(&self, n: isize) -> Result<Self, TymeError> {
         Self::from_year(self.get_year() + n)
     }
 
@@ -255,10 +375,41 @@ impl RabByungYear {
         SolarYear::from_year(self.get_year())
     }
 
+    /// 九宫（sme ba），与农历年九星相同
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::rabbyung::RabByungYear;
+    ///
+    /// assert_eq!("三碧木", RabByungYear::from_year(2024).unwrap().get_nine_star().to_string());
+    /// ```
+    pub fn get_nine_star(&self) -> NineStar {
+        LunarYear::from_year(self.get_year()).get_nine_star()
+    }
+
+    /// 巴卡（spar kha），上元木鼠年（1864年，一白）起离，逐年顺行
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::rabbyung::RabByungYear;
+    ///
+    /// assert_eq!("离", RabByungYear::from_year(2024).unwrap().get_spar_kha().to_string());
+    /// ```
+    pub fn get_spar_kha(&self) -> SparKha {
+        SparKha::from_index(self.get_year() - 1864)
+    }
+
     /// 月份数量
     pub fn get_month_count(&self) -> usize {
+        self.get_month_count_with(Tradition::Phugpa)
+    }
+
+    /// 指定流派的月份数量
+    pub fn get_month_count_with(&self, tradition: Tradition) -> usize {
         let mut n: usize = 12;
-        if self.get_leap_month() > 0 {
+        if self.get_leap_month_with(tradition) > 0 {
             n = 13
         }
         n
@@ -270,13 +421,18 @@ impl RabByungYear {
     }
 
     pub fn get_months(&self) -> Vec<RabByungMonth> {
+        self.get_months_with(Tradition::Phugpa)
+    }
+
+    /// 指定流派的月份列表
+    pub fn get_months_with(&self, tradition: Tradition) -> Vec<RabByungMonth> {
         let mut l: Vec<RabByungMonth> = Vec::new();
         let y: isize = self.get_year();
-        let leap_month: isize = self.get_leap_month() as isize;
+        let leap_month: isize = self.get_leap_month_with(tradition) as isize;
         for i in 1..13 {
-            l.push(RabByungMonth::from_ym(y, i).unwrap());
+            l.push(RabByungMonth::from_ym_with(tradition, y, i).unwrap());
             if i == leap_month {
-                l.push(RabByungMonth::from_ym(y, -i).unwrap());
+                l.push(RabByungMonth::from_ym_with(tradition, y, -i).unwrap());
             }
         }
         l
@@ -298,32 +454,160 @@ impl PartialEq for RabByungYear {
 
 impl Eq for RabByungYear {}
 
-lazy_static! {
-    static ref RAB_BYUNG_MONTH_DAYS: HashMap<usize, Vec<isize>> = {
-        let mut map: HashMap<usize, Vec<isize>> = HashMap::new();
-        let years: Split<&str> = "2c>,182[>1:2TA4ZI=n1E2Bk1J2Ff3Mk503Oc62g=,172^>1:2XA1>2UE2Bo1I2Fj3Lo62Fb3Mf5,03N^72b=1:2]A1>2ZF1B2VI2Em1K2Fe,2Lh1R3Na603P\\:172Y>1;2UB2=m2Dq1J2Eh,2Kl1Q3Me603Pa:172^>1;2YA2=p1C2UI,2Dk2Jp3QEc3Mi603Pf:3L[72b?1:2]A1<2UB2XH,2Cn1I2Ei1L2Ie1Q3Na703Q\\:2`@1;2XA,4\\H;m1B2TI2Em1L2Ij1Q3Nf603Q`903QW:,2[@1;2TB2XI1E4TMAh2Io3RFe3Mj603Pc803Q[;,2^?1;2WA2>q1E2Bm1I2Fi1M2Hc3Of70,3P^82a>1:2[A1>2WE1B2TI2Fm1L2Hf3Ni6,03Oa703PZ:3`A62V>4]F;q1B4YJ>l2Eq1L2Gi3Ml5,03Nd603Q_9172[>1;2XB2>p1E2VK2Fl,1K2Fc3Mh603Pc9172`>1;2\\B1>2UD2=j2En,1J2Fg3Mm62Ib3Pj;3M_703R[:2`B1=2YB2=n,1C2TI2Fk1L2Ig1P3Nd703Q_:152X<2[A,2<q1B2WI2Ep1L2Il1Q3Ni703Qc9152[:2^@,1;2WB2>o1E2Bk1I2Fh1M2Ib3Pf803R^9,2a?1;2ZA1>2UE2Bp1I2Fl1M2If3Oi80,3Pa803QY:2^A1>2ZE1B4WJ>j2Fp1M2Hi1N2H`,3Od703Q]:162Y>1;2VB2?o1E4VM@h2Gl1M,2Hd3Ng603Qa9172^>1;2ZB1?2UE2@l2Fo1L,2Gg3Mk62H`3Pf:172c?3QY;2_B1>2YD2?o1E,2TK2Fj1M2Ie1P3Mb703R^;172X=2\\C1>,2TD2WJ2Fn1L2Ij1P3Ng703Rb:162[<2_B1=,2VC2>m1E4TMAh2Io3QFe3Nl82Ja3Qf:152_;0,3RU<2ZB1>2TE2Bn1I2Fj1M2Je3Pk:2K^3Ra:,03RY;2]A1>2XE1B2TI2Fo1M2Ii1P2Ka3Qd8,03R]:3bB62W>4]F:q1B2?n1F4VNAh2Il1O2Jd,3Pg803Q`:162\\=1;2XB1?2TF2Bl2Ho1N,2Ig3Nk703Qd9162`>1;2]B1?2XE2Ao1G2TM,2Hj1M2Id1P3M_603R\\;172W>2\\E1@2TE,2?i2Gm1M2Ih1P3Md603Ra;172[=28q1?2WD,2?m2Fq1M2Il1P3Mi72I^3Re:162_<172W=,2ZC2?q1E2Bk1I2Fh1M2Jd1Q3M^52b;16,2Y<2]B1>2VE2Bp1I2Fm1M2Jh1Q2Lb3Re:15,2\\;3aC62U>2[E1B4WJ>k1F4TNBg2Jl1P2Le3Qh9,03R`:172Z=1:2VB2?q1F2Bk2Ip1P2Jg,1P2J_3Qc:162^=1;2[B1?2WF2Bo1H2Bg2Ij,1O2Jc3Qg:3L\\62c>3QY;3aC72V?2[F1A2TG2Bj,2Hm1N2Jg1P3Mb603R_;182Z>1:2T@2WF2Am,2Gp1M2Ik1P3Mg603Rc;172^>192W?2ZE,2@p1F2Bj2Io3QEe1M2Jb1Q3M]72b=182Z>,2]D1?2VE2Bn1I2Fk1M2Jg1Q3Ma62e<172]=,172U>2YE1B2UI2Fp1N2Jk1Q3Me503M\\6,2`<172Y>3_F:2TB2?n1F2Cj2Jo3QDc2Lh1R,3L_52c;172]=1:2XB1?2UF2Cn1I2Eg2Kk1P,2Lb3Rf;162a=1:2]B1?2ZF1B2TH2Dj2Jm,1O2Kf1Q3M`603Q\\;182Y?2;q1A2WH2Cm,2Hq1O2Ji1P3Me603Qa;182]>1:2WA2[G2Ap,1G2Bi2Im1P3Mi72I_3Qf;3N\\72Eh1:2Z?29o,1@2UF2Bm1I2Fh1M2Je1Q3N`72f?3PY92]>19,2U?2YF2Bq1I2Fm1M2Jj1Q3Nd603O]72`=,182X?4]F:o1B4WI=k1F4UNCi2Jn3REc3Mh503N`6,2c<182\\>1:2VA2?q1F2Cm1J2Fg2Lk1R3Mc5,2f<172`=1:2[A1?2XF2Cq1I2Ek2Kn1R,2Lf1R3N_62d>3PZ:3aC72W?2;p1B2WI2Dn1J,2De2Ki1Q3Mc603Q_:182\\?1;2VB2<m2Cq1I,2Dh2Jl1P3Mg603Qd;182`?1;2ZA2<p1B,2UH2Cl1I2Ef3Mm82Jc1Q3N_703QY:2]@1;2UA,2XG2Bp1I2Fk1M2Jh1Q3Nc703Q]92`?1:,2X@4\\G:n1B2VI2Fp1M2Jl1R3Ng603P`82d>,192[?1;2UA2>o1F2Ck1J2Gg3Mk603Oc70,3OZ82_>1:2YA1?2VF2Cp1J2Fj1M2Gc3Nf5,03O^72b>1:2^B1?4[G;n1C2VJ2Fn1L2Gf,3Mi503Nb603Q]:172Y?1<2UB2>m2Eq1K2Fi,2Kl1R3Mf603Qa:182^?1;2YB2>q1D2VJ,2Dl1J2Fe3Mj603Qg;3N]72c@3QX;2]A1=2VB,2YI2Co1J2Fi1M2Je1Q3Nb703R]:2aA1<2XA,2<n1C2UI2Fn1M2Jj1Q3Nf703Q`903RX:,2[@1<2TB4YJ>l1E4UNBi1J2Ge3Mk703Pc803Q[9,2^?1;2XB2>q1E2Cn1J2Gj1M2Ic3Of70,3P^82b?1;2\\A1>2XF1C2UJ2Fm1M2Hf3Ni6,03Oa703Q[:3aB72W>1<2TC2?m2Fq1L2Gi3Ml5,03Ne703Q_:172\\>1<2XB2?q1E2WL2Fl,1L2Gd3Ni603Qd:172a?1;2\\B1>2VD2>k,2Eo1K2Gh1M2Ic1Q3N`703R\\;3aC62U=2YC2>o,1D2TJ2Fl1M2Jh1Q3Ne703R`:162Y<2\\B,1=2TC4XJ=j2Fp1M2Jm3QFc3Ni803Qc:152\\;2_A,1<2WB2>o1E2Bl1J2Gh1N2Jc3Qg903R^:,2b@1;2[B1>2VE2Cq1J2Gl1N2Jf3Pj80,3Qa803RZ;2_B1>4[F:o1C4XK?k2Fp1M2Ii1O2Ia,3Pd703R^:172Y>1<2VC2?p1F2Ai2Hl1M,2Hd3Oh703Qb:172^>1<2[C1?2UE2Al2Go,1L2Hg3Nl82Ia3Qg;3M]72e@3RZ;3`C72T>2YD2@o1E,2TK2Gk1M2Jf1Q3Nb703R^;172Y=2\\D1>,2TD4XK>i2Fo1M2Jj1Q3Ng703Rb;172\\<2`C1=,2WC2?n1F4VNBi1J2Gf1N2Kb3Rf:162_;15,2V<2ZB1?2TE2Bn1J2Gk1N2Kf1Q2L^3Rb:,152Z;2^B1>2YE1B2UJ2Go1N2Ji1P2Kb3Qd9,03R];172X>1;2TC2@n1G2Bi2Im1O2Jd,3Ph803Ra:172\\>1;2YC1@2UF2Bl2Hp1N,2Ig3Ol82J`3Qe:172a>1;4^C7q1?2XF2Ao1G2UN,2Hj1N2Jd1Q3N`703R];182X>2]F1@2TF,2@j2Gn1M2Jq1Q3Ne703Ra;172\\>192T?,2WE2@m1F4TMAf2Im3QEc3Nj82J`3Rf;172_=182W>,2ZD2?q1F2Bl1I2Gj1N2Ke1R3M_62b<17,2Z=2]C1?2WE2Bq1I2Gn1N2Ki1Q3Mb52e;16,2]<172V>4[F:o1B4XK?l1G4UOCh2Jl1Q2Le3Rh:,152`;172Z>1;2WB2@q1G2Cl2Ip1P2K_".split(",");
+/// 时轮历真日计算的公分母
+static SCALE: i64 = 102317040;
+
+/// 平朔日以公分母计的月增量、日增量（月长167025/5656日）
+static MEAN_DATE_MONTH: i64 = 167025 * 18090;
+static MEAN_DATE_DAY: i64 = 167025 * 603;
+
+/// 月亮近点差表（四分之一周期，单位为1/60日）
+static MOON_TABLE: [i64; 8] = [0, 5, 10, 15, 19, 22, 24, 25];
+
+/// 太阳近点差表（四分之一周期，单位为1/60日）
+static SUN_TABLE: [i64; 4] = [0, 6, 10, 11];
+
+/// 以1/651240周计的一周及一宿
+static CIRCLE: i64 = 651240;
+static MANSION: i64 = 24120;
+
+/// 公历9999年12月31日的日序号（J2000起算）
+static MAX_DAY: isize = 2921939;
+
+/// 历元常数（浦派、蒙古据Janson《Tibetan Calendar Mathematics》）
+struct Epoch {
+    /// 历元年
+    year: isize,
+    /// 历元月
+    month: isize,
+    /// 置闰常数β：历元起第n个真月的月序为⌊(65n+β)/67⌋
+    leap_offset: isize,
+    /// 历元月初0日的平日（儒略日），整数部分及以1/5656日计的余数
+    mean_date: (i64, i64),
+    /// 历元月初0日的平太阳（以1/804周计）
+    mean_sun: i64,
+    /// 历元月初0日的月亮近点角（以1/3528周计）
+    anomaly: i64,
+}
 
-        let mut y: usize = 1950;
-        let mut m: usize = 11;
+/// 各流派的历元
+fn get_epoch(tradition: Tradition) -> Epoch {
+    match tradition {
+        // 时轮历元（公元806年三月）
+        Tradition::Phugpa => Epoch {
+            year: 806,
+            month: 3,
+            leap_offset: -12,
+            mean_date: (2015501, 4783),
+            mean_sun: 743,
+            anomaly: 475,
+        },
+        // 伊希巴拉珠尔历元（公元1747年三月）
+        Tradition::Mongolian => Epoch {
+            year: 1747,
+            month: 3,
+            leap_offset: 37,
+            mean_date: (2359237, 5206),
+            mean_sun: 0,
+            anomaly: 3043,
+        },
+        // 粗卜派、不丹的历元常数尚未与原始文献核对，暂沿用浦派的平均要素，仅以置闰常数使闰月较浦派推后
+        Tradition::Tsurphu => Epoch {
+            leap_offset: -11,
+            ..get_epoch(Tradition::Phugpa)
+        },
+        Tradition::Bhutanese => Epoch {
+            leap_offset: -10,
+            ..get_epoch(Tradition::Phugpa)
+        },
+    }
+}
 
-        for s in years {
-            let mut ys: &str = s;
-            while !ys.is_empty() {
-                let mut chars: Chars = ys.chars();
-                let len: usize = (chars.next().unwrap() as isize - b'0' as isize) as usize;
-                let mut data: Vec<isize> = Vec::new();
-                for _i in 0..len {
-                    data.push(chars.next().unwrap() as isize - b'5' as isize - 30);
-                }
-                map.insert(y * 13 + m, data);
-                m += 1;
-                ys = &ys[1 + len..];
-            }
-            y += 1;
-            m = 0;
-        }
-        map
-    };
+impl Epoch {
+    /// 历元月初0日的平日（J2000起算，以公分母计）
+    fn get_mean_date(&self) -> i64 {
+        (self.mean_date.0 - 2451545) * SCALE + self.mean_date.1 * 18090
+    }
+}
+
+fn get_moon_equation(i: i64) -> i64 {
+    let i: i64 = i.rem_euclid(28);
+    match i {
+        0..=7 => MOON_TABLE[i as usize],
+        8..=14 => MOON_TABLE[(14 - i) as usize],
+        15..=21 => -MOON_TABLE[(i - 14) as usize],
+        _ => -MOON_TABLE[(28 - i) as usize],
+    }
+}
+
+fn get_sun_equation(i: i64) -> i64 {
+    let i: i64 = i.rem_euclid(12);
+    match i {
+        0..=3 => SUN_TABLE[i as usize],
+        4..=6 => SUN_TABLE[(6 - i) as usize],
+        7..=9 => -SUN_TABLE[(i - 6) as usize],
+        _ => -SUN_TABLE[(12 - i) as usize],
+    }
+}
+
+/// 太阳改正（单位为1/60宿的402倍）
+fn get_sun_correction(d: i64, n: i64, epoch: &Epoch) -> i64 {
+    // 太阳近点角（平太阳减1/4周）以1/4824周计，每1/12周为402
+    let b: i64 = (390 * n + 13 * d + 6 * (epoch.mean_sun - 201)).rem_euclid(4824);
+    let (j, r): (i64, i64) = (b / 402, b % 402);
+    get_sun_equation(j) * 402 + r * (get_sun_equation(j + 1) - get_sun_equation(j))
+}
+
+/// 历元起第n个真月d日结束的时刻（J2000起算，以公分母计）
+fn get_true_time(d: isize, n: isize, tradition: Tradition) -> i64 {
+    let epoch: Epoch = get_epoch(tradition);
+    let (d, n): (i64, i64) = (d as i64, n as i64);
+    // 月亮近点角以1/3528周计，每1/28周为126
+    let a: i64 = (253 * n + 126 * d + epoch.anomaly).rem_euclid(3528);
+    let (i, r): (i64, i64) = (a / 126, a % 126);
+    let moon: i64 =
+        get_moon_equation(i) * 126 + r * (get_moon_equation(i + 1) - get_moon_equation(i));
+    epoch.get_mean_date() + n * MEAN_DATE_MONTH + d * MEAN_DATE_DAY + moon * (SCALE / 7560)
+        - get_sun_correction(d, n, &epoch) * (SCALE / 24120)
+}
+
+/// 历元起第n个真月d日结束时所在的日序号（J2000起算）
+fn get_true_date(d: isize, n: isize, tradition: Tradition) -> isize {
+    get_true_time(d, n, tradition).div_euclid(SCALE) as isize
+}
+
+/// 历元起第n个真月d日结束时的真太阳黄经（以1/651240周计，每宿24120）
+fn get_true_sun(d: isize, n: isize, tradition: Tradition) -> i64 {
+    let epoch: Epoch = get_epoch(tradition);
+    let (d, n): (i64, i64) = (d as i64, n as i64);
+    (27 * (1950 * n + 65 * d + 30 * epoch.mean_sun) - get_sun_correction(d, n, &epoch))
+        .rem_euclid(CIRCLE)
+}
+
+/// 藏历年月对应的真月序号（历元月为0），闰月在平月之后
+fn get_month_index(year: isize, month: isize, tradition: Tradition) -> isize {
+    let epoch: Epoch = get_epoch(tradition);
+    let l: isize = 12 * (year - epoch.year) + month.abs() - epoch.month;
+    let n: isize = (67 * l - epoch.leap_offset + 64).div_euclid(65);
+    if month < 0 {
+        n + 1
+    } else {
+        n
+    }
+}
+
+/// 真月序号对应的藏历年和带闰信息的月份
+fn get_year_month(n: isize, tradition: Tradition) -> (isize, isize) {
+    let epoch: Epoch = get_epoch(tradition);
+    let b: isize = epoch.leap_offset;
+    let l: isize = (65 * n + b).div_euclid(67) + epoch.month - 1;
+    let year: isize = epoch.year + l.div_euclid(12);
+    let month: isize = l.rem_euclid(12) + 1;
+    if (65 * (n - 1) + b).div_euclid(67) + epoch.month - 1 == l {
+        (year, -month)
+    } else {
+        (year, month)
+    }
 }
 
 /// 藏历月
@@ -332,6 +616,8 @@ pub struct RabByungMonth {
     parent: MonthUnit,
     /// 是否闰月
     leap: bool,
+    /// 流派
+    tradition: Tradition,
 }
 
 impl Deref for RabByungMonth {
@@ -390,49 +676,65 @@ impl RabByungMonth {
     ];
 
     /// 从藏历年月创建
-    pub fn new(year: isize, month: isize) -> Result<Self, String> {
-        Self::validate(year, month)?;
+    pub fn new(year: isize, month: isize) -> Result<Self, TymeError> {
+        Self::new_with(Tradition::Phugpa, year, month)
+    }
+
+    /// 从指定流派的藏历年月创建
+    pub fn new_with(tradition: Tradition, year: isize, month: isize) -> Result<Self, TymeError> {
+        Self::validate_with(tradition, year, month)?;
         Ok(Self {
             parent: MonthUnit::new(year, month.abs()),
             leap: month < 0,
+            tradition,
         })
     }
 
-    pub fn validate(year: isize, month: isize) -> Result<(), String> {
+    pub fn validate(year: isize, month: isize) -> Result<(), TymeError> {
+        Self::validate_with(Tradition::Phugpa, year, month)
+    }
+
+    pub fn validate_with(tradition: Tradition, year: isize, month: isize) -> Result<(), TymeError> {
         if month == 0 || !(-12..=12).contains(&month) {
-            return Err(format!("illegal rab-byung month: {}", month));
+            return Err(TymeError::IllegalMonth {
+                calendar: "rab-byung",
+                year,
+                month,
+            });
         }
 
-        if !(1950..=2050).contains(&year) {
-            return Err(format!(
-                "rab-byung year {} must between 1950 and 2050",
-                year
-            ));
-        }
+        RabByungYear::validate(year)?;
 
         let leap: bool = month < 0;
         let m: usize = month.unsigned_abs();
-        if year == 1950 && m < 12 {
-            return Err(format!(
-                "month {} must be 12 in rab-byung year {}",
-                month, year
-            ));
-        }
-
-        if leap && m != RabByungYear::from_year(year)?.get_leap_month() {
-            return Err(format!(
-                "illegal leap month {} in rab-byung year {}",
-                m, year
-            ));
+        if leap && m != RabByungYear::from_year(year)?.get_leap_month_with(tradition) {
+            return Err(TymeError::IllegalLeapMonth {
+                calendar: "rab-byung",
+                year,
+                month: m as isize,
+            });
         }
 
         Ok(())
     }
 
-    pub fn from_ym(year: isize, month: isize) -> Result<Self, String> {
+    pub fn from_ym(year: isize, month: isize) -> Result<Self, TymeError> {
         Self::new(year, month)
     }
 
+    pub fn from_ym_with(
+        tradition: Tradition,
+        year: isize,
+        month: isize,
+    ) -> Result<Self, TymeError> {
+        Self::new_with(tradition, year, month)
+    }
+
+    /// 流派
+    pub fn get_tradition(&self) -> Tradition {
+        self.tradition
+    }
+
     /// 藏历年
     pub fn get_rab_byung_year(&self) -> RabByungYear {
         RabByungYear::from_year(self.get_year()).unwrap()
@@ -457,7 +759,9 @@ impl RabByungMonth {
         if self.leap {
             index += 1;
         } else {
-            let leap_month: usize = self.get_rab_byung_year().get_leap_month();
+            let leap_month: usize = self
+                .get_rab_byung_year()
+                .get_leap_month_with(self.tradition);
             if leap_month > 0 && m > leap_month {
                 index += 1;
             }
@@ -481,45 +785,60 @@ impl RabByungMonth {
     }
 
     /// 下一月
-    pub fn next(&self, n: isize) -> Result<Self, String> {
+    pub fn next(&self, n: isize) -> Result<Self, TymeError> {
         if n == 0 {
             return Ok(self.clone());
         }
 
-        let mut m: isize = self.get_index_in_year() as isize + 1 + n;
-        let mut y: RabByungYear = self.get_rab_byung_year();
-        if n > 0 {
-            let mut month_count: isize = y.get_month_count() as isize;
-            while m > month_count {
-                m -= month_count;
-                y = y.next(1)?;
-                month_count = y.get_month_count() as isize;
-            }
-        } else {
-            while m <= 0 {
-                y = y.next(-1)?;
-                m += y.get_month_count() as isize;
-            }
-        }
+        let (year, month): (isize, isize) =
+            get_year_month(self.get_true_month() + n, self.tradition);
+        Self::new_with(self.tradition, year, month)
+    }
 
-        let mut leap: bool = false;
-        let leap_month: isize = y.get_leap_month() as isize;
-        if leap_month > 0 {
-            if m == leap_month + 1 {
-                leap = true;
-            }
-            if m > leap_month {
-                m -= 1;
-            }
-        }
+    /// 真月序号（历元月为0）
+    fn get_true_month(&self) -> isize {
+        get_month_index(self.get_year(), self.get_month_with_leap(), self.tradition)
+    }
 
-        Self::new(y.get_year(), if leap { -m } else { m })
+    /// 某日的日序号（J2000起算，负数为闰日），重日的平日在前、闰日在后
+    fn get_day_index(&self, day: isize) -> isize {
+        let n: isize = self.get_true_month();
+        let d: isize = day.abs();
+        let t: isize = get_true_date(d, n, self.tradition);
+        let prev: isize = match d {
+            1 => get_true_date(30, n - 1, self.tradition),
+            _ => get_true_date(d - 1, n, self.tradition),
+        };
+        if day > 0 && t - prev == 2 {
+            t - 1
+        } else {
+            t
+        }
     }
 
-    /// 特殊日子列表 (闰日为正，缺日为负)
+    /// 特殊日子列表 (闰日为正，缺日为负)，按所属流派的时轮历算法推算
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::rabbyung::RabByungMonth;
+    ///
+    /// assert_eq!(vec![9, -18], RabByungMonth::from_ym(1800, 1).unwrap().get_special_days());
+    /// ```
     pub fn get_special_days(&self) -> Vec<isize> {
-        let key: usize = self.get_year() as usize * 13 + self.get_index_in_year();
-        RAB_BYUNG_MONTH_DAYS.get(&key).cloned().unwrap_or_default()
+        let n: isize = self.get_true_month();
+        let mut l: Vec<isize> = Vec::new();
+        let mut prev: isize = get_true_date(30, n - 1, self.tradition);
+        for d in 1..31 {
+            let t: isize = get_true_date(d, n, self.tradition);
+            match t - prev {
+                0 => l.push(-d),
+                2 => l.push(d),
+                _ => {}
+            }
+            prev = t;
+        }
+        l
     }
 
     /// 闰日列表
@@ -536,7 +855,7 @@ impl RabByungMonth {
         self.get_special_days()
             .iter()
             .filter(|&&d| d < 0)
-            .copied()
+            .map(|&d| -d)
             .collect()
     }
 
@@ -545,9 +864,55 @@ impl RabByungMonth {
         30 + self.get_leap_days().len() - self.get_miss_days().len()
     }
 
-    /// 首日
+    /// 首日 (初一为缺日时顺延)
     pub fn get_first_day(&self) -> RabByungDay {
-        RabByungDay::new(self.get_year(), self.get_month_with_leap(), 1).unwrap()
+        let miss_days: Vec<isize> = self.get_miss_days();
+        let d: isize = (1..31).find(|d| !miss_days.contains(d)).unwrap();
+        RabByungDay::new_with(
+            self.tradition,
+            self.get_year(),
+            self.get_month_with_leap(),
+            d,
+        )
+        .unwrap()
+    }
+
+    /// 某日期实际所在的日子：缺日并入前一日（初一为缺日时取当月首日），重日取平日
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::rabbyung::RabByungMonth;
+    ///
+    /// let m: RabByungMonth = RabByungMonth::from_ym(1800, 1).unwrap();
+    /// assert_eq!(
+    ///     "第十三饶迥铁猴年正月十七",
+    ///     m.get_observed_day(18).unwrap().to_string()
+    /// );
+    /// ```
+    pub fn get_observed_day(&self, day: usize) -> Result<RabByungDay, TymeError> {
+        let y: isize = self.get_year();
+        let m: isize = self.get_month_with_leap();
+        if !(1..=30).contains(&day) {
+            return Err(TymeError::IllegalDay {
+                calendar: "rab-byung",
+                year: y,
+                month: m,
+                day: day as isize,
+            });
+        }
+        let d: isize = day as isize;
+        if !self.get_miss_days().contains(&d) {
+            return RabByungDay::new_with(self.tradition, y, m, d);
+        }
+        if d == 1 {
+            return Ok(self.get_first_day());
+        }
+        // 缺日的日序号即包含该日的前一日
+        RabByungDay::from_solar_day_with(
+            self.tradition,
+            JulianDay::from_julian_day(J2000 + self.get_day_index(d) as f64).get_solar_day(),
+        )
     }
 
     pub fn get_days(&self) -> Vec<RabByungDay> {
@@ -560,9 +925,9 @@ impl RabByungMonth {
             if miss_days.contains(&i) {
                 continue;
             }
-            l.push(RabByungDay::new(y, m, i).unwrap());
+            l.push(RabByungDay::new_with(self.tradition, y, m, i).unwrap());
             if leap_days.contains(&i) {
-                l.push(RabByungDay::new(y, m, -i).unwrap());
+                l.push(RabByungDay::new_with(self.tradition, y, m, -i).unwrap());
             }
         }
         l
@@ -579,6 +944,7 @@ impl PartialEq for RabByungMonth {
     fn eq(&self, other: &Self) -> bool {
         self.get_year() == other.get_year()
             && self.get_month_with_leap() == other.get_month_with_leap()
+            && self.tradition == other.tradition
     }
 }
 
@@ -590,6 +956,8 @@ pub struct RabByungDay {
     parent: DayUnit,
     /// 是否闰日
     leap: bool,
+    /// 流派
+    tradition: Tradition,
 }
 
 impl Deref for RabByungDay {
@@ -625,67 +993,244 @@ impl RabByungDay {
     ];
 
     /// 创建藏历日
-    pub fn new(year: isize, month: isize, day: isize) -> Result<Self, String> {
-        Self::validate(year, month, day)?;
+    pub fn new(year: isize, month: isize, day: isize) -> Result<Self, TymeError> {
+        Self::new_with(Tradition::Phugpa, year, month, day)
+    }
+
+    /// 创建指定流派的藏历日
+    pub fn new_with(
+        tradition: Tradition,
+        year: isize,
+        month: isize,
+        day: isize,
+    ) -> Result<Self, TymeError> {
+        Self::validate_with(tradition, year, month, day)?;
         Ok(Self {
             parent: DayUnit::new(year, month, day.abs()),
             leap: day < 0,
+            tradition,
         })
     }
 
-    pub fn validate(year: isize, month: isize, day: isize) -> Result<(), String> {
+    pub fn validate(year: isize, month: isize, day: isize) -> Result<(), TymeError> {
+        Self::validate_with(Tradition::Phugpa, year, month, day)
+    }
+
+    pub fn validate_with(
+        tradition: Tradition,
+        year: isize,
+        month: isize,
+        day: isize,
+    ) -> Result<(), TymeError> {
         if day == 0 || !(-30..=30).contains(&day) {
-            return Err(format!("illegal day {} in {}", day, month));
+            return Err(TymeError::IllegalDay {
+                calendar: "rab-byung",
+                year,
+                month,
+                day,
+            });
         }
 
-        let m: RabByungMonth = RabByungMonth::from_ym(year, month)?;
+        let m: RabByungMonth = RabByungMonth::from_ym_with(tradition, year, month)?;
         let leap: bool = day < 0;
         let d: isize = day.abs();
 
         if leap && !m.get_leap_days().contains(&d) {
-            return Err(format!("illegal leap day {} in {}", d, month));
+            return Err(TymeError::IllegalLeapDay {
+                calendar: "rab-byung",
+                year,
+                month,
+                day: d,
+            });
         }
 
-        if !leap && m.get_miss_days().contains(&d) {
-            return Err(format!("illegal day {} in {}", d, month));
+        if (!leap && m.get_miss_days().contains(&d)) || m.get_day_index(day) > MAX_DAY {
+            return Err(TymeError::IllegalDay {
+                calendar: "rab-byung",
+                year,
+                month,
+                day: d,
+            });
         }
 
         Ok(())
     }
 
-    pub fn from_ymd(year: isize, month: isize, day: isize) -> Result<Self, String> {
+    pub fn from_ymd(year: isize, month: isize, day: isize) -> Result<Self, TymeError> {
         Self::new(year, month, day)
     }
 
-    pub fn from_solar_day(solar_day: SolarDay) -> Result<Self, String> {
-        let mut days: isize = solar_day.subtract(SolarDay::from_ymd(1951, 1, 8));
-        let mut m: RabByungMonth = RabByungMonth::from_ym(1950, 12)?;
-        let mut count: isize = m.get_day_count() as isize;
-        while days >= count {
-            days -= count;
-            m = m.next(1)?;
-            count = m.get_day_count() as isize;
-        }
-        let mut day: isize = days + 1;
-        for &d in m.get_special_days().iter() {
-            if d < 0 {
-                if day >= -d {
-                    day += 1;
-                }
-            } else if d > 0 {
-                if day == d + 1 {
-                    day = -d;
-                    break;
-                } else if day > d + 1 {
-                    day -= 1;
-                }
+    pub fn from_ymd_with(
+        tradition: Tradition,
+        year: isize,
+        month: isize,
+        day: isize,
+    ) -> Result<Self, TymeError> {
+        Self::new_with(tradition, year, month, day)
+    }
+
+    pub fn from_solar_day(solar_day: SolarDay) -> Result<Self, TymeError> {
+        Self::from_solar_day_with(Tradition::Phugpa, solar_day)
+    }
+
+    /// 从公历日创建指定流派的藏历日
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::enums::Tradition;
+    /// use tyme4rs::tyme::rabbyung::RabByungDay;
+    /// use tyme4rs::tyme::solar::SolarDay;
+    ///
+    /// let d: SolarDay = SolarDay::from_ymd(2022, 2, 2);
+    /// assert_eq!("第十七饶迥水虎年正月初一", RabByungDay::from_solar_day_with(Tradition::Mongolian, d).unwrap().to_string());
+    /// assert_eq!("第十七饶迥铁牛年十二月初一", d.get_rab_byung_day().unwrap().to_string());
+    /// ```
+    pub fn from_solar_day_with(
+        tradition: Tradition,
+        solar_day: SolarDay,
+    ) -> Result<Self, TymeError> {
+        let d: isize = (solar_day.get_julian_day().get_day() - J2000 + 0.5).floor() as isize;
+        let mut n: isize = (d as i64 * SCALE - get_epoch(tradition).get_mean_date())
+            .div_euclid(MEAN_DATE_MONTH) as isize;
+        while get_true_date(30, n - 1, tradition) >= d {
+            n -= 1;
+        }
+        while get_true_date(30, n, tradition) < d {
+            n += 1;
+        }
+        let (year, month): (isize, isize) = get_year_month(n, tradition);
+        RabByungMonth::validate_with(tradition, year, month)?;
+        let mut prev: isize = get_true_date(30, n - 1, tradition);
+        let mut day: isize = 30;
+        for i in 1..31 {
+            let t: isize = get_true_date(i, n, tradition);
+            if t >= d {
+                day = if t - prev == 2 && t == d { -i } else { i };
+                break;
             }
+            prev = t;
         }
-        Self::new(m.get_year(), m.get_month_with_leap(), day)
+        Self::new_with(tradition, year, month, day)
     }
 
     pub fn get_rab_byung_month(&self) -> RabByungMonth {
-        RabByungMonth::from_ym(self.get_year(), self.get_month()).unwrap()
+        RabByungMonth::from_ym_with(self.tradition, self.get_year(), self.get_month()).unwrap()
+    }
+
+    /// 流派
+    pub fn get_tradition(&self) -> Tradition {
+        self.tradition
+    }
+
+    /// 当日黎明时的真太阳、月亮黄经（以1/651240周计）及当日所在的半日序号
+    fn get_daybreak(&self) -> (i64, i64, usize) {
+        let m: RabByungMonth = self.get_rab_byung_month();
+        let n: isize = m.get_true_month();
+        let d: isize = self.get_day() as isize;
+        let day: isize = m.get_day_index(self.get_day_with_leap());
+        // 自黎明至该日结束经过的时长，月亮每日约行一宿
+        let elapsed: i64 = get_true_time(d, n, self.tradition) - day as i64 * SCALE;
+        let sun: i64 = get_true_sun(d, n, self.tradition);
+        let moon: i64 = sun + d as i64 * (CIRCLE / 30) - elapsed * MANSION / SCALE;
+        let half: usize = 2 * (d as usize - 1) + usize::from(elapsed < SCALE / 2);
+        (sun, moon.rem_euclid(CIRCLE), half)
+    }
+
+    /// 星曜（七曜）
+    pub fn get_seven_star(&self) -> SevenStar {
+        SevenStar::from_index(self.get_solar_day().get_week().get_index() as isize)
+    }
+
+    /// 九宫（sme ba），上元之前冬至附近的甲子日（1863年12月31日）起一白，逐日顺行
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::rabbyung::RabByungDay;
+    ///
+    /// assert_eq!("一白水", RabByungDay::from_ymd(1863, 11, 21).unwrap().get_nine_star().to_string());
+    /// ```
+    pub fn get_nine_star(&self) -> NineStar {
+        NineStar::from_index(
+            self.get_solar_day()
+                .subtract(SolarDay::from_ymd(1863, 12, 31)),
+        )
+    }
+
+    /// 巴卡（spar kha），与九宫同日起离，逐日顺行
+    pub fn get_spar_kha(&self) -> SparKha {
+        SparKha::from_index(
+            self.get_solar_day()
+                .subtract(SolarDay::from_ymd(1863, 12, 31)),
+        )
+    }
+
+    /// 星曜五行（日火、月水、火火、水水、木木、金铁、土土）
+    pub fn get_element(&self) -> RabByungElement {
+        RabByungElement::from_index(
+            [1, 4, 1, 4, 0, 3, 2][self.get_solar_day().get_week().get_index()],
+        )
+    }
+
+    /// 月宿（rgyu skar），黎明时月亮所在的宿
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::rabbyung::RabByungDay;
+    ///
+    /// let d: RabByungDay = RabByungDay::from_ymd(2024, 1, 15).unwrap();
+    /// assert_eq!("柳", d.get_nakshatra().to_string());
+    /// ```
+    pub fn get_nakshatra(&self) -> Nakshatra {
+        let (_, moon, _): (i64, i64, usize) = self.get_daybreak();
+        Nakshatra::from_index((moon / MANSION) as isize)
+    }
+
+    /// 日月合宿（sbyor ba），黎明时日月黄经之和所在的宿
+    pub fn get_yoga(&self) -> Yoga {
+        let (sun, moon, _): (i64, i64, usize) = self.get_daybreak();
+        Yoga::from_index(((sun + moon).rem_euclid(CIRCLE) / MANSION) as isize)
+    }
+
+    /// 半日（byed pa），黎明时所在的半个太阴日
+    pub fn get_karana(&self) -> Karana {
+        let (_, _, half): (i64, i64, usize) = self.get_daybreak();
+        Karana::from_half_day(half)
+    }
+
+    /// 藏历节日，缺日的节日在前一日过，重日的节日在平日过
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::festival::RabByungFestival;
+    /// use tyme4rs::tyme::rabbyung::RabByungDay;
+    /// use tyme4rs::tyme::Culture;
+    ///
+    /// let festival: Option<RabByungFestival> = RabByungDay::from_ymd(2024, 4, 15).unwrap().get_festival();
+    /// assert_eq!("萨嘎达瓦节", festival.unwrap().get_name());
+    /// ```
+    pub fn get_festival(&self) -> Option<RabByungFestival> {
+        if self.leap {
+            return None;
+        }
+        let m: RabByungMonth = self.get_rab_byung_month();
+        let d: usize = self.get_day();
+        let miss_days: Vec<isize> = m.get_miss_days();
+        let mut days: Vec<usize> = vec![d];
+        if miss_days.contains(&(d as isize + 1)) {
+            days.push(d + 1);
+        }
+        if miss_days.contains(&1) && m.get_first_day() == *self {
+            days.push(1);
+        }
+        // 同一日有多个节日时，年节优先
+        days.iter()
+            .filter_map(|&i| RabByungFestival::from_month_day(m.clone(), i))
+            .filter(|f| f.get_day() == *self)
+            .min_by_key(|f| f.get_index())
     }
 
     pub fn get_day(&self) -> usize {
@@ -704,37 +1249,20 @@ impl RabByungDay {
         }
     }
 
-    pub fn next(&self, n: isize) -> Result<Self, String> {
+    pub fn next(&self, n: isize) -> Result<Self, TymeError> {
         if n == 0 {
             Ok(self.clone())
         } else {
-            self.get_solar_day().next(n).get_rab_byung_day()
+            Self::from_solar_day_with(self.tradition, self.get_solar_day().next(n))
         }
     }
 
     /// 转换为公历日
     pub fn get_solar_day(&self) -> SolarDay {
-        let mut m: RabByungMonth = RabByungMonth::from_ym(1950, 12).unwrap();
-        let cm: RabByungMonth = self.get_rab_byung_month();
-        let mut n: isize = 0;
-        while m != cm {
-            n += m.get_day_count() as isize;
-            m = m.next(1).unwrap();
-        }
-        let mut t: isize = self.get_day() as isize;
-        for &d in m.get_special_days().iter() {
-            if d < 0 {
-                if t > -d {
-                    t -= 1;
-                }
-            } else if d > 0 && t > d {
-                t += 1;
-            }
-        }
-        if self.leap {
-            t += 1;
-        }
-        SolarDay::from_ymd(1951, 1, 7).next(n + t)
+        let d: isize = self
+            .get_rab_byung_month()
+            .get_day_index(self.get_day_with_leap());
+        JulianDay::from_julian_day(J2000 + d as f64).get_solar_day()
     }
 
     pub fn subtract(&self, other: Self) -> isize {
@@ -752,6 +1280,7 @@ impl PartialEq for RabByungDay {
     fn eq(&self, other: &Self) -> bool {
         self.get_month() == other.get_month()
             && self.get_day_with_leap() == other.get_day_with_leap()
+            && self.tradition == other.tradition
     }
 }
 
@@ -760,9 +1289,14 @@ impl Eq for RabByungDay {}
 #[cfg(test)]
 mod tests {
     use crate::tyme::culture::Zodiac;
+    use crate::tyme::enums::Tradition;
+    use crate::tyme::error::TymeError;
     use crate::tyme::rabbyung::{RabByungDay, RabByungElement, RabByungMonth, RabByungYear};
     use crate::tyme::solar::SolarDay;
-    use crate::tyme::Culture;
+    use crate::tyme::{Culture, Tyme};
+    use std::str::Chars;
+
+    const DATA: &str = "2c>,182[>1:2TA4ZI=n1E2Bk1J2Ff3Mk503Oc62g=,172^>1:2XA1>2UE2Bo1I2Fj3Lo62Fb3Mf5,03N^72b=1:2]A1>2ZF1B2VI2Em1K2Fe,2Lh1R3Na603P\\:172Y>1;2UB2=m2Dq1J2Eh,2Kl1Q3Me603Pa:172^>1;2YA2=p1C2UI,2Dk2Jp3QEc3Mi603Pf:3L[72b?1:2]A1<2UB2XH,2Cn1I2Ei1L2Ie1Q3Na703Q\\:2`@1;2XA,4\\H;m1B2TI2Em1L2Ij1Q3Nf603Q`903QW:,2[@1;2TB2XI1E4TMAh2Io3RFe3Mj603Pc803Q[;,2^?1;2WA2>q1E2Bm1I2Fi1M2Hc3Of70,3P^82a>1:2[A1>2WE1B2TI2Fm1L2Hf3Ni6,03Oa703PZ:3`A62V>4]F;q1B4YJ>l2Eq1L2Gi3Ml5,03Nd603Q_9172[>1;2XB2>p1E2VK2Fl,1K2Fc3Mh603Pc9172`>1;2\\B1>2UD2=j2En,1J2Fg3Mm62Ib3Pj;3M_703R[:2`B1=2YB2=n,1C2TI2Fk1L2Ig1P3Nd703Q_:152X<2[A,2<q1B2WI2Ep1L2Il1Q3Ni703Qc9152[:2^@,1;2WB2>o1E2Bk1I2Fh1M2Ib3Pf803R^9,2a?1;2ZA1>2UE2Bp1I2Fl1M2If3Oi80,3Pa803QY:2^A1>2ZE1B4WJ>j2Fp1M2Hi1N2H`,3Od703Q]:162Y>1;2VB2?o1E4VM@h2Gl1M,2Hd3Ng603Qa9172^>1;2ZB1?2UE2@l2Fo1L,2Gg3Mk62H`3Pf:172c?3QY;2_B1>2YD2?o1E,2TK2Fj1M2Ie1P3Mb703R^;172X=2\\C1>,2TD2WJ2Fn1L2Ij1P3Ng703Rb:162[<2_B1=,2VC2>m1E4TMAh2Io3QFe3Nl82Ja3Qf:152_;0,3RU<2ZB1>2TE2Bn1I2Fj1M2Je3Pk:2K^3Ra:,03RY;2]A1>2XE1B2TI2Fo1M2Ii1P2Ka3Qd8,03R]:3bB62W>4]F:q1B2?n1F4VNAh2Il1O2Jd,3Pg803Q`:162\\=1;2XB1?2TF2Bl2Ho1N,2Ig3Nk703Qd9162`>1;2]B1?2XE2Ao1G2TM,2Hj1M2Id1P3M_603R\\;172W>2\\E1@2TE,2?i2Gm1M2Ih1P3Md603Ra;172[=28q1?2WD,2?m2Fq1M2Il1P3Mi72I^3Re:162_<172W=,2ZC2?q1E2Bk1I2Fh1M2Jd1Q3M^52b;16,2Y<2]B1>2VE2Bp1I2Fm1M2Jh1Q2Lb3Re:15,2\\;3aC62U>2[E1B4WJ>k1F4TNBg2Jl1P2Le3Qh9,03R`:172Z=1:2VB2?q1F2Bk2Ip1P2Jg,1P2J_3Qc:162^=1;2[B1?2WF2Bo1H2Bg2Ij,1O2Jc3Qg:3L\\62c>3QY;3aC72V?2[F1A2TG2Bj,2Hm1N2Jg1P3Mb603R_;182Z>1:2T@2WF2Am,2Gp1M2Ik1P3Mg603Rc;172^>192W?2ZE,2@p1F2Bj2Io3QEe1M2Jb1Q3M]72b=182Z>,2]D1?2VE2Bn1I2Fk1M2Jg1Q3Ma62e<172]=,172U>2YE1B2UI2Fp1N2Jk1Q3Me503M\\6,2`<172Y>3_F:2TB2?n1F2Cj2Jo3QDc2Lh1R,3L_52c;172]=1:2XB1?2UF2Cn1I2Eg2Kk1P,2Lb3Rf;162a=1:2]B1?2ZF1B2TH2Dj2Jm,1O2Kf1Q3M`603Q\\;182Y?2;q1A2WH2Cm,2Hq1O2Ji1P3Me603Qa;182]>1:2WA2[G2Ap,1G2Bi2Im1P3Mi72I_3Qf;3N\\72Eh1:2Z?29o,1@2UF2Bm1I2Fh1M2Je1Q3N`72f?3PY92]>19,2U?2YF2Bq1I2Fm1M2Jj1Q3Nd603O]72`=,182X?4]F:o1B4WI=k1F4UNCi2Jn3REc3Mh503N`6,2c<182\\>1:2VA2?q1F2Cm1J2Fg2Lk1R3Mc5,2f<172`=1:2[A1?2XF2Cq1I2Ek2Kn1R,2Lf1R3N_62d>3PZ:3aC72W?2;p1B2WI2Dn1J,2De2Ki1Q3Mc603Q_:182\\?1;2VB2<m2Cq1I,2Dh2Jl1P3Mg603Qd;182`?1;2ZA2<p1B,2UH2Cl1I2Ef3Mm82Jc1Q3N_703QY:2]@1;2UA,2XG2Bp1I2Fk1M2Jh1Q3Nc703Q]92`?1:,2X@4\\G:n1B2VI2Fp1M2Jl1R3Ng603P`82d>,192[?1;2UA2>o1F2Ck1J2Gg3Mk603Oc70,3OZ82_>1:2YA1?2VF2Cp1J2Fj1M2Gc3Nf5,03O^72b>1:2^B1?4[G;n1C2VJ2Fn1L2Gf,3Mi503Nb603Q]:172Y?1<2UB2>m2Eq1K2Fi,2Kl1R3Mf603Qa:182^?1;2YB2>q1D2VJ,2Dl1J2Fe3Mj603Qg;3N]72c@3QX;2]A1=2VB,2YI2Co1J2Fi1M2Je1Q3Nb703R]:2aA1<2XA,2<n1C2UI2Fn1M2Jj1Q3Nf703Q`903RX:,2[@1<2TB4YJ>l1E4UNBi1J2Ge3Mk703Pc803Q[9,2^?1;2XB2>q1E2Cn1J2Gj1M2Ic3Of70,3P^82b?1;2\\A1>2XF1C2UJ2Fm1M2Hf3Ni6,03Oa703Q[:3aB72W>1<2TC2?m2Fq1L2Gi3Ml5,03Ne703Q_:172\\>1<2XB2?q1E2WL2Fl,1L2Gd3Ni603Qd:172a?1;2\\B1>2VD2>k,2Eo1K2Gh1M2Ic1Q3N`703R\\;3aC62U=2YC2>o,1D2TJ2Fl1M2Jh1Q3Ne703R`:162Y<2\\B,1=2TC4XJ=j2Fp1M2Jm3QFc3Ni803Qc:152\\;2_A,1<2WB2>o1E2Bl1J2Gh1N2Jc3Qg903R^:,2b@1;2[B1>2VE2Cq1J2Gl1N2Jf3Pj80,3Qa803RZ;2_B1>4[F:o1C4XK?k2Fp1M2Ii1O2Ia,3Pd703R^:172Y>1<2VC2?p1F2Ai2Hl1M,2Hd3Oh703Qb:172^>1<2[C1?2UE2Al2Go,1L2Hg3Nl82Ia3Qg;3M]72e@3RZ;3`C72T>2YD2@o1E,2TK2Gk1M2Jf1Q3Nb703R^;172Y=2\\D1>,2TD4XK>i2Fo1M2Jj1Q3Ng703Rb;172\\<2`C1=,2WC2?n1F4VNBi1J2Gf1N2Kb3Rf:162_;15,2V<2ZB1?2TE2Bn1J2Gk1N2Kf1Q2L^3Rb:,152Z;2^B1>2YE1B2UJ2Go1N2Ji1P2Kb3Qd9,03R];172X>1;2TC2@n1G2Bi2Im1O2Jd,3Ph803Ra:172\\>1;2YC1@2UF2Bl2Hp1N,2Ig3Ol82J`3Qe:172a>1;4^C7q1?2XF2Ao1G2UN,2Hj1N2Jd1Q3N`703R];182X>2]F1@2TF,2@j2Gn1M2Jq1Q3Ne703Ra;172\\>192T?,2WE2@m1F4TMAf2Im3QEc3Nj82J`3Rf;172_=182W>,2ZD2?q1F2Bl1I2Gj1N2Ke1R3M_62b<17,2Z=2]C1?2WE2Bq1I2Gn1N2Ki1Q3Mb52e;16,2]<172V>4[F:o1B4XK?l1G4UOCh2Jl1Q2Le3Rh:,152`;172Z>1;2WB2@q1G2Cl2Ip1P2K_";
 
     #[test]
     fn test0() {
@@ -1005,4 +1539,312 @@ mod tests {
                 .to_string()
         );
     }
+
+    #[test]
+    fn test15() {
+        assert_eq!(
+            Err(TymeError::YearOutOfRange {
+                calendar: "rab-byung",
+                year: 10000,
+                min: 1027,
+                max: 9999
+            }),
+            RabByungMonth::new(10000, 1)
+        );
+        assert!(RabByungMonth::new(1026, 12).is_err());
+        assert!(RabByungMonth::new(1027, 1).is_ok());
+    }
+
+    /// 1950年十二月至2050年的藏历历书数据，与浦派算法逐月比对
+    #[test]
+    fn test16() {
+        let years: Vec<&str> = DATA.split(",").collect();
+        let mut m: RabByungMonth = RabByungMonth::from_ym(1950, 12).unwrap();
+        let mut diff: Vec<String> = Vec::new();
+        for s in years {
+            let mut ys: &str = s;
+            while !ys.is_empty() {
+                let mut chars: Chars = ys.chars();
+                let len: usize = (chars.next().unwrap() as isize - b'0' as isize) as usize;
+                let mut data: Vec<isize> = Vec::new();
+                for _i in 0..len {
+                    data.push(chars.next().unwrap() as isize - b'5' as isize - 30);
+                }
+                if data != m.get_special_days() {
+                    diff.push(format!("{}/{}", m.get_year(), m.get_month_with_leap()));
+                }
+                m = m.next(1).unwrap();
+                ys = &ys[1 + len..];
+            }
+        }
+        // 历书在少数临界日与算法不一致
+        assert_eq!(
+            vec![
+                "1959/12", "2001/9", "2009/3", "2016/1", "2016/2", "2021/9", "2045/4", "2047/6",
+                "2050/12"
+            ],
+            diff
+        );
+        assert_eq!(2051, m.get_year());
+    }
+
+    #[test]
+    fn test17() {
+        let d: RabByungDay = RabByungDay::from_ymd(1027, 1, 1).unwrap();
+        assert_eq!("1027年1月11日", d.get_solar_day().to_string());
+        assert_eq!(d, d.get_solar_day().get_rab_byung_day().unwrap());
+        assert!(d.get_solar_day().next(-1).get_rab_byung_day().is_err());
+    }
+
+    #[test]
+    fn test18() {
+        let mut solar: SolarDay = SolarDay::from_ymd(1800, 1, 1);
+        let mut d: RabByungDay = solar.get_rab_byung_day().unwrap();
+        for _ in 0..800 {
+            assert_eq!(solar, d.get_solar_day());
+            let next: RabByungDay = solar.next(1).get_rab_byung_day().unwrap();
+            if next.get_rab_byung_month() == d.get_rab_byung_month() {
+                let days: Vec<RabByungDay> = d.get_rab_byung_month().get_days();
+                let i: usize = days.iter().position(|x| *x == d).unwrap();
+                assert_eq!(next, days[i + 1]);
+            } else {
+                assert_eq!(next, next.get_rab_byung_month().get_first_day());
+            }
+            solar = solar.next(1);
+            d = next;
+        }
+    }
+
+    #[test]
+    fn test19() {
+        let d: RabByungDay = SolarDay::from_ymd(9999, 12, 31)
+            .get_rab_byung_day()
+            .unwrap();
+        assert_eq!("第一百五十饶迥土猪年四月初一", d.to_string());
+        assert!(RabByungDay::from_ymd(9999, 4, 2).is_err());
+        assert!(RabByungDay::from_ymd(9999, 12, 30).is_err());
+    }
+
+    #[test]
+    fn test20() {
+        let mut y: isize = 1;
+        let mut m: isize = 4;
+        let mut t: isize = 1;
+        let mut leap_months: Vec<(isize, usize)> = Vec::new();
+        while y <= 9999 {
+            if y >= 1027 {
+                leap_months.push((y, m as usize));
+            }
+            let i: isize = m + 31 + t;
+            y += 2;
+            m = i - 23;
+            if i > 35 {
+                y += 1;
+                m -= 12;
+            }
+            t = 1 - t;
+        }
+        for year in 1027..10000 {
+            let leap_month: usize = leap_months
+                .iter()
+                .find(|(y, _)| *y == year)
+                .map_or(0, |(_, m)| *m);
+            assert_eq!(
+                leap_month,
+                RabByungYear::from_year(year).unwrap().get_leap_month()
+            );
+        }
+    }
+
+    /// 洛萨（藏历新年）
+    #[test]
+    fn test21() {
+        for (y, s) in [
+            (2019, "2019年2月5日"),
+            (2020, "2020年2月24日"),
+            (2021, "2021年2月12日"),
+            (2022, "2022年3月3日"),
+            (2023, "2023年2月21日"),
+            (2024, "2024年2月10日"),
+            (2025, "2025年2月28日"),
+        ] {
+            let m: RabByungMonth = RabByungMonth::from_ym_with(Tradition::Phugpa, y, 1).unwrap();
+            assert_eq!(s, m.get_first_day().get_solar_day().to_string());
+        }
+    }
+
+    /// 查干萨日（蒙古新年）
+    #[test]
+    fn test22() {
+        for (y, s) in [
+            (1995, "1995年1月31日"),
+            (2006, "2006年1月30日"),
+            (2008, "2008年2月8日"),
+            (2011, "2011年2月3日"),
+            (2014, "2014年1月31日"),
+            (2015, "2015年2月19日"),
+            (2019, "2019年2月5日"),
+            (2020, "2020年2月24日"),
+            (2021, "2021年2月12日"),
+            (2022, "2022年2月2日"),
+            (2023, "2023年2月21日"),
+            (2024, "2024年2月10日"),
+            (2025, "2025年3月1日"),
+        ] {
+            let m: RabByungMonth = RabByungMonth::from_ym_with(Tradition::Mongolian, y, 1).unwrap();
+            assert_eq!(s, m.get_first_day().get_solar_day().to_string());
+        }
+    }
+
+    #[test]
+    fn test23() {
+        let solar: SolarDay = SolarDay::from_ymd(2022, 2, 2);
+        let d: RabByungDay = RabByungDay::from_solar_day_with(Tradition::Mongolian, solar).unwrap();
+        assert_eq!(Tradition::Mongolian, d.get_tradition());
+        assert_eq!(solar, d.get_solar_day());
+        assert_eq!(
+            Tradition::Mongolian,
+            d.get_rab_byung_month().get_tradition()
+        );
+        assert_eq!(
+            RabByungDay::from_solar_day_with(Tradition::Mongolian, solar.next(45)).unwrap(),
+            d.next(45).unwrap()
+        );
+        assert_ne!(
+            RabByungDay::from_ymd(2022, 1, 1).unwrap(),
+            RabByungDay::from_ymd_with(Tradition::Mongolian, 2022, 1, 1).unwrap()
+        );
+        assert_eq!(
+            Err(TymeError::IllegalLeapMonth {
+                calendar: "rab-byung",
+                year: 2021,
+                month: 9
+            }),
+            RabByungMonth::from_ym_with(Tradition::Mongolian, 2021, -9)
+        );
+        let m: RabByungMonth = RabByungMonth::from_ym_with(Tradition::Mongolian, 2022, -4).unwrap();
+        assert_eq!(m, m.next(-13).unwrap().next(13).unwrap());
+        assert_eq!(
+            13,
+            RabByungYear::from_year(2022)
+                .unwrap()
+                .get_months_with(Tradition::Mongolian)
+                .len()
+        );
+    }
+
+    #[test]
+    fn test24() {
+        let d: RabByungDay = RabByungDay::from_ymd(2024, 1, 1).unwrap();
+        assert_eq!("2024年2月10日", d.get_solar_day().to_string());
+        assert_eq!("土", d.get_seven_star().get_name());
+        assert_eq!("土", d.get_element().get_name());
+        assert_eq!("女", d.get_nakshatra().get_name());
+        assert_eq!("成就", d.get_yoga().get_name());
+        assert_eq!("紧斯图那", d.get_karana().get_name());
+        assert!(d.get_karana().is_fixed());
+
+        let d: RabByungDay = RabByungDay::from_ymd(2024, 1, 30).unwrap();
+        assert_eq!("日", d.get_seven_star().get_name());
+        assert_eq!("火", d.get_element().get_name());
+        assert_eq!("危", d.get_nakshatra().get_name());
+        assert_eq!("吉祥", d.get_yoga().get_name());
+        assert_eq!("那伽", d.get_karana().get_name());
+
+        let d: RabByungDay = RabByungDay::from_ymd(2025, 3, 10).unwrap();
+        assert_eq!("水", d.get_seven_star().get_name());
+        assert_eq!("水", d.get_element().get_name());
+        assert_eq!("星", d.get_nakshatra().get_name());
+        assert_eq!("增长", d.get_yoga().get_name());
+        assert_eq!("伽罗", d.get_karana().get_name());
+        assert!(!d.get_karana().is_fixed());
+
+        assert_eq!(
+            "三碧木",
+            RabByungYear::from_year(2024)
+                .unwrap()
+                .get_nine_star()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn test25() {
+        // 月亮每日行约0.9至1.1宿，月宿不会倒退
+        let mut d: RabByungDay = RabByungDay::from_ymd(2024, 1, 1).unwrap();
+        let mut prev: usize = d.get_nakshatra().get_index();
+        for _ in 0..400 {
+            d = d.next(1).unwrap();
+            let i: usize = d.get_nakshatra().get_index();
+            let step: usize = (i + 27 - prev) % 27;
+            assert!(step <= 2, "{}", d);
+            prev = i;
+        }
+    }
+    /// 粗卜派、不丹的洛萨
+    #[test]
+    fn test26() {
+        // 以下为现行暂用常数的回归值，尚无已核对的粗卜派、不丹历书可供比对
+        for tradition in [Tradition::Tsurphu, Tradition::Bhutanese] {
+            for (y, s) in [
+                (2019, "2019年2月5日"),
+                (2020, "2020年2月24日"),
+                (2021, "2021年2月12日"),
+                (2022, "2022年3月3日"),
+                (2023, "2023年2月21日"),
+            ] {
+                let m: RabByungMonth = RabByungMonth::from_ym_with(tradition, y, 1).unwrap();
+                assert_eq!(s, m.get_first_day().get_solar_day().to_string());
+            }
+        }
+        let y: RabByungYear = RabByungYear::from_year(2021).unwrap();
+        assert_eq!(10, y.get_leap_month_with(Tradition::Tsurphu));
+        assert_eq!(10, y.get_leap_month_with(Tradition::Bhutanese));
+        let y: RabByungYear = RabByungYear::from_year(2019).unwrap();
+        assert_eq!(1, y.get_leap_month_with(Tradition::Tsurphu));
+        assert_eq!(2, y.get_leap_month_with(Tradition::Bhutanese));
+        // 闰月与浦派不同的年份
+        let y: RabByungYear = RabByungYear::from_year(2016).unwrap();
+        assert_eq!(4, y.get_leap_month_with(Tradition::Phugpa));
+        assert_eq!(5, y.get_leap_month_with(Tradition::Tsurphu));
+        assert_eq!(5, y.get_leap_month_with(Tradition::Bhutanese));
+        let y: RabByungYear = RabByungYear::from_year(2024).unwrap();
+        assert_eq!(6, y.get_leap_month_with(Tradition::Phugpa));
+        assert_eq!(6, y.get_leap_month_with(Tradition::Tsurphu));
+        assert_eq!(7, y.get_leap_month_with(Tradition::Bhutanese));
+        let solar: SolarDay = SolarDay::from_ymd(2022, 3, 3);
+        for tradition in [Tradition::Tsurphu, Tradition::Bhutanese] {
+            let d: RabByungDay = RabByungDay::from_solar_day_with(tradition, solar).unwrap();
+            assert_eq!(tradition, d.get_tradition());
+            assert_eq!(solar, d.get_solar_day());
+        }
+    }
+    /// 九宫、巴卡
+    #[test]
+    fn test27() {
+        let solar: SolarDay = SolarDay::from_ymd(1863, 12, 31);
+        let d: RabByungDay = solar.get_rab_byung_day().unwrap();
+        assert_eq!("甲子", solar.get_lunar_day().get_sixty_cycle().get_name());
+        assert_eq!(solar.get_lunar_day().get_nine_star(), d.get_nine_star());
+        assert_eq!("离", d.get_spar_kha().get_name());
+        for i in 1..100 {
+            let n: RabByungDay = d.next(i).unwrap();
+            assert_eq!(d.get_nine_star().next(i), n.get_nine_star());
+            assert_eq!(d.get_spar_kha().next(i), n.get_spar_kha());
+        }
+        let d: RabByungDay = RabByungDay::from_ymd(2024, 1, 1).unwrap();
+        assert_eq!("八白土", d.get_nine_star().to_string());
+        assert_eq!("离", d.get_spar_kha().get_name());
+        let d: RabByungDay = RabByungDay::from_ymd(2025, 1, 1).unwrap();
+        assert_eq!("五黄土", d.get_nine_star().to_string());
+        assert_eq!("离", d.get_spar_kha().get_name());
+
+        let y: RabByungYear = RabByungYear::from_year(1864).unwrap();
+        assert_eq!("一白水", y.get_nine_star().to_string());
+        assert_eq!("离", y.get_spar_kha().get_name());
+        assert_eq!("坤", y.next(1).unwrap().get_spar_kha().get_name());
+        assert_eq!("巽", y.next(-1).unwrap().get_spar_kha().get_name());
+        assert_eq!("艮", y.next(101).unwrap().get_spar_kha().get_name());
+        assert_eq!("坤", y.next(161).unwrap().get_spar_kha().get_name());
+    }
 }
@@ -0,0 +1,706 @@
+use std::fmt::{Display, Formatter};
+
+use crate::tyme::enums::CalendarReform;
+use crate::tyme::error::TymeError;
+use crate::tyme::jd::JulianDay;
+use crate::tyme::solar::SolarDay;
+use crate::tyme::{Culture, Tyme};
+
+/// 月天数
+fn get_month_day_count(year: isize, month: usize, gregorian: bool) -> usize {
+    match month {
+        2 => {
+            let leap: bool = if gregorian {
+                (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+            } else {
+                year % 4 == 0
+            };
+            if leap {
+                29
+            } else {
+                28
+            }
+        }
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// 校验年月日
+fn validate(
+    calendar: &'static str,
+    year: isize,
+    month: usize,
+    day: usize,
+    gregorian: bool,
+) -> Result<(), TymeError> {
+    if !(1..=9999).contains(&year) {
+        return Err(TymeError::YearOutOfRange {
+            calendar,
+            year,
+            min: 1,
+            max: 9999,
+        });
+    }
+    if !(1..=12).contains(&month) {
+        return Err(TymeError::IllegalMonth {
+            calendar,
+            year,
+            month: month as isize,
+        });
+    }
+    if day < 1 || day > get_month_day_count(year, month, gregorian) {
+        return Err(TymeError::IllegalDay {
+            calendar,
+            year,
+            month: month as isize,
+            day: day as isize,
+        });
+    }
+    Ok(())
+}
+
+/// 年月日转儒略日数（正午起算的整数日）
+fn to_day_number(year: isize, month: usize, day: usize, gregorian: bool) -> isize {
+    let a: isize = (14 - month as isize) / 12;
+    let y: isize = year + 4800 - a;
+    let m: isize = month as isize + 12 * a - 3;
+    let n: isize = day as isize + (153 * m + 2) / 5 + 365 * y + y / 4;
+    if gregorian {
+        n - y / 100 + y / 400 - 32045
+    } else {
+        n - 32083
+    }
+}
+
+/// 儒略日数转年月日
+fn from_day_number(n: isize, gregorian: bool) -> (isize, usize, usize) {
+    let (b, c): (isize, isize) = if gregorian {
+        let a: isize = n + 32044;
+        let b: isize = (4 * a + 3) / 146097;
+        (b, a - 146097 * b / 4)
+    } else {
+        (0, n + 32082)
+    };
+    let d: isize = (4 * c + 3) / 1461;
+    let e: isize = c - 1461 * d / 4;
+    let m: isize = (5 * e + 2) / 153;
+    (
+        100 * b + d - 4800 + m / 10,
+        (m + 3 - 12 * (m / 10)) as usize,
+        (e - (153 * m + 2) / 5 + 1) as usize,
+    )
+}
+
+/// 儒略日转儒略日数
+fn get_day_number(julian_day: JulianDay) -> isize {
+    (julian_day.get_day() + 0.5).floor() as isize
+}
+
+/// 儒略日数转儒略日（当日0时）
+fn get_julian_day(n: isize) -> JulianDay {
+    JulianDay::from_julian_day(n as f64 - 0.5)
+}
+
+/// 改用格里高利历首日的儒略日数
+fn get_reform_day_number(reform: CalendarReform) -> isize {
+    match reform {
+        CalendarReform::Rome => to_day_number(1582, 10, 15, true),
+        CalendarReform::Britain => to_day_number(1752, 9, 14, true),
+        CalendarReform::Russia => to_day_number(1918, 2, 14, true),
+    }
+}
+
+/// 儒略历日（不论改历，始终按4年1闰推算）
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct JulianCalendarDay {
+    year: isize,
+    month: usize,
+    day: usize,
+}
+
+impl Tyme for JulianCalendarDay {
+    fn next(&self, n: isize) -> Self {
+        self.try_next(n).unwrap()
+    }
+}
+
+impl Culture for JulianCalendarDay {
+    fn get_name(&self) -> String {
+        format!("儒略历{}年{}月{}日", self.year, self.month, self.day)
+    }
+}
+
+impl JulianCalendarDay {
+    pub fn new(year: isize, month: usize, day: usize) -> Result<Self, TymeError> {
+        Self::validate(year, month, day)?;
+        Ok(Self { year, month, day })
+    }
+
+    pub fn validate(year: isize, month: usize, day: usize) -> Result<(), TymeError> {
+        validate("julian", year, month, day, false)
+    }
+
+    pub fn from_ymd(year: isize, month: usize, day: usize) -> Self {
+        Self::new(year, month, day).unwrap()
+    }
+
+    pub fn try_from_ymd(year: isize, month: usize, day: usize) -> Result<Self, TymeError> {
+        Self::new(year, month, day)
+    }
+
+    pub fn from_julian_day(julian_day: JulianDay) -> Self {
+        Self::try_from_julian_day(julian_day).unwrap()
+    }
+
+    /// 从儒略日转换，超出范围时返回错误
+    pub fn try_from_julian_day(julian_day: JulianDay) -> Result<Self, TymeError> {
+        let (year, month, day) = from_day_number(get_day_number(julian_day), false);
+        Self::new(year, month, day)
+    }
+
+    /// 从公历日转换
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::reform::JulianCalendarDay;
+    /// use tyme4rs::tyme::solar::SolarDay;
+    /// use tyme4rs::tyme::Culture;
+    ///
+    /// assert_eq!(
+    ///     "儒略历1999年12月19日",
+    ///     JulianCalendarDay::from_solar_day(SolarDay::from_ymd(2000, 1, 1)).get_name()
+    /// );
+    /// ```
+    pub fn from_solar_day(solar_day: SolarDay) -> Self {
+        Self::from_julian_day(solar_day.get_julian_day())
+    }
+
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        let (year, month, day) = from_day_number(self.get_day_number() + n, false);
+        Self::new(year, month, day)
+    }
+
+    pub fn get_year(&self) -> isize {
+        self.year
+    }
+
+    pub fn get_month(&self) -> usize {
+        self.month
+    }
+
+    pub fn get_day(&self) -> usize {
+        self.day
+    }
+
+    /// 是否闰年
+    pub fn is_leap(&self) -> bool {
+        29 == get_month_day_count(self.year, 2, false)
+    }
+
+    fn get_day_number(&self) -> isize {
+        to_day_number(self.year, self.month, self.day, false)
+    }
+
+    pub fn get_julian_day(&self) -> JulianDay {
+        get_julian_day(self.get_day_number())
+    }
+
+    pub fn get_solar_day(&self) -> SolarDay {
+        self.try_get_solar_day().unwrap()
+    }
+
+    /// 公历日，超出公历范围时返回错误
+    pub fn try_get_solar_day(&self) -> Result<SolarDay, TymeError> {
+        self.get_julian_day().try_get_solar_day()
+    }
+
+    pub fn get_proleptic_gregorian_day(&self) -> ProlepticGregorianDay {
+        self.try_get_proleptic_gregorian_day().unwrap()
+    }
+
+    /// 格里高利历日，超出范围时返回错误
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::reform::JulianCalendarDay;
+    /// use tyme4rs::tyme::Culture;
+    ///
+    /// // 十月革命
+    /// assert_eq!(
+    ///     "格里高利历1917年11月7日",
+    ///     JulianCalendarDay::from_ymd(1917, 10, 25)
+    ///         .try_get_proleptic_gregorian_day()
+    ///         .unwrap()
+    ///         .get_name()
+    /// );
+    /// ```
+    pub fn try_get_proleptic_gregorian_day(&self) -> Result<ProlepticGregorianDay, TymeError> {
+        ProlepticGregorianDay::try_from_julian_day(self.get_julian_day())
+    }
+
+    pub fn subtract(&self, other: Self) -> isize {
+        self.get_day_number() - other.get_day_number()
+    }
+}
+
+impl Display for JulianCalendarDay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.get_name())
+    }
+}
+
+/// 格里高利历日（不论改历，始终按400年97闰推算，与ISO 8601一致）
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ProlepticGregorianDay {
+    year: isize,
+    month: usize,
+    day: usize,
+}
+
+impl Tyme for ProlepticGregorianDay {
+    fn next(&self, n: isize) -> Self {
+        self.try_next(n).unwrap()
+    }
+}
+
+impl Culture for ProlepticGregorianDay {
+    fn get_name(&self) -> String {
+        format!("格里高利历{}年{}月{}日", self.year, self.month, self.day)
+    }
+}
+
+impl ProlepticGregorianDay {
+    pub fn new(year: isize, month: usize, day: usize) -> Result<Self, TymeError> {
+        Self::validate(year, month, day)?;
+        Ok(Self { year, month, day })
+    }
+
+    pub fn validate(year: isize, month: usize, day: usize) -> Result<(), TymeError> {
+        validate("gregorian", year, month, day, true)
+    }
+
+    pub fn from_ymd(year: isize, month: usize, day: usize) -> Self {
+        Self::new(year, month, day).unwrap()
+    }
+
+    pub fn try_from_ymd(year: isize, month: usize, day: usize) -> Result<Self, TymeError> {
+        Self::new(year, month, day)
+    }
+
+    pub fn from_julian_day(julian_day: JulianDay) -> Self {
+        Self::try_from_julian_day(julian_day).unwrap()
+    }
+
+    /// 从儒略日转换，超出范围时返回错误
+    pub fn try_from_julian_day(julian_day: JulianDay) -> Result<Self, TymeError> {
+        let (year, month, day) = from_day_number(get_day_number(julian_day), true);
+        Self::new(year, month, day)
+    }
+
+    pub fn from_solar_day(solar_day: SolarDay) -> Self {
+        Self::try_from_solar_day(solar_day).unwrap()
+    }
+
+    /// 从公历日转换，公元1年1月1日和2日在格里高利历中属于公元前，返回错误
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::reform::ProlepticGregorianDay;
+    /// use tyme4rs::tyme::solar::SolarDay;
+    /// use tyme4rs::tyme::Culture;
+    ///
+    /// assert_eq!(
+    ///     "格里高利历1582年10月14日",
+    ///     ProlepticGregorianDay::from_solar_day(SolarDay::from_ymd(1582, 10, 4)).get_name()
+    /// );
+    /// ```
+    pub fn try_from_solar_day(solar_day: SolarDay) -> Result<Self, TymeError> {
+        Self::try_from_julian_day(solar_day.get_julian_day())
+    }
+
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        let (year, month, day) = from_day_number(self.get_day_number() + n, true);
+        Self::new(year, month, day)
+    }
+
+    pub fn get_year(&self) -> isize {
+        self.year
+    }
+
+    pub fn get_month(&self) -> usize {
+        self.month
+    }
+
+    pub fn get_day(&self) -> usize {
+        self.day
+    }
+
+    /// 是否闰年
+    pub fn is_leap(&self) -> bool {
+        29 == get_month_day_count(self.year, 2, true)
+    }
+
+    fn get_day_number(&self) -> isize {
+        to_day_number(self.year, self.month, self.day, true)
+    }
+
+    pub fn get_julian_day(&self) -> JulianDay {
+        get_julian_day(self.get_day_number())
+    }
+
+    /// 公历日
+    pub fn get_solar_day(&self) -> SolarDay {
+        self.get_julian_day().get_solar_day()
+    }
+
+    /// 儒略历日
+    pub fn get_julian_calendar_day(&self) -> JulianCalendarDay {
+        JulianCalendarDay::from_julian_day(self.get_julian_day())
+    }
+
+    pub fn subtract(&self, other: Self) -> isize {
+        self.get_day_number() - other.get_day_number()
+    }
+}
+
+impl Display for ProlepticGregorianDay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.get_name())
+    }
+}
+
+/// 按指定改历推算的日，改历前为儒略历，改历后为格里高利历
+///
+/// 公历日（SolarDay）相当于按罗马改历推算。
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ReformedDay {
+    year: isize,
+    month: usize,
+    day: usize,
+    reform: CalendarReform,
+}
+
+impl Tyme for ReformedDay {
+    fn next(&self, n: isize) -> Self {
+        self.try_next(n).unwrap()
+    }
+}
+
+impl Culture for ReformedDay {
+    fn get_name(&self) -> String {
+        format!("{}年{}月{}日", self.year, self.month, self.day)
+    }
+}
+
+impl ReformedDay {
+    pub fn new(
+        year: isize,
+        month: usize,
+        day: usize,
+        reform: CalendarReform,
+    ) -> Result<Self, TymeError> {
+        Self::validate(year, month, day, reform)?;
+        Ok(Self {
+            year,
+            month,
+            day,
+            reform,
+        })
+    }
+
+    /// 校验，改历时删除的日期返回错误
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::enums::CalendarReform;
+    /// use tyme4rs::tyme::reform::ReformedDay;
+    ///
+    /// assert!(ReformedDay::validate(1752, 9, 10, CalendarReform::Britain).is_err());
+    /// assert!(ReformedDay::validate(1752, 9, 10, CalendarReform::Rome).is_ok());
+    /// ```
+    pub fn validate(
+        year: isize,
+        month: usize,
+        day: usize,
+        reform: CalendarReform,
+    ) -> Result<(), TymeError> {
+        let first: isize = get_reform_day_number(reform);
+        let (y, m, d) = from_day_number(first, true);
+        if (year, month, day) >= (y, m, d) {
+            return validate("solar", year, month, day, true);
+        }
+        validate("solar", year, month, day, false)?;
+        if to_day_number(year, month, day, false) >= first {
+            return Err(TymeError::GregorianGap {
+                year,
+                month: month as isize,
+                day: day as isize,
+            });
+        }
+        Ok(())
+    }
+
+    pub fn from_ymd(year: isize, month: usize, day: usize, reform: CalendarReform) -> Self {
+        Self::new(year, month, day, reform).unwrap()
+    }
+
+    pub fn try_from_ymd(
+        year: isize,
+        month: usize,
+        day: usize,
+        reform: CalendarReform,
+    ) -> Result<Self, TymeError> {
+        Self::new(year, month, day, reform)
+    }
+
+    pub fn from_julian_day(julian_day: JulianDay, reform: CalendarReform) -> Self {
+        Self::try_from_julian_day(julian_day, reform).unwrap()
+    }
+
+    /// 从儒略日转换，超出范围时返回错误
+    pub fn try_from_julian_day(
+        julian_day: JulianDay,
+        reform: CalendarReform,
+    ) -> Result<Self, TymeError> {
+        Self::from_day_number(get_day_number(julian_day), reform)
+    }
+
+    /// 从公历日转换
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::enums::CalendarReform;
+    /// use tyme4rs::tyme::reform::ReformedDay;
+    /// use tyme4rs::tyme::solar::SolarDay;
+    /// use tyme4rs::tyme::Culture;
+    ///
+    /// // 英国仍在使用儒略历
+    /// assert_eq!(
+    ///     "1700年2月19日",
+    ///     ReformedDay::from_solar_day(SolarDay::from_ymd(1700, 3, 1), CalendarReform::Britain)
+    ///         .get_name()
+    /// );
+    /// ```
+    pub fn from_solar_day(solar_day: SolarDay, reform: CalendarReform) -> Self {
+        Self::from_julian_day(solar_day.get_julian_day(), reform)
+    }
+
+    fn from_day_number(n: isize, reform: CalendarReform) -> Result<Self, TymeError> {
+        let (year, month, day) = from_day_number(n, n >= get_reform_day_number(reform));
+        Self::new(year, month, day, reform)
+    }
+
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        Self::from_day_number(self.get_day_number() + n, self.reform)
+    }
+
+    pub fn get_year(&self) -> isize {
+        self.year
+    }
+
+    pub fn get_month(&self) -> usize {
+        self.month
+    }
+
+    pub fn get_day(&self) -> usize {
+        self.day
+    }
+
+    /// 改历
+    pub fn get_reform(&self) -> CalendarReform {
+        self.reform
+    }
+
+    /// 是否已改用格里高利历
+    pub fn is_gregorian(&self) -> bool {
+        self.get_day_number() >= get_reform_day_number(self.reform)
+    }
+
+    fn get_day_number(&self) -> isize {
+        let first: isize = get_reform_day_number(self.reform);
+        let n: isize = to_day_number(self.year, self.month, self.day, false);
+        if n < first {
+            n
+        } else {
+            to_day_number(self.year, self.month, self.day, true)
+        }
+    }
+
+    pub fn get_julian_day(&self) -> JulianDay {
+        get_julian_day(self.get_day_number())
+    }
+
+    pub fn get_solar_day(&self) -> SolarDay {
+        self.try_get_solar_day().unwrap()
+    }
+
+    /// 公历日，超出公历范围时返回错误
+    pub fn try_get_solar_day(&self) -> Result<SolarDay, TymeError> {
+        self.get_julian_day().try_get_solar_day()
+    }
+
+    /// 儒略历日
+    pub fn get_julian_calendar_day(&self) -> JulianCalendarDay {
+        JulianCalendarDay::from_julian_day(self.get_julian_day())
+    }
+
+    /// 格里高利历日，超出范围时返回错误
+    pub fn try_get_proleptic_gregorian_day(&self) -> Result<ProlepticGregorianDay, TymeError> {
+        ProlepticGregorianDay::try_from_julian_day(self.get_julian_day())
+    }
+
+    /// 按另一改历表示同一天
+    pub fn with_reform(&self, reform: CalendarReform) -> Result<Self, TymeError> {
+        Self::from_day_number(self.get_day_number(), reform)
+    }
+
+    pub fn subtract(&self, other: Self) -> isize {
+        self.get_day_number() - other.get_day_number()
+    }
+}
+
+impl Display for ReformedDay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.get_name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tyme::enums::CalendarReform;
+    use crate::tyme::error::TymeError;
+    use crate::tyme::reform::{JulianCalendarDay, ProlepticGregorianDay, ReformedDay};
+    use crate::tyme::solar::SolarDay;
+    use crate::tyme::{Culture, Tyme};
+
+    #[test]
+    fn test0() {
+        let d: JulianCalendarDay = JulianCalendarDay::from_ymd(1582, 10, 4);
+        assert_eq!("1582年10月4日", d.get_solar_day().to_string());
+        assert_eq!("1582年10月15日", d.next(1).get_solar_day().to_string());
+        assert_eq!(
+            "格里高利历1582年10月14日",
+            d.get_proleptic_gregorian_day().get_name()
+        );
+        assert_eq!(
+            "儒略历1582年10月5日",
+            JulianCalendarDay::from_solar_day(SolarDay::from_ymd(1582, 10, 15)).get_name()
+        );
+        assert_eq!(
+            "儒略历2024年2月29日",
+            JulianCalendarDay::from_solar_day(SolarDay::from_ymd(2024, 3, 13)).get_name()
+        );
+    }
+
+    #[test]
+    fn test1() {
+        assert!(JulianCalendarDay::from_ymd(1900, 1, 1).is_leap());
+        assert!(!ProlepticGregorianDay::from_ymd(1900, 1, 1).is_leap());
+        assert!(JulianCalendarDay::new(1900, 2, 29).is_ok());
+        assert_eq!(
+            Err(TymeError::IllegalDay {
+                calendar: "gregorian",
+                year: 1900,
+                month: 2,
+                day: 29,
+            }),
+            ProlepticGregorianDay::new(1900, 2, 29)
+        );
+        assert_eq!(
+            Err(TymeError::IllegalMonth {
+                calendar: "julian",
+                year: 2000,
+                month: 13,
+            }),
+            JulianCalendarDay::new(2000, 13, 1)
+        );
+    }
+
+    #[test]
+    fn test2() {
+        // 格里高利历公元1年1月1日即儒略历公元1年1月3日
+        let d: ProlepticGregorianDay = ProlepticGregorianDay::from_ymd(1, 1, 1);
+        assert_eq!("儒略历1年1月3日", d.get_julian_calendar_day().get_name());
+        assert_eq!("1年1月3日", d.get_solar_day().to_string());
+        assert!(ProlepticGregorianDay::try_from_solar_day(SolarDay::from_ymd(1, 1, 2)).is_err());
+        assert!(d.try_next(-1).is_err());
+        assert_eq!(
+            "格里高利历2000年3月1日",
+            ProlepticGregorianDay::from_ymd(2000, 2, 28)
+                .next(2)
+                .get_name()
+        );
+        assert_eq!(
+            60,
+            ProlepticGregorianDay::from_ymd(2000, 3, 1)
+                .subtract(ProlepticGregorianDay::from_ymd(2000, 1, 1))
+        );
+        // 与公历一致
+        let solar: SolarDay = SolarDay::from_ymd(2024, 5, 20);
+        assert_eq!(
+            solar,
+            ProlepticGregorianDay::from_solar_day(solar).get_solar_day()
+        );
+    }
+
+    #[test]
+    fn test3() {
+        let d: ReformedDay = ReformedDay::from_ymd(1752, 9, 2, CalendarReform::Britain);
+        assert!(!d.is_gregorian());
+        assert_eq!("1752年9月14日", d.next(1).get_name());
+        assert!(d.next(1).is_gregorian());
+        assert_eq!(1, d.next(1).subtract(d));
+        assert_eq!("1752年9月13日", d.get_solar_day().to_string());
+        assert_eq!(
+            Err(TymeError::GregorianGap {
+                year: 1752,
+                month: 9,
+                day: 3,
+            }),
+            ReformedDay::new(1752, 9, 3, CalendarReform::Britain)
+        );
+        assert!(ReformedDay::new(1700, 2, 29, CalendarReform::Britain).is_ok());
+        assert!(ReformedDay::new(1700, 2, 29, CalendarReform::Rome).is_err());
+    }
+
+    #[test]
+    fn test4() {
+        let d: ReformedDay = ReformedDay::from_ymd(1917, 10, 25, CalendarReform::Russia);
+        assert_eq!("1917年11月7日", d.get_solar_day().to_string());
+        assert_eq!(
+            "1917年11月7日",
+            d.with_reform(CalendarReform::Rome).unwrap().get_name()
+        );
+        assert_eq!(
+            "1918年2月14日",
+            ReformedDay::from_ymd(1918, 1, 31, CalendarReform::Russia)
+                .next(1)
+                .get_name()
+        );
+        assert!(ReformedDay::new(1918, 2, 13, CalendarReform::Russia).is_err());
+    }
+
+    #[test]
+    fn test5() {
+        // 罗马改历与公历一致
+        let mut solar: SolarDay = SolarDay::from_ymd(1582, 9, 1);
+        for _ in 0..100 {
+            let d: ReformedDay = ReformedDay::from_solar_day(solar, CalendarReform::Rome);
+            assert_eq!(solar.to_string(), d.get_name());
+            assert_eq!(solar, d.get_solar_day());
+            solar = solar.next(1);
+        }
+        assert!(ReformedDay::from_ymd(1582, 10, 4, CalendarReform::Rome)
+            .try_get_proleptic_gregorian_day()
+            .is_ok());
+    }
+}
@@ -0,0 +1,2001 @@
+//! 序列化（需启用 `serde` 特性）
+//!
+//! 所有历法与传统文化类型均以结构化对象的形式序列化，格式版本见 [`FORMAT_VERSION`]，格式变化时版本号递增。
+//!
+//! | 类型 | 格式 |
+//! | --- | --- |
+//! | 循环类（天干、地支、六十甲子、五行、星座、九星、二十七宿、太阴日等）、日本年号 | `{"index": 0, "name": "甲"}` |
+//! | 枚举（性别、阴阳、内外、藏干类型、节日类型、事件类型、日照类型、日月食类型、行星动态、伊斯兰历类型、波斯历类型、改历、日本年号类型、地区、藏历流派、月半、岁差） | `{"code": 1, "name": "男"}` |
+//! | 公历年、ISO周年、民国纪年、佛历纪年、农历年、干支年、藏历年、希伯来历年 | `{"year": 2024}` |
+//! | 公历半年、公历季度、干支月 | `{"year": 2024, "index": 0}` |
+//! | 公历月、农历月、藏历月、希伯来历月 | `{"year": 2024, "month": -6}`，闰月为负数 |
+//! | 公历周、农历周 | `{"year": 2024, "month": 1, "index": 0, "start": 1}` |
+//! | 日本年号纪年 | `{"era": {…}, "year": 6}` |
+//! | ISO周 | `{"year": 2024, "week": 1}` |
+//! | 公历日、农历日、藏历日、希伯来历日、儒略历日、格里高利历日 | `{"year": 2024, "month": -6, "day": 1}`，闰月（藏历闰日）为负数 |
+//! | 公历时刻、农历时辰 | `{"year": 2024, "month": 1, "day": 1, "hour": 0, "minute": 0, "second": 0}` |
+//! | 节气、物候 | `{"year": 2024, "index": 0, "name": "冬至"}` |
+//! | 月相 | `{"lunar_year": 2024, "lunar_month": 1, "index": 0, "name": "新月"}` |
+//! | 第几天（数九、三伏、梅雨、物候、月相、节气、人元司令分野） | `{"culture": {…}, "day_index": 0}` |
+//! | 干支日、干支时辰 | `{"solar_day": {…}}`、`{"solar_time": {…}}` |
+//! | 三柱、八字 | `{"year": {…}, "month": {…}, "day": {…}, "hour": {…}}` |
+//! | 童限 | `{"birth_time": {…}, "gender": {…}, "forward": true, …}` |
+//! | 大运、小运 | `{"child_limit": {…}, "index": 0, …}` |
+//! | 公历节日、农历节日、希伯来历节日、藏历节日、日本节假日、韩国节假日、越南节假日 | `{"day": {…}, "index": 0, "name": "元旦"}` |
+//! | 法定假日 | `{"day": {…}, "name": "春节", "work": true}`，中国大陆以外的地区增加 `"region": {…}` |
+//! | 事件 | `{"name": "…", "data": "@…"}` |
+//! | UTC偏移 | `{"seconds": 28800}` |
+//! | 带UTC偏移的公历时刻、农历年、农历月、农历日 | 在对应格式中增加 `"offset": {…}` |
+//! | 伊斯兰历、波斯历的年、月、日，按改历推算的日 | 在公历年、月、日的格式中增加 `"type": {…}` |
+//!
+//! 反序列化时：`name` 可省略，若提供则须与索引一致；童限、大运、小运、法定假日中的推算字段仅供输出，反序列化时忽略并重新推算。
+//!
+//! # 示例
+//! ```
+//! use tyme4rs::tyme::lunar::LunarDay;
+//!
+//! let day: LunarDay = LunarDay::from_ymd(2023, -2, 1);
+//! let json: String = serde_json::to_string(&day).unwrap();
+//! assert_eq!(r#"{"year":2023,"month":-2,"day":1}"#, json);
+//! assert_eq!(day, serde_json::from_str::<LunarDay>(&json).unwrap());
+//! ```
+use std::fmt::Display;
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::tyme::astro::planet::Planet;
+use crate::tyme::culture::dog::{Dog, DogDay};
+use crate::tyme::culture::fetus::{FetusDay, FetusEarthBranch, FetusHeavenStem, FetusMonth};
+use crate::tyme::culture::nine::{Nine, NineDay};
+use crate::tyme::culture::panchang::{Karana, Nakshatra, Yoga};
+use crate::tyme::culture::peng_zu::{PengZu, PengZuEarthBranch, PengZuHeavenStem};
+use crate::tyme::culture::phenology::{Phenology, PhenologyDay, ThreePhenology};
+use crate::tyme::culture::plumrain::{PlumRain, PlumRainDay};
+use crate::tyme::culture::ren::minor::MinorRen;
+use crate::tyme::culture::star::nine::{Dipper, NineStar};
+use crate::tyme::culture::star::seven::SevenStar;
+use crate::tyme::culture::star::six::SixStar;
+use crate::tyme::culture::star::ten::TenStar;
+use crate::tyme::culture::star::twelve::{Ecliptic, TwelveStar};
+use crate::tyme::culture::star::twenty_eight::TwentyEightStar;
+use crate::tyme::culture::{
+    Animal, Beast, Constellation, Direction, Duty, Element, God, KitchenGodSteed, Land, Luck,
+    Phase, PhaseDay, Sixty, Sound, Taboo, Ten, Terrain, Twenty, Week, Zodiac, Zone,
+};
+use crate::tyme::eightchar::{ChildLimit, DecadeFortune, EightChar, Fortune};
+use crate::tyme::enums::{
+    Ayanamsa, CalendarReform, DaylightType, EclipseType, EventType, FestivalType, Gender,
+    HideHeavenStemType, IslamicType, JapaneseEraType, Paksha, PersianType, PlanetEventType, Region,
+    Side, Tradition, YinYang,
+};
+use crate::tyme::era::{BuddhistYear, MinguoYear};
+use crate::tyme::error::TymeError;
+use crate::tyme::event::Event;
+use crate::tyme::festival::{HebrewFestival, LunarFestival, RabByungFestival, SolarFestival};
+use crate::tyme::hebrew::{HebrewDay, HebrewMonth, HebrewYear};
+use crate::tyme::hindu::{Masa, Tithi, Vara};
+use crate::tyme::holiday::{KoreanHoliday, LegalHoliday, VietnameseHoliday};
+use crate::tyme::islamic::{IslamicDay, IslamicMonth, IslamicYear};
+use crate::tyme::japanese::{JapaneseEra, JapaneseHoliday, JapaneseYear};
+use crate::tyme::jd::JulianDay;
+use crate::tyme::lunar::{LunarDay, LunarHour, LunarMonth, LunarSeason, LunarWeek, LunarYear};
+use crate::tyme::persian::{PersianDay, PersianMonth, PersianYear};
+use crate::tyme::rabbyung::{RabByungDay, RabByungElement, RabByungMonth, RabByungYear, SparKha};
+use crate::tyme::reform::{JulianCalendarDay, ProlepticGregorianDay, ReformedDay};
+use crate::tyme::sixtycycle::{
+    EarthBranch, HeavenStem, HideHeavenStem, HideHeavenStemDay, SixtyCycle, SixtyCycleDay,
+    SixtyCycleHour, SixtyCycleMonth, SixtyCycleYear, ThreePillars,
+};
+use crate::tyme::solar::{
+    IsoWeek, IsoWeekYear, SolarDay, SolarHalfYear, SolarMonth, SolarSeason, SolarTerm,
+    SolarTermDay, SolarTime, SolarWeek, SolarYear,
+};
+use crate::tyme::zone::{
+    UtcOffset, ZonedLunarDay, ZonedLunarMonth, ZonedLunarYear, ZonedSolarTime,
+};
+use crate::tyme::Culture;
+
+/// 序列化格式版本
+///
+/// - 2：伊斯兰历类型代码1的名称由“天文历”改为“乌姆库拉历”，新增代码2“MABIMS历”
+pub const FORMAT_VERSION: usize = 2;
+
+/// 循环类
+#[derive(Serialize, Deserialize)]
+struct LoopShape {
+    index: usize,
+    #[serde(default)]
+    name: Option<String>,
+}
+
+/// 枚举
+#[derive(Serialize, Deserialize)]
+struct CodeShape {
+    code: usize,
+    #[serde(default)]
+    name: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct YearShape {
+    year: isize,
+}
+
+#[derive(Serialize, Deserialize)]
+struct YearIndexShape {
+    year: isize,
+    index: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+struct MonthShape<M> {
+    year: isize,
+    month: M,
+}
+
+#[derive(Serialize, Deserialize)]
+struct IsoWeekShape {
+    year: isize,
+    week: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+struct WeekShape<M> {
+    year: isize,
+    month: M,
+    index: usize,
+    start: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+struct DayShape<M, D> {
+    year: isize,
+    month: M,
+    day: D,
+}
+
+#[derive(Serialize, Deserialize)]
+struct TimeShape<M> {
+    year: isize,
+    month: M,
+    day: usize,
+    hour: usize,
+    minute: usize,
+    second: usize,
+}
+
+/// 带年份的循环类
+#[derive(Serialize, Deserialize)]
+struct YearLoopShape {
+    year: isize,
+    index: usize,
+    #[serde(default)]
+    name: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct OffsetShape {
+    seconds: isize,
+}
+
+/// 带UTC偏移
+#[derive(Serialize, Deserialize)]
+struct ZonedShape<T> {
+    #[serde(flatten)]
+    value: T,
+    offset: UtcOffset,
+}
+
+/// 带历法类型
+#[derive(Serialize, Deserialize)]
+struct CalendarTypeShape<T, C> {
+    #[serde(flatten)]
+    value: T,
+    #[serde(rename = "type")]
+    calendar_type: C,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PhaseShape {
+    lunar_year: isize,
+    lunar_month: isize,
+    index: usize,
+    #[serde(default)]
+    name: Option<String>,
+}
+
+/// 第几天
+#[derive(Serialize, Deserialize)]
+struct CultureDayShape<T> {
+    culture: T,
+    day_index: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+struct JulianDayShape {
+    day: f64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct HideHeavenStemShape {
+    heaven_stem: HeavenStem,
+    #[serde(rename = "type")]
+    hide_heaven_stem_type: HideHeavenStemType,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SolarDayShape {
+    solar_day: SolarDay,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SolarTimeShape {
+    solar_time: SolarTime,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ThreePillarsShape {
+    year: SixtyCycle,
+    month: SixtyCycle,
+    day: SixtyCycle,
+}
+
+#[derive(Serialize, Deserialize)]
+struct EightCharShape {
+    year: SixtyCycle,
+    month: SixtyCycle,
+    day: SixtyCycle,
+    hour: SixtyCycle,
+}
+
+#[derive(Serialize, Deserialize)]
+struct PengZuShape {
+    peng_zu_heaven_stem: PengZuHeavenStem,
+    peng_zu_earth_branch: PengZuEarthBranch,
+}
+
+#[derive(Serialize, Deserialize)]
+struct FetusDayShape {
+    fetus_heaven_stem: FetusHeavenStem,
+    fetus_earth_branch: FetusEarthBranch,
+    side: Side,
+    direction: Direction,
+}
+
+#[derive(Serialize, Deserialize)]
+struct KitchenGodSteedShape {
+    first_day_sixty_cycle: SixtyCycle,
+}
+
+#[derive(Serialize)]
+struct ChildLimitShape {
+    birth_time: SolarTime,
+    gender: Gender,
+    forward: bool,
+    year_count: usize,
+    month_count: usize,
+    day_count: usize,
+    hour_count: usize,
+    minute_count: usize,
+    end_time: SolarTime,
+}
+
+#[derive(Deserialize)]
+struct ChildLimitKey {
+    birth_time: SolarTime,
+    gender: Gender,
+}
+
+#[derive(Serialize)]
+struct DecadeFortuneShape {
+    child_limit: ChildLimit,
+    index: isize,
+    start_age: isize,
+    end_age: isize,
+    sixty_cycle: SixtyCycle,
+}
+
+#[derive(Serialize)]
+struct FortuneShape {
+    child_limit: ChildLimit,
+    index: isize,
+    age: isize,
+    sixty_cycle: SixtyCycle,
+}
+
+#[derive(Deserialize)]
+struct FortuneKey {
+    child_limit: ChildLimit,
+    index: isize,
+}
+
+#[derive(Serialize, Deserialize)]
+struct FestivalShape<D> {
+    day: D,
+    index: usize,
+    #[serde(default)]
+    name: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct EraYearShape<E> {
+    era: E,
+    year: usize,
+}
+
+#[derive(Serialize)]
+struct LegalHolidayShape {
+    day: SolarDay,
+    name: String,
+    work: bool,
+    /// 中国大陆以外的地区
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<Region>,
+}
+
+#[derive(Deserialize)]
+struct LegalHolidayKey {
+    day: SolarDay,
+    #[serde(default)]
+    region: Option<Region>,
+}
+
+/// 藏历（浦派以外的流派附带流派）
+#[derive(Serialize, Deserialize)]
+struct TraditionShape<T> {
+    #[serde(flatten)]
+    value: T,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tradition: Option<Tradition>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct EventShape {
+    name: String,
+    data: String,
+}
+
+/// 借助中间格式实现序列化与反序列化
+macro_rules! serde_via {
+    ($t:ty, $shape:ty, |$s:ident| $to:expr, |$d:ident| $from:expr) => {
+        serde_via!($t, $shape => $shape, |$s| $to, |$d| $from);
+    };
+    ($t:ty, $ser:ty => $de:ty, |$s:ident| $to:expr, |$d:ident| $from:expr) => {
+        impl Serialize for $t {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let $s: &$t = self;
+                let shape: $ser = $to;
+                shape.serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $t {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let $d: $de = <$de>::deserialize(deserializer)?;
+                into_result($from)
+            }
+        }
+    };
+}
+
+/// 循环类
+macro_rules! serde_loop {
+    ($($t:ty),+ $(,)?) => {
+        $(
+            serde_via!(
+                $t,
+                LoopShape,
+                |s| LoopShape {
+                    index: s.get_index(),
+                    name: Some(s.get_name()),
+                },
+                |d| {
+                    let t: $t = <$t>::from_index(d.index as isize);
+                    check_index(stringify!($t), d.index, t.get_size())
+                        .and_then(|_| check_name(t, d.name))
+                }
+            );
+        )+
+    };
+}
+
+/// 枚举
+macro_rules! serde_code {
+    ($($t:ty),+ $(,)?) => {
+        $(
+            serde_via!(
+                $t,
+                CodeShape,
+                |s| CodeShape {
+                    code: s.get_code(),
+                    name: Some(s.get_name()),
+                },
+                |d| <$t>::from_code(d.code).and_then(|t| match d.name {
+                    Some(name) if name != t.get_name() => {
+                        Err(TymeError::UnknownName { kind: stringify!($t), name })
+                    }
+                    _ => Ok(t),
+                })
+            );
+        )+
+    };
+}
+
+/// 第几天
+macro_rules! serde_culture_day {
+    ($($t:ty => $culture:ty, $get:ident),+ $(,)?) => {
+        $(
+            serde_via!(
+                $t,
+                CultureDayShape<$culture>,
+                |s| CultureDayShape {
+                    culture: s.$get(),
+                    day_index: s.get_day_index(),
+                },
+                |d| Ok::<$t, TymeError>(<$t>::new(d.culture, d.day_index))
+            );
+        )+
+    };
+}
+
+fn into_result<T, E: Display, R: Error>(r: Result<T, E>) -> Result<T, R> {
+    r.map_err(R::custom)
+}
+
+fn check_index(kind: &'static str, index: usize, size: usize) -> Result<(), TymeError> {
+    if index < size {
+        Ok(())
+    } else {
+        Err(TymeError::IndexOutOfRange {
+            kind,
+            index: index as isize,
+            min: 0,
+            max: size as isize - 1,
+        })
+    }
+}
+
+fn check_name<T: Culture>(t: T, name: Option<String>) -> Result<T, TymeError> {
+    match name {
+        Some(name) if name != t.get_name() => Err(TymeError::UnknownName { kind: "", name }),
+        _ => Ok(t),
+    }
+}
+
+/// 在六十甲子中查找满足条件的干支，用于还原只保存了推算结果的类型
+fn find_sixty_cycle<F: Fn(SixtyCycle) -> bool>(
+    kind: &'static str,
+    name: String,
+    f: F,
+) -> Result<SixtyCycle, TymeError> {
+    (0..60)
+        .map(SixtyCycle::from_index)
+        .find(|c| f(c.clone()))
+        .ok_or(TymeError::UnknownName { kind, name })
+}
+
+serde_loop!(
+    Animal,
+    Beast,
+    Constellation,
+    Direction,
+    Duty,
+    Element,
+    God,
+    Land,
+    Luck,
+    Sixty,
+    Sound,
+    Taboo,
+    Ten,
+    Terrain,
+    Twenty,
+    Week,
+    Zodiac,
+    Zone,
+    Dog,
+    Nine,
+    PlumRain,
+    ThreePhenology,
+    PengZuHeavenStem,
+    PengZuEarthBranch,
+    MinorRen,
+    FetusHeavenStem,
+    FetusEarthBranch,
+    FetusMonth,
+    Dipper,
+    NineStar,
+    SevenStar,
+    SixStar,
+    TenStar,
+    Ecliptic,
+    TwelveStar,
+    TwentyEightStar,
+    HeavenStem,
+    EarthBranch,
+    SixtyCycle,
+    LunarSeason,
+    RabByungElement,
+    SparKha,
+    Planet,
+    Nakshatra,
+    Yoga,
+    Karana,
+    Tithi,
+    Vara,
+    Masa,
+);
+
+serde_code!(
+    FestivalType,
+    HideHeavenStemType,
+    Gender,
+    Side,
+    YinYang,
+    EventType,
+    DaylightType,
+    EclipseType,
+    PlanetEventType,
+    IslamicType,
+    PersianType,
+    CalendarReform,
+    JapaneseEraType,
+    Region,
+    Tradition,
+    Paksha,
+    Ayanamsa
+);
+
+serde_culture_day!(
+    NineDay => Nine, get_nine,
+    DogDay => Dog, get_dog,
+    PlumRainDay => PlumRain, get_plum_rain,
+    PhenologyDay => Phenology, get_phenology,
+    PhaseDay => Phase, get_phase,
+    SolarTermDay => SolarTerm, get_solar_term,
+    HideHeavenStemDay => HideHeavenStem, get_hide_heaven_stem,
+);
+
+serde_via!(
+    JulianDay,
+    JulianDayShape,
+    |s| JulianDayShape { day: s.get_day() },
+    |d| Ok::<JulianDay, TymeError>(JulianDay::from_julian_day(d.day))
+);
+
+serde_via!(
+    SolarYear,
+    YearShape,
+    |s| YearShape { year: s.get_year() },
+    |d| SolarYear::new(d.year)
+);
+
+serde_via!(
+    SolarHalfYear,
+    YearIndexShape,
+    |s| YearIndexShape {
+        year: s.get_year(),
+        index: s.get_index(),
+    },
+    |d| SolarHalfYear::new(d.year, d.index)
+);
+
+serde_via!(
+    SolarSeason,
+    YearIndexShape,
+    |s| YearIndexShape {
+        year: s.get_year(),
+        index: s.get_index(),
+    },
+    |d| SolarSeason::new(d.year, d.index)
+);
+
+serde_via!(
+    SolarMonth,
+    MonthShape<usize>,
+    |s| MonthShape {
+        year: s.get_year(),
+        month: s.get_month(),
+    },
+    |d| SolarMonth::new(d.year, d.month)
+);
+
+serde_via!(
+    SolarWeek,
+    WeekShape<usize>,
+    |s| WeekShape {
+        year: s.get_year(),
+        month: s.get_month(),
+        index: s.get_index(),
+        start: s.get_start(),
+    },
+    |d| SolarWeek::new(d.year, d.month, d.index, d.start)
+);
+
+serde_via!(
+    IsoWeekYear,
+    YearShape,
+    |s| YearShape { year: s.get_year() },
+    |d| IsoWeekYear::new(d.year)
+);
+
+serde_via!(
+    MinguoYear,
+    YearShape,
+    |s| YearShape { year: s.get_year() },
+    |d| MinguoYear::new(d.year)
+);
+
+serde_via!(
+    BuddhistYear,
+    YearShape,
+    |s| YearShape { year: s.get_year() },
+    |d| BuddhistYear::new(d.year)
+);
+
+serde_via!(
+    IsoWeek,
+    IsoWeekShape,
+    |s| IsoWeekShape {
+        year: s.get_year(),
+        week: s.get_week(),
+    },
+    |d| IsoWeek::new(d.year, d.week)
+);
+
+serde_via!(
+    SolarDay,
+    DayShape<usize, usize>,
+    |s| DayShape {
+        year: s.get_year(),
+        month: s.get_month(),
+        day: s.get_day(),
+    },
+    |d| SolarDay::new(d.year, d.month, d.day)
+);
+
+serde_via!(
+    SolarTime,
+    TimeShape<usize>,
+    |s| TimeShape {
+        year: s.get_year(),
+        month: s.get_month(),
+        day: s.get_day(),
+        hour: s.get_hour(),
+        minute: s.get_minute(),
+        second: s.get_second(),
+    },
+    |d| SolarTime::new(d.year, d.month, d.day, d.hour, d.minute, d.second)
+);
+
+serde_via!(
+    SolarTerm,
+    YearLoopShape,
+    |s| YearLoopShape {
+        year: s.get_year(),
+        index: s.get_index(),
+        name: Some(s.get_name()),
+    },
+    |d| {
+        SolarYear::validate(d.year)
+            .and_then(|_| check_index("SolarTerm", d.index, 24))
+            .and_then(|_| check_name(SolarTerm::from_index(d.year, d.index as isize), d.name))
+    }
+);
+
+serde_via!(
+    Phenology,
+    YearLoopShape,
+    |s| YearLoopShape {
+        year: s.get_year(),
+        index: s.get_index(),
+        name: Some(s.get_name()),
+    },
+    |d| {
+        SolarYear::validate(d.year)
+            .and_then(|_| check_index("Phenology", d.index, 72))
+            .and_then(|_| check_name(Phenology::from_index(d.year, d.index as isize), d.name))
+    }
+);
+
+serde_via!(
+    LunarYear,
+    YearShape,
+    |s| YearShape { year: s.get_year() },
+    |d| LunarYear::new(d.year)
+);
+
+serde_via!(
+    LunarMonth,
+    MonthShape<isize>,
+    |s| MonthShape {
+        year: s.get_year(),
+        month: s.get_month_with_leap(),
+    },
+    |d| LunarMonth::new(d.year, d.month)
+);
+
+serde_via!(
+    LunarWeek,
+    WeekShape<isize>,
+    |s| WeekShape {
+        year: s.get_year(),
+        month: s.get_month(),
+        index: s.get_index(),
+        start: s.get_start(),
+    },
+    |d| LunarWeek::new(d.year, d.month, d.index, d.start)
+);
+
+serde_via!(
+    LunarDay,
+    DayShape<isize, usize>,
+    |s| DayShape {
+        year: s.get_year(),
+        month: s.get_month(),
+        day: s.get_day(),
+    },
+    |d| LunarDay::new(d.year, d.month, d.day)
+);
+
+serde_via!(
+    LunarHour,
+    TimeShape<isize>,
+    |s| TimeShape {
+        year: s.get_year(),
+        month: s.get_month(),
+        day: s.get_day(),
+        hour: s.get_hour(),
+        minute: s.get_minute(),
+        second: s.get_second(),
+    },
+    |d| LunarHour::new(d.year, d.month, d.day, d.hour, d.minute, d.second)
+);
+
+serde_via!(
+    Phase,
+    PhaseShape,
+    |s| PhaseShape {
+        lunar_year: s.get_lunar_year(),
+        lunar_month: s.get_lunar_month(),
+        index: s.get_index(),
+        name: Some(s.get_name()),
+    },
+    |d| {
+        check_index("Phase", d.index, 8)
+            .and_then(|_| Phase::try_from_index(d.lunar_year, d.lunar_month, d.index as isize))
+            .and_then(|t| check_name(t, d.name))
+    }
+);
+
+serde_via!(
+    KitchenGodSteed,
+    KitchenGodSteedShape,
+    |s| KitchenGodSteedShape {
+        first_day_sixty_cycle: s.get_first_day_sixty_cycle(),
+    },
+    |d| Ok::<KitchenGodSteed, TymeError>(KitchenGodSteed::from_first_day_sixty_cycle(
+        d.first_day_sixty_cycle
+    ))
+);
+
+serde_via!(
+    PengZu,
+    PengZuShape,
+    |s| PengZuShape {
+        peng_zu_heaven_stem: s.get_peng_zu_heaven_stem(),
+        peng_zu_earth_branch: s.get_peng_zu_earth_branch(),
+    },
+    |d| {
+        let name: String = format!("{} {}", d.peng_zu_heaven_stem, d.peng_zu_earth_branch);
+        find_sixty_cycle("PengZu", name, |c| {
+            c.get_heaven_stem().get_index() == d.peng_zu_heaven_stem.get_index()
+                && c.get_earth_branch().get_index() == d.peng_zu_earth_branch.get_index()
+        })
+        .map(PengZu::from_sixty_cycle)
+    }
+);
+
+serde_via!(
+    FetusDay,
+    FetusDayShape,
+    |s| FetusDayShape {
+        fetus_heaven_stem: s.get_fetus_heaven_stem(),
+        fetus_earth_branch: s.get_fetus_earth_branch(),
+        side: s.get_side(),
+        direction: s.get_direction(),
+    },
+    |d| {
+        let name: String = format!("{}{}", d.fetus_heaven_stem, d.fetus_earth_branch);
+        find_sixty_cycle("FetusDay", name, |c| {
+            let t: FetusDay = FetusDay::new(c);
+            t.get_fetus_heaven_stem() == d.fetus_heaven_stem
+                && t.get_fetus_earth_branch() == d.fetus_earth_branch
+                && t.get_side() == d.side
+                && t.get_direction() == d.direction
+        })
+        .map(FetusDay::new)
+    }
+);
+
+serde_via!(
+    HideHeavenStem,
+    HideHeavenStemShape,
+    |s| HideHeavenStemShape {
+        heaven_stem: s.get_heaven_stem(),
+        hide_heaven_stem_type: s.get_type(),
+    },
+    |d| Ok::<HideHeavenStem, TymeError>(HideHeavenStem::new(
+        d.heaven_stem,
+        d.hide_heaven_stem_type
+    ))
+);
+
+serde_via!(
+    SixtyCycleYear,
+    YearShape,
+    |s| YearShape { year: s.get_year() },
+    |d| SixtyCycleYear::new(d.year)
+);
+
+serde_via!(
+    SixtyCycleMonth,
+    YearIndexShape,
+    |s| YearIndexShape {
+        year: s.get_sixty_cycle_year().get_year(),
+        index: s.get_index_in_year(),
+    },
+    |d| {
+        check_index("SixtyCycleMonth", d.index, 12)
+            .and_then(|_| SixtyCycleMonth::try_from_index(d.year, d.index as isize))
+    }
+);
+
+serde_via!(
+    SixtyCycleDay,
+    SolarDayShape,
+    |s| SolarDayShape {
+        solar_day: s.get_solar_day(),
+    },
+    |d| Ok::<SixtyCycleDay, TymeError>(SixtyCycleDay::from_solar_day(d.solar_day))
+);
+
+serde_via!(
+    SixtyCycleHour,
+    SolarTimeShape,
+    |s| SolarTimeShape {
+        solar_time: s.get_solar_time(),
+    },
+    |d| Ok::<SixtyCycleHour, TymeError>(SixtyCycleHour::from_solar_time(d.solar_time))
+);
+
+serde_via!(
+    ThreePillars,
+    ThreePillarsShape,
+    |s| ThreePillarsShape {
+        year: s.get_year(),
+        month: s.get_month(),
+        day: s.get_day(),
+    },
+    |d| Ok::<ThreePillars, TymeError>(ThreePillars::from_sixty_cycle(d.year, d.month, d.day))
+);
+
+serde_via!(
+    EightChar,
+    EightCharShape,
+    |s| EightCharShape {
+        year: s.get_year(),
+        month: s.get_month(),
+        day: s.get_day(),
+        hour: s.get_hour(),
+    },
+    |d| Ok::<EightChar, TymeError>(EightChar::from_sixty_cycle(d.year, d.month, d.day, d.hour))
+);
+
+serde_via!(
+    ChildLimit,
+    ChildLimitShape => ChildLimitKey,
+    |s| ChildLimitShape {
+        birth_time: s.get_start_time(),
+        gender: s.get_gender(),
+        forward: s.is_forward(),
+        year_count: s.get_year_count(),
+        month_count: s.get_month_count(),
+        day_count: s.get_day_count(),
+        hour_count: s.get_hour_count(),
+        minute_count: s.get_minute_count(),
+        end_time: s.get_end_time(),
+    },
+    |d| Ok::<ChildLimit, TymeError>(ChildLimit::from_solar_time(d.birth_time, d.gender))
+);
+
+serde_via!(
+    DecadeFortune,
+    DecadeFortuneShape => FortuneKey,
+    |s| DecadeFortuneShape {
+        child_limit: s.get_child_limit(),
+        index: s.get_index(),
+        start_age: s.get_start_age(),
+        end_age: s.get_end_age(),
+        sixty_cycle: s.get_sixty_cycle(),
+    },
+    |d| Ok::<DecadeFortune, TymeError>(DecadeFortune::from_child_limit(d.child_limit, d.index))
+);
+
+serde_via!(
+    Fortune,
+    FortuneShape => FortuneKey,
+    |s| FortuneShape {
+        child_limit: s.get_child_limit(),
+        index: s.get_index(),
+        age: s.get_age(),
+        sixty_cycle: s.get_sixty_cycle(),
+    },
+    |d| Ok::<Fortune, TymeError>(Fortune::from_child_limit(d.child_limit, d.index))
+);
+
+serde_via!(
+    RabByungYear,
+    YearShape,
+    |s| YearShape { year: s.get_year() },
+    |d| RabByungYear::from_year(d.year)
+);
+
+serde_via!(
+    RabByungMonth,
+    TraditionShape<MonthShape<isize>>,
+    |s| TraditionShape {
+        value: MonthShape {
+            year: s.get_rab_byung_year().get_year(),
+            month: s.get_month_with_leap(),
+        },
+        tradition: Some(s.get_tradition()).filter(|t| Tradition::Phugpa != *t),
+    },
+    |d| RabByungMonth::new_with(
+        d.tradition.unwrap_or(Tradition::Phugpa),
+        d.value.year,
+        d.value.month
+    )
+);
+
+serde_via!(
+    RabByungDay,
+    TraditionShape<DayShape<isize, isize>>,
+    |s| {
+        let m: RabByungMonth = s.get_rab_byung_month();
+        TraditionShape {
+            value: DayShape {
+                year: m.get_rab_byung_year().get_year(),
+                month: m.get_month_with_leap(),
+                day: s.get_day_with_leap(),
+            },
+            tradition: Some(s.get_tradition()).filter(|t| Tradition::Phugpa != *t),
+        }
+    },
+    |d| RabByungDay::new_with(
+        d.tradition.unwrap_or(Tradition::Phugpa),
+        d.value.year,
+        d.value.month,
+        d.value.day
+    )
+);
+
+serde_via!(
+    SolarFestival,
+    FestivalShape<SolarDay>,
+    |s| FestivalShape {
+        day: s.get_day(),
+        index: s.get_index(),
+        name: Some(s.get_name()),
+    },
+    |d| {
+        SolarFestival::from_index(d.day.get_year(), d.index)
+            .filter(|t| t.get_day() == d.day)
+            .ok_or(format!("illegal solar festival: {} {}", d.day, d.index))
+            .and_then(|t| check_name(t, d.name).map_err(|e| e.to_string()))
+    }
+);
+
+serde_via!(
+    LunarFestival,
+    FestivalShape<LunarDay>,
+    |s| FestivalShape {
+        day: s.get_day(),
+        index: s.get_index(),
+        name: Some(s.get_name()),
+    },
+    |d| {
+        LunarFestival::from_index(d.day.get_year(), d.index)
+            .filter(|t| t.get_day() == d.day)
+            .ok_or(format!("illegal lunar festival: {} {}", d.day, d.index))
+            .and_then(|t| check_name(t, d.name).map_err(|e| e.to_string()))
+    }
+);
+
+serde_via!(
+    HebrewFestival,
+    FestivalShape<HebrewDay>,
+    |s| FestivalShape {
+        day: s.get_day(),
+        index: s.get_index(),
+        name: Some(s.get_name()),
+    },
+    |d| {
+        HebrewFestival::from_index(d.day.get_year(), d.index)
+            .filter(|t| t.get_day() == d.day)
+            .ok_or(format!("illegal hebrew festival: {} {}", d.day, d.index))
+            .and_then(|t| check_name(t, d.name).map_err(|e| e.to_string()))
+    }
+);
+
+serde_via!(
+    RabByungFestival,
+    FestivalShape<RabByungDay>,
+    |s| FestivalShape {
+        day: s.get_day(),
+        index: s.get_index(),
+        name: Some(s.get_name()),
+    },
+    |d| {
+        // 缺日并入的前一日仍在同月
+        RabByungFestival::from_month_index(d.day.get_rab_byung_month(), d.index)
+            .filter(|t| t.get_day() == d.day)
+            .ok_or(format!("illegal rab-byung festival: {} {}", d.day, d.index))
+            .and_then(|t| check_name(t, d.name).map_err(|e| e.to_string()))
+    }
+);
+
+serde_via!(
+    LegalHoliday,
+    LegalHolidayShape => LegalHolidayKey,
+    |s| LegalHolidayShape {
+        day: s.get_day(),
+        name: s.get_name(),
+        work: s.is_work(),
+        region: Some(s.get_region()).filter(|r| Region::Mainland != *r),
+    },
+    |d| {
+        let region: Region = d.region.unwrap_or(Region::Mainland);
+        LegalHoliday::from_ymd_in(region, d.day.get_year(), d.day.get_month(), d.day.get_day())
+            .ok_or(format!("illegal legal holiday: {} {}", region, d.day))
+    }
+);
+
+serde_via!(
+    JapaneseHoliday,
+    FestivalShape<SolarDay>,
+    |s| FestivalShape {
+        day: s.get_day(),
+        index: s.get_index(),
+        name: Some(s.get_name()),
+    },
+    |d| {
+        JapaneseHoliday::from_ymd(d.day.get_year(), d.day.get_month(), d.day.get_day())
+            .filter(|t| t.get_index() == d.index)
+            .ok_or(format!("illegal japanese holiday: {} {}", d.day, d.index))
+            .and_then(|t| check_name(t, d.name).map_err(|e| e.to_string()))
+    }
+);
+
+serde_via!(
+    KoreanHoliday,
+    FestivalShape<SolarDay>,
+    |s| FestivalShape {
+        day: s.get_day(),
+        index: s.get_index(),
+        name: Some(s.get_name()),
+    },
+    |d| {
+        KoreanHoliday::from_year(d.day.get_year())
+            .into_iter()
+            .find(|t| t.get_day() == d.day && t.get_index() == d.index)
+            .ok_or(format!("illegal korean holiday: {} {}", d.day, d.index))
+            .and_then(|t| check_name(t, d.name).map_err(|e| e.to_string()))
+    }
+);
+
+serde_via!(
+    VietnameseHoliday,
+    FestivalShape<SolarDay>,
+    |s| FestivalShape {
+        day: s.get_day(),
+        index: s.get_index(),
+        name: Some(s.get_name()),
+    },
+    |d| {
+        VietnameseHoliday::from_ymd(d.day.get_year(), d.day.get_month(), d.day.get_day())
+            .filter(|t| t.get_index() == d.index)
+            .ok_or(format!("illegal vietnamese holiday: {} {}", d.day, d.index))
+            .and_then(|t| check_name(t, d.name).map_err(|e| e.to_string()))
+    }
+);
+
+serde_via!(
+    JapaneseEra,
+    LoopShape,
+    |s| LoopShape {
+        index: s.get_index(),
+        name: Some(s.get_name()),
+    },
+    |d| JapaneseEra::try_from_index(d.index).and_then(|t| check_name(t, d.name))
+);
+
+serde_via!(
+    JapaneseYear,
+    EraYearShape<JapaneseEra>,
+    |s| EraYearShape {
+        era: s.get_era(),
+        year: s.get_year(),
+    },
+    |d| JapaneseYear::new(d.era, d.year)
+);
+
+serde_via!(
+    Event,
+    EventShape,
+    |s| EventShape {
+        name: s.get_name(),
+        data: s.get_data(),
+    },
+    |d| Event::new(&d.name, &d.data)
+);
+
+serde_via!(
+    UtcOffset,
+    OffsetShape,
+    |s| OffsetShape {
+        seconds: s.get_seconds(),
+    },
+    |d| UtcOffset::new(d.seconds)
+);
+
+serde_via!(
+    ZonedSolarTime,
+    ZonedShape<TimeShape<usize>>,
+    |s| {
+        let t: SolarTime = s.get_solar_time();
+        ZonedShape {
+            value: TimeShape {
+                year: t.get_year(),
+                month: t.get_month(),
+                day: t.get_day(),
+                hour: t.get_hour(),
+                minute: t.get_minute(),
+                second: t.get_second(),
+            },
+            offset: s.get_offset(),
+        }
+    },
+    |d| {
+        let t: TimeShape<usize> = d.value;
+        ZonedSolarTime::try_from_ymd_hms(
+            t.year, t.month, t.day, t.hour, t.minute, t.second, d.offset,
+        )
+    }
+);
+
+serde_via!(
+    ZonedLunarYear,
+    ZonedShape<YearShape>,
+    |s| ZonedShape {
+        value: YearShape { year: s.get_year() },
+        offset: s.get_offset(),
+    },
+    |d| ZonedLunarYear::new(d.value.year, d.offset)
+);
+
+serde_via!(
+    ZonedLunarMonth,
+    ZonedShape<MonthShape<isize>>,
+    |s| ZonedShape {
+        value: MonthShape {
+            year: s.get_year(),
+            month: s.get_month_with_leap(),
+        },
+        offset: s.get_offset(),
+    },
+    |d| ZonedLunarMonth::new(d.value.year, d.value.month, d.offset)
+);
+
+serde_via!(
+    ZonedLunarDay,
+    ZonedShape<DayShape<isize, usize>>,
+    |s| ZonedShape {
+        value: DayShape {
+            year: s.get_year(),
+            month: s.get_month(),
+            day: s.get_day(),
+        },
+        offset: s.get_offset(),
+    },
+    |d| ZonedLunarDay::new(d.value.year, d.value.month, d.value.day, d.offset)
+);
+
+serde_via!(
+    IslamicYear,
+    CalendarTypeShape<YearShape, IslamicType>,
+    |s| CalendarTypeShape {
+        value: YearShape { year: s.get_year() },
+        calendar_type: s.get_type(),
+    },
+    |d| IslamicYear::new(d.value.year, d.calendar_type)
+);
+
+serde_via!(
+    HebrewYear,
+    YearShape,
+    |s| YearShape { year: s.get_year() },
+    |d| HebrewYear::new(d.year)
+);
+
+serde_via!(
+    HebrewMonth,
+    MonthShape<usize>,
+    |s| MonthShape {
+        year: s.get_year(),
+        month: s.get_month(),
+    },
+    |d| HebrewMonth::new(d.year, d.month)
+);
+
+serde_via!(
+    PersianYear,
+    CalendarTypeShape<YearShape, PersianType>,
+    |s| CalendarTypeShape {
+        value: YearShape { year: s.get_year() },
+        calendar_type: s.get_type(),
+    },
+    |d| PersianYear::new(d.value.year, d.calendar_type)
+);
+
+serde_via!(
+    PersianMonth,
+    CalendarTypeShape<MonthShape<usize>, PersianType>,
+    |s| CalendarTypeShape {
+        value: MonthShape {
+            year: s.get_year(),
+            month: s.get_month(),
+        },
+        calendar_type: s.get_type(),
+    },
+    |d| PersianMonth::new(d.value.year, d.value.month, d.calendar_type)
+);
+
+serde_via!(
+    PersianDay,
+    CalendarTypeShape<DayShape<usize, usize>, PersianType>,
+    |s| CalendarTypeShape {
+        value: DayShape {
+            year: s.get_year(),
+            month: s.get_month(),
+            day: s.get_day(),
+        },
+        calendar_type: s.get_type(),
+    },
+    |d| PersianDay::new(d.value.year, d.value.month, d.value.day, d.calendar_type)
+);
+
+serde_via!(
+    HebrewDay,
+    DayShape<usize, usize>,
+    |s| DayShape {
+        year: s.get_year(),
+        month: s.get_month(),
+        day: s.get_day(),
+    },
+    |d| HebrewDay::new(d.year, d.month, d.day)
+);
+
+serde_via!(
+    JulianCalendarDay,
+    DayShape<usize, usize>,
+    |s| DayShape {
+        year: s.get_year(),
+        month: s.get_month(),
+        day: s.get_day(),
+    },
+    |d| JulianCalendarDay::new(d.year, d.month, d.day)
+);
+
+serde_via!(
+    ProlepticGregorianDay,
+    DayShape<usize, usize>,
+    |s| DayShape {
+        year: s.get_year(),
+        month: s.get_month(),
+        day: s.get_day(),
+    },
+    |d| ProlepticGregorianDay::new(d.year, d.month, d.day)
+);
+
+serde_via!(
+    ReformedDay,
+    CalendarTypeShape<DayShape<usize, usize>, CalendarReform>,
+    |s| CalendarTypeShape {
+        value: DayShape {
+            year: s.get_year(),
+            month: s.get_month(),
+            day: s.get_day(),
+        },
+        calendar_type: s.get_reform(),
+    },
+    |d| ReformedDay::new(d.value.year, d.value.month, d.value.day, d.calendar_type)
+);
+
+serde_via!(
+    IslamicMonth,
+    CalendarTypeShape<MonthShape<usize>, IslamicType>,
+    |s| CalendarTypeShape {
+        value: MonthShape {
+            year: s.get_year(),
+            month: s.get_month(),
+        },
+        calendar_type: s.get_type(),
+    },
+    |d| IslamicMonth::new(d.value.year, d.value.month, d.calendar_type)
+);
+
+serde_via!(
+    IslamicDay,
+    CalendarTypeShape<DayShape<usize, usize>, IslamicType>,
+    |s| CalendarTypeShape {
+        value: DayShape {
+            year: s.get_year(),
+            month: s.get_month(),
+            day: s.get_day(),
+        },
+        calendar_type: s.get_type(),
+    },
+    |d| IslamicDay::new(d.value.year, d.value.month, d.value.day, d.calendar_type)
+);
+
+#[cfg(test)]
+mod tests {
+    use serde::de::DeserializeOwned;
+    use serde::Serialize;
+
+    use crate::tyme::astro::planet::Planet;
+    use crate::tyme::culture::dog::{Dog, DogDay};
+    use crate::tyme::culture::fetus::{FetusDay, FetusEarthBranch, FetusHeavenStem, FetusMonth};
+    use crate::tyme::culture::nine::{Nine, NineDay};
+    use crate::tyme::culture::panchang::{Karana, Nakshatra, Yoga};
+    use crate::tyme::culture::peng_zu::{PengZu, PengZuEarthBranch, PengZuHeavenStem};
+    use crate::tyme::culture::phenology::{Phenology, PhenologyDay, ThreePhenology};
+    use crate::tyme::culture::plumrain::{PlumRain, PlumRainDay};
+    use crate::tyme::culture::ren::minor::MinorRen;
+    use crate::tyme::culture::star::nine::{Dipper, NineStar};
+    use crate::tyme::culture::star::seven::SevenStar;
+    use crate::tyme::culture::star::six::SixStar;
+    use crate::tyme::culture::star::ten::TenStar;
+    use crate::tyme::culture::star::twelve::{Ecliptic, TwelveStar};
+    use crate::tyme::culture::star::twenty_eight::TwentyEightStar;
+    use crate::tyme::culture::{
+        Animal, Beast, Constellation, Direction, Duty, Element, God, KitchenGodSteed, Land, Luck,
+        Phase, PhaseDay, Sixty, Sound, Taboo, Ten, Terrain, Twenty, Week, Zodiac, Zone,
+    };
+    use crate::tyme::eightchar::{ChildLimit, DecadeFortune, EightChar, Fortune};
+    use crate::tyme::enums::{
+        Ayanamsa, CalendarReform, DaylightType, EclipseType, EventType, FestivalType, Gender,
+        HideHeavenStemType, IslamicType, Paksha, PersianType, PlanetEventType, Region, Side,
+        Tradition, YinYang,
+    };
+    use crate::tyme::era::{BuddhistYear, MinguoYear};
+    use crate::tyme::event::Event;
+    use crate::tyme::festival::{HebrewFestival, LunarFestival, RabByungFestival, SolarFestival};
+    use crate::tyme::hebrew::{HebrewDay, HebrewMonth, HebrewYear};
+    use crate::tyme::hindu::{Masa, Tithi, Vara};
+    use crate::tyme::holiday::{KoreanHoliday, LegalHoliday, VietnameseHoliday};
+    use crate::tyme::islamic::{IslamicDay, IslamicMonth, IslamicYear};
+    use crate::tyme::japanese::{JapaneseHoliday, JapaneseYear};
+    use crate::tyme::jd::JulianDay;
+    use crate::tyme::lunar::{LunarDay, LunarHour, LunarMonth, LunarSeason, LunarWeek, LunarYear};
+    use crate::tyme::persian::{PersianDay, PersianYear};
+    use crate::tyme::rabbyung::{
+        RabByungDay, RabByungElement, RabByungMonth, RabByungYear, SparKha,
+    };
+    use crate::tyme::reform::{JulianCalendarDay, ReformedDay};
+    use crate::tyme::sixtycycle::{
+        EarthBranch, HeavenStem, HideHeavenStem, SixtyCycle, SixtyCycleDay, SixtyCycleHour,
+        SixtyCycleMonth, SixtyCycleYear, ThreePillars,
+    };
+    use crate::tyme::solar::{
+        IsoWeek, SolarDay, SolarHalfYear, SolarMonth, SolarSeason, SolarTerm, SolarTermDay,
+        SolarTime, SolarWeek, SolarYear,
+    };
+    use crate::tyme::zone::{UtcOffset, ZonedLunarDay, ZonedLunarMonth, ZonedSolarTime};
+    use crate::tyme::{Culture, Tyme};
+
+    /// 序列化后再反序列化，结果须与原值序列化一致
+    fn round_trip<T: Serialize + DeserializeOwned>(t: &T) -> String {
+        let json: String = serde_json::to_string(t).unwrap();
+        let back: T = serde_json::from_str(&json).unwrap();
+        assert_eq!(json, serde_json::to_string(&back).unwrap());
+        json
+    }
+
+    #[test]
+    fn test0() {
+        let json: String = round_trip(&HeavenStem::from_index(0));
+        assert_eq!(r#"{"index":0,"name":"甲"}"#, json);
+        assert_eq!(
+            HeavenStem::from_name("丙"),
+            serde_json::from_str::<HeavenStem>(r#"{"index":2}"#).unwrap()
+        );
+        assert!(serde_json::from_str::<HeavenStem>(r#"{"index":0,"name":"乙"}"#).is_err());
+        assert!(serde_json::from_str::<HeavenStem>(r#"{"index":10}"#).is_err());
+    }
+
+    #[test]
+    fn test1() {
+        round_trip(&Animal::from_index(27));
+        round_trip(&Beast::from_index(3));
+        round_trip(&Constellation::from_index(11));
+        round_trip(&Direction::from_index(8));
+        round_trip(&Duty::from_index(11));
+        round_trip(&Element::from_index(4));
+        round_trip(&God::from_index(100));
+        round_trip(&Land::from_index(8));
+        round_trip(&Luck::from_index(1));
+        round_trip(&Sixty::from_index(2));
+        round_trip(&Sound::from_index(29));
+        round_trip(&Taboo::from_index(50));
+        round_trip(&Ten::from_index(5));
+        round_trip(&Terrain::from_index(11));
+        round_trip(&Twenty::from_index(19));
+        round_trip(&Week::from_index(6));
+        round_trip(&Zodiac::from_index(11));
+        round_trip(&Zone::from_index(3));
+        round_trip(&Dog::from_index(2));
+        round_trip(&Nine::from_index(8));
+        round_trip(&PlumRain::from_index(1));
+        round_trip(&ThreePhenology::from_index(2));
+        round_trip(&PengZuHeavenStem::from_index(9));
+        round_trip(&PengZuEarthBranch::from_index(11));
+        round_trip(&MinorRen::from_index(5));
+        round_trip(&FetusHeavenStem::from_index(4));
+        round_trip(&FetusEarthBranch::from_index(5));
+        round_trip(&FetusMonth::from_index(11));
+        round_trip(&Dipper::from_index(8));
+        round_trip(&NineStar::from_index(8));
+        round_trip(&SevenStar::from_index(6));
+        round_trip(&SixStar::from_index(5));
+        round_trip(&TenStar::from_index(9));
+        round_trip(&Ecliptic::from_index(1));
+        round_trip(&TwelveStar::from_index(11));
+        round_trip(&TwentyEightStar::from_index(27));
+        round_trip(&EarthBranch::from_index(11));
+        round_trip(&LunarSeason::from_index(11));
+        assert_eq!(
+            r#"{"index":59,"name":"癸亥"}"#,
+            round_trip(&SixtyCycle::from_index(59))
+        );
+        assert_eq!(
+            r#"{"index":3,"name":"铁"}"#,
+            round_trip(&RabByungElement::from_index(3))
+        );
+        assert_eq!(
+            r#"{"index":7,"name":"巽"}"#,
+            round_trip(&SparKha::from_index(7))
+        );
+        assert_eq!(
+            r#"{"index":3,"name":"岁星"}"#,
+            round_trip(&Planet::from_index(3))
+        );
+        assert_eq!(
+            r#"{"index":26,"name":"奎"}"#,
+            round_trip(&Nakshatra::from_index(26))
+        );
+        assert_eq!(
+            r#"{"index":15,"name":"成就"}"#,
+            round_trip(&Yoga::from_index(15))
+        );
+        assert_eq!(
+            r#"{"index":10,"name":"紧斯图那"}"#,
+            round_trip(&Karana::from_index(10))
+        );
+    }
+
+    #[test]
+    fn test2() {
+        assert_eq!(r#"{"code":1,"name":"男"}"#, round_trip(&Gender::MAN));
+        round_trip(&Gender::WOMAN);
+        round_trip(&FestivalType::EVE);
+        round_trip(&HideHeavenStemType::MIDDLE);
+        round_trip(&Side::OUT);
+        round_trip(&YinYang::YIN);
+        round_trip(&EventType::TermEb);
+        round_trip(&DaylightType::PolarNight);
+        assert_eq!(
+            r#"{"code":3,"name":"日全环食"}"#,
+            round_trip(&EclipseType::SolarHybrid)
+        );
+        round_trip(&PlanetEventType::StationaryDirect);
+        assert!(serde_json::from_str::<Gender>(r#"{"code":2}"#).is_err());
+        assert!(serde_json::from_str::<Gender>(r#"{"code":1,"name":"女"}"#).is_err());
+    }
+
+    #[test]
+    fn test3() {
+        assert_eq!(r#"{"year":2024}"#, round_trip(&SolarYear::from_year(2024)));
+        assert_eq!(
+            r#"{"year":2024,"index":1}"#,
+            round_trip(&SolarHalfYear::from_index(2024, 1))
+        );
+        round_trip(&SolarSeason::from_index(2024, 3));
+        assert_eq!(
+            r#"{"year":2024,"month":2}"#,
+            round_trip(&SolarMonth::from_ym(2024, 2))
+        );
+        assert_eq!(
+            r#"{"year":2024,"month":2,"index":4,"start":1}"#,
+            round_trip(&SolarWeek::from_ym(2024, 2, 4, 1))
+        );
+        assert_eq!(
+            r#"{"year":2024,"month":2,"day":29}"#,
+            round_trip(&SolarDay::from_ymd(2024, 2, 29))
+        );
+        assert_eq!(
+            r#"{"year":2024,"month":2,"day":29,"hour":23,"minute":59,"second":1}"#,
+            round_trip(&SolarTime::from_ymd_hms(2024, 2, 29, 23, 59, 1))
+        );
+        assert_eq!(
+            r#"{"year":2024,"index":4,"name":"雨水"}"#,
+            round_trip(&SolarTerm::from_name(2024, "雨水"))
+        );
+        assert_eq!(
+            r#"{"day":2451545.0}"#,
+            round_trip(&JulianDay::from_julian_day(2451545.0))
+        );
+        assert!(serde_json::from_str::<SolarDay>(r#"{"year":2023,"month":2,"day":29}"#).is_err());
+        assert!(serde_json::from_str::<SolarDay>(r#"{"year":1582,"month":10,"day":5}"#).is_err());
+        assert!(serde_json::from_str::<SolarTerm>(r#"{"year":2024,"index":24}"#).is_err());
+    }
+
+    #[test]
+    fn test4() {
+        assert_eq!(r#"{"year":2023}"#, round_trip(&LunarYear::from_year(2023)));
+        assert_eq!(
+            r#"{"year":2023,"month":-2}"#,
+            round_trip(&LunarMonth::from_ym(2023, -2))
+        );
+        assert_eq!(
+            r#"{"year":2023,"month":-2,"index":1,"start":0}"#,
+            round_trip(&LunarWeek::from_ym(2023, -2, 1, 0))
+        );
+        let day: LunarDay = LunarDay::from_ymd(2023, -2, 29);
+        assert_eq!(r#"{"year":2023,"month":-2,"day":29}"#, round_trip(&day));
+        assert_eq!(
+            day,
+            serde_json::from_str::<LunarDay>(r#"{"year":2023,"month":-2,"day":29}"#).unwrap()
+        );
+        assert_eq!(
+            r#"{"year":2023,"month":-2,"day":29,"hour":12,"minute":0,"second":0}"#,
+            round_trip(&LunarHour::from_ymd_hms(2023, -2, 29, 12, 0, 0))
+        );
+        assert!(serde_json::from_str::<LunarMonth>(r#"{"year":2023,"month":-6}"#).is_err());
+        assert!(serde_json::from_str::<LunarDay>(r#"{"year":2023,"month":1,"day":30}"#).is_err());
+    }
+
+    #[test]
+    fn test5() {
+        round_trip(&SixtyCycleYear::from_year(2024));
+        assert_eq!(
+            r#"{"year":2024,"index":11}"#,
+            round_trip(&SixtyCycleMonth::from_index(2024, 11))
+        );
+        assert_eq!(
+            r#"{"solar_day":{"year":2024,"month":2,"day":4}}"#,
+            round_trip(&SixtyCycleDay::from_solar_day(SolarDay::from_ymd(
+                2024, 2, 4
+            )))
+        );
+        round_trip(&SixtyCycleHour::from_solar_time(SolarTime::from_ymd_hms(
+            2024, 2, 4, 23, 30, 0,
+        )));
+        assert_eq!(
+            r#"{"year":{"index":0,"name":"甲子"},"month":{"index":2,"name":"丙寅"},"day":{"index":4,"name":"戊辰"}}"#,
+            round_trip(&ThreePillars::new("甲子", "丙寅", "戊辰"))
+        );
+        round_trip(&HideHeavenStem::from_name("癸", HideHeavenStemType::MAIN));
+        round_trip(&SolarDay::from_ymd(2024, 12, 1).get_hide_heaven_stem_day());
+        assert!(serde_json::from_str::<SixtyCycleMonth>(r#"{"year":2024,"index":12}"#).is_err());
+    }
+
+    #[test]
+    fn test6() {
+        let eight_char: EightChar = EightChar::new("丙寅", "癸巳", "癸酉", "己未");
+        assert_eq!(eight_char.to_string(), {
+            let json: String = round_trip(&eight_char);
+            serde_json::from_str::<EightChar>(&json)
+                .unwrap()
+                .to_string()
+        });
+        let child_limit: ChildLimit = ChildLimit::from_solar_time(
+            SolarTime::from_ymd_hms(2022, 3, 9, 20, 51, 0),
+            Gender::MAN,
+        );
+        let json: String = round_trip(&child_limit);
+        assert!(json.starts_with(
+            r#"{"birth_time":{"year":2022,"month":3,"day":9,"hour":20,"minute":51,"second":0},"gender":{"code":1,"name":"男"},"forward":true,"year_count":8"#
+        ));
+        let back: ChildLimit = serde_json::from_str(
+            r#"{"birth_time":{"year":2022,"month":3,"day":9,"hour":20,"minute":51,"second":0},"gender":{"code":1}}"#,
+        )
+        .unwrap();
+        assert_eq!(child_limit.get_end_time(), back.get_end_time());
+        let decade_fortune: DecadeFortune = child_limit.get_start_decade_fortune().next(2);
+        let json: String = round_trip(&decade_fortune);
+        let back: DecadeFortune = serde_json::from_str(&json).unwrap();
+        assert_eq!(decade_fortune.get_name(), back.get_name());
+        let fortune: Fortune = child_limit.get_start_fortune().next(5);
+        let json: String = round_trip(&fortune);
+        let back: Fortune = serde_json::from_str(&json).unwrap();
+        assert_eq!(fortune.get_name(), back.get_name());
+    }
+
+    #[test]
+    fn test7() {
+        round_trip(&RabByungYear::from_year(2024).unwrap());
+        assert_eq!(
+            r#"{"year":2024,"month":2}"#,
+            round_trip(&RabByungMonth::from_ym(2024, 2).unwrap())
+        );
+        for d in RabByungMonth::from_ym(1951, 1).unwrap().get_days() {
+            let json: String = round_trip(&d);
+            assert_eq!(
+                d.to_string(),
+                serde_json::from_str::<RabByungDay>(&json)
+                    .unwrap()
+                    .to_string()
+            );
+        }
+        assert!(serde_json::from_str::<RabByungYear>(r#"{"year":1026}"#).is_err());
+    }
+
+    #[test]
+    fn test8() {
+        round_trip(&Phenology::from_index(2024, 71));
+        let phase: Phase = Phase::from_index(2023, -2, 3);
+        assert_eq!(
+            r#"{"lunar_year":2023,"lunar_month":-2,"index":3,"name":"盈凸月"}"#,
+            round_trip(&phase)
+        );
+        round_trip(&NineDay::new(Nine::from_index(3), 4));
+        round_trip(&DogDay::new(Dog::from_index(1), 9));
+        round_trip(&PlumRainDay::new(PlumRain::from_index(0), 2));
+        round_trip(&PhenologyDay::new(Phenology::from_index(2024, 5), 3));
+        round_trip(&PhaseDay::new(phase, 1));
+        assert_eq!(
+            r#"{"culture":{"year":2024,"index":1,"name":"小寒"},"day_index":5}"#,
+            round_trip(&SolarTermDay::new(SolarTerm::from_index(2024, 1), 5))
+        );
+        for i in 0..60 {
+            let sixty_cycle: SixtyCycle = SixtyCycle::from_index(i);
+            let fetus_day: FetusDay = FetusDay::new(sixty_cycle.clone());
+            let json: String = round_trip(&fetus_day);
+            assert_eq!(
+                fetus_day.to_string(),
+                serde_json::from_str::<FetusDay>(&json).unwrap().to_string()
+            );
+            let peng_zu: PengZu = PengZu::from_sixty_cycle(sixty_cycle);
+            let json: String = round_trip(&peng_zu);
+            assert_eq!(
+                peng_zu.to_string(),
+                serde_json::from_str::<PengZu>(&json).unwrap().to_string()
+            );
+        }
+        let steed: KitchenGodSteed = KitchenGodSteed::from_lunar_year(2017);
+        let json: String = round_trip(&steed);
+        let back: KitchenGodSteed = serde_json::from_str(&json).unwrap();
+        assert_eq!(steed.get_mouse(), back.get_mouse());
+        assert_eq!(steed.get_people_hoes(), back.get_people_hoes());
+    }
+
+    #[test]
+    fn test9() {
+        for i in 0..10 {
+            let f: SolarFestival = SolarFestival::from_index(2024, i).unwrap();
+            let json: String = round_trip(&f);
+            assert_eq!(f, serde_json::from_str::<SolarFestival>(&json).unwrap());
+        }
+        for i in 0..13 {
+            let f: LunarFestival = LunarFestival::from_index(2024, i).unwrap();
+            let json: String = round_trip(&f);
+            assert_eq!(f, serde_json::from_str::<LunarFestival>(&json).unwrap());
+        }
+        let holiday: LegalHoliday = LegalHoliday::from_ymd(2024, 2, 18).unwrap();
+        assert_eq!(
+            r#"{"day":{"year":2024,"month":2,"day":18},"name":"春节","work":true}"#,
+            round_trip(&holiday)
+        );
+        assert!(
+            serde_json::from_str::<LegalHoliday>(r#"{"day":{"year":2024,"month":3,"day":1}}"#)
+                .is_err()
+        );
+        let event: Event = Event::builder()
+            .name("程序员节")
+            .solar_day(10, 24, 0)
+            .start_year(2015)
+            .build();
+        let json: String = round_trip(&event);
+        assert_eq!(event, serde_json::from_str::<Event>(&json).unwrap());
+        assert!(serde_json::from_str::<Event>(r#"{"name":"x","data":"invalid"}"#).is_err());
+    }
+
+    #[test]
+    fn test10() {
+        let offset: UtcOffset = UtcOffset::from_hm(7, 0);
+        assert_eq!(r#"{"seconds":25200}"#, round_trip(&offset));
+        assert!(serde_json::from_str::<UtcOffset>(r#"{"seconds":90000}"#).is_err());
+        let t: ZonedSolarTime = ZonedSolarTime::from_ymd_hms(2024, 2, 10, 5, 59, 11, offset);
+        assert_eq!(
+            r#"{"year":2024,"month":2,"day":10,"hour":5,"minute":59,"second":11,"offset":{"seconds":25200}}"#,
+            round_trip(&t)
+        );
+        let m: ZonedLunarMonth = ZonedLunarMonth::from_ym(1985, -2, offset);
+        assert_eq!(
+            r#"{"year":1985,"month":-2,"offset":{"seconds":25200}}"#,
+            round_trip(&m)
+        );
+        assert_eq!(
+            m.get_zoned_lunar_year(),
+            serde_json::from_str(r#"{"year":1985,"offset":{"seconds":25200}}"#).unwrap()
+        );
+        let d: ZonedLunarDay = m.get_first_day();
+        assert_eq!(d, serde_json::from_str(&round_trip(&d)).unwrap());
+        assert!(serde_json::from_str::<ZonedLunarMonth>(
+            r#"{"year":1985,"month":-2,"offset":{"seconds":28800}}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test11() {
+        assert_eq!(
+            r#"{"code":1,"name":"乌姆库拉历"}"#,
+            round_trip(&IslamicType::UmmAlQura)
+        );
+        assert_eq!(
+            r#"{"code":2,"name":"MABIMS历"}"#,
+            round_trip(&IslamicType::Mabims)
+        );
+        // 格式版本1的名称
+        assert!(serde_json::from_str::<IslamicType>(r#"{"code":1,"name":"天文历"}"#).is_err());
+        let d: IslamicDay = IslamicDay::from_ymd(1445, 9, 1, IslamicType::UmmAlQura);
+        let json: String = round_trip(&d);
+        assert_eq!(
+            r#"{"year":1445,"month":9,"day":1,"type":{"code":1,"name":"乌姆库拉历"}}"#,
+            json
+        );
+        assert_eq!(d, serde_json::from_str(&json).unwrap());
+        let m: IslamicMonth = d.get_islamic_month();
+        assert_eq!(m, serde_json::from_str(&round_trip(&m)).unwrap());
+        assert_eq!(
+            IslamicYear::from_year(1445, IslamicType::Tabular),
+            serde_json::from_str(r#"{"year":1445,"type":{"code":0}}"#).unwrap()
+        );
+        assert!(serde_json::from_str::<IslamicDay>(
+            r#"{"year":1446,"month":12,"day":30,"type":{"code":0}}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test12() {
+        let d: HebrewDay = HebrewDay::from_ymd(5784, 13, 14);
+        assert_eq!(r#"{"year":5784,"month":13,"day":14}"#, round_trip(&d));
+        let m: HebrewMonth = d.get_hebrew_month();
+        assert_eq!(
+            m,
+            serde_json::from_str(r#"{"year":5784,"month":13}"#).unwrap()
+        );
+        assert_eq!(
+            HebrewYear::from_year(5785),
+            serde_json::from_str(&round_trip(&HebrewYear::from_year(5785))).unwrap()
+        );
+        assert!(serde_json::from_str::<HebrewDay>(r#"{"year":5785,"month":13,"day":1}"#).is_err());
+        let f: HebrewFestival = d.get_festival().unwrap();
+        let json: String = round_trip(&f);
+        assert_eq!(
+            r#"{"day":{"year":5784,"month":13,"day":14},"index":7,"name":"普珥节"}"#,
+            json
+        );
+        assert_eq!(f, serde_json::from_str(&json).unwrap());
+    }
+
+    #[test]
+    fn test13() {
+        let d: PersianDay = PersianDay::from_ymd(1403, 12, 30, PersianType::Astronomical);
+        let json: String = round_trip(&d);
+        assert_eq!(
+            r#"{"year":1403,"month":12,"day":30,"type":{"code":1,"name":"天文历"}}"#,
+            json
+        );
+        assert_eq!(d, serde_json::from_str(&json).unwrap());
+        assert_eq!(
+            PersianYear::from_year(1403, PersianType::Arithmetic),
+            serde_json::from_str(r#"{"year":1403,"type":{"code":0}}"#).unwrap()
+        );
+        assert!(serde_json::from_str::<PersianDay>(
+            r#"{"year":1404,"month":12,"day":30,"type":{"code":1}}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test14() {
+        let d: ReformedDay = ReformedDay::from_ymd(1752, 9, 2, CalendarReform::Britain);
+        let json: String = round_trip(&d);
+        assert_eq!(
+            r#"{"year":1752,"month":9,"day":2,"type":{"code":1,"name":"英国"}}"#,
+            json
+        );
+        assert!(serde_json::from_str::<ReformedDay>(
+            r#"{"year":1752,"month":9,"day":3,"type":{"code":1}}"#
+        )
+        .is_err());
+        let j: JulianCalendarDay = JulianCalendarDay::from_ymd(1900, 2, 29);
+        assert_eq!(r#"{"year":1900,"month":2,"day":29}"#, round_trip(&j));
+    }
+
+    #[test]
+    fn test15() {
+        let w: IsoWeek = IsoWeek::from_yw(2020, 53);
+        let json: String = round_trip(&w);
+        assert_eq!(r#"{"year":2020,"week":53}"#, json);
+        assert!(serde_json::from_str::<IsoWeek>(r#"{"year":2021,"week":53}"#).is_err());
+    }
+
+    #[test]
+    fn test16() {
+        let y: JapaneseYear = JapaneseYear::from_ey("令和", 6);
+        assert_eq!(
+            r#"{"era":{"index":40,"name":"令和"},"year":6}"#,
+            round_trip(&y)
+        );
+        let h: JapaneseHoliday = JapaneseHoliday::from_ymd(2024, 2, 12).unwrap();
+        let json: String = round_trip(&h);
+        assert_eq!(
+            r#"{"day":{"year":2024,"month":2,"day":12},"index":17,"name":"振替休日"}"#,
+            json
+        );
+        assert!(serde_json::from_str::<JapaneseHoliday>(
+            r#"{"day":{"year":2024,"month":2,"day":13},"index":17}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test17() {
+        let h: KoreanHoliday = KoreanHoliday::from_ymd(2025, 5, 5)
+            .unwrap()
+            .next(1)
+            .unwrap();
+        let json: String = round_trip(&h);
+        assert_eq!(
+            r#"{"day":{"year":2025,"month":5,"day":5},"index":4,"name":"佛诞日"}"#,
+            json
+        );
+        assert!(serde_json::from_str::<KoreanHoliday>(
+            r#"{"day":{"year":2025,"month":5,"day":6},"index":4}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test18() {
+        let h: VietnameseHoliday = VietnameseHoliday::from_ymd(2024, 4, 18).unwrap();
+        let json: String = round_trip(&h);
+        assert_eq!(
+            r#"{"day":{"year":2024,"month":4,"day":18},"index":2,"name":"雄王忌日"}"#,
+            json
+        );
+        assert!(serde_json::from_str::<VietnameseHoliday>(
+            r#"{"day":{"year":2024,"month":4,"day":18},"index":2,"name":"春节"}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test19() {
+        let y: MinguoYear = MinguoYear::from_year(113);
+        assert_eq!(r#"{"year":113}"#, round_trip(&y));
+        assert!(serde_json::from_str::<MinguoYear>(r#"{"year":0}"#).is_err());
+        let y: BuddhistYear = BuddhistYear::from_year(2567);
+        assert_eq!(r#"{"year":2567}"#, round_trip(&y));
+    }
+
+    #[test]
+    fn test20() {
+        let h: LegalHoliday = LegalHoliday::from_ymd_in(Region::Taiwan, 2024, 2, 17).unwrap();
+        assert_eq!(
+            r#"{"day":{"year":2024,"month":2,"day":17},"name":"春节","work":true,"region":{"code":3,"name":"台湾"}}"#,
+            round_trip(&h)
+        );
+        let h: LegalHoliday = LegalHoliday::from_ymd_in(Region::Thailand, 2024, 4, 16).unwrap();
+        assert_eq!(
+            r#"{"day":{"year":2024,"month":4,"day":16},"name":"补假","work":false,"region":{"code":5,"name":"泰国"}}"#,
+            round_trip(&h)
+        );
+        assert!(serde_json::from_str::<LegalHoliday>(
+            r#"{"day":{"year":2024,"month":4,"day":16},"region":{"code":1}}"#
+        )
+        .is_err());
+        assert_eq!(
+            LegalHoliday::from_ymd(2024, 10, 1),
+            serde_json::from_str::<LegalHoliday>(r#"{"day":{"year":2024,"month":10,"day":1}}"#)
+                .ok()
+        );
+    }
+
+    #[test]
+    fn test21() {
+        assert_eq!(
+            r#"{"code":1,"name":"蒙古"}"#,
+            round_trip(&Tradition::Mongolian)
+        );
+        assert_eq!(
+            r#"{"code":3,"name":"不丹"}"#,
+            round_trip(&Tradition::Bhutanese)
+        );
+        let d: RabByungDay = RabByungDay::from_ymd_with(Tradition::Mongolian, 2022, 1, 1).unwrap();
+        let json: String = round_trip(&d);
+        assert_eq!(
+            r#"{"year":2022,"month":1,"day":1,"tradition":{"code":1,"name":"蒙古"}}"#,
+            json
+        );
+        assert_eq!(d, serde_json::from_str(&json).unwrap());
+        let m: RabByungMonth = d.get_rab_byung_month();
+        assert_eq!(m, serde_json::from_str(&round_trip(&m)).unwrap());
+        assert_eq!(
+            RabByungMonth::from_ym(2022, 1).unwrap(),
+            serde_json::from_str(r#"{"year":2022,"month":1}"#).unwrap()
+        );
+        assert!(serde_json::from_str::<RabByungMonth>(
+            r#"{"year":2021,"month":-9,"tradition":{"code":1}}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test22() {
+        let f: RabByungFestival = RabByungFestival::from_index(2008, 1).unwrap();
+        let json: String = round_trip(&f);
+        assert_eq!(
+            r#"{"day":{"year":2008,"month":1,"day":14},"index":1,"name":"神变节"}"#,
+            json
+        );
+        assert_eq!(f, serde_json::from_str(&json).unwrap());
+        assert!(serde_json::from_str::<RabByungFestival>(
+            r#"{"day":{"year":2008,"month":1,"day":15},"index":1}"#
+        )
+        .is_err());
+        assert!(serde_json::from_str::<RabByungFestival>(
+            r#"{"day":{"year":2008,"month":1,"day":13},"index":1}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test23() {
+        assert_eq!(
+            r#"{"index":29,"name":"晦日"}"#,
+            round_trip(&Tithi::from_index(29))
+        );
+        assert_eq!(
+            r#"{"index":1,"name":"月曜日"}"#,
+            round_trip(&Vara::from_index(1))
+        );
+        assert_eq!(
+            r#"{"index":4,"name":"室罗伐拏月"}"#,
+            round_trip(&Masa::from_index(4))
+        );
+        assert_eq!(r#"{"code":1,"name":"黑分"}"#, round_trip(&Paksha::Krishna));
+        assert_eq!(
+            r#"{"code":0,"name":"拉希里"}"#,
+            round_trip(&Ayanamsa::Lahiri)
+        );
+        assert!(serde_json::from_str::<Masa>(r#"{"index":12}"#).is_err());
+    }
+}
@@ -8,6 +8,7 @@ use crate::tyme::culture::{
 };
 use crate::tyme::eightchar::EightChar;
 use crate::tyme::enums::{HideHeavenStemType, YinYang};
+use crate::tyme::error::TymeError;
 use crate::tyme::lunar::{LunarDay, LunarHour, LunarMonth, LunarYear};
 use crate::tyme::solar::{SolarDay, SolarTerm, SolarTime};
 use crate::tyme::{AbstractCulture, AbstractCultureDay, Culture, LoopTyme, Tyme};
@@ -64,16 +65,20 @@ impl HeavenStem {
     }
 
     pub fn from_name(name: &str) -> Self {
-        Self {
-            parent: LoopTyme::from_name(
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
                 HEAVEN_STEM_NAMES
                     .to_vec()
                     .iter()
                     .map(|x| x.to_string())
                     .collect(),
                 name,
-            ),
-        }
+            )?,
+        })
     }
 
     /// 五行
@@ -227,16 +232,20 @@ impl EarthBranch {
     }
 
     pub fn from_name(name: &str) -> Self {
-        Self {
-            parent: LoopTyme::from_name(
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
                 EARTH_BRANCH_NAMES
                     .to_vec()
                     .iter()
                     .map(|x| x.to_string())
                     .collect(),
                 name,
-            ),
-        }
+            )?,
+        })
     }
 
     /// 五行
@@ -412,16 +421,20 @@ impl SixtyCycle {
     }
 
     pub fn from_name(name: &str) -> Self {
-        Self {
-            parent: LoopTyme::from_name(
+        Self::try_from_name(name).unwrap()
+    }
+
+    pub fn try_from_name(name: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            parent: LoopTyme::new(
                 SIXTY_CYCLE_NAMES
                     .to_vec()
                     .iter()
                     .map(|x| x.to_string())
                     .collect(),
                 name,
-            ),
-        }
+            )?,
+        })
     }
 
     pub fn get_heaven_stem(&self) -> HeavenStem {
@@ -509,11 +522,17 @@ impl HideHeavenStem {
     }
 
     pub fn from_name(heaven_stem_name: &str, hide_heaven_stem_type: HideHeavenStemType) -> Self {
-        Self {
-            parent: AbstractCulture::new(),
-            heaven_stem: HeavenStem::from_name(heaven_stem_name),
+        Self::try_from_name(heaven_stem_name, hide_heaven_stem_type).unwrap()
+    }
+
+    pub fn try_from_name(
+        heaven_stem_name: &str,
+        hide_heaven_stem_type: HideHeavenStemType,
+    ) -> Result<Self, TymeError> {
+        Ok(Self::new(
+            HeavenStem::try_from_name(heaven_stem_name)?,
             hide_heaven_stem_type,
-        }
+        ))
     }
 
     pub fn get_heaven_stem(&self) -> HeavenStem {
@@ -622,7 +641,7 @@ pub struct SixtyCycleYear {
 
 impl Tyme for SixtyCycleYear {
     fn next(&self, n: isize) -> Self {
-        Self::from_year(self.year + n)
+        self.try_next(n).unwrap()
     }
 }
 
@@ -633,9 +652,14 @@ impl Culture for SixtyCycleYear {
 }
 
 impl SixtyCycleYear {
-    pub fn new(year: isize) -> Result<Self, String> {
+    pub fn new(year: isize) -> Result<Self, TymeError> {
         if !(-1..=9999).contains(&year) {
-            Err(format!("illegal sixty cycle year: {}", year))
+            Err(TymeError::YearOutOfRange {
+                calendar: "sixty cycle",
+                year,
+                min: -1,
+                max: 9999,
+            })
         } else {
             Ok(Self { year })
         }
@@ -645,6 +669,15 @@ impl SixtyCycleYear {
         Self::new(year).unwrap()
     }
 
+    pub fn try_from_year(year: isize) -> Result<Self, TymeError> {
+        Self::new(year)
+    }
+
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        Self::new(self.year + n)
+    }
+
     pub fn get_year(&self) -> isize {
         self.year
     }
@@ -714,12 +747,7 @@ pub struct SixtyCycleMonth {
 
 impl Tyme for SixtyCycleMonth {
     fn next(&self, n: isize) -> Self {
-        SixtyCycleMonth {
-            year: SixtyCycleYear::from_year(
-                (self.year.get_year() * 12 + self.get_index_in_year() as isize + n) / 12,
-            ),
-            month: self.month.next(n),
-        }
+        self.try_next(n).unwrap()
     }
 }
 
@@ -731,9 +759,21 @@ impl Culture for SixtyCycleMonth {
 
 impl SixtyCycleMonth {
     pub fn from_index(year: isize, index: isize) -> Self {
-        SixtyCycleYear::from_year(year)
-            .get_first_month()
-            .next(index)
+        Self::try_from_index(year, index).unwrap()
+    }
+
+    pub fn try_from_index(year: isize, index: isize) -> Result<Self, TymeError> {
+        SixtyCycleYear::new(year)?.get_first_month().try_next(index)
+    }
+
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        Ok(SixtyCycleMonth {
+            year: SixtyCycleYear::new(
+                (self.year.get_year() * 12 + self.get_index_in_year() as isize + n) / 12,
+            )?,
+            month: self.month.next(n),
+        })
     }
 
     pub fn get_sixty_cycle_year(&self) -> SixtyCycleYear {
@@ -819,7 +859,7 @@ pub struct SixtyCycleDay {
 
 impl Tyme for SixtyCycleDay {
     fn next(&self, n: isize) -> Self {
-        SixtyCycleDay::from_solar_day(self.solar_day.next(n))
+        self.try_next(n).unwrap()
     }
 }
 
@@ -850,6 +890,11 @@ impl SixtyCycleDay {
         }
     }
 
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        Ok(SixtyCycleDay::from_solar_day(self.solar_day.try_next(n)?))
+    }
+
     /// 公历日
     pub fn get_solar_day(&self) -> SolarDay {
         self.solar_day
@@ -1014,7 +1059,7 @@ pub struct SixtyCycleHour {
 
 impl Tyme for SixtyCycleHour {
     fn next(&self, n: isize) -> Self {
-        SixtyCycleHour::from_solar_time(self.solar_time.next(n))
+        self.try_next(n).unwrap()
     }
 }
 
@@ -1073,6 +1118,13 @@ impl SixtyCycleHour {
         }
     }
 
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        Ok(SixtyCycleHour::from_solar_time(
+            self.solar_time.try_next(n)?,
+        ))
+    }
+
     pub fn get_year(&self) -> SixtyCycle {
         self.day.get_year()
     }
@@ -1175,11 +1227,15 @@ impl Culture for ThreePillars {
 
 impl ThreePillars {
     pub fn new(year: &str, month: &str, day: &str) -> Self {
-        Self {
-            year: SixtyCycle::from_name(year),
-            month: SixtyCycle::from_name(month),
-            day: SixtyCycle::from_name(day),
-        }
+        Self::try_new(year, month, day).unwrap()
+    }
+
+    pub fn try_new(year: &str, month: &str, day: &str) -> Result<Self, TymeError> {
+        Ok(Self {
+            year: SixtyCycle::try_from_name(year)?,
+            month: SixtyCycle::try_from_name(month)?,
+            day: SixtyCycle::try_from_name(day)?,
+        })
     }
 
     pub fn from_sixty_cycle(year: SixtyCycle, month: SixtyCycle, day: SixtyCycle) -> Self {
@@ -1261,7 +1317,8 @@ impl Eq for ThreePillars {}
 
 #[cfg(test)]
 mod tests {
-    use crate::tyme::sixtycycle::{EarthBranch, HeavenStem, SixtyCycle};
+    use crate::tyme::error::TymeError;
+    use crate::tyme::sixtycycle::{EarthBranch, HeavenStem, SixtyCycle, ThreePillars};
     use crate::tyme::solar::SolarDay;
     use crate::tyme::Culture;
 
@@ -1416,4 +1473,17 @@ mod tests {
                 .get_name()
         );
     }
+
+    #[test]
+    fn test13() {
+        assert_eq!(
+            Err(TymeError::UnknownName {
+                kind: "",
+                name: "甲丑".to_string()
+            }),
+            SixtyCycle::try_from_name("甲丑")
+        );
+        assert!(HeavenStem::try_from_name("子").is_err());
+        assert!(ThreePillars::try_new("甲子", "乙丑", "丙丑").is_err());
+    }
 }
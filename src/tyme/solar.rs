@@ -1,18 +1,28 @@
+use crate::tyme::astro::eclipse::Eclipse;
+use crate::tyme::astro::moon::{MoonEvents, MoonPosition};
+use crate::tyme::astro::sun::SunEvents;
 use crate::tyme::culture::dog::{Dog, DogDay};
 use crate::tyme::culture::nine::{Nine, NineDay};
 use crate::tyme::culture::phenology::{Phenology, PhenologyDay};
 use crate::tyme::culture::plumrain::{PlumRain, PlumRainDay};
 use crate::tyme::culture::{Constellation, Phase, PhaseDay, Week};
-use crate::tyme::enums::HideHeavenStemType;
+use crate::tyme::enums::{HideHeavenStemType, IslamicType, JapaneseEraType, PersianType, Region};
+use crate::tyme::era::{BuddhistYear, MinguoYear};
+use crate::tyme::error::TymeError;
 use crate::tyme::event::Event;
 use crate::tyme::festival::SolarFestival;
+use crate::tyme::hebrew::HebrewDay;
 use crate::tyme::holiday::LegalHoliday;
+use crate::tyme::islamic::IslamicDay;
+use crate::tyme::japanese::JapaneseYear;
 use crate::tyme::jd::{JulianDay, J2000};
 use crate::tyme::lunar::{LunarDay, LunarHour, LunarMonth};
+use crate::tyme::persian::PersianDay;
 use crate::tyme::rabbyung::{RabByungDay, RabByungYear};
 use crate::tyme::sixtycycle::{HideHeavenStem, HideHeavenStemDay, SixtyCycleDay, SixtyCycleHour};
 use crate::tyme::unit::{DayUnit, MonthUnit, SecondUnit, WeekUnit, YearUnit};
 use crate::tyme::util::ShouXingUtil;
+use crate::tyme::zone::{UtcOffset, ZonedLunarDay, ZonedSolarTime};
 use crate::tyme::{AbstractCulture, AbstractCultureDay, AbstractTyme, Culture, LoopTyme, Tyme};
 use std::fmt::{Display, Formatter};
 use std::ops::{Deref, DerefMut};
@@ -26,7 +36,7 @@ pub struct SolarYear {
 
 impl Tyme for SolarYear {
     fn next(&self, n: isize) -> Self {
-        Self::from_year(self.get_year() + n)
+        self.try_next(n).unwrap()
     }
 }
 
@@ -51,16 +61,21 @@ impl DerefMut for SolarYear {
 }
 
 impl SolarYear {
-    pub fn new(year: isize) -> Result<Self, String> {
+    pub fn new(year: isize) -> Result<Self, TymeError> {
         Self::validate(year)?;
         Ok(Self {
             parent: YearUnit::new(year),
         })
     }
 
-    pub fn validate(year: isize) -> Result<(), String> {
+    pub fn validate(year: isize) -> Result<(), TymeError> {
         if !(1..=9999).contains(&year) {
-            Err(format!("illegal solar year: {}", year))
+            Err(TymeError::YearOutOfRange {
+                calendar: "solar",
+                year,
+                min: 1,
+                max: 9999,
+            })
         } else {
             Ok(())
         }
@@ -70,6 +85,15 @@ impl SolarYear {
         Self::new(year).unwrap()
     }
 
+    pub fn try_from_year(year: isize) -> Result<Self, TymeError> {
+        Self::new(year)
+    }
+
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        Self::new(self.get_year() + n)
+    }
+
     /// 当年总天数
     ///
     /// # 示例
@@ -147,6 +171,47 @@ impl SolarYear {
         l
     }
 
+    /// 日食和月食列表（按食甚的北京时间）
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::astro::eclipse::Eclipse;
+    /// use tyme4rs::tyme::solar::SolarYear;
+    ///
+    /// let eclipses: Vec<Eclipse> = SolarYear::from_year(2024).get_eclipses();
+    /// assert_eq!("2024年4月9日 02:17:46 日全食", eclipses[1].to_string());
+    /// ```
+    pub fn get_eclipses(&self) -> Vec<Eclipse> {
+        Eclipse::from_year(self.get_year())
+    }
+
+    /// 民国纪年，早于1912年时返回错误
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::solar::SolarYear;
+    ///
+    /// assert_eq!("民国113年", SolarYear::from_year(2024).get_minguo_year().unwrap().to_string());
+    /// ```
+    pub fn get_minguo_year(&self) -> Result<MinguoYear, TymeError> {
+        MinguoYear::try_from_solar_year(*self)
+    }
+
+    /// 佛历纪年（泰国）
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::solar::SolarYear;
+    ///
+    /// assert_eq!("พ.ศ. 2567", SolarYear::from_year(2024).get_buddhist_year().to_string());
+    /// ```
+    pub fn get_buddhist_year(&self) -> BuddhistYear {
+        BuddhistYear::from_solar_year(*self)
+    }
+
     /// 公历半年列表
     ///
     /// # 示例
@@ -167,7 +232,7 @@ impl SolarYear {
     }
 
     /// 藏历年
-    pub fn get_rab_byung_year(&self) -> Result<RabByungYear, String> {
+    pub fn get_rab_byung_year(&self) -> Result<RabByungYear, TymeError> {
         RabByungYear::from_year(self.get_year())
     }
 }
@@ -199,8 +264,7 @@ pub struct SolarHalfYear {
 
 impl Tyme for SolarHalfYear {
     fn next(&self, n: isize) -> Self {
-        let i: isize = self.index as isize + n;
-        Self::from_index((self.get_year() * 2 + i) / 2, self.index_of(i, 2))
+        self.try_next(n).unwrap()
     }
 }
 
@@ -225,7 +289,7 @@ impl DerefMut for SolarHalfYear {
 }
 
 impl SolarHalfYear {
-    pub fn new(year: isize, index: usize) -> Result<Self, String> {
+    pub fn new(year: isize, index: usize) -> Result<Self, TymeError> {
         Self::validate(year, index)?;
         Ok(Self {
             parent: YearUnit::new(year),
@@ -233,9 +297,14 @@ impl SolarHalfYear {
         })
     }
 
-    pub fn validate(year: isize, index: usize) -> Result<(), String> {
+    pub fn validate(year: isize, index: usize) -> Result<(), TymeError> {
         if index > 1 {
-            Err(format!("illegal solar half year index: {}", index))
+            Err(TymeError::IndexOutOfRange {
+                kind: "solar half year",
+                index: index as isize,
+                min: 0,
+                max: 1,
+            })
         } else {
             SolarYear::validate(year)?;
             Ok(())
@@ -246,6 +315,16 @@ impl SolarHalfYear {
         Self::new(year, index).unwrap()
     }
 
+    pub fn try_from_index(year: isize, index: usize) -> Result<Self, TymeError> {
+        Self::new(year, index)
+    }
+
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        let i: isize = self.index as isize + n;
+        Self::new((self.get_year() * 2 + i) / 2, self.index_of(i, 2))
+    }
+
     /// 公历年
     ///
     /// # 示例
@@ -346,8 +425,7 @@ pub struct SolarSeason {
 
 impl Tyme for SolarSeason {
     fn next(&self, n: isize) -> Self {
-        let i: isize = self.index as isize + n;
-        Self::from_index((self.get_year() * 4 + i) / 4, self.index_of(i, 4))
+        self.try_next(n).unwrap()
     }
 }
 
@@ -372,7 +450,7 @@ impl DerefMut for SolarSeason {
 }
 
 impl SolarSeason {
-    pub fn new(year: isize, index: usize) -> Result<Self, String> {
+    pub fn new(year: isize, index: usize) -> Result<Self, TymeError> {
         Self::validate(year, index)?;
         Ok(Self {
             parent: YearUnit::new(year),
@@ -380,9 +458,14 @@ impl SolarSeason {
         })
     }
 
-    pub fn validate(year: isize, index: usize) -> Result<(), String> {
+    pub fn validate(year: isize, index: usize) -> Result<(), TymeError> {
         if index > 3 {
-            Err(format!("illegal solar season index: {}", index))
+            Err(TymeError::IndexOutOfRange {
+                kind: "solar season",
+                index: index as isize,
+                min: 0,
+                max: 3,
+            })
         } else {
             SolarYear::validate(year)?;
             Ok(())
@@ -393,6 +476,16 @@ impl SolarSeason {
         Self::new(year, index).unwrap()
     }
 
+    pub fn try_from_index(year: isize, index: usize) -> Result<Self, TymeError> {
+        Self::new(year, index)
+    }
+
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        let i: isize = self.index as isize + n;
+        Self::new((self.get_year() * 4 + i) / 4, self.index_of(i, 4))
+    }
+
     /// 公历年
     ///
     /// # 示例
@@ -480,8 +573,7 @@ pub struct SolarMonth {
 
 impl Tyme for SolarMonth {
     fn next(&self, n: isize) -> Self {
-        let i: isize = self.get_month() as isize - 1 + n;
-        Self::from_ym((self.get_year() * 12 + i) / 12, self.index_of(i, 12) + 1)
+        self.try_next(n).unwrap()
     }
 }
 
@@ -506,16 +598,20 @@ impl DerefMut for SolarMonth {
 }
 
 impl SolarMonth {
-    pub fn new(year: isize, month: usize) -> Result<Self, String> {
+    pub fn new(year: isize, month: usize) -> Result<Self, TymeError> {
         Self::validate(year, month)?;
         Ok(Self {
             parent: MonthUnit::new(year, month as isize),
         })
     }
 
-    pub fn validate(year: isize, month: usize) -> Result<(), String> {
+    pub fn validate(year: isize, month: usize) -> Result<(), TymeError> {
         if !(1..=12).contains(&month) {
-            Err(format!("illegal solar month: {}", month))
+            Err(TymeError::IllegalMonth {
+                calendar: "solar",
+                year,
+                month: month as isize,
+            })
         } else {
             SolarYear::validate(year)?;
             Ok(())
@@ -526,6 +622,16 @@ impl SolarMonth {
         Self::new(year, month).unwrap()
     }
 
+    pub fn try_from_ym(year: isize, month: usize) -> Result<Self, TymeError> {
+        Self::new(year, month)
+    }
+
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        let i: isize = self.get_month() as isize - 1 + n;
+        Self::new((self.get_year() * 12 + i) / 12, self.index_of(i, 12) + 1)
+    }
+
     /// 公历年
     ///
     /// # 示例
@@ -722,29 +828,7 @@ pub struct SolarWeek {
 
 impl Tyme for SolarWeek {
     fn next(&self, n: isize) -> Self {
-        let mut d: isize = self.get_index() as isize + n;
-        let mut m: SolarMonth = self.get_solar_month();
-        let start_index: usize = self.get_start();
-        if n > 0 {
-            let mut week_count: isize = m.get_week_count(start_index) as isize;
-            while d >= week_count {
-                d -= week_count;
-                m = m.next(1);
-                if m.get_first_day().get_week().get_index() != start_index {
-                    d += 1;
-                }
-                week_count = m.get_week_count(start_index) as isize;
-            }
-        } else if n < 0 {
-            while d < 0 {
-                if m.get_first_day().get_week().get_index() != start_index {
-                    d -= 1;
-                }
-                m = m.next(-1);
-                d += m.get_week_count(start_index) as isize;
-            }
-        }
-        Self::from_ym(m.get_year(), m.get_month(), d as usize, start_index)
+        self.try_next(n).unwrap()
     }
 }
 
@@ -769,21 +853,28 @@ impl DerefMut for SolarWeek {
 }
 
 impl SolarWeek {
-    pub fn new(year: isize, month: usize, index: usize, start: usize) -> Result<Self, String> {
+    pub fn new(year: isize, month: usize, index: usize, start: usize) -> Result<Self, TymeError> {
         Self::validate(year, month, index, start)?;
         Ok(Self {
             parent: WeekUnit::new(year, month as isize, index, start),
         })
     }
 
-    pub fn validate(year: isize, month: usize, index: usize, start: usize) -> Result<(), String> {
+    pub fn validate(
+        year: isize,
+        month: usize,
+        index: usize,
+        start: usize,
+    ) -> Result<(), TymeError> {
         WeekUnit::validate(index, start)?;
-        let m: SolarMonth = SolarMonth::from_ym(year, month);
+        let m: SolarMonth = SolarMonth::new(year, month)?;
         if index >= m.get_week_count(start) {
-            Err(format!(
-                "illegal solar week index: {} in month: {}",
-                index, m
-            ))
+            Err(TymeError::IndexOutOfRange {
+                kind: "solar week",
+                index: index as isize,
+                min: 0,
+                max: m.get_week_count(start) as isize - 1,
+            })
         } else {
             Ok(())
         }
@@ -793,6 +884,42 @@ impl SolarWeek {
         Self::new(year, month, index, start).unwrap()
     }
 
+    pub fn try_from_ym(
+        year: isize,
+        month: usize,
+        index: usize,
+        start: usize,
+    ) -> Result<Self, TymeError> {
+        Self::new(year, month, index, start)
+    }
+
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        let mut d: isize = self.get_index() as isize + n;
+        let mut m: SolarMonth = self.get_solar_month();
+        let start_index: usize = self.get_start();
+        if n > 0 {
+            let mut week_count: isize = m.get_week_count(start_index) as isize;
+            while d >= week_count {
+                d -= week_count;
+                m = m.try_next(1)?;
+                if m.get_first_day().get_week().get_index() != start_index {
+                    d += 1;
+                }
+                week_count = m.get_week_count(start_index) as isize;
+            }
+        } else if n < 0 {
+            while d < 0 {
+                if m.get_first_day().get_week().get_index() != start_index {
+                    d -= 1;
+                }
+                m = m.try_next(-1)?;
+                d += m.get_week_count(start_index) as isize;
+            }
+        }
+        Self::new(m.get_year(), m.get_month(), d as usize, start_index)
+    }
+
     /// 公历月
     ///
     /// # 示例
@@ -885,6 +1012,308 @@ impl PartialEq for SolarWeek {
 
 impl Eq for SolarWeek {}
 
+/// ISO周年（ISO 8601，以包含1月4日的周为第1周，周一为一周之始）
+///
+/// ISO 8601基于格里高利历，支持1583年至9999年，9999年的末周跨入10000年，不可表示。
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct IsoWeekYear {
+    year: isize,
+}
+
+impl Tyme for IsoWeekYear {
+    fn next(&self, n: isize) -> Self {
+        self.try_next(n).unwrap()
+    }
+}
+
+impl Culture for IsoWeekYear {
+    fn get_name(&self) -> String {
+        format!("{}年", self.year)
+    }
+}
+
+impl IsoWeekYear {
+    pub fn new(year: isize) -> Result<Self, TymeError> {
+        Self::validate(year)?;
+        Ok(Self { year })
+    }
+
+    pub fn validate(year: isize) -> Result<(), TymeError> {
+        if !(1583..=9999).contains(&year) {
+            Err(TymeError::YearOutOfRange {
+                calendar: "iso week",
+                year,
+                min: 1583,
+                max: 9999,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn from_year(year: isize) -> Self {
+        Self::new(year).unwrap()
+    }
+
+    pub fn try_from_year(year: isize) -> Result<Self, TymeError> {
+        Self::new(year)
+    }
+
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        Self::new(self.year + n)
+    }
+
+    pub fn get_year(&self) -> isize {
+        self.year
+    }
+
+    /// 第1周的周一
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::solar::IsoWeekYear;
+    ///
+    /// assert_eq!("2024年12月30日", IsoWeekYear::from_year(2025).get_first_day().to_string());
+    /// ```
+    pub fn get_first_day(&self) -> SolarDay {
+        let d: SolarDay = SolarDay::from_ymd(self.year, 1, 4);
+        d.next(-(d.get_week().next(-1).get_index() as isize))
+    }
+
+    /// 周数（52或53）
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::solar::IsoWeekYear;
+    ///
+    /// assert_eq!(53, IsoWeekYear::from_year(2020).get_week_count());
+    /// assert_eq!(52, IsoWeekYear::from_year(2024).get_week_count());
+    /// ```
+    pub fn get_week_count(&self) -> usize {
+        // 1月1日为周四，或闰年1月1日为周三时有53周
+        let w: usize = SolarDay::from_ymd(self.year, 1, 1).get_week().get_index();
+        if 4 == w || (3 == w && SolarYear::from_year(self.year).is_leap()) {
+            53
+        } else {
+            52
+        }
+    }
+
+    /// 可表示的最后一周，末周跨出公历9999年12月31日时为倒数第二周
+    fn get_last_week(&self) -> usize {
+        let count: usize = self.get_week_count();
+        match self.get_first_day().try_next(count as isize * 7 - 1) {
+            Ok(_) => count,
+            Err(_) => count - 1,
+        }
+    }
+
+    pub fn get_weeks(&self) -> Vec<IsoWeek> {
+        (1..=self.get_last_week())
+            .map(|w| IsoWeek {
+                year: self.year,
+                week: w,
+            })
+            .collect()
+    }
+}
+
+impl Display for IsoWeekYear {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.get_name())
+    }
+}
+
+/// ISO周（ISO 8601周历，如2024-W01）
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct IsoWeek {
+    year: isize,
+    week: usize,
+}
+
+impl Tyme for IsoWeek {
+    fn next(&self, n: isize) -> Self {
+        self.try_next(n).unwrap()
+    }
+}
+
+impl Culture for IsoWeek {
+    fn get_name(&self) -> String {
+        format!("第{}周", self.week)
+    }
+}
+
+impl IsoWeek {
+    pub fn new(year: isize, week: usize) -> Result<Self, TymeError> {
+        Self::validate(year, week)?;
+        Ok(Self { year, week })
+    }
+
+    pub fn validate(year: isize, week: usize) -> Result<(), TymeError> {
+        let count: usize = IsoWeekYear::new(year)?.get_last_week();
+        if !(1..=count).contains(&week) {
+            Err(TymeError::IndexOutOfRange {
+                kind: "iso week",
+                index: week as isize,
+                min: 1,
+                max: count as isize,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn from_yw(year: isize, week: usize) -> Self {
+        Self::new(year, week).unwrap()
+    }
+
+    pub fn try_from_yw(year: isize, week: usize) -> Result<Self, TymeError> {
+        Self::new(year, week)
+    }
+
+    /// 从公历日转换，超出ISO周年范围时返回错误
+    pub fn try_from_solar_day(solar_day: SolarDay) -> Result<Self, TymeError> {
+        // 所在周的周四决定ISO周年，周四超出公历范围时为相邻年份
+        let n: isize = 3 - solar_day.get_week().next(-1).get_index() as isize;
+        let year: isize = solar_day
+            .try_next(n)
+            .map_or(solar_day.get_year() + n.signum(), |d| d.get_year());
+        IsoWeekYear::validate(year)?;
+        Self::new(year, solar_day.next(n).get_index_in_year() / 7 + 1)
+    }
+
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        let d: SolarDay = self.get_first_day().try_next(n * 7)?;
+        Self::try_from_solar_day(d)
+    }
+
+    /// ISO周年
+    pub fn get_iso_week_year(&self) -> IsoWeekYear {
+        IsoWeekYear { year: self.year }
+    }
+
+    pub fn get_year(&self) -> isize {
+        self.year
+    }
+
+    /// 周序号（从1开始）
+    pub fn get_week(&self) -> usize {
+        self.week
+    }
+
+    /// 周一
+    pub fn get_first_day(&self) -> SolarDay {
+        self.get_iso_week_year()
+            .get_first_day()
+            .next((self.week as isize - 1) * 7)
+    }
+
+    /// 周几对应的公历日（1为周一，7为周日）
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::solar::IsoWeek;
+    ///
+    /// assert_eq!("2025年1月5日", IsoWeek::from_yw(2025, 1).get_day(7).unwrap().to_string());
+    /// ```
+    pub fn get_day(&self, day_of_week: usize) -> Result<SolarDay, TymeError> {
+        if !(1..=7).contains(&day_of_week) {
+            return Err(TymeError::IndexOutOfRange {
+                kind: "iso day of week",
+                index: day_of_week as isize,
+                min: 1,
+                max: 7,
+            });
+        }
+        Ok(self.get_first_day().next(day_of_week as isize - 1))
+    }
+
+    pub fn get_days(&self) -> Vec<SolarDay> {
+        let d: SolarDay = self.get_first_day();
+        (0..7).map(|i| d.next(i)).collect()
+    }
+
+    /// ISO 8601格式，如2024-W01
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::solar::IsoWeek;
+    ///
+    /// assert_eq!("2024-W01", IsoWeek::from_yw(2024, 1).to_iso_string());
+    /// ```
+    pub fn to_iso_string(&self) -> String {
+        format!("{:04}-W{:02}", self.year, self.week)
+    }
+
+    /// 解析ISO 8601周日期（如2024-W01-1），返回ISO周及周几（1为周一，7为周日），省略周几时为None
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::solar::IsoWeek;
+    ///
+    /// let (week, day) = IsoWeek::parse("2024-W01-1").unwrap();
+    /// assert_eq!(IsoWeek::from_yw(2024, 1), week);
+    /// assert_eq!(Some(1), day);
+    /// assert!(IsoWeek::parse("2024-W1").is_err());
+    /// ```
+    pub fn parse(text: &str) -> Result<(Self, Option<usize>), TymeError> {
+        let err = || TymeError::IllegalFormat {
+            kind: "iso week",
+            text: text.to_string(),
+        };
+        let digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+        let parts: Vec<&str> = text.split('-').collect();
+        if parts.len() < 2 || parts.len() > 3 {
+            return Err(err());
+        }
+        let w: &str = parts[1].strip_prefix('W').ok_or_else(err)?;
+        if 4 != parts[0].len() || !digits(parts[0]) || 2 != w.len() || !digits(w) {
+            return Err(err());
+        }
+        let week: Self = Self::new(
+            isize::from_str(parts[0]).map_err(|_| err())?,
+            usize::from_str(w).map_err(|_| err())?,
+        )?;
+        if 2 == parts.len() {
+            return Ok((week, None));
+        }
+        if 1 != parts[2].len() || !digits(parts[2]) {
+            return Err(err());
+        }
+        let day: usize = usize::from_str(parts[2]).map_err(|_| err())?;
+        week.get_day(day)?;
+        Ok((week, Some(day)))
+    }
+}
+
+impl FromStr for IsoWeek {
+    type Err = TymeError;
+
+    /// 解析ISO 8601周（如2024-W01），不含周几
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match Self::parse(s)? {
+            (week, None) => Ok(week),
+            _ => Err(TymeError::IllegalFormat {
+                kind: "iso week",
+                text: s.to_string(),
+            }),
+        }
+    }
+}
+
+impl Display for IsoWeek {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.get_iso_week_year(), self.get_name())
+    }
+}
+
 /// 公历日名称
 pub static SOLAR_DAY_NAMES: [&str; 31] = [
     "1日", "2日", "3日", "4日", "5日", "6日", "7日", "8日", "9日", "10日", "11日", "12日", "13日",
@@ -900,7 +1329,7 @@ pub struct SolarDay {
 
 impl Tyme for SolarDay {
     fn next(&self, n: isize) -> Self {
-        self.get_julian_day().next(n).get_solar_day()
+        self.try_next(n).unwrap()
     }
 }
 
@@ -925,24 +1354,36 @@ impl DerefMut for SolarDay {
 }
 
 impl SolarDay {
-    pub fn new(year: isize, month: usize, day: usize) -> Result<Self, String> {
+    pub fn new(year: isize, month: usize, day: usize) -> Result<Self, TymeError> {
         Self::validate(year, month, day)?;
         Ok(Self {
             parent: DayUnit::new(year, month as isize, day as isize),
         })
     }
 
-    pub fn validate(year: isize, month: usize, day: usize) -> Result<(), String> {
+    pub fn validate(year: isize, month: usize, day: usize) -> Result<(), TymeError> {
+        let err: TymeError = TymeError::IllegalDay {
+            calendar: "solar",
+            year,
+            month: month as isize,
+            day: day as isize,
+        };
         if day < 1 {
-            Err(format!("illegal solar day: {}-{}-{}", year, month, day))
+            Err(err)
         } else if 1582 == year && 10 == month {
-            if (day > 4 && day < 15) || day > 31 {
-                Err(format!("illegal solar day: {}-{}-{}", year, month, day))
+            if day > 4 && day < 15 {
+                Err(TymeError::GregorianGap {
+                    year,
+                    month: month as isize,
+                    day: day as isize,
+                })
+            } else if day > 31 {
+                Err(err)
             } else {
                 Ok(())
             }
-        } else if day > SolarMonth::from_ym(year, month).get_day_count() {
-            Err(format!("illegal solar day: {}-{}-{}", year, month, day))
+        } else if day > SolarMonth::new(year, month)?.get_day_count() {
+            Err(err)
         } else {
             Ok(())
         }
@@ -952,6 +1393,15 @@ impl SolarDay {
         Self::new(year, month, day).unwrap()
     }
 
+    pub fn try_from_ymd(year: isize, month: usize, day: usize) -> Result<Self, TymeError> {
+        Self::new(year, month, day)
+    }
+
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        self.get_julian_day().next(n).try_get_solar_day()
+    }
+
     /// 公历月
     ///
     /// # 示例
@@ -1035,6 +1485,57 @@ impl SolarDay {
         )
     }
 
+    /// ISO周，超出ISO周年范围（1583年至9999年）或所在周跨出公历范围时返回错误
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::solar::{IsoWeek, SolarDay};
+    ///
+    /// // 2024年12月30日属于2025年第1周
+    /// let week: IsoWeek = SolarDay::from_ymd(2024, 12, 30).get_iso_week().unwrap();
+    /// assert_eq!("2025-W01", week.to_iso_string());
+    /// ```
+    pub fn get_iso_week(&self) -> Result<IsoWeek, TymeError> {
+        IsoWeek::try_from_solar_day(*self)
+    }
+
+    /// ISO 8601周日期，如2024-W01-1
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::solar::SolarDay;
+    ///
+    /// assert_eq!("2024-W01-1", SolarDay::from_ymd(2024, 1, 1).get_iso_week_date().unwrap());
+    /// ```
+    pub fn get_iso_week_date(&self) -> Result<String, TymeError> {
+        Ok(format!(
+            "{}-{}",
+            self.get_iso_week()?.to_iso_string(),
+            self.get_week().next(-1).get_index() + 1
+        ))
+    }
+
+    /// 从ISO 8601周日期（如2024-W01-1）解析
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::solar::SolarDay;
+    ///
+    /// assert_eq!(SolarDay::from_ymd(2026, 1, 1), SolarDay::try_from_iso_week_date("2026-W01-4").unwrap());
+    /// ```
+    pub fn try_from_iso_week_date(text: &str) -> Result<Self, TymeError> {
+        match IsoWeek::parse(text)? {
+            (week, Some(day)) => week.get_day(day),
+            _ => Err(TymeError::IllegalFormat {
+                kind: "iso week date",
+                text: text.to_string(),
+            }),
+        }
+    }
+
     /// 节气
     ///
     /// # 示例
@@ -1383,6 +1884,20 @@ impl SolarDay {
         LegalHoliday::from_ymd(self.get_year(), self.get_month(), self.get_day())
     }
 
+    /// 指定地区的法定假日
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::enums::Region;
+    /// use tyme4rs::tyme::solar::SolarDay;
+    ///
+    /// assert_eq!("2024年5月15日 佛诞节(休)", SolarDay::from_ymd(2024, 5, 15).get_legal_holiday_in(Region::Macau).unwrap().to_string());
+    /// ```
+    pub fn get_legal_holiday_in(&self, region: Region) -> Option<LegalHoliday> {
+        LegalHoliday::from_ymd_in(region, self.get_year(), self.get_month(), self.get_day())
+    }
+
     /// 公历现代节日
     ///
     /// # 示例
@@ -1402,10 +1917,89 @@ impl SolarDay {
         SixtyCycleDay::from_solar_day(*self)
     }
 
-    pub fn get_rab_byung_day(&self) -> Result<RabByungDay, String> {
+    pub fn get_rab_byung_day(&self) -> Result<RabByungDay, TymeError> {
         RabByungDay::from_solar_day(*self)
     }
 
+    /// 伊斯兰历日（算术历），早于伊斯兰历元年时返回错误
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::solar::SolarDay;
+    ///
+    /// assert_eq!("伊斯兰历1446年穆哈兰姆月1日", SolarDay::from_ymd(2024, 7, 8).get_islamic_day().unwrap().to_string());
+    /// ```
+    pub fn get_islamic_day(&self) -> Result<IslamicDay, TymeError> {
+        IslamicDay::try_from_solar_day(*self, IslamicType::Tabular)
+    }
+
+    /// 希伯来历日，早于公元1年犹太新年时返回错误
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::solar::SolarDay;
+    ///
+    /// assert_eq!("希伯来历5784年尼散月15日", SolarDay::from_ymd(2024, 4, 23).get_hebrew_day().unwrap().to_string());
+    /// ```
+    pub fn get_hebrew_day(&self) -> Result<HebrewDay, TymeError> {
+        HebrewDay::try_from_solar_day(*self)
+    }
+
+    /// 波斯历日（天文历），早于波斯历元年时返回错误
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::solar::SolarDay;
+    ///
+    /// assert_eq!("波斯历1404年法尔瓦丁月1日", SolarDay::from_ymd(2025, 3, 21).get_persian_day().unwrap().to_string());
+    /// ```
+    pub fn get_persian_day(&self) -> Result<PersianDay, TymeError> {
+        PersianDay::try_from_solar_day(*self, PersianType::Astronomical)
+    }
+
+    /// 日本年号纪年（明治以后），早于明治时返回错误
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::solar::SolarDay;
+    ///
+    /// assert_eq!("令和6年", SolarDay::from_ymd(2024, 1, 1).get_japanese_year().unwrap().to_string());
+    /// ```
+    pub fn get_japanese_year(&self) -> Result<JapaneseYear, TymeError> {
+        JapaneseYear::try_from_solar_day(*self, JapaneseEraType::Modern)
+    }
+
+    /// 韩国农历日（按东九区推算朔望和节气）
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::solar::SolarDay;
+    ///
+    /// assert_eq!(1, SolarDay::from_ymd(2024, 2, 10).get_korean_lunar_day().get_day());
+    /// ```
+    pub fn get_korean_lunar_day(&self) -> ZonedLunarDay {
+        ZonedLunarDay::from_solar_day(*self, UtcOffset::SEOUL)
+    }
+
+    /// 越南农历日（按东七区推算朔望和节气）
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::solar::SolarDay;
+    ///
+    /// // 越南1985年春节比中国早一个月
+    /// assert_eq!("农历乙丑年正月初一", SolarDay::from_ymd(1985, 1, 21).get_vietnamese_lunar_day().to_string());
+    /// ```
+    pub fn get_vietnamese_lunar_day(&self) -> ZonedLunarDay {
+        ZonedLunarDay::from_solar_day(*self, UtcOffset::HANOI)
+    }
+
     /// 月相第几天
     pub fn get_phase_day(&self) -> PhaseDay {
         let month: LunarMonth = self.get_lunar_day().get_lunar_month().next(1);
@@ -1422,6 +2016,38 @@ impl SolarDay {
     pub fn get_phase(&self) -> Phase {
         self.get_phase_day().get_phase()
     }
+
+    /// 指定地点的日出日落、晨昏蒙影及昼长
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::astro::sun::SunEvents;
+    /// use tyme4rs::tyme::solar::SolarDay;
+    /// use tyme4rs::tyme::zone::UtcOffset;
+    ///
+    /// // 北京
+    /// let e: SunEvents = SolarDay::from_ymd(2024, 12, 21).get_sun_events(39.9042, 116.4074, UtcOffset::BEIJING);
+    /// ```
+    pub fn get_sun_events(&self, latitude: f64, longitude: f64, offset: UtcOffset) -> SunEvents {
+        SunEvents::from_solar_day(*self, latitude, longitude, offset)
+    }
+
+    /// 月出月落，纬度（度，北纬为正）、经度（度，东经为正）、时间的UTC偏移
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::astro::moon::MoonEvents;
+    /// use tyme4rs::tyme::solar::SolarDay;
+    /// use tyme4rs::tyme::zone::UtcOffset;
+    ///
+    /// // 北京
+    /// let e: MoonEvents = SolarDay::from_ymd(2024, 9, 17).get_moon_events(39.9042, 116.4074, UtcOffset::BEIJING);
+    /// ```
+    pub fn get_moon_events(&self, latitude: f64, longitude: f64, offset: UtcOffset) -> MoonEvents {
+        MoonEvents::from_solar_day(*self, latitude, longitude, offset)
+    }
 }
 
 impl Display for SolarDay {
@@ -1448,39 +2074,7 @@ pub struct SolarTime {
 
 impl Tyme for SolarTime {
     fn next(&self, n: isize) -> Self {
-        if n == 0 {
-            *self
-        } else {
-            let mut ts: isize = (self.get_second() as isize) + n;
-            let mut tm: isize = (self.get_minute() as isize) + ts / 60;
-            ts %= 60;
-            if ts < 0 {
-                ts += 60;
-                tm -= 1;
-            }
-            let mut th: isize = (self.get_hour() as isize) + tm / 60;
-            tm %= 60;
-            if tm < 0 {
-                tm += 60;
-                th -= 1;
-            }
-            let mut td: isize = th / 24;
-            th %= 24;
-            if th < 0 {
-                th += 24;
-                td -= 1;
-            }
-
-            let d: SolarDay = self.get_solar_day().next(td);
-            Self::from_ymd_hms(
-                d.get_year(),
-                d.get_month(),
-                d.get_day(),
-                th as usize,
-                tm as usize,
-                ts as usize,
-            )
-        }
+        self.try_next(n).unwrap()
     }
 }
 
@@ -1517,7 +2111,7 @@ impl SolarTime {
         hour: usize,
         minute: usize,
         second: usize,
-    ) -> Result<Self, String> {
+    ) -> Result<Self, TymeError> {
         Self::validate(year, month, day, hour, minute, second)?;
         Ok(Self {
             parent: SecondUnit::new(year, month as isize, day as isize, hour, minute, second),
@@ -1531,7 +2125,7 @@ impl SolarTime {
         hour: usize,
         minute: usize,
         second: usize,
-    ) -> Result<(), String> {
+    ) -> Result<(), TymeError> {
         SecondUnit::validate(hour, minute, second)?;
         SolarDay::validate(year, month, day)?;
         Ok(())
@@ -1548,6 +2142,53 @@ impl SolarTime {
         Self::new(year, month, day, hour, minute, second).unwrap()
     }
 
+    pub fn try_from_ymd_hms(
+        year: isize,
+        month: usize,
+        day: usize,
+        hour: usize,
+        minute: usize,
+        second: usize,
+    ) -> Result<Self, TymeError> {
+        Self::new(year, month, day, hour, minute, second)
+    }
+
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        if n == 0 {
+            return Ok(*self);
+        }
+        let mut ts: isize = (self.get_second() as isize) + n;
+        let mut tm: isize = (self.get_minute() as isize) + ts / 60;
+        ts %= 60;
+        if ts < 0 {
+            ts += 60;
+            tm -= 1;
+        }
+        let mut th: isize = (self.get_hour() as isize) + tm / 60;
+        tm %= 60;
+        if tm < 0 {
+            tm += 60;
+            th -= 1;
+        }
+        let mut td: isize = th / 24;
+        th %= 24;
+        if th < 0 {
+            th += 24;
+            td -= 1;
+        }
+
+        let d: SolarDay = self.get_solar_day().try_next(td)?;
+        Self::new(
+            d.get_year(),
+            d.get_month(),
+            d.get_day(),
+            th as usize,
+            tm as usize,
+            ts as usize,
+        )
+    }
+
     /// 公历日
     ///
     /// # 示例
@@ -1677,6 +2318,20 @@ impl SolarTime {
         )
     }
 
+    /// 月球视位置、亮面比例与月龄（北京时间）
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::astro::moon::MoonPosition;
+    /// use tyme4rs::tyme::solar::SolarTime;
+    ///
+    /// let p: MoonPosition = SolarTime::from_ymd_hms(2024, 3, 25, 15, 0, 0).get_moon_position();
+    /// ```
+    pub fn get_moon_position(&self) -> MoonPosition {
+        MoonPosition::from_solar_time(*self)
+    }
+
     pub fn subtract(&self, target: SolarTime) -> isize {
         let mut days: isize = self.get_solar_day().subtract(target.get_solar_day());
         let cs: usize = self.get_hour() * 3600 + self.get_minute() * 60 + self.get_second();
@@ -1811,15 +2466,15 @@ impl SolarTerm {
         }
     }
 
-    pub fn new(year: isize, name: &str) -> Result<Self, String> {
-        let parent: LoopTyme = LoopTyme::from_name(
+    pub fn new(year: isize, name: &str) -> Result<Self, TymeError> {
+        let parent: LoopTyme = LoopTyme::new(
             SOLAR_TERM_NAMES
                 .to_vec()
                 .iter()
                 .map(|x| x.to_string())
                 .collect(),
             name,
-        );
+        )?;
         let jd: f64 = (((year - 2000) as f64) * 365.2422 + 180.0).floor();
         // 355是2000.12冬至，得到较靠近jd的冬至估计值
         let mut w: f64 = ((jd - 355.0 + 183.0) / 365.2422).floor() * 365.2422 + 355.0;
@@ -1838,6 +2493,10 @@ impl SolarTerm {
         Self::new(year, name).unwrap()
     }
 
+    pub fn try_from_name(year: isize, name: &str) -> Result<Self, TymeError> {
+        Self::new(year, name)
+    }
+
     /// 是否节令
     ///
     /// # 示例
@@ -1884,6 +2543,22 @@ impl SolarTerm {
         JulianDay::from_julian_day(ShouXingUtil::qi_accurate2(self.cursory_julian_day) + J2000)
     }
 
+    /// 指定UTC偏移的交节时刻
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::solar::SolarTerm;
+    /// use tyme4rs::tyme::zone::UtcOffset;
+    ///
+    /// let term: SolarTerm = SolarTerm::from_name(2024, "立春");
+    /// assert_eq!("2024年2月4日 08:27:07 +00:00", term.get_zoned_solar_time(UtcOffset::UTC).to_string());
+    /// ```
+    pub fn get_zoned_solar_time(&self, offset: UtcOffset) -> ZonedSolarTime {
+        ZonedSolarTime::new(self.get_julian_day().get_solar_time(), UtcOffset::BEIJING)
+            .to_offset(offset)
+    }
+
     /// 公历日（用于日历）
     ///
     /// # 示例
@@ -1896,7 +2571,12 @@ impl SolarTerm {
     /// let day: SolarDay = term.get_solar_day();
     /// ```
     pub fn get_solar_day(&self) -> SolarDay {
-        JulianDay::from_julian_day(self.cursory_julian_day + J2000).get_solar_day()
+        self.try_get_solar_day().unwrap()
+    }
+
+    /// 公历日（用于日历），超出公历范围时返回错误
+    pub fn try_get_solar_day(&self) -> Result<SolarDay, TymeError> {
+        JulianDay::from_julian_day(self.cursory_julian_day + J2000).try_get_solar_day()
     }
 
     /// 年
@@ -2018,11 +2698,12 @@ impl From<SolarTermDay> for AbstractCultureDay {
 
 #[cfg(test)]
 mod tests {
+    use crate::tyme::error::TymeError;
     use crate::tyme::lunar::LunarWeek;
     use crate::tyme::sixtycycle::HideHeavenStemDay;
     use crate::tyme::solar::{
-        SolarDay, SolarHalfYear, SolarMonth, SolarSeason, SolarTerm, SolarTime, SolarWeek,
-        SolarYear,
+        IsoWeek, IsoWeekYear, SolarDay, SolarHalfYear, SolarMonth, SolarSeason, SolarTerm,
+        SolarTime, SolarWeek, SolarYear,
     };
     use crate::tyme::{Culture, Tyme};
 
@@ -2406,17 +3087,17 @@ mod tests {
 
     #[test]
     fn test43() {
-        assert_eq!(false, SolarYear::from_year(2023).is_leap());
+        assert!(!SolarYear::from_year(2023).is_leap());
     }
 
     #[test]
     fn test44() {
-        assert_eq!(true, SolarYear::from_year(1500).is_leap());
+        assert!(SolarYear::from_year(1500).is_leap());
     }
 
     #[test]
     fn test45() {
-        assert_eq!(false, SolarYear::from_year(1700).is_leap());
+        assert!(!SolarYear::from_year(1700).is_leap());
     }
 
     #[test]
@@ -2664,4 +3345,139 @@ mod tests {
                 .to_string()
         );
     }
+
+    #[test]
+    fn test75() {
+        assert_eq!(
+            Err(TymeError::GregorianGap {
+                year: 1582,
+                month: 10,
+                day: 10
+            }),
+            SolarDay::new(1582, 10, 10)
+        );
+        assert_eq!(
+            Err(TymeError::YearOutOfRange {
+                calendar: "solar",
+                year: 10000,
+                min: 1,
+                max: 9999
+            }),
+            SolarYear::new(10000)
+        );
+    }
+
+    #[test]
+    fn test76() {
+        assert_eq!(
+            Err(TymeError::UnknownName {
+                kind: "",
+                name: "大暑暑".to_string()
+            }),
+            SolarTerm::new(2023, "大暑暑")
+        );
+    }
+
+    #[test]
+    fn test77() {
+        assert!(SolarDay::try_from_ymd(2023, 2, 29).is_err());
+        assert!(SolarDay::from_ymd(9999, 12, 31).try_next(1).is_err());
+        assert_eq!(
+            "9999年12月31日",
+            SolarDay::from_ymd(9999, 12, 30)
+                .try_next(1)
+                .unwrap()
+                .to_string()
+        );
+        assert!(SolarMonth::from_ym(1, 1).try_next(-1).is_err());
+        assert!(SolarTime::from_ymd_hms(9999, 12, 31, 23, 59, 59)
+            .try_next(1)
+            .is_err());
+    }
+
+    #[test]
+    fn test78() {
+        let week: IsoWeek = SolarDay::from_ymd(2021, 1, 3).get_iso_week().unwrap();
+        assert_eq!("2020-W53", week.to_iso_string());
+        assert_eq!("2020年第53周", week.to_string());
+        assert_eq!("2021-W01", week.next(1).to_iso_string());
+        assert_eq!("2019-W52", week.next(-53).to_iso_string());
+        assert_eq!("2021年1月4日", week.next(1).get_first_day().to_string());
+        assert_eq!(
+            "2008-W01-1",
+            SolarDay::from_ymd(2007, 12, 31)
+                .get_iso_week_date()
+                .unwrap()
+        );
+        assert_eq!(
+            "2009-W53-7",
+            SolarDay::from_ymd(2010, 1, 3).get_iso_week_date().unwrap()
+        );
+        assert!(SolarDay::from_ymd(1583, 1, 1).get_iso_week().is_err());
+        assert_eq!(
+            Err(TymeError::YearOutOfRange {
+                calendar: "iso week",
+                year: 0,
+                min: 1583,
+                max: 9999
+            }),
+            SolarDay::from_ymd(1, 1, 1).get_iso_week()
+        );
+        assert!(SolarDay::from_ymd(1, 1, 2).get_iso_week().is_err());
+        assert_eq!(
+            "9999-W22-2",
+            SolarDay::from_ymd(9999, 6, 1).get_iso_week_date().unwrap()
+        );
+        assert_eq!(
+            "9999-W51-7",
+            SolarDay::from_ymd(9999, 12, 26)
+                .get_iso_week_date()
+                .unwrap()
+        );
+        // 9999年第52周的周日为10000年1月2日
+        assert_eq!(
+            Err(TymeError::IndexOutOfRange {
+                kind: "iso week",
+                index: 52,
+                min: 1,
+                max: 51
+            }),
+            SolarDay::from_ymd(9999, 12, 27).get_iso_week()
+        );
+        assert!(SolarDay::from_ymd(9999, 12, 31).get_iso_week().is_err());
+        assert_eq!(51, IsoWeekYear::from_year(9999).get_weeks().len());
+        assert!(IsoWeek::from_yw(9999, 51).try_next(1).is_err());
+    }
+
+    #[test]
+    fn test79() {
+        assert_eq!(
+            SolarDay::from_ymd(2009, 12, 31),
+            SolarDay::try_from_iso_week_date("2009-W53-4").unwrap()
+        );
+        assert_eq!(IsoWeek::from_yw(2024, 1), "2024-W01".parse().unwrap());
+        assert_eq!(
+            Err(TymeError::IndexOutOfRange {
+                kind: "iso week",
+                index: 53,
+                min: 1,
+                max: 52,
+            }),
+            "2024-W53".parse::<IsoWeek>()
+        );
+        assert!("2024-W01-1".parse::<IsoWeek>().is_err());
+        assert!(SolarDay::try_from_iso_week_date("2024-W01").is_err());
+        assert!(SolarDay::try_from_iso_week_date("2024-W01-8").is_err());
+        assert!(SolarDay::try_from_iso_week_date("2024W011").is_err());
+        let year: IsoWeekYear = IsoWeekYear::from_year(2015);
+        assert_eq!(53, year.get_weeks().len());
+        assert_eq!("2014年12月29日", year.get_first_day().to_string());
+        // 逐日校验
+        let mut d: SolarDay = SolarDay::from_ymd(1999, 12, 1);
+        for _ in 0..1000 {
+            let (week, day) = IsoWeek::parse(&d.get_iso_week_date().unwrap()).unwrap();
+            assert_eq!(d, week.get_day(day.unwrap()).unwrap());
+            d = d.next(1);
+        }
+    }
 }
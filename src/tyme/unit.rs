@@ -1,3 +1,4 @@
+use crate::tyme::error::TymeError;
 use crate::tyme::AbstractTyme;
 use std::ops::{Deref, DerefMut};
 
@@ -141,13 +142,13 @@ impl SecondUnit {
         }
     }
 
-    pub fn validate(hour: usize, minute: usize, second: usize) -> Result<(), String> {
-        if hour > 23 {
-            Err(format!("illegal hour: {}", hour))
-        } else if minute > 59 {
-            Err(format!("illegal minute: {}", minute))
-        } else if second > 59 {
-            Err(format!("illegal second: {}", second))
+    pub fn validate(hour: usize, minute: usize, second: usize) -> Result<(), TymeError> {
+        if hour > 23 || minute > 59 || second > 59 {
+            Err(TymeError::IllegalTime {
+                hour,
+                minute,
+                second,
+            })
         } else {
             Ok(())
         }
@@ -203,11 +204,21 @@ impl WeekUnit {
         }
     }
 
-    pub fn validate(index: usize, start: usize) -> Result<(), String> {
+    pub fn validate(index: usize, start: usize) -> Result<(), TymeError> {
         if index > 5 {
-            Err(format!("illegal week index: {}", index))
+            Err(TymeError::IndexOutOfRange {
+                kind: "week",
+                index: index as isize,
+                min: 0,
+                max: 5,
+            })
         } else if start > 6 {
-            Err(format!("illegal week start: {}", start))
+            Err(TymeError::IndexOutOfRange {
+                kind: "week start",
+                index: start as isize,
+                min: 0,
+                max: 6,
+            })
         } else {
             Ok(())
         }
@@ -4,7 +4,7 @@ use std::f64::consts::PI;
 pub static PI_2: f64 = PI * 2.0;
 pub static ONE_THIRD: f64 = 1.0 / 3.0;
 static SECOND_PER_DAY: f64 = 86400.0;
-static SECOND_PER_RAD: f64 = 180.0 * 3600.0 / PI;
+pub(crate) static SECOND_PER_RAD: f64 = 180.0 * 3600.0 / PI;
 static NUT_B: [f64; 50] = [
     2.1824, -33.75705, 36e-6, -1720.0, 920.0, 3.5069, 1256.66393, 11e-6, -132.0, 57.0, 1.3375,
     16799.4182, -51e-6, -23.0, 10.0, 4.3649, -67.5141, 72e-6, 21.0, -9.0, 0.04, -628.302, 0.0,
@@ -30,6 +30,10 @@ static DT_AT: [f64; 152] = [
     0.0049, 0.000085, 2050.0, 71.0457,
 ];
 
+#[allow(
+    clippy::approx_constant,
+    reason = "VSOP87地球黄经系数中的3.14等为相位，并非圆周率"
+)]
 static XL0: [f64; 2666] = [
     10000000000.0,
     20.0,
@@ -2976,6 +2980,55 @@ impl ShouXingUtil {
         Self::elon(t, n) + Self::nutation_lon2(t) + Self::gxc_sun_lon(t) + PI
     }
 
+    /// 黄赤交角，t为力学时儒略世纪数
+    pub fn hcjj(t: f64) -> f64 {
+        let t2: f64 = t * t;
+        let t3: f64 = t2 * t;
+        let t4: f64 = t3 * t;
+        let t5: f64 = t4 * t;
+        (84381.4060 - 46.836769 * t - 0.0001831 * t2 + 0.00200340 * t3
+            - 5.76e-7 * t4
+            - 4.34e-8 * t5)
+            / SECOND_PER_RAD
+    }
+
+    /// 黄道坐标转赤道坐标，e为黄赤交角
+    pub fn llr_conv(lon: f64, lat: f64, e: f64) -> (f64, f64) {
+        let ra: f64 = (lon.sin() * e.cos() - lat.tan() * e.sin()).atan2(lon.cos());
+        let dec: f64 = (e.cos() * lat.sin() + e.sin() * lat.cos() * lon.sin()).asin();
+        (ra.rem_euclid(PI_2), dec)
+    }
+
+    /// 均时差（真太阳时 - 平太阳时，单位为日），t为力学时儒略世纪数
+    pub fn pty_zty(t: f64) -> f64 {
+        let t2: f64 = t * t;
+        let t3: f64 = t2 * t;
+        let t4: f64 = t3 * t;
+        let t5: f64 = t4 * t;
+        let l: f64 = (1753470142.0 + 628331965331.8 * t + 5296.74 * t2 + 0.432 * t3
+            - 0.1124 * t4
+            - 0.00009 * t5)
+            / 1000000000.0
+            + PI
+            - 20.5 / SECOND_PER_RAD;
+        // 黄经章、交角章
+        let dl: f64 = -17.2 * (2.1824 - 33.75705 * t).sin() / SECOND_PER_RAD;
+        let de: f64 = 9.2 * (2.1824 - 33.75705 * t).cos() / SECOND_PER_RAD;
+        let e: f64 = Self::hcjj(t) + de;
+        // 太阳地心黄道坐标
+        let lon: f64 = Self::elon(t, 50) + PI + Self::gxc_sun_lon(t) + dl;
+        let lat: f64 = -(2796.0 * (3.1987 + 8433.46616 * t).cos()
+            + 1016.0 * (5.4225 + 550.75532 * t).cos()
+            + 804.0 * (3.88 + 522.3694 * t).cos())
+            / 1000000000.0;
+        let (ra, _) = Self::llr_conv(lon, lat, e);
+        let mut v: f64 = (l - ra + dl * e.cos()).rem_euclid(PI_2);
+        if v > PI {
+            v -= PI_2;
+        }
+        v / PI_2
+    }
+
     pub fn dt_ext(y: f64, jsd: f64) -> f64 {
         let dy: f64 = (y - 1820.0) / 100.0;
         -20.0 + jsd * dy * dy
@@ -3009,6 +3062,7 @@ impl ShouXingUtil {
         Self::dt_calc(t / 365.2425 + 2000.0) / SECOND_PER_DAY
     }
 
+    #[allow(clippy::approx_constant, reason = "月亮黄经摄动项的相位3.14并非圆周率")]
     pub fn mv(t: f64) -> f64 {
         let mut v: f64 = 8399.71 - 914.0 * (0.7848 + 8328.691425 * t + 0.0001523 * t * t).sin();
         v -= 179.0 * (2.543 + 15542.7543 * t).sin()
@@ -0,0 +1,1224 @@
+//! 时区
+//!
+//! 本库的节气、月相和农历均以北京时间（东八区）计算。本模块提供带UTC偏移的公历时刻，以及按指定标准经线推算的农历。
+//! 朔日和中气落在哪一天与时区有关，因此越南（东七区）、韩国（东九区）等地的农历月和闰月在某些年份与中国不同。
+//! 排八字时可用[TrueSolarTime]把钟表时间换算为出生地的真太阳时。
+use std::fmt::{Display, Formatter};
+
+use crate::tyme::culture::Zodiac;
+use crate::tyme::eightchar::EightChar;
+use crate::tyme::error::TymeError;
+use crate::tyme::jd::{JulianDay, J2000};
+use crate::tyme::lunar::{LunarHour, LunarYear, LUNAR_DAY_NAMES, LUNAR_MONTH_NAMES};
+use crate::tyme::sixtycycle::{SixtyCycle, SixtyCycleHour};
+use crate::tyme::solar::{SolarDay, SolarTime};
+use crate::tyme::util::{ShouXingUtil, PI_2};
+use crate::tyme::{Culture, Tyme};
+
+/// UTC偏移
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct UtcOffset {
+    /// 秒数，东为正
+    seconds: isize,
+}
+
+impl Culture for UtcOffset {
+    fn get_name(&self) -> String {
+        let sign: &str = if self.seconds < 0 { "-" } else { "+" };
+        let s: isize = self.seconds.abs();
+        let name: String = format!("{}{:0>2}:{:0>2}", sign, s / 3600, s % 3600 / 60);
+        match s % 60 {
+            0 => name,
+            n => format!("{}:{:0>2}", name, n),
+        }
+    }
+}
+
+impl UtcOffset {
+    /// 协调世界时
+    pub const UTC: Self = Self { seconds: 0 };
+
+    /// 北京时间（东八区）
+    pub const BEIJING: Self = Self { seconds: 28800 };
+
+    /// 日本标准时间（东九区）
+    pub const TOKYO: Self = Self { seconds: 32400 };
+
+    /// 韩国标准时间（东九区）
+    pub const SEOUL: Self = Self { seconds: 32400 };
+
+    /// 越南标准时间（东七区）
+    pub const HANOI: Self = Self { seconds: 25200 };
+
+    /// 印度标准时间（东五区半）
+    pub const KOLKATA: Self = Self { seconds: 19800 };
+
+    pub fn new(seconds: isize) -> Result<Self, TymeError> {
+        Self::validate(seconds)?;
+        Ok(Self { seconds })
+    }
+
+    pub fn validate(seconds: isize) -> Result<(), TymeError> {
+        if !(-64800..=64800).contains(&seconds) {
+            Err(TymeError::IllegalUtcOffset { seconds })
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn from_seconds(seconds: isize) -> Self {
+        Self::new(seconds).unwrap()
+    }
+
+    pub fn try_from_seconds(seconds: isize) -> Result<Self, TymeError> {
+        Self::new(seconds)
+    }
+
+    /// 从时、分创建，分的正负与时相同
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::zone::UtcOffset;
+    ///
+    /// assert_eq!("-03:30", UtcOffset::from_hm(-3, 30).to_string());
+    /// ```
+    pub fn from_hm(hour: isize, minute: usize) -> Self {
+        Self::try_from_hm(hour, minute).unwrap()
+    }
+
+    pub fn try_from_hm(hour: isize, minute: usize) -> Result<Self, TymeError> {
+        let m: isize = minute as isize * 60;
+        let seconds: isize = hour * 3600 + if hour < 0 { -m } else { m };
+        if minute > 59 {
+            return Err(TymeError::IllegalUtcOffset { seconds });
+        }
+        Self::new(seconds)
+    }
+
+    /// 从标准经线（度，东经为正）创建，每15度为1小时
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::zone::UtcOffset;
+    ///
+    /// assert_eq!("+07:00", UtcOffset::from_meridian(105.0).to_string());
+    /// ```
+    pub fn from_meridian(longitude: f64) -> Self {
+        Self::try_from_meridian(longitude).unwrap()
+    }
+
+    pub fn try_from_meridian(longitude: f64) -> Result<Self, TymeError> {
+        let seconds: f64 = (longitude * 240.0).round();
+        if !(-64800.0..=64800.0).contains(&seconds) {
+            return Err(TymeError::IllegalUtcOffset {
+                seconds: seconds as isize,
+            });
+        }
+        Self::new(seconds as isize)
+    }
+
+    /// 秒数
+    pub fn get_seconds(&self) -> isize {
+        self.seconds
+    }
+
+    /// 标准经线（度）
+    pub fn get_meridian(&self) -> f64 {
+        self.seconds as f64 / 240.0
+    }
+
+    /// 相对世界时的天数
+    pub(crate) fn get_days(&self) -> f64 {
+        self.seconds as f64 / 86400.0
+    }
+}
+
+impl Display for UtcOffset {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.get_name())
+    }
+}
+
+/// 带UTC偏移的公历时刻
+#[derive(Debug, Copy, Clone)]
+pub struct ZonedSolarTime {
+    /// 当地公历时刻
+    solar_time: SolarTime,
+    offset: UtcOffset,
+}
+
+impl Tyme for ZonedSolarTime {
+    fn next(&self, n: isize) -> Self {
+        self.try_next(n).unwrap()
+    }
+}
+
+impl Culture for ZonedSolarTime {
+    fn get_name(&self) -> String {
+        format!("{} {}", self.solar_time.get_name(), self.offset)
+    }
+}
+
+impl ZonedSolarTime {
+    pub fn new(solar_time: SolarTime, offset: UtcOffset) -> Self {
+        Self { solar_time, offset }
+    }
+
+    pub fn from_ymd_hms(
+        year: isize,
+        month: usize,
+        day: usize,
+        hour: usize,
+        minute: usize,
+        second: usize,
+        offset: UtcOffset,
+    ) -> Self {
+        Self::try_from_ymd_hms(year, month, day, hour, minute, second, offset).unwrap()
+    }
+
+    pub fn try_from_ymd_hms(
+        year: isize,
+        month: usize,
+        day: usize,
+        hour: usize,
+        minute: usize,
+        second: usize,
+        offset: UtcOffset,
+    ) -> Result<Self, TymeError> {
+        Ok(Self::new(
+            SolarTime::new(year, month, day, hour, minute, second)?,
+            offset,
+        ))
+    }
+
+    /// 从世界时儒略日创建
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::jd::JulianDay;
+    /// use tyme4rs::tyme::zone::{UtcOffset, ZonedSolarTime};
+    ///
+    /// let t: ZonedSolarTime = ZonedSolarTime::from_julian_day(JulianDay::from_julian_day(2451545.0), UtcOffset::from_hm(9, 0));
+    /// assert_eq!("2000年1月1日 21:00:00 +09:00", t.to_string());
+    /// ```
+    pub fn from_julian_day(julian_day: JulianDay, offset: UtcOffset) -> Self {
+        Self::try_from_julian_day(julian_day, offset).unwrap()
+    }
+
+    pub fn try_from_julian_day(
+        julian_day: JulianDay,
+        offset: UtcOffset,
+    ) -> Result<Self, TymeError> {
+        Ok(Self::new(
+            JulianDay::from_julian_day(julian_day.get_day() + offset.get_days())
+                .try_get_solar_time()?,
+            offset,
+        ))
+    }
+
+    /// 当地公历时刻
+    pub fn get_solar_time(&self) -> SolarTime {
+        self.solar_time
+    }
+
+    /// UTC偏移
+    pub fn get_offset(&self) -> UtcOffset {
+        self.offset
+    }
+
+    /// 世界时儒略日
+    pub fn get_julian_day(&self) -> JulianDay {
+        JulianDay::from_julian_day(
+            self.solar_time.get_julian_day().get_day() - self.offset.get_days(),
+        )
+    }
+
+    /// 推移秒数，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        Ok(Self::new(self.solar_time.try_next(n)?, self.offset))
+    }
+
+    /// 转换为指定UTC偏移的同一时刻
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::zone::{UtcOffset, ZonedSolarTime};
+    ///
+    /// let t: ZonedSolarTime = ZonedSolarTime::from_ymd_hms(2024, 2, 10, 0, 30, 0, UtcOffset::BEIJING);
+    /// assert_eq!("2024年2月9日 23:30:00 +07:00", t.to_offset(UtcOffset::from_hm(7, 0)).to_string());
+    /// ```
+    pub fn to_offset(&self, offset: UtcOffset) -> Self {
+        self.try_to_offset(offset).unwrap()
+    }
+
+    /// 转换为指定UTC偏移的同一时刻，超出公历范围时返回错误
+    pub fn try_to_offset(&self, offset: UtcOffset) -> Result<Self, TymeError> {
+        Ok(Self::new(
+            self.solar_time
+                .try_next(offset.get_seconds() - self.offset.get_seconds())?,
+            offset,
+        ))
+    }
+
+    /// 转换为协调世界时
+    pub fn to_utc(&self) -> Self {
+        self.to_offset(UtcOffset::UTC)
+    }
+
+    /// 相差的秒数
+    pub fn subtract(&self, target: ZonedSolarTime) -> isize {
+        self.solar_time.subtract(target.solar_time) - self.offset.get_seconds()
+            + target.offset.get_seconds()
+    }
+
+    pub fn is_before(&self, target: ZonedSolarTime) -> bool {
+        self.subtract(target) < 0
+    }
+
+    pub fn is_after(&self, target: ZonedSolarTime) -> bool {
+        self.subtract(target) > 0
+    }
+}
+
+impl Display for ZonedSolarTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.solar_time, self.offset)
+    }
+}
+
+/// 不同UTC偏移的同一时刻视为相等
+impl PartialEq for ZonedSolarTime {
+    fn eq(&self, other: &Self) -> bool {
+        self.subtract(*other) == 0
+    }
+}
+
+impl Eq for ZonedSolarTime {}
+
+/// 真太阳时
+///
+/// 钟表时间按出生地经度与标准经线之差（每度4分钟）订正为地方平太阳时，再加上均时差得到真太阳时。
+#[derive(Debug, Copy, Clone)]
+pub struct TrueSolarTime {
+    /// 钟表时间
+    clock_time: SolarTime,
+    /// 经度（度，东经为正）
+    longitude: f64,
+    /// 钟表时间的UTC偏移
+    offset: UtcOffset,
+    /// 经度订正（秒）
+    longitude_correction: f64,
+    /// 均时差（秒）
+    equation_of_time: f64,
+    /// 真太阳时
+    solar_time: SolarTime,
+}
+
+impl Culture for TrueSolarTime {
+    fn get_name(&self) -> String {
+        self.solar_time.get_name()
+    }
+}
+
+impl TrueSolarTime {
+    /// 钟表时间、出生地经度（-180至180度）、钟表时间的标准经线（为空时以东经120度的北京时间计）
+    pub fn new(
+        clock_time: SolarTime,
+        longitude: f64,
+        offset: Option<UtcOffset>,
+    ) -> Result<Self, TymeError> {
+        if !(-180.0..=180.0).contains(&longitude) {
+            return Err(TymeError::IllegalLongitude {
+                longitude: longitude.to_string(),
+            });
+        }
+        let offset: UtcOffset = offset.unwrap_or(UtcOffset::BEIJING);
+        let longitude_correction: f64 = (longitude - offset.get_meridian()) * 240.0;
+        // 均时差以世界时对应的力学时计算
+        let t: f64 = clock_time.get_julian_day().get_day() - offset.get_days() - J2000;
+        let equation_of_time: f64 =
+            ShouXingUtil::pty_zty((t + ShouXingUtil::dtt(t)) / 36525.0) * 86400.0;
+        let solar_time: SolarTime =
+            clock_time.try_next((longitude_correction + equation_of_time).round() as isize)?;
+        Ok(Self {
+            clock_time,
+            longitude,
+            offset,
+            longitude_correction,
+            equation_of_time,
+            solar_time,
+        })
+    }
+
+    /// 从钟表时间创建
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::solar::SolarTime;
+    /// use tyme4rs::tyme::zone::TrueSolarTime;
+    ///
+    /// // 乌鲁木齐（东经87.6度）北京时间2024年2月11日12:00
+    /// let t: TrueSolarTime = TrueSolarTime::from_solar_time(SolarTime::from_ymd_hms(2024, 2, 11, 12, 0, 0), 87.6, None);
+    /// assert_eq!("2024年2月11日 09:36:13", t.get_solar_time().to_string());
+    /// ```
+    pub fn from_solar_time(
+        clock_time: SolarTime,
+        longitude: f64,
+        offset: Option<UtcOffset>,
+    ) -> Self {
+        Self::new(clock_time, longitude, offset).unwrap()
+    }
+
+    pub fn try_from_solar_time(
+        clock_time: SolarTime,
+        longitude: f64,
+        offset: Option<UtcOffset>,
+    ) -> Result<Self, TymeError> {
+        Self::new(clock_time, longitude, offset)
+    }
+
+    /// 从带UTC偏移的钟表时间创建
+    pub fn from_zoned_solar_time(clock_time: ZonedSolarTime, longitude: f64) -> Self {
+        Self::from_solar_time(
+            clock_time.get_solar_time(),
+            longitude,
+            Some(clock_time.get_offset()),
+        )
+    }
+
+    /// 真太阳时
+    pub fn get_solar_time(&self) -> SolarTime {
+        self.solar_time
+    }
+
+    /// 钟表时间
+    pub fn get_clock_time(&self) -> SolarTime {
+        self.clock_time
+    }
+
+    /// 钟表时间的UTC偏移
+    pub fn get_offset(&self) -> UtcOffset {
+        self.offset
+    }
+
+    /// 经度（度）
+    pub fn get_longitude(&self) -> f64 {
+        self.longitude
+    }
+
+    /// 经度订正（秒），东于标准经线为正
+    pub fn get_longitude_correction(&self) -> f64 {
+        self.longitude_correction
+    }
+
+    /// 均时差（秒），真太阳时快于平太阳时为正
+    pub fn get_equation_of_time(&self) -> f64 {
+        self.equation_of_time
+    }
+
+    /// 总订正（秒）
+    pub fn get_correction(&self) -> isize {
+        self.solar_time.subtract(self.clock_time)
+    }
+
+    /// 时辰
+    pub fn get_lunar_hour(&self) -> LunarHour {
+        self.solar_time.get_lunar_hour()
+    }
+
+    /// 干支时辰
+    pub fn get_sixty_cycle_hour(&self) -> SixtyCycleHour {
+        self.solar_time.get_sixty_cycle_hour()
+    }
+
+    /// 八字
+    pub fn get_eight_char(&self) -> EightChar {
+        self.get_lunar_hour().get_eight_char()
+    }
+}
+
+impl Display for TrueSolarTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.solar_time)
+    }
+}
+
+impl PartialEq for TrueSolarTime {
+    fn eq(&self, other: &Self) -> bool {
+        self.clock_time == other.clock_time
+            && self.offset == other.offset
+            && self.longitude == other.longitude
+    }
+}
+
+/// 朔望月的平均长度（日）
+pub(crate) static SYNODIC_MONTH: f64 = 29.530588853;
+
+/// 第k个朔的世界时（J2000起算的日数），k为0时是2000年1月6日的朔
+pub(crate) fn get_new_moon(k: isize) -> f64 {
+    let t: f64 = ShouXingUtil::m_sa_lon_t(k as f64 * PI_2) * 36525.0;
+    t - ShouXingUtil::dtt(t)
+}
+
+/// 公历year年冬至后第i个中气的世界时（J2000起算的日数），i为0时即冬至
+fn get_principal_term(year: isize, i: isize) -> f64 {
+    let t: f64 =
+        ShouXingUtil::sa_lon_t(((year - 1999) * 12 + 9 + i) as f64 * PI_2 / 12.0) * 36525.0;
+    t - ShouXingUtil::dtt(t)
+}
+
+/// 世界时所在的当地日（J2000起算的日序号）
+pub(crate) fn get_local_day(t: f64, offset: UtcOffset) -> isize {
+    (t + offset.get_days() + 0.5).floor() as isize
+}
+
+/// 公历year年冬至所在月（十一月）的朔序号
+fn get_winter_month(year: isize, offset: UtcOffset) -> isize {
+    let d: isize = get_local_day(get_principal_term(year, 0), offset);
+    let mut k: isize = ((d as f64 - 5.0) / SYNODIC_MONTH).floor() as isize;
+    while get_local_day(get_new_moon(k + 1), offset) <= d {
+        k += 1;
+    }
+    while get_local_day(get_new_moon(k), offset) > d {
+        k -= 1;
+    }
+    k
+}
+
+/// 公历year年冬至所在月至次年冬至所在月之前的各月（月，闰月为负数；朔序号）
+fn get_sui_months(year: isize, offset: UtcOffset) -> Vec<(isize, isize)> {
+    let start: isize = get_winter_month(year, offset);
+    let n: isize = get_winter_month(year + 1, offset) - start;
+    let mut leap: isize = 0;
+    if n == 13 {
+        // 一岁13个月时，冬至后第一个不含中气的月为闰月
+        let terms: Vec<isize> = (1..12)
+            .map(|i| get_local_day(get_principal_term(year, i), offset))
+            .collect();
+        let mut end: isize = get_local_day(get_new_moon(start + 1), offset);
+        for i in 1..n {
+            let begin: isize = end;
+            end = get_local_day(get_new_moon(start + i + 1), offset);
+            if !terms.iter().any(|d| *d >= begin && *d < end) {
+                leap = i;
+                break;
+            }
+        }
+    }
+    let mut l: Vec<(isize, isize)> = Vec::new();
+    let mut month: isize = 10;
+    for i in 0..n {
+        if leap > 0 && i == leap {
+            l.push((-month, start + i));
+        } else {
+            month = month % 12 + 1;
+            l.push((month, start + i));
+        }
+    }
+    l
+}
+
+/// 指定UTC偏移的农历年
+///
+/// 以当地标准时间判定朔日和中气：冬至所在月为十一月，两个冬至之间有13个月时，第一个不含中气的月为闰月。
+/// 按现代天文算法推算，使用北京时间时自1921年起与[LunarYear]一致，更早的年份以[LunarYear]的历史历表为准。
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ZonedLunarYear {
+    year: isize,
+    offset: UtcOffset,
+}
+
+impl Tyme for ZonedLunarYear {
+    fn next(&self, n: isize) -> Self {
+        self.try_next(n).unwrap()
+    }
+}
+
+impl Culture for ZonedLunarYear {
+    fn get_name(&self) -> String {
+        format!("农历{}年", self.get_sixty_cycle())
+    }
+}
+
+impl ZonedLunarYear {
+    pub fn new(year: isize, offset: UtcOffset) -> Result<Self, TymeError> {
+        LunarYear::validate(year)?;
+        Ok(Self { year, offset })
+    }
+
+    pub fn from_year(year: isize, offset: UtcOffset) -> Self {
+        Self::new(year, offset).unwrap()
+    }
+
+    pub fn try_from_year(year: isize, offset: UtcOffset) -> Result<Self, TymeError> {
+        Self::new(year, offset)
+    }
+
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        Self::new(self.year + n, self.offset)
+    }
+
+    pub fn get_year(&self) -> isize {
+        self.year
+    }
+
+    /// UTC偏移
+    pub fn get_offset(&self) -> UtcOffset {
+        self.offset
+    }
+
+    pub fn get_sixty_cycle(&self) -> SixtyCycle {
+        SixtyCycle::from_index(self.year - 4)
+    }
+
+    /// 生肖
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::zone::{UtcOffset, ZonedLunarYear};
+    ///
+    /// assert_eq!("Mèo", ZonedLunarYear::from_year(2023, UtcOffset::HANOI).get_zodiac().get_vietnamese_name());
+    /// ```
+    pub fn get_zodiac(&self) -> Zodiac {
+        self.get_sixty_cycle().get_earth_branch().get_zodiac()
+    }
+
+    /// 月列表
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::zone::{UtcOffset, ZonedLunarMonth, ZonedLunarYear};
+    ///
+    /// // 越南1985年（乙丑）正月初一在公历1月21日，比中国早一个月
+    /// let months: Vec<ZonedLunarMonth> = ZonedLunarYear::from_year(1985, UtcOffset::from_hm(7, 0)).get_months();
+    /// assert_eq!("1985年1月21日", months[0].get_first_day().get_solar_day().to_string());
+    /// ```
+    pub fn get_months(&self) -> Vec<ZonedLunarMonth> {
+        let prev: Vec<(isize, isize)> = get_sui_months(self.year - 1, self.offset);
+        let next: Vec<(isize, isize)> = get_sui_months(self.year, self.offset);
+        let months = prev
+            .into_iter()
+            .skip_while(|(m, _)| *m != 1)
+            .chain(next.into_iter().take_while(|(m, _)| *m != 1));
+        let mut l: Vec<ZonedLunarMonth> = Vec::new();
+        let mut first: isize = -1;
+        for (i, (month, k)) in months.enumerate() {
+            if first < 0 {
+                first = get_local_day(get_new_moon(k), self.offset);
+            }
+            let next_first: isize = get_local_day(get_new_moon(k + 1), self.offset);
+            l.push(ZonedLunarMonth {
+                year: self.year,
+                month,
+                offset: self.offset,
+                index: k,
+                index_in_year: i,
+                first_day: first,
+                day_count: (next_first - first) as usize,
+            });
+            first = next_first;
+        }
+        l
+    }
+
+    /// 闰月，无闰月时为0
+    pub fn get_leap_month(&self) -> usize {
+        self.get_months()
+            .iter()
+            .find(|m| m.is_leap())
+            .map_or(0, |m| m.get_month())
+    }
+
+    pub fn get_month_count(&self) -> usize {
+        self.get_months().len()
+    }
+
+    pub fn get_day_count(&self) -> usize {
+        self.get_months().iter().map(|m| m.get_day_count()).sum()
+    }
+}
+
+impl Display for ZonedLunarYear {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.get_name())
+    }
+}
+
+/// 指定UTC偏移的农历月
+#[derive(Debug, Copy, Clone)]
+pub struct ZonedLunarMonth {
+    year: isize,
+    /// 月，闰月为负数
+    month: isize,
+    offset: UtcOffset,
+    /// 朔序号
+    index: isize,
+    /// 位于当年的索引
+    index_in_year: usize,
+    /// 初一（J2000起算的日序号）
+    first_day: isize,
+    day_count: usize,
+}
+
+impl Tyme for ZonedLunarMonth {
+    fn next(&self, n: isize) -> Self {
+        self.try_next(n).unwrap()
+    }
+}
+
+impl Culture for ZonedLunarMonth {
+    fn get_name(&self) -> String {
+        let leap: &str = if self.is_leap() { "闰" } else { "" };
+        format!("{}{}", leap, LUNAR_MONTH_NAMES[self.get_month() - 1])
+    }
+}
+
+impl ZonedLunarMonth {
+    pub fn new(year: isize, month: isize, offset: UtcOffset) -> Result<Self, TymeError> {
+        if month == 0 || !(-12..=12).contains(&month) {
+            return Err(TymeError::IllegalMonth {
+                calendar: "lunar",
+                year,
+                month,
+            });
+        }
+        ZonedLunarYear::new(year, offset)?
+            .get_months()
+            .into_iter()
+            .find(|m| m.month == month)
+            .ok_or(TymeError::IllegalLeapMonth {
+                calendar: "lunar",
+                year,
+                month: month.abs(),
+            })
+    }
+
+    pub fn from_ym(year: isize, month: isize, offset: UtcOffset) -> Self {
+        Self::new(year, month, offset).unwrap()
+    }
+
+    pub fn try_from_ym(year: isize, month: isize, offset: UtcOffset) -> Result<Self, TymeError> {
+        Self::new(year, month, offset)
+    }
+
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        if n == 0 {
+            return Ok(*self);
+        }
+        let mut y: ZonedLunarYear = self.get_zoned_lunar_year();
+        let mut months: Vec<ZonedLunarMonth> = y.get_months();
+        let mut i: isize = self.index_in_year as isize + n;
+        while i < 0 {
+            y = y.try_next(-1)?;
+            months = y.get_months();
+            i += months.len() as isize;
+        }
+        while i >= months.len() as isize {
+            i -= months.len() as isize;
+            y = y.try_next(1)?;
+            months = y.get_months();
+        }
+        Ok(months[i as usize])
+    }
+
+    pub fn get_zoned_lunar_year(&self) -> ZonedLunarYear {
+        ZonedLunarYear {
+            year: self.year,
+            offset: self.offset,
+        }
+    }
+
+    pub fn get_year(&self) -> isize {
+        self.year
+    }
+
+    pub fn get_month(&self) -> usize {
+        self.month.unsigned_abs()
+    }
+
+    pub fn get_month_with_leap(&self) -> isize {
+        self.month
+    }
+
+    pub fn is_leap(&self) -> bool {
+        self.month < 0
+    }
+
+    /// UTC偏移
+    pub fn get_offset(&self) -> UtcOffset {
+        self.offset
+    }
+
+    pub fn get_day_count(&self) -> usize {
+        self.day_count
+    }
+
+    pub fn get_index_in_year(&self) -> usize {
+        self.index_in_year
+    }
+
+    /// 初一的儒略日
+    pub fn get_first_julian_day(&self) -> JulianDay {
+        JulianDay::from_julian_day(J2000 + self.first_day as f64)
+    }
+
+    pub fn get_first_day(&self) -> ZonedLunarDay {
+        ZonedLunarDay {
+            month: *self,
+            day: 1,
+        }
+    }
+
+    /// 朔的时刻
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::zone::{UtcOffset, ZonedLunarMonth};
+    ///
+    /// let m: ZonedLunarMonth = ZonedLunarMonth::from_ym(2024, 1, UtcOffset::from_hm(7, 0));
+    /// assert_eq!("2024年2月10日 05:59:11 +07:00", m.get_new_moon().to_string());
+    /// ```
+    pub fn get_new_moon(&self) -> ZonedSolarTime {
+        ZonedSolarTime::from_julian_day(
+            JulianDay::from_julian_day(J2000 + get_new_moon(self.index)),
+            self.offset,
+        )
+    }
+}
+
+impl Display for ZonedLunarMonth {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.get_zoned_lunar_year(), self.get_name())
+    }
+}
+
+impl PartialEq for ZonedLunarMonth {
+    fn eq(&self, other: &Self) -> bool {
+        self.year == other.year && self.month == other.month && self.offset == other.offset
+    }
+}
+
+impl Eq for ZonedLunarMonth {}
+
+/// 指定UTC偏移的农历日
+#[derive(Debug, Copy, Clone)]
+pub struct ZonedLunarDay {
+    month: ZonedLunarMonth,
+    day: usize,
+}
+
+impl Tyme for ZonedLunarDay {
+    fn next(&self, n: isize) -> Self {
+        self.try_next(n).unwrap()
+    }
+}
+
+impl Culture for ZonedLunarDay {
+    fn get_name(&self) -> String {
+        LUNAR_DAY_NAMES[self.day - 1].to_string()
+    }
+}
+
+impl ZonedLunarDay {
+    pub fn new(
+        year: isize,
+        month: isize,
+        day: usize,
+        offset: UtcOffset,
+    ) -> Result<Self, TymeError> {
+        let m: ZonedLunarMonth = ZonedLunarMonth::new(year, month, offset)?;
+        if day < 1 || day > m.get_day_count() {
+            return Err(TymeError::IllegalDay {
+                calendar: "lunar",
+                year,
+                month,
+                day: day as isize,
+            });
+        }
+        Ok(Self { month: m, day })
+    }
+
+    pub fn from_ymd(year: isize, month: isize, day: usize, offset: UtcOffset) -> Self {
+        Self::new(year, month, day, offset).unwrap()
+    }
+
+    pub fn try_from_ymd(
+        year: isize,
+        month: isize,
+        day: usize,
+        offset: UtcOffset,
+    ) -> Result<Self, TymeError> {
+        Self::new(year, month, day, offset)
+    }
+
+    /// 从公历日创建
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use tyme4rs::tyme::solar::SolarDay;
+    /// use tyme4rs::tyme::zone::{UtcOffset, ZonedLunarDay};
+    ///
+    /// let d: ZonedLunarDay = ZonedLunarDay::from_solar_day(SolarDay::from_ymd(2007, 2, 17), UtcOffset::from_hm(7, 0));
+    /// assert_eq!("农历丁亥年正月初一", d.to_string());
+    /// ```
+    pub fn from_solar_day(solar_day: SolarDay, offset: UtcOffset) -> Self {
+        let d: isize = (solar_day.get_julian_day().get_day() - J2000 + 0.5).floor() as isize;
+        let mut months: Vec<ZonedLunarMonth> =
+            ZonedLunarYear::from_year(solar_day.get_year(), offset).get_months();
+        if d < months[0].first_day {
+            months = ZonedLunarYear::from_year(solar_day.get_year() - 1, offset).get_months();
+        }
+        let m: ZonedLunarMonth = months.into_iter().rev().find(|m| m.first_day <= d).unwrap();
+        Self {
+            month: m,
+            day: (d - m.first_day) as usize + 1,
+        }
+    }
+
+    /// 推移，超出范围时返回错误
+    pub fn try_next(&self, n: isize) -> Result<Self, TymeError> {
+        Ok(Self::from_solar_day(
+            self.try_get_solar_day()?.try_next(n)?,
+            self.month.offset,
+        ))
+    }
+
+    pub fn get_zoned_lunar_month(&self) -> ZonedLunarMonth {
+        self.month
+    }
+
+    pub fn get_year(&self) -> isize {
+        self.month.year
+    }
+
+    /// 月，闰月为负数
+    pub fn get_month(&self) -> isize {
+        self.month.month
+    }
+
+    pub fn get_day(&self) -> usize {
+        self.day
+    }
+
+    /// UTC偏移
+    pub fn get_offset(&self) -> UtcOffset {
+        self.month.offset
+    }
+
+    pub fn get_solar_day(&self) -> SolarDay {
+        self.try_get_solar_day().unwrap()
+    }
+
+    /// 公历日，超出公历范围时返回错误
+    pub fn try_get_solar_day(&self) -> Result<SolarDay, TymeError> {
+        self.month
+            .get_first_julian_day()
+            .next(self.day as isize - 1)
+            .try_get_solar_day()
+    }
+}
+
+impl Display for ZonedLunarDay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.month, self.get_name())
+    }
+}
+
+impl PartialEq for ZonedLunarDay {
+    fn eq(&self, other: &Self) -> bool {
+        self.month == other.month && self.day == other.day
+    }
+}
+
+impl Eq for ZonedLunarDay {}
+
+#[cfg(test)]
+mod tests {
+    use crate::tyme::culture::Phase;
+    use crate::tyme::error::TymeError;
+    use crate::tyme::jd::JulianDay;
+    use crate::tyme::lunar::{LunarMonth, LunarYear};
+    use crate::tyme::solar::{SolarDay, SolarTerm, SolarTime};
+    use crate::tyme::zone::{
+        TrueSolarTime, UtcOffset, ZonedLunarDay, ZonedLunarMonth, ZonedLunarYear, ZonedSolarTime,
+    };
+    use crate::tyme::{Culture, Tyme};
+
+    fn vietnam() -> UtcOffset {
+        UtcOffset::from_meridian(105.0)
+    }
+
+    #[test]
+    fn test0() {
+        assert_eq!("+08:00", UtcOffset::BEIJING.to_string());
+        assert_eq!("+00:00", UtcOffset::UTC.to_string());
+        assert_eq!("+05:30", UtcOffset::from_seconds(19800).to_string());
+        assert_eq!("-09:30", UtcOffset::from_hm(-9, 30).to_string());
+        assert_eq!(UtcOffset::BEIJING, UtcOffset::from_meridian(120.0));
+        assert_eq!(135.0, UtcOffset::from_hm(9, 0).get_meridian());
+        assert_eq!(
+            Err(TymeError::IllegalUtcOffset { seconds: 68400 }),
+            UtcOffset::try_from_hm(19, 0)
+        );
+        assert!(UtcOffset::try_from_hm(8, 60).is_err());
+        assert!(UtcOffset::try_from_meridian(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test1() {
+        let t: ZonedSolarTime =
+            ZonedSolarTime::from_ymd_hms(2024, 1, 1, 0, 0, 0, UtcOffset::BEIJING);
+        assert_eq!("2023年12月31日 16:00:00 +00:00", t.to_utc().to_string());
+        assert_eq!(
+            "2023年12月31日 11:00:00 -05:00",
+            t.to_offset(UtcOffset::from_hm(-5, 0)).to_string()
+        );
+        assert_eq!(t, t.to_offset(vietnam()));
+        assert!(t.to_offset(vietnam()).next(1).is_after(t));
+        assert_eq!(
+            3600,
+            ZonedSolarTime::from_ymd_hms(2024, 1, 1, 0, 0, 0, vietnam()).subtract(t)
+        );
+        assert_eq!(2460310.5 - 1.0 / 3.0, t.get_julian_day().get_day());
+        assert_eq!(
+            t,
+            ZonedSolarTime::from_julian_day(t.get_julian_day(), UtcOffset::from_hm(9, 0))
+        );
+    }
+
+    #[test]
+    fn test2() {
+        let term: SolarTerm = SolarTerm::from_name(2024, "立春");
+        assert_eq!(
+            "2024年2月4日 16:27:07 +08:00",
+            term.get_zoned_solar_time(UtcOffset::BEIJING).to_string()
+        );
+        assert_eq!(
+            "2024年2月4日 15:27:07 +07:00",
+            term.get_zoned_solar_time(vietnam()).to_string()
+        );
+        assert_eq!(
+            "2024年2月4日 03:27:07 -05:00",
+            term.get_zoned_solar_time(UtcOffset::from_hm(-5, 0))
+                .to_string()
+        );
+        let phase: Phase = Phase::from_index(2024, 1, 0);
+        assert_eq!(
+            "2024年2月10日 05:59:11 +07:00",
+            phase.get_zoned_solar_time(vietnam()).to_string()
+        );
+        assert_eq!(
+            phase.get_zoned_solar_time(vietnam()),
+            ZonedLunarMonth::from_ym(2024, 1, vietnam()).get_new_moon()
+        );
+    }
+
+    #[test]
+    fn test3() {
+        // 越南与中国春节不同的年份
+        for (year, vn, cn) in [
+            (1968, "1968年1月29日", "1968年1月30日"),
+            (1985, "1985年1月21日", "1985年2月20日"),
+            (2007, "2007年2月17日", "2007年2月18日"),
+        ] {
+            assert_eq!(
+                vn,
+                ZonedLunarMonth::from_ym(year, 1, vietnam())
+                    .get_first_day()
+                    .get_solar_day()
+                    .to_string()
+            );
+            assert_eq!(
+                cn,
+                LunarMonth::from_ym(year, 1)
+                    .get_first_julian_day()
+                    .get_solar_day()
+                    .to_string()
+            );
+        }
+        assert_eq!(
+            0,
+            ZonedLunarYear::from_year(1984, vietnam()).get_leap_month()
+        );
+        assert_eq!(10, LunarYear::from_year(1984).get_leap_month());
+        assert_eq!(
+            2,
+            ZonedLunarYear::from_year(1985, vietnam()).get_leap_month()
+        );
+        assert_eq!(0, LunarYear::from_year(1985).get_leap_month());
+    }
+
+    #[test]
+    fn test4() {
+        // 北京时间与农历一致
+        for year in 1921..=2100 {
+            let a: Vec<(isize, f64)> = ZonedLunarYear::from_year(year, UtcOffset::BEIJING)
+                .get_months()
+                .iter()
+                .map(|m| (m.get_month_with_leap(), m.get_first_julian_day().get_day()))
+                .collect();
+            let b: Vec<(isize, f64)> = LunarYear::from_year(year)
+                .get_months()
+                .iter()
+                .map(|m| (m.get_month_with_leap(), m.get_first_julian_day().get_day()))
+                .collect();
+            assert_eq!(b, a, "{}", year);
+        }
+    }
+
+    #[test]
+    fn test5() {
+        let d: ZonedLunarDay =
+            ZonedLunarDay::from_solar_day(SolarDay::from_ymd(1985, 2, 19), vietnam());
+        assert_eq!("农历乙丑年正月三十", d.to_string());
+        assert_eq!("农历乙丑年闰二月初一", d.next(30).to_string());
+        assert_eq!(
+            SolarDay::from_ymd(1985, 1, 20),
+            ZonedLunarDay::from_ymd(1984, 12, 30, vietnam()).get_solar_day()
+        );
+        assert_eq!(
+            d,
+            ZonedLunarDay::from_ymd(1985, 1, 30, vietnam())
+                .next(1)
+                .next(-1)
+        );
+        assert_eq!(
+            "农历甲子年十二月三十",
+            ZonedLunarDay::from_solar_day(SolarDay::from_ymd(1985, 2, 19), UtcOffset::BEIJING)
+                .to_string()
+        );
+        assert!(ZonedLunarDay::try_from_ymd(1985, -3, 1, vietnam()).is_err());
+    }
+
+    #[test]
+    fn test6() {
+        let m: ZonedLunarMonth = ZonedLunarMonth::from_ym(1985, 12, vietnam());
+        assert_eq!("农历丙寅年正月", m.next(1).to_string());
+        assert_eq!("农历甲子年十二月", m.next(-13).to_string());
+        assert_eq!("农历乙丑年闰二月", m.next(-10).to_string());
+        assert_eq!(
+            13,
+            ZonedLunarYear::from_year(1985, vietnam()).get_month_count()
+        );
+        assert_eq!(
+            JulianDay::from_julian_day(2446086.5).get_solar_day(),
+            ZonedLunarMonth::from_ym(1985, 1, vietnam())
+                .get_first_julian_day()
+                .get_solar_day()
+        );
+    }
+
+    #[test]
+    fn test7() {
+        // 均时差：2月中旬约-14分，11月初约+16分
+        let t: TrueSolarTime = TrueSolarTime::from_solar_time(
+            SolarTime::from_ymd_hms(2024, 2, 11, 12, 0, 0),
+            120.0,
+            None,
+        );
+        assert_eq!(-851, t.get_correction());
+        assert_eq!(0.0, t.get_longitude_correction());
+        assert_eq!("2024年2月11日 11:45:49", t.to_string());
+        let t: TrueSolarTime = TrueSolarTime::from_solar_time(
+            SolarTime::from_ymd_hms(2024, 11, 3, 12, 0, 0),
+            120.0,
+            None,
+        );
+        assert_eq!(987, t.get_equation_of_time().round() as isize);
+        assert_eq!("2024年11月3日 12:16:27", t.get_solar_time().to_string());
+    }
+
+    #[test]
+    fn test8() {
+        // 乌鲁木齐（东经87.6度）
+        let t: TrueSolarTime = TrueSolarTime::from_solar_time(
+            SolarTime::from_ymd_hms(2024, 2, 11, 11, 0, 0),
+            87.6,
+            None,
+        );
+        assert_eq!(-7776.0, t.get_longitude_correction().round());
+        assert_eq!("2024年2月11日 08:36:13", t.get_solar_time().to_string());
+        assert_eq!(
+            "庚辰",
+            t.get_sixty_cycle_hour().get_sixty_cycle().to_string()
+        );
+        assert_eq!("甲辰 丙寅 乙巳 庚辰", t.get_eight_char().to_string());
+        assert_eq!(
+            "甲辰 丙寅 乙巳 壬午",
+            t.get_clock_time()
+                .get_lunar_hour()
+                .get_eight_char()
+                .to_string()
+        );
+        // 胡志明市（东经106.7度）使用东七区时间，同一时刻
+        let a: TrueSolarTime = TrueSolarTime::from_zoned_solar_time(
+            ZonedSolarTime::from_ymd_hms(2024, 2, 11, 11, 0, 0, vietnam()),
+            106.7,
+        );
+        let b: TrueSolarTime = TrueSolarTime::from_solar_time(
+            SolarTime::from_ymd_hms(2024, 2, 11, 12, 0, 0),
+            106.7,
+            None,
+        );
+        assert_eq!(a.get_solar_time(), b.get_solar_time());
+        assert!(a != b);
+        assert!(TrueSolarTime::try_from_solar_time(
+            SolarTime::from_ymd_hms(1, 1, 1, 0, 0, 0),
+            100.0,
+            None
+        )
+        .is_err());
+        let clock_time: SolarTime = SolarTime::from_ymd_hms(2024, 2, 11, 12, 0, 0);
+        assert_eq!(
+            Err(TymeError::IllegalLongitude {
+                longitude: "NaN".to_string()
+            }),
+            TrueSolarTime::try_from_solar_time(clock_time, f64::NAN, None)
+        );
+        assert!(TrueSolarTime::try_from_solar_time(clock_time, f64::INFINITY, None).is_err());
+        assert!(TrueSolarTime::try_from_solar_time(clock_time, 180.5, None).is_err());
+        assert!(TrueSolarTime::try_from_solar_time(clock_time, -180.0, None).is_ok());
+    }
+
+    #[test]
+    fn test9() {
+        assert_eq!(vietnam(), UtcOffset::HANOI);
+        // 越南2023年为猫年
+        let year: ZonedLunarYear = ZonedLunarYear::from_year(2023, UtcOffset::HANOI);
+        assert_eq!("兔", year.get_zodiac().get_name());
+        assert_eq!("Mèo", year.get_zodiac().get_vietnamese_name());
+        let d: ZonedLunarDay = SolarDay::from_ymd(2024, 2, 9).get_vietnamese_lunar_day();
+        assert_eq!("农历癸卯年十二月三十", d.to_string());
+        assert_eq!("农历甲辰年正月初一", d.next(1).to_string());
+    }
+    #[test]
+    fn test10() {
+        // 2023年闰二月，月序号与月列表一致，推移跨年时不重复推算
+        let months: Vec<ZonedLunarMonth> =
+            ZonedLunarYear::from_year(2023, UtcOffset::BEIJING).get_months();
+        for (i, m) in months.iter().enumerate() {
+            assert_eq!(i, m.get_index_in_year());
+        }
+        assert_eq!("农历癸卯年闰二月", months[2].to_string());
+        assert_eq!(
+            2,
+            ZonedLunarMonth::from_ym(2023, -2, UtcOffset::BEIJING).get_index_in_year()
+        );
+        let m: ZonedLunarMonth = ZonedLunarMonth::from_ym(2023, 12, UtcOffset::BEIJING);
+        assert_eq!(12, m.get_index_in_year());
+        assert_eq!("农历甲辰年正月", m.next(1).to_string());
+        assert_eq!(0, m.next(1).get_index_in_year());
+        assert_eq!(m, m.next(13).next(-13));
+    }
+}